@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 
 const PROPAGATED_ATTRS: [&str; 1] = ["doc"];
 
-#[proc_macro_derive(EnumFlatten, attributes(enum_flatten))]
+#[proc_macro_derive(EnumFlatten, attributes(enum_flatten, enum_flatten_derive, enum_flatten_propagate))]
 pub fn enum_flatten_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as _);
     TokenStream::from(match impl_enum_flatten(ast) {
@@ -43,12 +43,31 @@ fn impl_enum_flatten(ast: DeriveInput) -> Result<TokenStream2> {
         panic!("Error: enum_flatten(...) attribute was specified multiple times");
     };
 
+    // Optional extras for the generated structs. Derives are applied to every generated struct
+    // and to the flat enum, and propagated attributes are copied from the flattened enum's
+    // variants and fields (in addition to doc comments). The latter is typically needed for
+    // derives that have helper attributes, like serde.
+    let derives = paths_from_attrs(&ast.attrs, "enum_flatten_derive")?;
+    let propagate = paths_from_attrs(&ast.attrs, "enum_flatten_propagate")?;
+
     match ast.data {
-        Data::Struct(s) => impl_enum_flatten_for_struct(item_vis, name, s, flatten_field),
+        Data::Struct(s) => impl_enum_flatten_for_struct(item_vis, name, s, flatten_field, derives, propagate),
         _ => panic!("EnumFlatten may only be used on structs"),
     }
 }
 
+fn paths_from_attrs(attrs: &[Attribute], attr_name: &str) -> Result<Vec<String>> {
+    let mut paths = Vec::new();
+    for attr in attrs {
+        if attr.path.is_ident(attr_name) {
+            let list = attr.parse_args_with(punctuated::Punctuated::<Path, Token![,]>::parse_terminated)?;
+            paths.extend(list.iter().map(|path| path.to_token_stream().to_string()));
+        }
+    }
+
+    Ok(paths)
+}
+
 #[derive(Serialize, Deserialize, Hash, PartialEq, Eq)]
 struct SerializableIdent(String);
 
@@ -86,6 +105,27 @@ struct SerializableAttribute {
     tokens: String,
 }
 
+impl SerializableAttribute {
+    pub fn to_attribute(&self) -> Attribute {
+        Attribute {
+            pound_token: Default::default(),
+            style: AttrStyle::Outer,
+            bracket_token: Default::default(),
+            path: parse_str(&self.path).expect("Error parsing attribute path"),
+            tokens: parse_str(&self.tokens).expect("Error parsing attribute tokens"),
+        }
+    }
+}
+
+impl From<&Attribute> for SerializableAttribute {
+    fn from(attr: &Attribute) -> Self {
+        Self {
+            path: attr.path.to_token_stream().to_string(),
+            tokens: attr.tokens.to_string(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct StructField {
     attrs: Vec<SerializableAttribute>,
@@ -101,21 +141,7 @@ impl StructField {
 
     pub fn to_field_with_attrs_vis(&self, allowed_attrs: &[&str], force_visible: Option<&Visibility>) -> Field {
         Field {
-            attrs: self.attrs.iter()
-                .filter_map(|attr| {
-                    if allowed_attrs.iter().any(|&a| a == &attr.path) {
-                        Some(Attribute {
-                            pound_token: Default::default(),
-                            style: AttrStyle::Outer,
-                            bracket_token: Default::default(),
-                            path: parse_str(&attr.path).expect("Error parsing attribute path"),
-                            tokens: parse_str(&attr.tokens).expect("Error parsing attribute tokens"),
-                        })
-                    } else {
-                        None
-                    }
-                })
-                .collect(),
+            attrs: filter_attrs(&self.attrs, allowed_attrs),
             vis: force_visible.cloned().unwrap_or_else(|| {
                 parse_str(&self.vis).expect("Error parsing field vis")
             }),
@@ -126,16 +152,18 @@ impl StructField {
     }
 }
 
+fn filter_attrs(attrs: &[SerializableAttribute], allowed_attrs: &[&str]) -> Vec<Attribute> {
+    attrs.iter()
+        .filter(|attr| allowed_attrs.iter().any(|&a| a == attr.path))
+        .map(|attr| attr.to_attribute())
+        .collect()
+}
+
 impl From<&Field> for StructField {
     fn from(field: &Field) -> Self {
         Self {
             attrs: field.attrs.iter()
-                .map(|attr| {
-                    SerializableAttribute {
-                        path: attr.path.to_token_stream().to_string(),
-                        tokens: attr.tokens.to_string(),
-                    }
-                })
+                .map(|attr| attr.into())
                 .collect(),
             vis: field.vis.to_token_stream().to_string(),
             name: field.ident.to_token_stream().to_string(),
@@ -147,6 +175,8 @@ impl From<&Field> for StructField {
 #[derive(Serialize, Deserialize)]
 struct FlattenableEnumVariant {
     name: String,
+    #[serde(default)]
+    attrs: Vec<SerializableAttribute>,
     fields: Vec<StructField>,
 }
 
@@ -163,6 +193,10 @@ struct FlattenEnumMeta {
     flatten_item_name: String,
     flatten_item_type: String,
     fields: Vec<StructField>,
+    #[serde(default)]
+    derives: Vec<String>,
+    #[serde(default)]
+    propagate: Vec<String>,
 }
 
 fn find_stored_enum(ty: &Type) -> Option<FlattenableEnumMeta> {
@@ -195,7 +229,7 @@ fn store_struct(ty: &Type, st: FlattenEnumMeta) {
         .expect("Internal error: Failed to save struct");
 }
 
-fn impl_enum_flatten_for_struct(item_vis: Visibility, name: Ident, s: DataStruct, flatten_field_name: Ident) -> Result<TokenStream2> {
+fn impl_enum_flatten_for_struct(item_vis: Visibility, name: Ident, s: DataStruct, flatten_field_name: Ident, derives: Vec<String>, propagate: Vec<String>) -> Result<TokenStream2> {
     let (flatten_field_vis, flatten_field_type) = match &s.fields {
         Fields::Named(fields) => {
             fields.named.iter()
@@ -235,6 +269,8 @@ fn impl_enum_flatten_for_struct(item_vis: Visibility, name: Ident, s: DataStruct
         flatten_item_name: flatten_field_name.to_string(),
         flatten_item_type: flatten_field_type.to_token_stream().to_string(),
         fields,
+        derives,
+        propagate,
     };
 
     if let Some(en) = find_stored_enum(flatten_field_type) {
@@ -286,6 +322,9 @@ fn impl_enum_flattenable_for_enum(name: Ident, e: DataEnum) -> Result<TokenStrea
             .map(|variant| {
                 FlattenableEnumVariant {
                     name: variant.ident.to_string(),
+                    attrs: variant.attrs.iter()
+                        .map(|attr| attr.into())
+                        .collect(),
                     fields: variant.fields.iter()
                         .map(|field| field.into())
                         .collect(),
@@ -309,8 +348,14 @@ fn generate_enum_flatten_impl(st: FlattenEnumMeta, en: FlattenableEnumMeta) -> R
     let flattened_field_name: Ident = parse_str(&st.flatten_item_name).expect("Error parsing flatten item name");
     let flattened_field_type: Type = parse_str(&st.flatten_item_type).expect("Error parsing flatten item type");
     let flat_enum_name = Ident::new(&format!("{}Flat", st.name), Span::call_site());
+    let derives: Vec<Path> = st.derives.iter()
+        .map(|derive| parse_str(derive).expect("Error parsing derive path"))
+        .collect();
+    let propagated_attrs: Vec<&str> = PROPAGATED_ATTRS.iter().copied()
+        .chain(st.propagate.iter().map(|s| s.as_str()))
+        .collect();
     let common_fields: Vec<_> = st.fields.iter()
-        .map(|field| field.to_field_with_attrs(&PROPAGATED_ATTRS))
+        .map(|field| field.to_field_with_attrs(&propagated_attrs))
         .collect();
     let common_field_names: Vec<Ident> = st.fields.iter()
         .map(|field| parse_str(&field.name).expect("Error parsing common field name"))
@@ -320,12 +365,13 @@ fn generate_enum_flatten_impl(st: FlattenEnumMeta, en: FlattenableEnumMeta) -> R
             let variant_name: Ident = parse_str(&variant.name).expect("Error parsing variant name");
             let child_struct_name = Ident::new(&format!("{}{}", st.name, variant.name), Span::call_site());
             let child_fields: Vec<_> = variant.fields.iter()
-                .map(|field| field.to_field_with_attrs_vis(&PROPAGATED_ATTRS, Some(&flattened_field_vis)))
+                .map(|field| field.to_field_with_attrs_vis(&propagated_attrs, Some(&flattened_field_vis)))
                 .collect();
+            let variant_attrs = filter_attrs(&variant.attrs, &propagated_attrs);
 
             quote! {
-                // TODO: Let user specify derives for this
-                #[derive(Debug, Clone)]
+                #[derive(Debug, Clone, #(#derives),*)]
+                #(#variant_attrs)*
                 #struct_vis struct #child_struct_name {
                     #(#common_fields,)*
                     #(#child_fields,)*
@@ -398,8 +444,7 @@ fn generate_enum_flatten_impl(st: FlattenEnumMeta, en: FlattenableEnumMeta) -> R
         quote! {
             #(#child_structs)*
 
-            // TODO: Let user specify derives for this
-            #[derive(Debug, Clone)]
+            #[derive(Debug, Clone, #(#derives),*)]
             #struct_vis enum #flat_enum_name {
                 #(#flat_enum_variants,)*
             }
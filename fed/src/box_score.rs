@@ -0,0 +1,335 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{FedEvent, FedEventData, GameEvent, GameState, GameStateError, PlayerRef, TeamState};
+use crate::stats::{BattingStats, PitchingStats, stat_changes};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BattingLine {
+    pub player: PlayerRef,
+    #[serde(flatten)]
+    pub stats: BattingStats,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PitchingLine {
+    /// The pitcher, or None for stats from before the feed said who was pitching
+    pub player: Option<PlayerRef>,
+    #[serde(flatten)]
+    pub stats: PitchingStats,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamBoxScore {
+    pub team_id: Uuid,
+    pub name: Option<String>,
+    pub nickname: Option<String>,
+
+    /// Runs scored in each inning this team batted in. Runs gained or lost outside of at-bats, like
+    /// from Sun 2, count towards the inning they happened in.
+    pub line_score: Vec<f32>,
+
+    /// Final score
+    pub runs: f32,
+    pub hits: i32,
+
+    /// Batting lines in order of each player's first appearance
+    pub batting: Vec<BattingLine>,
+
+    /// Pitching lines in the order the pitchers appeared
+    pub pitching: Vec<PitchingLine>,
+}
+
+impl TeamBoxScore {
+    fn new(team_id: Uuid) -> Self {
+        Self {
+            team_id,
+            name: None,
+            nickname: None,
+            line_score: Vec::new(),
+            runs: 0.,
+            hits: 0,
+            batting: Vec::new(),
+            pitching: Vec::new(),
+        }
+    }
+
+    fn add_runs(&mut self, inning: i32, runs: f32) {
+        let inning = inning as usize;
+        if self.line_score.len() <= inning {
+            self.line_score.resize(inning + 1, 0.);
+        }
+        self.line_score[inning] += runs;
+    }
+
+    fn add_batting(&mut self, player: PlayerRef, stats: &BattingStats) {
+        self.hits += stats.hits;
+        if let Some(line) = self.batting.iter_mut().find(|line| line.player.is(player.id, &player.name)) {
+            if let Some(id) = player.id {
+                line.player.learn_id(id);
+            }
+            line.stats += stats;
+        } else {
+            self.batting.push(BattingLine { player, stats: stats.clone() });
+        }
+    }
+
+    fn add_pitching(&mut self, player: Option<PlayerRef>, stats: &PitchingStats) {
+        let existing = self.pitching.iter_mut().find(|line| match (&line.player, &player) {
+            (Some(a), Some(b)) => a.is(b.id, &b.name),
+            (None, None) => true,
+            _ => false,
+        });
+        if let Some(line) = existing {
+            line.stats += stats;
+        } else {
+            self.pitching.push(PitchingLine { player, stats: stats.clone() });
+        }
+    }
+
+    fn update_from(&mut self, team: &TeamState) {
+        self.name = team.name.clone();
+        self.nickname = team.nickname.clone();
+        self.runs = team.score;
+    }
+
+    fn label(&self) -> String {
+        self.nickname.clone()
+            .or_else(|| self.name.clone())
+            .unwrap_or_else(|| self.team_id.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayByPlayEntry {
+    pub event_id: Uuid,
+    pub play: i64,
+
+    /// Zero-indexed inning
+    pub inning: i32,
+    pub top_of_inning: bool,
+
+    /// Outs and score after the event
+    pub outs: i32,
+    pub away_score: f32,
+    pub home_score: f32,
+
+    pub description: String,
+}
+
+/// Line score, batting and pitching lines, and play-by-play for one game. The stats come from the
+/// typed event data and the replayed GameState, not the event descriptions. The play-by-play
+/// descriptions are the ones the event builder generates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxScore {
+    pub game_id: Uuid,
+    pub season: i32,
+    pub day: i32,
+    pub away: TeamBoxScore,
+    pub home: TeamBoxScore,
+    pub play_by_play: Vec<PlayByPlayEntry>,
+}
+
+impl BoxScore {
+    /// Builds a box score from every event of one game, in order. Returns None if there are no game
+    /// events.
+    pub fn from_events<'a>(events: impl IntoIterator<Item=&'a FedEvent>) -> Result<Option<Self>, GameStateError> {
+        let mut builder: Option<BoxScoreBuilder> = None;
+        for event in events {
+            let Some(game) = event.data.game() else { continue };
+            builder.get_or_insert_with(|| BoxScoreBuilder::new(game, event.season, event.day))
+                .push(event)?;
+        }
+
+        Ok(builder.map(BoxScoreBuilder::finish))
+    }
+}
+
+/// Builds a BoxScore one event at a time
+pub struct BoxScoreBuilder {
+    state: GameState,
+    box_score: BoxScore,
+}
+
+impl BoxScoreBuilder {
+    pub fn new(game: &GameEvent, season: i32, day: i32) -> Self {
+        Self {
+            state: GameState::new(game),
+            box_score: BoxScore {
+                game_id: game.game_id,
+                season,
+                day,
+                away: TeamBoxScore::new(game.away_team),
+                home: TeamBoxScore::new(game.home_team),
+                play_by_play: Vec::new(),
+            },
+        }
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    fn team_mut(&mut self, event_type: &str, team_id: Uuid) -> Result<&mut TeamBoxScore, GameStateError> {
+        if team_id == self.box_score.home.team_id {
+            Ok(&mut self.box_score.home)
+        } else if team_id == self.box_score.away.team_id {
+            Ok(&mut self.box_score.away)
+        } else {
+            Err(GameStateError::TeamNotInGame { event_type: event_type.to_string(), team_id })
+        }
+    }
+
+    pub fn push(&mut self, event: &FedEvent) -> Result<(), GameStateError> {
+        if event.data.game().is_none() {
+            return Ok(());
+        }
+
+        let event_type: &str = event.data.as_ref();
+        let changes = stat_changes(&self.state, &event.data);
+        let (away_before, home_before) = (self.state.away.score, self.state.home.score);
+        self.state.apply(event)?;
+
+        for change in changes.batting {
+            self.team_mut(event_type, change.team_id)?.add_batting(change.player, &change.stats);
+        }
+        if let Some((team_id, pitcher, stats)) = changes.pitching {
+            self.team_mut(event_type, team_id)?.add_pitching(pitcher, &stats);
+        }
+
+        let inning = self.state.inning;
+        if let FedEventData::HalfInningStart { top_of_inning, .. } = &event.data {
+            // Make sure scoreless innings show up
+            let team = if *top_of_inning { &mut self.box_score.away } else { &mut self.box_score.home };
+            team.add_runs(inning, 0.);
+        }
+        if self.state.away.score != away_before {
+            self.box_score.away.add_runs(inning, self.state.away.score - away_before);
+        }
+        if self.state.home.score != home_before {
+            self.box_score.home.add_runs(inning, self.state.home.score - home_before);
+        }
+
+        self.box_score.play_by_play.push(PlayByPlayEntry {
+            event_id: event.id,
+            play: self.state.last_play.unwrap_or_default(),
+            inning,
+            top_of_inning: self.state.top_of_inning,
+            outs: self.state.outs,
+            away_score: self.state.away.score,
+            home_score: self.state.home.score,
+            description: event.to_string(),
+        });
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> BoxScore {
+        self.box_score.away.update_from(&self.state.away);
+        self.box_score.home.update_from(&self.state.home);
+        self.box_score
+    }
+}
+
+fn write_table(f: &mut Formatter<'_>, title: &str, columns: &[&str], rows: &[(String, Vec<String>)]) -> std::fmt::Result {
+    let name_width = rows.iter()
+        .map(|(name, _)| name.len())
+        .chain(std::iter::once(title.len()))
+        .max()
+        .unwrap_or_default();
+    let widths: Vec<_> = columns.iter().enumerate()
+        .map(|(i, column)| {
+            rows.iter()
+                .filter_map(|(_, cells)| cells.get(i))
+                .map(|cell| cell.len())
+                .chain(std::iter::once(column.len()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    write!(f, "{title:name_width$}")?;
+    for (column, width) in columns.iter().zip(&widths) {
+        write!(f, " {column:>width$}")?;
+    }
+    writeln!(f)?;
+
+    for (name, cells) in rows {
+        write!(f, "{name:name_width$}")?;
+        for (cell, width) in cells.iter().zip(&widths) {
+            write!(f, " {cell:>width$}")?;
+        }
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+impl Display for BoxScore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let away = self.away.name.clone().unwrap_or_else(|| self.away.label());
+        let home = self.home.name.clone().unwrap_or_else(|| self.home.label());
+        writeln!(f, "{away} at {home}, Season {} Day {}", self.season + 1, self.day + 1)?;
+        writeln!(f)?;
+
+        let num_innings = self.away.line_score.len().max(self.home.line_score.len());
+        let inning_names: Vec<_> = (1..=num_innings).map(|i| i.to_string()).collect();
+        let mut columns: Vec<_> = inning_names.iter().map(|s| s.as_str()).collect();
+        columns.extend(["R", "H"]);
+        let line_rows: Vec<_> = [&self.away, &self.home].into_iter()
+            .map(|team| {
+                let mut cells: Vec<_> = (0..num_innings)
+                    .map(|i| team.line_score.get(i).map_or("X".to_string(), |runs| runs.to_string()))
+                    .collect();
+                cells.push(team.runs.to_string());
+                cells.push(team.hits.to_string());
+                (team.label(), cells)
+            })
+            .collect();
+        write_table(f, "", &columns, &line_rows)?;
+
+        for team in [&self.away, &self.home] {
+            writeln!(f)?;
+            let rows: Vec<_> = team.batting.iter()
+                .map(|line| {
+                    let s = &line.stats;
+                    let cells = [s.plate_appearances, s.at_bats, s.runs, s.hits, s.doubles, s.triples,
+                        s.quadruples, s.home_runs, s.runs_batted_in, s.walks, s.hit_by_pitches,
+                        s.strikeouts, s.stolen_bases, s.caught_stealing];
+                    (line.player.name.clone(), cells.iter().map(|n| n.to_string()).collect())
+                })
+                .collect();
+            write_table(f, &format!("{} batting", team.label()), &["PA", "AB", "R", "H", "2B", "3B", "4B", "HR", "RBI", "BB", "HBP", "K", "SB", "CS"], &rows)?;
+
+            writeln!(f)?;
+            let rows: Vec<_> = team.pitching.iter()
+                .map(|line| {
+                    let s = &line.stats;
+                    let mut cells = vec![s.innings_pitched()];
+                    cells.extend([s.batters_faced, s.hits_allowed, s.runs_allowed, s.walks, s.hit_batters,
+                        s.strikeouts, s.home_runs_allowed].iter().map(|n| n.to_string()));
+                    let name = line.player.as_ref().map_or("Unknown pitcher".to_string(), |p| p.name.clone());
+                    (name, cells)
+                })
+                .collect();
+            write_table(f, &format!("{} pitching", team.label()), &["IP", "BF", "H", "R", "BB", "HBP", "K", "HR"], &rows)?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Play by play")?;
+        for entry in &self.play_by_play {
+            let half = if entry.top_of_inning { "Top" } else { "Bottom" };
+            let description = entry.description.replace('\n', " ");
+            writeln!(f, "{half} {}, {} out, {}-{}: {description}",
+                     entry.inning + 1, entry.outs, entry.away_score, entry.home_score)?;
+        }
+
+        Ok(())
+    }
+}
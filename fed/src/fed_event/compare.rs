@@ -0,0 +1,210 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{FedEvent, SubEvent};
+
+/// The ways two [`FedEvent`]s can be compared with [`FedEvent::eq_by`] and [`FedEvent::diff_by`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FedEventComparison {
+    /// Every field must match. This is what `==` does.
+    Structural,
+
+    /// Every field must match except the id, created, and nuts of sub-events. Those can't be known
+    /// when generating events, so this is how generated events are compared to measured ones.
+    IgnoringSubEvents,
+
+    /// Only `created` must match. This is the comparison that orders events; see
+    /// [`FedEvent::cmp_created`].
+    CreatedOnly,
+}
+
+/// One step of a [`FedEventPath`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FedEventPathSegment {
+    /// A field, by its name in the serialized event
+    Field(String),
+    /// An element of a list
+    Index(usize),
+}
+
+/// Location of a value within a serialized [`FedEvent`]. Event-specific data and game data are
+/// flattened into the event when serialized, so e.g. the play of a game event is just `play`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FedEventPath(pub Vec<FedEventPathSegment>);
+
+impl FedEventPath {
+    fn with(&self, segment: FedEventPathSegment) -> Self {
+        let mut segments = self.0.clone();
+        segments.push(segment);
+        Self(segments)
+    }
+}
+
+impl Display for FedEventPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                FedEventPathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                FedEventPathSegment::Field(name) => write!(f, ".{name}")?,
+                FedEventPathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A value that differs between two events, as found by [`FedEvent::diff`]. `expected` comes from
+/// the event `diff` was called on and `actual` from its argument. A value is None if it's missing
+/// on that side, which happens when the events are different types or lists have different
+/// lengths.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FedEventDifference {
+    pub path: FedEventPath,
+    pub expected: Option<Value>,
+    pub actual: Option<Value>,
+}
+
+impl Display for FedEventDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn or_missing(value: &Option<Value>) -> String {
+            value.as_ref().map_or_else(|| "(missing)".to_string(), Value::to_string)
+        }
+
+        write!(f, "{}: expected {}, got {}", self.path, or_missing(&self.expected), or_missing(&self.actual))
+    }
+}
+
+impl FedEvent {
+    /// Compares two events using the given kind of comparison
+    pub fn eq_by(&self, other: &Self, comparison: FedEventComparison) -> bool {
+        match comparison {
+            FedEventComparison::Structural => self == other,
+            FedEventComparison::IgnoringSubEvents => self.diff_by(other, comparison).is_empty(),
+            FedEventComparison::CreatedOnly => self.created == other.created,
+        }
+    }
+
+    /// Orders events by when they were created, which is the order they appear in the Feed
+    pub fn cmp_created(&self, other: &Self) -> Ordering {
+        self.created.cmp(&other.created)
+    }
+
+    /// Lists every value that differs between this event and `other`
+    pub fn diff(&self, other: &Self) -> Vec<FedEventDifference> {
+        self.diff_by(other, FedEventComparison::Structural)
+    }
+
+    /// Lists every value that differs between this event and `other`, ignoring the values that the
+    /// given kind of comparison ignores
+    pub fn diff_by(&self, other: &Self, comparison: FedEventComparison) -> Vec<FedEventDifference> {
+        let mut differences = Vec::new();
+        match comparison {
+            FedEventComparison::Structural | FedEventComparison::IgnoringSubEvents => {
+                // Comparing the events directly is much cheaper than serializing them
+                if self != other {
+                    let (expected, actual) = if comparison == FedEventComparison::IgnoringSubEvents {
+                        (to_json_without_sub_events(self), to_json_without_sub_events(other))
+                    } else {
+                        (to_json(self), to_json(other))
+                    };
+                    diff_values(&FedEventPath::default(), &expected, &actual, &mut differences);
+                }
+            }
+            FedEventComparison::CreatedOnly => {
+                if self.created != other.created {
+                    differences.push(FedEventDifference {
+                        path: FedEventPath(vec![FedEventPathSegment::Field("created".to_string())]),
+                        expected: Some(serde_json::to_value(self.created).expect("Dates should always serialize to JSON")),
+                        actual: Some(serde_json::to_value(other.created).expect("Dates should always serialize to JSON")),
+                    });
+                }
+            }
+        }
+        differences
+    }
+}
+
+fn to_json(event: &FedEvent) -> Value {
+    serde_json::to_value(event).expect("FedEvent should always serialize to JSON")
+}
+
+thread_local! {
+    /// Set while serializing for [`FedEventComparison::IgnoringSubEvents`]
+    static NIL_SUB_EVENTS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Serializes the event with every [`SubEvent`] in it serialized as [`SubEvent::nil`], so sub-event
+/// metadata never shows up as a difference
+fn to_json_without_sub_events(event: &FedEvent) -> Value {
+    struct ResetOnDrop;
+    impl Drop for ResetOnDrop {
+        fn drop(&mut self) {
+            NIL_SUB_EVENTS.with(|nil| nil.set(false));
+        }
+    }
+
+    NIL_SUB_EVENTS.with(|nil| nil.set(true));
+    let _reset = ResetOnDrop;
+    to_json(event)
+}
+
+impl Serialize for SubEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let sub_event = if NIL_SUB_EVENTS.with(Cell::get) { SubEvent::nil() } else { *self };
+
+        let mut state = serializer.serialize_struct("SubEvent", 3)?;
+        state.serialize_field("id", &sub_event.id)?;
+        state.serialize_field("created", &sub_event.created)?;
+        state.serialize_field("nuts", &sub_event.nuts)?;
+        state.end()
+    }
+}
+
+fn diff_values(path: &FedEventPath, expected: &Value, actual: &Value, differences: &mut Vec<FedEventDifference>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected_value) in expected {
+                let path = path.with(FedEventPathSegment::Field(key.clone()));
+                match actual.get(key) {
+                    Some(actual_value) => diff_values(&path, expected_value, actual_value, differences),
+                    None => differences.push(FedEventDifference { path, expected: Some(expected_value.clone()), actual: None }),
+                }
+            }
+            for (key, actual_value) in actual {
+                if !expected.contains_key(key) {
+                    let path = path.with(FedEventPathSegment::Field(key.clone()));
+                    differences.push(FedEventDifference { path, expected: None, actual: Some(actual_value.clone()) });
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            for i in 0..expected.len().max(actual.len()) {
+                let path = path.with(FedEventPathSegment::Index(i));
+                match (expected.get(i), actual.get(i)) {
+                    (Some(expected_value), Some(actual_value)) => {
+                        diff_values(&path, expected_value, actual_value, differences)
+                    }
+                    (expected_value, actual_value) => differences.push(FedEventDifference {
+                        path,
+                        expected: expected_value.cloned(),
+                        actual: actual_value.cloned(),
+                    }),
+                }
+            }
+        }
+        _ => {
+            if expected != actual {
+                differences.push(FedEventDifference {
+                    path: path.clone(),
+                    expected: Some(expected.clone()),
+                    actual: Some(actual.clone()),
+                });
+            }
+        }
+    }
+}
@@ -1,17 +1,19 @@
 use eventually_api::{EventCategory, EventType, EventuallyEvent};
 use itertools::Itertools;
 use serde_json::json;
-use std::fmt::Write;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write};
 use std::iter;
 
 use crate::parse::builder::{EventBuilderChild, EventBuilderChildFull, EventBuilderCommon, EventBuilderUpdate, make_free_refill_child, possessive};
 use crate::parse::event_builder_new::{EventBuilder, Possessive};
-use crate::{BatterSkippedReason, CoffeeBeanMod, ConsumerAttackEffect, Echo, EchoChamberModAdded, EchoIntoStatic, FedEvent, FedEventData, FloodingSweptEffect, HitType, ModChangeSubEventWithNamedPlayer, ModDuration, PitcherInfo, PlayerInfo, PlayerReverb, ReturnFromElsewhereFlavor, ReverbType, Scattered, StatChangeCategory, SubEvent, TimeElsewhere, TogglePerforming};
+use crate::parse::template::{HitTemplate, StrikeTemplate, WalkTemplate};
+use crate::{BatterSkippedReason, CoffeeBeanMod, ConsumerAttackEffect, Echo, EchoChamberModAdded, EchoIntoStatic, FedEvent, FedEventData, FloodingSweptEffect, Mod, ModChangeSubEventWithNamedPlayer, ModDuration, PitcherInfo, PlayerInfo, PlayerReverb, ReturnFromElsewhereFlavor, ReverbType, Scattered, StatChangeCategory, SubEvent, TimeElsewhere, TogglePerforming};
 
 #[deprecated = "This is part of the old event builder"]
-fn make_switch_performing_child(toggle: &TogglePerforming, description: &str, mod_source: &str) -> EventBuilderChildFull {
-    let mod_name = if toggle.is_overperforming { "OVERPERFORMING" } else { "UNDERPERFORMING" };
-    let opposite_mod_name = if toggle.is_overperforming { "UNDERPERFORMING" } else { "OVERPERFORMING" };
+fn make_switch_performing_child(toggle: &TogglePerforming, description: &str, mod_source: Mod) -> EventBuilderChildFull {
+    let mod_id = if toggle.is_overperforming { Mod::Overperforming } else { Mod::Underperforming };
+    let opposite_mod_id = if toggle.is_overperforming { Mod::Underperforming } else { Mod::Overperforming };
     if toggle.is_first_proc {
         EventBuilderChild::new(&toggle.sub_event)
             .update(EventBuilderUpdate {
@@ -23,9 +25,9 @@ fn make_switch_performing_child(toggle: &TogglePerforming, description: &str, mo
                 ..Default::default()
             })
             .metadata(json!({
-                "mod": mod_name,
+                "mod": mod_id,
                 "source": mod_source,
-                "type": 0, // ?
+                "type": ModDuration::Permanent as i64,
             }))
     } else {
         EventBuilderChild::new(&toggle.sub_event)
@@ -38,31 +40,72 @@ fn make_switch_performing_child(toggle: &TogglePerforming, description: &str, mo
                 ..Default::default()
             })
             .metadata(json!({
-                "from": opposite_mod_name,
+                "from": opposite_mod_id,
                 "source": mod_source,
-                "to": mod_name,
-                "type": 0, // ?
+                "to": mod_id,
+                "type": ModDuration::Permanent as i64,
             }))
     }
 }
 
+/// Returns the string in the lastUpdate field of the game update that corresponds to this feed
+/// event.
+///
+/// An event that was split into siblings (as happens in Gamma) gets one game update for all of
+/// them, so its text is every sibling's text in sibling order, separated by newlines. Each
+/// sibling's text is its description, except that the end of a game is shown as "Game over."
+/// rather than the final score. These rules are checked against the recorded game updates in
+/// `fed/tests/fixtures/game_updates`. Events with no corresponding game update return the same
+/// string they would if they had one.
+pub fn feed_event_last_update(feed_event: &EventuallyEvent) -> String {
+    if feed_event.metadata.siblings.is_empty() {
+        return sibling_last_update(feed_event).to_string();
+    }
+
+    let sibling_order = feed_event.metadata.sibling_ids.iter()
+        .flatten()
+        .enumerate()
+        .map(|(i, id)| (*id, i))
+        .collect::<HashMap<_, _>>();
+    feed_event.metadata.siblings.iter()
+        // Siblings that aren't in siblingIds keep their place after the ones that are
+        .sorted_by_key(|sibling| sibling_order.get(&sibling.id).copied().unwrap_or(usize::MAX))
+        .map(sibling_last_update)
+        .join("\n")
+}
+
+fn sibling_last_update(feed_event: &EventuallyEvent) -> &str {
+    match feed_event.r#type {
+        EventType::GameEnd => "Game over.",
+        _ => &feed_event.description,
+    }
+}
+
 impl FedEvent {
-    // I would like this to take by reference but it currently needs to call into into_feed_event,
-    // which consumes its input.
-    /// Returns the string that appears in the lastUpdate field in the corresponding game update for
-    /// this game event. Defaults to the parent event text (in Beta) / concatenation of sibling
-    /// event texts (in Gamma, not yet implemented) when the event has no corresponding game update.
-    pub fn last_update(self) -> String {
-        match self.data {
-            // I know it makes no sense to have a match statement with only a wildcard match but
-            // trust me, there will be special cases in the future.
-            _ => {
-                self.into_feed_event().description
-            }
-        }
+    /// Returns this event's part of the lastUpdate field of the corresponding game update, following
+    /// the rules of [`feed_event_last_update`]. A `FedEvent` doesn't know its siblings, so this is
+    /// the whole lastUpdate only if the event has none. Use [`FedEvent::play_last_update`] for an
+    /// event that was split into siblings.
+    pub fn last_update(&self) -> String {
+        sibling_last_update(&self.build_feed_event(true)).to_string()
+    }
+
+    /// Returns the lastUpdate field of the one game update that covers all of `events`, which are
+    /// the sibling events of one play in sibling order. Like [`feed_event_last_update`], this is
+    /// each event's text separated by newlines.
+    pub fn play_last_update<'a>(events: impl IntoIterator<Item=&'a FedEvent>) -> String {
+        events.into_iter()
+            .map(FedEvent::last_update)
+            .join("\n")
     }
 
     pub fn into_feed_event(self) -> EventuallyEvent {
+        self.build_feed_event(false)
+    }
+
+    /// Builds the feed event. With `description_only`, the description is the same but children,
+    /// tags, and metadata may be missing.
+    fn build_feed_event(&self, description_only: bool) -> EventuallyEvent {
         let event_builder = EventBuilderCommon {
             id: self.id,
             created: self.created,
@@ -72,6 +115,7 @@ impl FedEvent {
             season: self.season,
             tournament: self.tournament,
             nuts: self.nuts,
+            description_only,
         };
 
         let mut eb = EventBuilder::new(
@@ -84,22 +128,23 @@ impl FedEvent {
             self.phase.into(),
             self.nuts,
         );
+        eb.set_description_only(description_only);
 
-        match self.data {
+        match &self.data {
             FedEventData::BeingSpeech { being, message } => {
-                let being_id: i32 = being.into();
+                let being_id: i32 = being.clone().into();
                 event_builder
                     .fill(EventBuilderUpdate {
                         r#type: EventType::BigDeal,
                         category: EventCategory::Narrative,
-                        description: message,
+                        description: message.to_string(),
                         ..Default::default()
                     })
                     .metadata(json!({ "being": being_id }))
                     .build()
             }
             FedEventData::LetsGo { game, weather, stadium_id } => {
-                let weather_id: i32 = weather.into();
+                let weather_id: i32 = (*weather).into();
                 let mut metadata = json!({
                     "home": game.home_team,
                     "away": game.away_team,
@@ -108,7 +153,7 @@ impl FedEvent {
                 if let Some(id) = stadium_id {
                     metadata["stadium"] = json!(id);
                 }
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::LetsGo,
                         description: "Let's Go!".to_string(),
@@ -118,7 +163,7 @@ impl FedEvent {
                     .build()
             }
             FedEventData::PlayBall { game } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::PlayBall,
                         description: "Play ball!".to_string(),
@@ -127,7 +172,7 @@ impl FedEvent {
                     .build()
             }
             FedEventData::HalfInningStart { game, top_of_inning, inning, batting_team_name, subseasonal_mod_effects } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 for effect in subseasonal_mod_effects {
                     let description = if effect.was_added {
                         format!("The {} are {}.", effect.team_nickname, effect.source_mod_name)
@@ -138,12 +183,8 @@ impl FedEvent {
                     eb.push_child(effect.sub_event, |mut child| {
                         child.push_description(&description);
                         child.push_team_tag(effect.team_id);
-                        child.push_metadata_str("mod", match effect.source_mod_id.as_str() {
-                            "MIDDLING" | "LATE_TO_PARTY" => "OVERPERFORMING",
-                            "EARLY_TO_PARTY" => "UNDERPERFORMING",
-                            other => panic!("Unexpected mod in TeamPerformingChanged: '{other}'")
-                        });
-                        child.push_metadata_str("source", effect.source_mod_id);
+                        child.push_metadata_str("mod", effect.source_mod_id.performing_mod());
+                        child.push_metadata_str("source", effect.source_mod_id.source_mod());
                         child.push_metadata_i64("type", ModDuration::Permanent as i64);
                         child.build(if effect.was_added {
                             EventType::AddedModFromOtherMod
@@ -153,23 +194,23 @@ impl FedEvent {
                     })
                 }
                 eb.push_description(&format!("{} of {inning}, {batting_team_name} batting.",
-                                             if top_of_inning { "Top" } else { "Bottom" }));
+                                             if *top_of_inning { "Top" } else { "Bottom" }));
                 eb.build(EventType::HalfInning)
             }
-            FedEventData::BatterUp { ref game, ref batter_name, team_nickname: ref team_name, ref wielding_item, ref inhabiting, is_repeating } => {
+            FedEventData::BatterUp { game, batter_name, team_nickname: team_name, wielding_item, inhabiting, is_repeating } => {
                 let item_suffix = if let Some(item_name) = wielding_item {
                     format!(", wielding {}", item_name)
                 } else {
                     String::default()
                 };
 
-                let prefix = if is_repeating {
+                let prefix = if *is_repeating {
                     format!("{batter_name} is Repeating!\n")
                 } else {
                     String::default()
                 };
 
-                let inhabiting_child = inhabiting.as_ref()
+                let inhabiting_child = || inhabiting.as_ref()
                     .and_then(|inhabiting| {
                         inhabiting.sub_event.as_ref().map(|sub_event|
                             EventBuilderChild::new(sub_event)
@@ -183,8 +224,8 @@ impl FedEvent {
                                     ..Default::default()
                                 })
                                 .metadata(json!({
-                                    "mod": "INHABITING",
-                                    "type": 0, // ?
+                                    "mod": Mod::Inhabiting,
+                                    "type": ModDuration::Permanent as i64,
                                 }))
                         )
                     });
@@ -192,7 +233,7 @@ impl FedEvent {
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::BatterUp,
-                        category: EventCategory::special_if(inhabiting.is_some() || is_repeating),
+                        category: EventCategory::special_if(inhabiting.is_some() || *is_repeating),
                         description: if let Some(inhabiting) = &inhabiting {
                             format!("{prefix}{batter_name} is Inhabiting {}!\n{batter_name} batting for the {team_name}{item_suffix}.",
                                     inhabiting.inhabited_player_name)
@@ -209,33 +250,33 @@ impl FedEvent {
                     .children(inhabiting_child)
                     .build()
             }
-            FedEventData::SuperyummyGameStart { ref game, ref toggle } => {
+            FedEventData::SuperyummyGameStart { game, toggle } => {
                 let description = format!("{} {} Peanuts.", toggle.player_name,
                                           if toggle.is_overperforming { "loves" } else { "misses" });
-                let change_event = make_switch_performing_child(toggle, &description, "SUPERYUMMY");
+                let change_event = || make_switch_performing_child(toggle, &description, Mod::Superyummy);
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         category: EventCategory::Special,
                         r#type: EventType::Superyummy,
-                        description,
+                        description: description.clone(),
                         ..Default::default()
                     })
                     .child(change_event)
                     .build()
             }
-            FedEventData::EchoedSuperyummyGameStart { ref game, ref player_name, peanuts_present: peanuts } => {
+            FedEventData::EchoedSuperyummyGameStart { game, player_name, peanuts_present: peanuts } => {
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         category: EventCategory::Special,
                         r#type: EventType::Superyummy,
                         description: format!("{} {} Peanuts.", player_name,
-                                             if peanuts { "loves" } else { "misses" }),
+                                             if *peanuts { "loves" } else { "misses" }),
                         ..Default::default()
                     })
                     .build()
             }
             FedEventData::Ball { game, balls, strikes, batter_item_damage } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Ball,
                         description: format!("Ball. {}-{}", balls, strikes),
@@ -244,66 +285,47 @@ impl FedEvent {
                     .named_item_damage_before_score(batter_item_damage.as_ref())
                     .build()
             }
-            FedEventData::StrikeSwinging { game, pitch, balls, strikes, pitcher_item_damage } => {
-                eb.set_game(game);
-                let is_double_strike = pitch.double_strike.is_some();
-                if is_double_strike { eb.set_category(EventCategory::Special); }
-                eb.push_pitch(pitch);
-                eb.push_description(&format!("Strike{}, swinging. {balls}-{strikes}",
-                                             if is_double_strike { "s" } else { "" }));
-                eb.push_named_item_damage(pitcher_item_damage);
-                eb.build(EventType::Strike)
-            }
-            FedEventData::StrikeLooking { game, pitch, balls, strikes, pitcher_item_damage } => {
+            data @ (FedEventData::StrikeSwinging { .. } | FedEventData::StrikeLooking { .. } | FedEventData::StrikeFlinching { .. }) => {
+                let (game, template) = StrikeTemplate::from_data(data.clone())
+                    .expect("StrikeTemplate should accept every strike event");
                 eb.set_game(game);
-                if pitch.double_strike.is_some() { eb.set_category(EventCategory::Special); }
-                eb.push_pitch(pitch);
-                eb.push_description(&format!("Strike, looking. {balls}-{strikes}"));
-                eb.push_named_item_damage(pitcher_item_damage);
-                eb.build(EventType::Strike)
-            }
-            FedEventData::StrikeFlinching { game, pitch, balls, strikes, pitcher_item_damage } => {
-                eb.set_game(game);
-                if pitch.double_strike.is_some() { eb.set_category(EventCategory::Special); }
-                eb.push_pitch(pitch);
-                eb.push_description(&format!("Strike, flinching. {balls}-{strikes}"));
-                eb.push_named_item_damage(pitcher_item_damage);
+                template.build(&mut eb);
                 eb.build(EventType::Strike)
             }
             FedEventData::FoulBall { game, pitch, balls, strikes, batter_item_damage, birds } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 let foul_ball_text = if pitch.double_strike.is_some() {
                     eb.set_category(EventCategory::Special);
                     "Foul Balls"
                 } else {
                     "Foul Ball"
                 };
-                eb.push_pitch(pitch);
+                eb.push_pitch(pitch.clone());
                 eb.push_description(&format!("{foul_ball_text}. {balls}-{strikes}"));
-                eb.push_named_item_damage(batter_item_damage);
-                eb.push_birds(birds);
+                eb.push_named_item_damage(batter_item_damage.clone());
+                eb.push_birds(*birds);
                 eb.build(EventType::FoulBall)
             }
-            FedEventData::Flyout { game, pitch, batter_name, fielder_name, scores, stopped_inhabiting, cooled_off, is_special, batter_debt, batter_item_damage, fielder_item_damage, other_player_item_damage, parasite, } => {
-                eb.set_game(game);
-                eb.set_category(EventCategory::special_if(scores.used_refill() || cooled_off.is_some() || is_special));
-                eb.push_pitch(pitch);
+            FedEventData::Flyout { game, pitch, batter_name, fielder_name, scores, stopped_inhabiting, cooled_off, special_reason, batter_debt, batter_item_damage, fielder_item_damage, other_player_item_damage, parasite, } => {
+                eb.set_game(game.clone());
+                eb.set_category(EventCategory::special_if(scores.used_refill() || cooled_off.is_some() || special_reason.is_some()));
+                eb.push_pitch(pitch.clone());
                 eb.push_description(&format!("{batter_name} hit a flyout to {fielder_name}."));
-                eb.push_item_damage(batter_item_damage, &batter_name);
-                eb.push_item_damage(fielder_item_damage, &fielder_name);
-                eb.push_named_item_damage(other_player_item_damage);
-                eb.push_batter_debt(batter_debt, &batter_name, &fielder_name);
-                eb.push_scores(scores, "tags up and scores!");
-                eb.push_stopped_inhabiting(stopped_inhabiting);
-                eb.push_cooled_off(cooled_off, &batter_name);
-                eb.push_parasite(parasite);
+                eb.push_batter_debt(batter_debt.clone(), batter_name, fielder_name);
+                eb.push_item_damage(fielder_item_damage.clone(), fielder_name);
+                eb.push_scores(scores.clone(), "tags up and scores!");
+                eb.push_item_damage(batter_item_damage.clone(), batter_name);
+                eb.push_named_item_damage(other_player_item_damage.clone());
+                eb.push_cooled_off(cooled_off.clone(), batter_name);
+                eb.push_stopped_inhabiting(stopped_inhabiting.clone());
+                eb.push_parasite(parasite.clone());
                 eb.build(EventType::FlyOut)
                 // let (suffix, observed_child, player_tags) = apply_batter_debt(&batter_debt, &batter_name, &fielder_name);
                 //
                 // event_builder.for_game(&game)
                 //     .fill(EventBuilderUpdate {
                 //         r#type: ,
-                //         category: EventCategory::special_if(scores.used_refill() || cooled_off.is_some() || is_special),
+                //         category: EventCategory::special_if(scores.used_refill() || cooled_off.is_some() || special_reason.is_some()),
                 //         description: format!("{batter_name} hit a flyout to {fielder_name}.{suffix}"),
                 //         player_tags,
                 //         ..Default::default()
@@ -317,67 +339,53 @@ impl FedEvent {
                 //     .named_item_damage_before_score(&other_player_item_damage)
                 //     .build()
             }
-            FedEventData::Hit { game, pitch, batter_name, batter_id, hit_type, scores, spicy_status, stopped_inhabiting, is_special, pitcher_item_damage, batter_item_damage, other_player_item_damage } => {
+            data @ FedEventData::Hit { .. } => {
+                let (game, template) = HitTemplate::from_data(data.clone())
+                    .expect("HitTemplate should accept every hit event");
                 eb.set_game(game);
-                eb.push_pitch(pitch);
-                eb.set_category(EventCategory::special_if(is_special));
-                eb.push_named_item_damage(pitcher_item_damage);
-                eb.push_item_damage(batter_item_damage, &batter_name);
-                eb.push_description(&format!("{batter_name} hits a {hit_type}!"));
-                eb.push_player_tag(batter_id);
-                match hit_type {
-                    HitType::Triple(power_charge) => {
-                        eb.push_charge_blood(power_charge, &batter_name, batter_id, "aaa");
-                    }
-                    _ => {}
-                }
-                eb.push_stopped_inhabiting(stopped_inhabiting);
-                eb.push_scores(scores, "scores!");
-                eb.push_spicy(spicy_status, &batter_name, batter_id);
-                eb.push_named_item_damage(other_player_item_damage);
-
+                template.build(&mut eb);
                 eb.build(EventType::Hit)
             }
-            FedEventData::HomeRun { game, pitch, magmatic, batter_name, batter_id, home_run_type, free_refills, spicy_status, stopped_inhabiting, is_special, big_bucket, attraction, damaged_items } => {
-                eb.set_game(game);
-                if is_special { eb.set_category(EventCategory::Special) }
-                eb.push_pitch(pitch);
-                eb.push_named_item_damages(damaged_items);
-                eb.push_magmatic(magmatic, &batter_name, batter_id);
+            FedEventData::HomeRun { game, pitch, magmatic, batter_name, batter_id, home_run_type, free_refills, spicy_status, stopped_inhabiting, special_reason, big_bucket, attraction, damaged_items } => {
+                eb.set_game(game.clone());
+                if special_reason.is_some() { eb.set_category(EventCategory::Special) }
+                eb.push_pitch(pitch.clone());
+                eb.push_named_item_damages(damaged_items.clone());
+                eb.push_magmatic(magmatic.clone(), batter_name, *batter_id);
 
                 // HR itself
                 eb.push_description(&format!("{batter_name} hits a {home_run_type}!"));
-                eb.push_player_tag(batter_id);
+                eb.push_player_tag(*batter_id);
+                eb.push_attraction_with_player(attraction.clone());
 
-                if big_bucket {
+                if *big_bucket {
                     eb.push_description("The ball lands in a Big Bucket. An extra Run scores!");
                 }
 
-                eb.push_free_refills(free_refills);
-                eb.push_stopped_inhabiting(stopped_inhabiting);
-                eb.push_spicy(spicy_status, &batter_name, batter_id);
-                eb.push_attraction_with_player(attraction);
+                eb.push_free_refills(free_refills.clone());
+                eb.push_spicy(spicy_status.clone(), batter_name, *batter_id);
+                eb.push_stopped_inhabiting(stopped_inhabiting.clone());
 
                 eb.build(EventType::HomeRun)
             }
-            FedEventData::GroundOut { game, pitch, batter_name, fielder_name, scores, stopped_inhabiting, cooled_off, is_special, batter_debt, batter_item_damage, pitcher_item_damage, fielder_item_damage } => {
-                eb.set_game(game);
-                eb.set_category(EventCategory::special_if(scores.used_refill() || cooled_off.is_some() || is_special));
-                eb.push_pitch(pitch);
+            FedEventData::GroundOut { game, pitch, batter_name, fielder_name, scores, stopped_inhabiting, cooled_off, special_reason, batter_debt, batter_item_damage, pitcher_item_damage, fielder_item_damage } => {
+                eb.set_game(game.clone());
+                eb.set_category(EventCategory::special_if(scores.used_refill() || cooled_off.is_some() || special_reason.is_some()));
+                eb.push_pitch(pitch.clone());
                 eb.push_description(&format!("{batter_name} hit a ground out to {fielder_name}."));
-                eb.push_scores(scores, "advances on the sacrifice.");
+                eb.push_batter_debt(batter_debt.clone(), batter_name, fielder_name);
+                eb.push_scores(scores.clone(), "advances on the sacrifice.");
                 // Per resim, it's definitely pitcher-batter-fielder in that order. It's also
                 // definitely somewhere after scores. Rest of the order is not yet known
-                eb.push_named_item_damage(pitcher_item_damage);
-                eb.push_item_damage(batter_item_damage, &batter_name);
-                eb.push_item_damage(fielder_item_damage, &fielder_name);
-                eb.push_stopped_inhabiting(stopped_inhabiting);
-                eb.push_cooled_off(cooled_off, &batter_name);
-                eb.push_batter_debt(batter_debt, &batter_name, &fielder_name);
+                eb.push_named_item_damage(pitcher_item_damage.clone());
+                eb.push_item_damage(batter_item_damage.clone(), batter_name);
+                eb.push_item_damage(fielder_item_damage.clone(), fielder_name);
+                eb.push_stopped_inhabiting(stopped_inhabiting.clone());
+                eb.push_cooled_off(cooled_off.clone(), batter_name);
                 eb.build(EventType::GroundOut)
             }
-            FedEventData::StolenBase { ref game, ref runner_name, runner_id, base_stolen, blaserunning, ref free_refill, ref runner_item_damage, is_special } => {
-                let blaserunning_str = if blaserunning {
+            FedEventData::StolenBase { game, runner_name, runner_id, base_stolen, blaserunning, free_refill, runner_item_damage, special_reason } => {
+                let blaserunning_str = if *blaserunning {
                     format!("\n{} scores with Blaserunning!", runner_name)
                 } else {
                     String::new()
@@ -392,63 +400,46 @@ impl FedEvent {
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::StolenBase,
-                        category: EventCategory::special_if(blaserunning || free_refill.is_some() || is_special),
+                        category: EventCategory::special_if(*blaserunning || free_refill.is_some() || special_reason.is_some()),
                         description: format!("{runner_name} steals {base_stolen} base!{blaserunning_str}{free_refill_str}"),
-                        player_tags: if blaserunning { vec![runner_id, runner_id] } else { vec![runner_id] },
+                        player_tags: if *blaserunning { vec![*runner_id, *runner_id] } else { vec![*runner_id] },
                         ..Default::default()
                     })
-                    .children(
-                        free_refill.as_ref()
-                            .map(|free_refill| make_free_refill_child(free_refill))
-                            .into_iter()
-                    )
+                    .children(|| free_refill.as_ref().map(make_free_refill_child))
                     .item_damage_after_score(runner_item_damage, runner_name)
                     .build()
             }
-            FedEventData::StrikeoutSwinging { game, pitch, batter_name, stopped_inhabiting, pitcher_item_damage, free_refill, is_special, parasite } => {
-                eb.set_game(game);
-                eb.set_category(EventCategory::special_if(is_special));
-                eb.push_pitch(pitch);
+            FedEventData::StrikeoutSwinging { game, pitch, batter_name, stopped_inhabiting, pitcher_item_damage, free_refill, special_reason, parasite } => {
+                eb.set_game(game.clone());
+                eb.set_category(EventCategory::special_if(special_reason.is_some()));
+                eb.push_pitch(pitch.clone());
                 eb.push_description(&format!("{} strikes out swinging.", batter_name));
-                eb.push_named_item_damage(pitcher_item_damage);
-                eb.push_stopped_inhabiting(stopped_inhabiting);
-                eb.push_free_refill(free_refill);
-                eb.push_parasite(parasite);
+                eb.push_stopped_inhabiting(stopped_inhabiting.clone());
+                eb.push_named_item_damage(pitcher_item_damage.clone());
+                eb.push_free_refill(free_refill.clone());
+                eb.push_parasite(parasite.clone());
                 eb.build(EventType::Strikeout)
             }
-            FedEventData::StrikeoutLooking { game, pitch, batter_name, stopped_inhabiting, pitcher_item_damage, free_refill, is_special, parasite } => {
-                eb.set_game(game);
-                eb.set_category(EventCategory::special_if(is_special));
-                eb.push_pitch(pitch);
+            FedEventData::StrikeoutLooking { game, pitch, batter_name, stopped_inhabiting, pitcher_item_damage, free_refill, special_reason, parasite } => {
+                eb.set_game(game.clone());
+                eb.set_category(EventCategory::special_if(special_reason.is_some()));
+                eb.push_pitch(pitch.clone());
                 eb.push_description(&format!("{} strikes out looking.", batter_name));
-                eb.push_named_item_damage(pitcher_item_damage);
-                eb.push_stopped_inhabiting(stopped_inhabiting);
-                eb.push_free_refill(free_refill);
-                eb.push_parasite(parasite);
+                eb.push_stopped_inhabiting(stopped_inhabiting.clone());
+                eb.push_named_item_damage(pitcher_item_damage.clone());
+                eb.push_free_refill(free_refill.clone());
+                eb.push_parasite(parasite.clone());
                 eb.build(EventType::Strikeout)
             }
-            FedEventData::Walk { game, batter_name, batter_id, scores, base_instincts, batter_item_damage, stopped_inhabiting, is_special } => {
-                let base_instincts_str = if let Some(base) = base_instincts {
-                    format!("\nBase Instincts take them directly to {} base!", base_name(base))
-                } else {
-                    String::new()
-                };
-
-                event_builder.for_game(&game)
-                    .fill(EventBuilderUpdate {
-                        r#type: EventType::Walk,
-                        category: EventCategory::special_if(scores.used_refill() || base_instincts.is_some() || is_special),
-                        description: format!("{batter_name} draws a walk.{base_instincts_str}"),
-                        player_tags: vec![batter_id],
-                        ..Default::default()
-                    })
-                    .scores(&scores, " scores!")
-                    .stopped_inhabiting(&stopped_inhabiting)
-                    .item_damage_before_score(&batter_item_damage, &batter_name)
-                    .build()
+            data @ FedEventData::Walk { .. } => {
+                let (game, template) = WalkTemplate::from_data(data.clone())
+                    .expect("WalkTemplate should accept every walk event");
+                eb.set_game(game);
+                template.build(&mut eb);
+                eb.build(EventType::Walk)
             }
             FedEventData::CaughtStealing { game, runner_name, base_stolen } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::StolenBase,
                         description: format!("{runner_name} gets caught stealing {base_stolen} base."),
@@ -458,8 +449,8 @@ impl FedEvent {
                     })
                     .build()
             }
-            FedEventData::InningEnd { ref game, inning_num, ref lost_triple_threat } => {
-                let (children, suffix) = self.make_mod_change_sub_events(lost_triple_threat, EventType::RemovedMod, "is no longer a Triple Threat.", "TRIPLE_THREAT");
+            FedEventData::InningEnd { game, inning_num, lost_triple_threat } => {
+                let (children, suffix) = self.make_mod_change_sub_events(lost_triple_threat, EventType::RemovedMod, "is no longer a Triple Threat.", Mod::TripleThreat);
 
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
@@ -468,36 +459,36 @@ impl FedEvent {
                         player_tags: lost_triple_threat.iter().map(|e| e.player_id).collect(),
                         ..Default::default()
                     })
-                    .children(children)
+                    .children(|| children)
                     .build()
             }
             FedEventData::CharmStrikeout { game, charmer_id, charmer_name, charmed_id, charmed_name, stopped_inhabiting, num_swings } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description(&format!("{charmer_name} charmed {charmed_name}!"));
                 eb.push_description(&format!("{charmed_name} swings {num_swings} times to strike out willingly!"));
                 // I do not know why the charmer appears twice, but that seems to be accurate
-                eb.push_player_tag(charmer_id);
-                eb.push_player_tag(charmer_id);
-                eb.push_player_tag(charmed_id);
-                eb.push_stopped_inhabiting(stopped_inhabiting);
+                eb.push_player_tag(*charmer_id);
+                eb.push_player_tag(*charmer_id);
+                eb.push_player_tag(*charmed_id);
+                eb.push_stopped_inhabiting(stopped_inhabiting.clone());
                 eb.build(EventType::Strikeout)
             }
-            FedEventData::FieldersChoice { game, pitch, batter_name, runner_out_name, out_at_base, scores, stopped_inhabiting, cooled_off, is_special, damaged_items } => {
-                eb.set_game(game);
-                if is_special { eb.set_category(EventCategory::Special); }
-                eb.push_pitch(pitch);
+            FedEventData::FieldersChoice { game, pitch, batter_name, runner_out_name, out_at_base, scores, stopped_inhabiting, cooled_off, special_reason, damaged_items } => {
+                eb.set_game(game.clone());
+                if special_reason.is_some() { eb.set_category(EventCategory::Special); }
+                eb.push_pitch(pitch.clone());
                 eb.push_description(&format!("{runner_out_name} out at {out_at_base} base."));
-                eb.push_stopped_inhabiting(stopped_inhabiting);
-                eb.push_scorers(scores.scores, "scores!");
-                eb.push_named_item_damages(damaged_items);
+                eb.push_named_item_damages(damaged_items.clone());
+                eb.push_scorers(scores.scores.clone(), "scores!");
                 eb.push_description(&format!("{batter_name} reaches on fielder's choice."));
-                eb.push_free_refills(scores.free_refills);
-                eb.push_cooled_off(cooled_off, &batter_name);
+                eb.push_free_refills(scores.free_refills.clone());
+                eb.push_stopped_inhabiting(stopped_inhabiting.clone());
+                eb.push_cooled_off(cooled_off.clone(), batter_name);
                 eb.build(EventType::GroundOut)
             }
             FedEventData::StrikeZapped { game } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::StrikeZapped,
                         category: EventCategory::Special,
@@ -507,26 +498,26 @@ impl FedEvent {
                     .build()
             }
             FedEventData::PeanutFlavorText { game, message } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::PeanutFlavorText,
                         category: EventCategory::Special,
-                        description: message,
+                        description: message.to_string(),
                         ..Default::default()
                     })
                     .build()
             }
             FedEventData::DoublePlay { game, pitch, batter_name, scores, stopped_inhabiting, cooled_off } => {
-                eb.set_game(game);
-                eb.push_pitch(pitch);
+                eb.set_game(game.clone());
+                eb.push_pitch(pitch.clone());
                 eb.push_description(&format!("{batter_name} hit into a double play!"));
-                eb.push_scores(scores, "scores!");
-                eb.push_stopped_inhabiting(stopped_inhabiting);
-                eb.push_cooled_off(cooled_off, &batter_name);
+                eb.push_scores(scores.clone(), "scores!");
+                eb.push_stopped_inhabiting(stopped_inhabiting.clone());
+                eb.push_cooled_off(cooled_off.clone(), batter_name);
                 eb.build(EventType::GroundOut)
             }
             FedEventData::GameEnd { game, winner_id, winning_team_name, winning_team_score, losing_team_name, losing_team_score, temp_stolen_player_returned } => {
-                let child = temp_stolen_player_returned
+                let child = || temp_stolen_player_returned.as_ref()
                     .map(|ret| {
                         EventBuilderChild::new(&ret.sub_event)
                             .update(EventBuilderUpdate {
@@ -549,7 +540,7 @@ impl FedEvent {
                                 "sendTeamName": ret.previous_team_nickname,
                             }))
                     });
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::GameEnd,
                         category: EventCategory::Outcomes,
@@ -564,8 +555,8 @@ impl FedEvent {
                     .children(child)
                     .build()
             }
-            FedEventData::MildPitch { ref game, pitcher_id, ref pitcher_name, balls, strikes, runners_advance, ref scores } => {
-                let runners_advance_str = if runners_advance {
+            FedEventData::MildPitch { game, pitcher_id, pitcher_name, balls, strikes, runners_advance, scores } => {
+                let runners_advance_str = if *runners_advance {
                     "\nRunners advance on the pathetic play!"
                 } else {
                     ""
@@ -576,33 +567,33 @@ impl FedEvent {
                         r#type: EventType::MildPitch,
                         category: EventCategory::Special,
                         description: format!("{pitcher_name} throws a Mild pitch!\nBall, {balls}-{strikes}.{runners_advance_str}"),
-                        player_tags: vec![pitcher_id],
+                        player_tags: vec![*pitcher_id],
                         ..Default::default()
                     })
                     .scores(scores, " scores!")
                     .build()
             }
-            FedEventData::CoffeeBean { ref game, player_id, ref player_name, ref roast, ref notes, ref which_mod, gained_mod, ref sub_event, team_id, ref previous } => {
+            FedEventData::CoffeeBean { game, player_id, player_name, roast, notes, which_mod, gained_mod, sub_event, team_id, previous } => {
                 let change_str = match (gained_mod, which_mod) {
                     (true, CoffeeBeanMod::Wired) => { "is Wired!" }
                     (true, CoffeeBeanMod::Tired) => { "is Tired." }
-                    (false, CoffeeBeanMod::Wired) => { "is no longer Wired." }
+                    (false, CoffeeBeanMod::Wired) => { "is no longer Wired!" }
                     (false, CoffeeBeanMod::Tired) => { "is no longer Tired!" }
                 };
                 let mod_id = which_mod.to_str();
-                let child = EventBuilderChild::new(sub_event)
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: if previous.is_some() {
                             EventType::ModChange
-                        } else if gained_mod {
+                        } else if *gained_mod {
                             EventType::AddedMod
                         } else {
                             EventType::RemovedMod
                         },
                         category: EventCategory::Changes,
                         description: format!("{player_name} {change_str}"),
-                        team_tags: team_id.into_iter().collect(),
-                        player_tags: vec![player_id],
+                        team_tags: team_id.iter().copied().collect(),
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(
@@ -611,12 +602,12 @@ impl FedEvent {
                             json!({
                                 "from": prev_mod_id,
                                 "to": mod_id,
-                                "type": 3, // ?
+                                "type": ModDuration::Game as i64,
                             })
                         } else {
                             json!({
                                 "mod": mod_id,
-                                "type": 3, // ?
+                                "type": ModDuration::Game as i64,
                             })
                         }
                     );
@@ -626,26 +617,26 @@ impl FedEvent {
                         r#type: EventType::CoffeeBean,
                         category: EventCategory::Special,
                         description: format!("{player_name} is Beaned by a {roast} roast with {notes}.\n{player_name} {change_str}"),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .child(child)
                     .build()
             }
             FedEventData::BecameMagmatic { game, player_id, player_name, is_unstable, magmatic_mod_added } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
-                if is_unstable {
+                if *is_unstable {
                     eb.push_description(&format!("{player_name} is Unstable!"));
                 }
                 eb.push_description(&format!("Rogue Umpire tried to incinerate {player_name}, but {player_name} ate the flame! They became Magmatic!"));
-                eb.push_player_tag(player_id);
+                eb.push_player_tag(*player_id);
                 if let Some(mod_added) = magmatic_mod_added {
                     eb.push_child(mod_added.sub_event, |mut child| {
                         child.set_description(format!("{player_name} ate some flame."));
-                        child.push_player_tag(player_id);
+                        child.push_player_tag(*player_id);
                         child.push_team_tag(mod_added.team_id);
-                        child.push_metadata_str("mod", "MAGMATIC");
+                        child.push_metadata_str("mod", Mod::Magmatic);
                         child.push_metadata_i64("type", ModDuration::Permanent as i64);
                         child.build(EventType::AddedMod)
                     })
@@ -661,8 +652,8 @@ impl FedEvent {
                 //         ..Default::default()
                 //     })
                 //     .metadata(json!({
-                //         "mod": "MAGMATIC",
-                //         "type": 0, // ?
+                //         "mod": Mod::Magmatic,
+                //         "type": ModDuration::Permanent as i64,
                 //     }));
                 // event_builder.for_game(game)
                 //     .fill(EventBuilderUpdate {
@@ -675,18 +666,18 @@ impl FedEvent {
                 //     .child(child)
                 //     .build()
             }
-            FedEventData::SpecialBlooddrain { ref game, sipper_id, ref sipper_name, sipped_id, sipped_team_id, ref sipped_name, ref sipped_category, ref action, ref sipped_event, rating_before, rating_after } => {
-                let child = EventBuilderChild::new(sipped_event)
+            FedEventData::SpecialBlooddrain { game, sipper_id, sipper_name, sipped_id, sipped_team_id, sipped_name, sipped_category, action, sipped_event, rating_before, rating_after } => {
+                let child = || EventBuilderChild::new(sipped_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::PlayerStatDecrease,
                         category: EventCategory::Changes,
                         description: format!("{sipped_name} had blood drained by {sipper_name}."),
-                        team_tags: vec![sipped_team_id],
-                        player_tags: vec![sipped_id],
+                        team_tags: vec![*sipped_team_id],
+                        player_tags: vec![*sipped_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "type": sipped_category.metadata_type(), // ?
+                        "type": sipped_category.metadata_type(),
                         "before": rating_before,
                         "after": rating_after,
                     }));
@@ -695,7 +686,7 @@ impl FedEvent {
                         r#type: EventType::BlooddrainSiphon,
                         category: EventCategory::Special,
                         description: format!("The Blooddrain gurgled!\n{sipper_name}'s Siphon activates!\n{sipper_name} siphoned some of {sipped_name}'s {sipped_category} ability!\n{sipper_name} {action}"),
-                        player_tags: vec![sipper_id, sipped_id],
+                        player_tags: vec![*sipper_id, *sipped_id],
                         ..Default::default()
                     })
                     .child(child)
@@ -706,14 +697,14 @@ impl FedEvent {
                     .fill(EventBuilderUpdate {
                         r#type: EventType::ModExpires,
                         category: EventCategory::Changes,
-                        description: format!("{} {} mods wore off.", possessive(player_name), mod_duration.to_string()),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        description: format!("{} {} mods wore off.", possessive(player_name.to_string()), mod_duration.to_string()),
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "mods": mods,
-                        "type": mod_duration as i32
+                        "type": *mod_duration as i32
                     }))
                     .build()
             }
@@ -722,18 +713,18 @@ impl FedEvent {
                     .fill(EventBuilderUpdate {
                         r#type: EventType::ModExpires,
                         category: EventCategory::Changes,
-                        description: format!("The {} {mod_duration} mods wore off.", possessive(team_nickname)),
-                        team_tags: vec![team_id],
+                        description: format!("The {} {mod_duration} mods wore off.", possessive(team_nickname.to_string())),
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "mods": mods,
-                        "type": mod_duration as i32
+                        "type": *mod_duration as i32
                     }))
                     .build()
             }
             FedEventData::BirdsCircle { game } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::BirdsCircle,
                         category: EventCategory::Special,
@@ -742,7 +733,7 @@ impl FedEvent {
                     })
                     .build()
             }
-            FedEventData::AmbushedByCrows { ref game, batter_id, ref batter_name, friend_of_crows: ref pitcher } => {
+            FedEventData::AmbushedByCrows { game, batter_id, batter_name, friend_of_crows: pitcher } => {
                 let prefix = if let Some(PitcherInfo { pitcher_name, .. }) = pitcher {
                     format!("{pitcher_name} calls upon their Friends!\n")
                 } else {
@@ -753,7 +744,7 @@ impl FedEvent {
                         r#type: EventType::AmbushedByCrows,
                         category: EventCategory::Special,
                         description: format!("{prefix}A murder of Crows ambush {batter_name}!\nThey run to safety, resulting in an out."),
-                        player_tags: if let Some(PitcherInfo { pitcher_id, .. }) = pitcher { vec![*pitcher_id, batter_id] } else { vec![batter_id] },
+                        player_tags: if let Some(PitcherInfo { pitcher_id, .. }) = pitcher { vec![*pitcher_id, *batter_id] } else { vec![*batter_id] },
                         ..Default::default()
                     })
                     .build()
@@ -764,7 +755,7 @@ impl FedEvent {
                         r#type: EventType::Sun2SetWin,
                         category: EventCategory::Outcomes,
                         description: format!("Sun 2 set a Win upon the {team_nickname}."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .build()
@@ -775,7 +766,7 @@ impl FedEvent {
                         r#type: EventType::BlackHoleSwallowedWin,
                         category: EventCategory::Outcomes,
                         description: format!("The Black Hole swallowed a Win from the {team_nickname}!"),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .build()
@@ -787,7 +778,7 @@ impl FedEvent {
                     String::new()
                 };
 
-                let child = if let Some(rays) = &caught_some_rays {
+                let child = || if let Some(rays) = &caught_some_rays {
                     Some(EventBuilderChild::new(&rays.sub_event)
                         .update(EventBuilderUpdate {
                             r#type: EventType::PlayerStatIncrease,
@@ -798,7 +789,7 @@ impl FedEvent {
                             ..Default::default()
                         })
                         .metadata(json!({
-                        "type": 4, // ?
+                        "type": StatChangeCategory::All as i64,
                         "before": rays.rating_before,
                         "after": rays.rating_after,
                     })))
@@ -806,7 +797,7 @@ impl FedEvent {
                     None
                 };
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Sun2,
                         category: EventCategory::Special,
@@ -822,13 +813,13 @@ impl FedEvent {
                     .build()
             }
             FedEventData::BlackHole { game, scoring_team_nickname, victim_team_nickname, carcinization, compressed_by_gamma } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description(&format!("The {scoring_team_nickname} collect 10!"));
                 eb.push_description(&format!("The Black Hole swallows the Runs and a {victim_team_nickname} Win."));
 
                 if let Some(carc_full) = carcinization {
-                    let carc = carc_full.mv; // convenience
+                    let carc = &carc_full.mv; // convenience
                     let carc_description = format!("The {} steal {} for the remainder of the game.",
                                                    carc_full.new_team_name, carc.player_name);
                     let mod_add_description = format!("{} was temporarily stolen.", carc.player_name);
@@ -840,19 +831,19 @@ impl FedEvent {
                         child.push_team_tag(carc.new_team_id);
                         child.push_metadata_i64("location", carc.location);
                         child.push_metadata_uuid("playerId", carc.player_id);
-                        child.push_metadata_str("playerName", carc.player_name);
+                        child.push_metadata_str("playerName", carc.player_name.clone());
                         child.push_metadata_i64("receiveLocation", carc.location);
                         child.push_metadata_uuid("receiveTeamId", carc.new_team_id);
-                        child.push_metadata_str("receiveTeamName", carc.new_team_nickname);
+                        child.push_metadata_str("receiveTeamName", carc.new_team_nickname.clone());
                         child.push_metadata_uuid("sendTeamId", carc.previous_team_id);
-                        child.push_metadata_str("sendTeamName", carc.previous_team_nickname);
+                        child.push_metadata_str("sendTeamName", carc.previous_team_nickname.clone());
                         child.build(EventType::PlayerMoved)
                     });
                     eb.push_child(carc_full.mod_added_sub_event, |mut child| {
                         child.push_description(&mod_add_description);
                         child.push_player_tag(carc.player_id);
                         child.push_team_tag(carc.new_team_id);
-                        child.push_metadata_str("mod", "TEMP_STOLEN");
+                        child.push_metadata_str("mod", Mod::TempStolen);
                         child.push_metadata_i64("type", ModDuration::Game as i64);
                         child.build(EventType::AddedMod)
                     });
@@ -868,7 +859,7 @@ impl FedEvent {
                         child.push_team_tag(gamma.team_id);
                         child.push_metadata_f64("before", gamma.rating_before);
                         child.push_metadata_f64("after", gamma.rating_after);
-                        child.push_metadata_i64("type", 4);
+                        child.push_metadata_i64("type", StatChangeCategory::All as i64);
                         child.build(EventType::PlayerStatDecrease)
                     })
                 }
@@ -881,7 +872,7 @@ impl FedEvent {
                         r#type: EventType::TeamDidShame,
                         category: EventCategory::Outcomes,
                         description: format!("The {shaming_team_nickname} shamed the {shamed_team_nickname}."),
-                        team_tags: vec![shaming_team_id],
+                        team_tags: vec![*shaming_team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
@@ -896,7 +887,7 @@ impl FedEvent {
                         r#type: EventType::TeamWasShamed,
                         category: EventCategory::Outcomes,
                         description: format!("The {shamed_team_nickname} were shamed by the {shaming_team_nickname}."),
-                        team_tags: vec![shamed_team_id],
+                        team_tags: vec![*shamed_team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
@@ -906,32 +897,32 @@ impl FedEvent {
                     .build()
             }
             FedEventData::CharmWalk { game, batter_name, batter_id, pitcher_name, batter_item_damage, pitcher_item_damage, scores } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Walk,
                         category: EventCategory::Special,
                         description: format!("{batter_name} charms {pitcher_name}!\n{batter_name} walks to first base."),
-                        player_tags: vec![batter_id, batter_id], // two of them
+                        player_tags: vec![*batter_id, *batter_id], // two of them
                         ..Default::default()
                     })
-                    .scores(&scores, " scores!")
-                    .item_damage_before_event(&pitcher_item_damage, &pitcher_name)
-                    .item_damage_before_event(&batter_item_damage, &batter_name)
+                    .scores(scores, " scores!")
+                    .item_damage_before_event(pitcher_item_damage, pitcher_name)
+                    .item_damage_before_event(batter_item_damage, batter_name)
                     .build()
             }
-            FedEventData::GainFreeRefill { ref game, player_id, ref player_name, ref roast, ref ingredient1, ref ingredient2, ref sub_event, team_id } => {
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::GainFreeRefill { game, player_id, player_name, roast, ingredient1, ingredient2, sub_event, team_id } => {
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::AddedMod,
                         category: EventCategory::Changes,
                         description: format!("{player_name} got a Free Refill."),
-                        team_tags: team_id.into_iter().collect(),
-                        player_tags: vec![player_id],
+                        team_tags: team_id.iter().copied().collect(),
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "COFFEE_RALLY",
-                        "type": 0, // ?
+                        "mod": Mod::CoffeeRally,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
                 event_builder.for_game(game)
@@ -939,24 +930,24 @@ impl FedEvent {
                         r#type: EventType::GainFreeRefill,
                         category: EventCategory::Special,
                         description: format!("{player_name} is Poured Over with a {roast} roast blending {ingredient1} and {ingredient2}!\n{player_name} got a Free Refill."),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
-                    .children(vec![child])
+                    .child(child)
                     .build()
             }
-            FedEventData::AllergicReaction { ref game, team_id, player_id, ref player_name, ref sub_event, rating_before, rating_after } => {
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::AllergicReaction { game, team_id, player_id, player_name, sub_event, rating_before, rating_after } => {
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::PlayerStatDecrease,
                         category: EventCategory::Changes,
                         description: format!("{player_name} had an allergic reaction."),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "type": 4, // ?
+                        "type": StatChangeCategory::All as i64,
                         "before": rating_before,
                         "after": rating_after,
                     }));
@@ -966,26 +957,26 @@ impl FedEvent {
                         r#type: EventType::AllergicReaction,
                         category: EventCategory::Special,
                         description: format!("{player_name} swallowed a stray peanut and had an allergic reaction!"),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .child(child)
                     .build()
             }
-            FedEventData::MildPitchWalk { ref game, pitcher_id, ref pitcher_name, batter_id, ref batter_name, ref scores } => {
+            FedEventData::MildPitchWalk { game, pitcher_id, pitcher_name, batter_id, batter_name, scores } => {
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::MildPitch,
                         category: EventCategory::Special,
                         description: format!("{pitcher_name} throws a Mild pitch!\n{batter_name} draws a walk."),
-                        player_tags: vec![pitcher_id, batter_id],
+                        player_tags: vec![*pitcher_id, *batter_id],
                         ..Default::default()
                     })
                     .scores(scores, " scores!")
                     .build()
             }
-            FedEventData::PerkUp { ref game, ref players } => {
-                let children = players.iter()
+            FedEventData::PerkUp { game, players } => {
+                let children = || players.iter()
                     .map(|player| {
                         EventBuilderChild::new(&player.sub_event)
                             .update(EventBuilderUpdate {
@@ -997,9 +988,9 @@ impl FedEvent {
                                 ..Default::default()
                             })
                             .metadata(json!({
-                                "mod": "OVERPERFORMING",
-                                "source": "PERK",
-                                "type": 3, // ?
+                                "mod": Mod::Overperforming,
+                                "source": Mod::Perk,
+                                "type": ModDuration::Game as i64,
                             }))
                     });
 
@@ -1015,8 +1006,8 @@ impl FedEvent {
                     .children(children)
                     .build()
             }
-            FedEventData::Blooddrain { ref game, is_siphon, ref sipper, ref sipped, sipped_category } => {
-                let children: Vec<_> = [
+            FedEventData::Blooddrain { game, is_siphon, sipper, sipped, sipped_category } => {
+                let children = || [
                     (sipped, EventType::PlayerStatDecrease, format!("{} had blood drained by {}.", sipped.player_name, sipper.player_name)),
                     (sipper, EventType::PlayerStatIncrease, format!("{} drained blood from {}.", sipper.player_name, sipped.player_name)),
                 ].into_iter().map(|(change, event_type, description)| {
@@ -1034,10 +1025,9 @@ impl FedEvent {
                             "before": change.rating_before,
                             "after": change.rating_after,
                         }))
-                })
-                    .collect();
+                });
 
-                let siphon_text = if is_siphon {
+                let siphon_text = if *is_siphon {
                     format!("\n{}'s Siphon activates!", sipper.player_name)
                 } else {
                     String::new()
@@ -1045,7 +1035,7 @@ impl FedEvent {
 
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
-                        r#type: if is_siphon { EventType::BlooddrainSiphon } else { EventType::Blooddrain },
+                        r#type: if *is_siphon { EventType::BlooddrainSiphon } else { EventType::Blooddrain },
                         category: EventCategory::Special,
                         description: format!("The Blooddrain gurgled!{siphon_text}\n{} siphoned some of {}'s {sipped_category} ability!\n{} increased their {sipped_category} ability!", sipper.player_name, sipped.player_name, sipper.player_name),
                         player_tags: vec![sipper.player_id, sipped.player_id],
@@ -1054,8 +1044,8 @@ impl FedEvent {
                     .children(children)
                     .build()
             }
-            FedEventData::Feedback { ref game, players: (ref player_a, ref player_b), position_type, ref sub_event } => {
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::Feedback { game, players: (player_a, player_b), position_type, sub_event } => {
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::PlayerTraded,
                         category: EventCategory::Changes,
@@ -1089,19 +1079,19 @@ impl FedEvent {
                     .child(child)
                     .build()
             }
-            FedEventData::BestowReverberating { ref game, team_id, player_id, ref player_name, ref sub_event } => {
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::BestowReverberating { game, team_id, player_id, player_name, sub_event } => {
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::AddedMod,
                         category: EventCategory::Changes,
                         description: format!("{player_name} is now Reverberating wildly!"),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "REVERBERATING",
-                        "type": 0, // ?
+                        "mod": Mod::Reverberating,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
                 event_builder.for_game(game)
@@ -1109,14 +1099,14 @@ impl FedEvent {
                         r#type: EventType::ReverbBestowsReverberating,
                         category: EventCategory::Special,
                         description: format!("Reverberations are at dangerous levels!\n{player_name} is now Reverberating wildly!"),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .child(child)
                     .build()
             }
             FedEventData::Reverb { game, team_id, team_nickname, reverb_type, gravity_players } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 // let get_child = |sub_event, event_type, shuffle_location| {
                 //     EventBuilderChild::new(sub_event)
@@ -1142,69 +1132,70 @@ impl FedEvent {
                     ReverbType::Lineup(sub_event) => {
                         eb.push_description("Reverberations are at unsafe levels!");
                         eb.push_description(&format!("The {team_nickname} had their lineup shuffled in the Reverb!"));
-                        eb.push_child(sub_event, |mut child| {
+                        eb.push_child(*sub_event, |mut child| {
                             child.push_description(&format!("The {team_nickname} had their lineup shuffled."));
-                            child.push_team_tag(team_id);
+                            child.push_team_tag(*team_id);
                             child.build(EventType::ReverbLineupShuffle)
                         });
-                        eb.push_gravity(gravity_players);
+                        eb.push_gravity(gravity_players.clone());
                         eb.build(EventType::ReverbRosterShuffle)
                     }
                     ReverbType::Rotation(sub_event) => {
                         eb.push_description("Reverberations are at unsafe levels!");
                         eb.push_description(&format!("The {team_nickname} had their rotation shuffled in the Reverb!"));
-                        eb.push_child(sub_event, |mut child| {
+                        eb.push_child(*sub_event, |mut child| {
                             child.push_description(&format!("The {team_nickname} had their rotation shuffled in the Reverb!"));
-                            child.push_team_tag(team_id);
+                            child.push_team_tag(*team_id);
                             child.build(EventType::ReverbRotationShuffle)
                         });
-                        eb.push_gravity(gravity_players);
+                        eb.push_gravity(gravity_players.clone());
                         eb.build(EventType::ReverbRosterShuffle)
                     }
                     ReverbType::Full(sub_event) => {
                         eb.push_description("Reverberations are at dangerous levels!");
                         eb.push_description(&format!("The {team_nickname} were shuffled in the Reverb!"));
-                        eb.push_child(sub_event, |mut child| {
+                        eb.push_child(*sub_event, |mut child| {
                             child.push_description(&format!("The {team_nickname} were shuffled in the Reverb!"));
-                            child.push_team_tag(team_id);
+                            child.push_team_tag(*team_id);
                             child.build(EventType::ReverbFullShuffle)
                         });
-                        eb.push_gravity(gravity_players);
+                        eb.push_gravity(gravity_players.clone());
                         eb.build(EventType::ReverbRosterShuffle)
                     }
                     ReverbType::SeveralPlayers(player_reverbs) => {
                         eb.push_description("Reverberations are at high levels!");
                         eb.push_description(&format!("The {team_nickname} had several players shuffled in the Reverb!"));
                         let common_description = format!("The {team_nickname} had several players shuffled in the Reverb!");
+                        // The parser reads the Gravity players' tags before the swapped players'
+                        eb.push_gravity(gravity_players.clone());
                         for player_reverb in player_reverbs {
                             match player_reverb {
                                 PlayerReverb::RepeatId(repeated_id) => {
-                                    eb.push_player_tag(repeated_id);
-                                    eb.push_player_tag(repeated_id);
+                                    eb.push_player_tag(*repeated_id);
+                                    eb.push_player_tag(*repeated_id);
                                 }
                                 PlayerReverb::Reverb { first_player_id, first_player_name, first_player_new_location, second_player_id, second_player_name, second_player_new_location, sub_event } => {
-                                    eb.push_player_tag(first_player_id);
-                                    eb.push_player_tag(second_player_id);
-                                    eb.push_child(sub_event, |mut child| {
+                                    eb.push_player_tag(*first_player_id);
+                                    eb.push_player_tag(*second_player_id);
+                                    eb.push_child(*sub_event, |mut child| {
                                         child.push_description(&common_description);
-                                        child.push_team_tag(team_id);
-                                        child.push_player_tag(first_player_id);
-                                        child.push_player_tag(second_player_id);
-                                        child.push_metadata_i64("aLocation", first_player_new_location as i64);
-                                        child.push_metadata_uuid("aPlayerId", first_player_id);
+                                        child.push_team_tag(*team_id);
+                                        child.push_player_tag(*first_player_id);
+                                        child.push_player_tag(*second_player_id);
+                                        child.push_metadata_i64("aLocation", *first_player_new_location as i64);
+                                        child.push_metadata_uuid("aPlayerId", *first_player_id);
                                         child.push_metadata_str("aPlayerName", first_player_name);
-                                        child.push_metadata_i64("bLocation", second_player_new_location as i64);
-                                        child.push_metadata_uuid("bPlayerId", second_player_id);
+                                        child.push_metadata_i64("bLocation", *second_player_new_location as i64);
+                                        child.push_metadata_uuid("bPlayerId", *second_player_id);
                                         child.push_metadata_str("bPlayerName", second_player_name);
-                                        child.push_metadata_uuid("teamId", team_id);
-                                        child.push_metadata_str("teamName", &team_nickname);
+                                        child.push_metadata_uuid("teamId", *team_id);
+                                        child.push_metadata_str("teamName", team_nickname);
 
                                         child.build(EventType::PlayerSwap)
                                     });
                                 }
                             }
                         }
-                        eb.push_gravity(gravity_players);
                         eb.build(EventType::ReverbRosterShuffle)
                     }
                 }
@@ -1214,32 +1205,32 @@ impl FedEvent {
                     .fill(EventBuilderUpdate {
                         r#type: EventType::TarotReading,
                         category: EventCategory::Changes,
-                        description,
-                        player_tags,
-                        team_tags,
+                        description: description.to_string(),
+                        player_tags: player_tags.clone(),
+                        team_tags: team_tags.clone(),
                         ..Default::default()
                     })
-                    .metadata(metadata)
+                    .metadata(metadata.clone())
                     .build()
             }
             FedEventData::TarotReadingAddedOrRemovedMod { team_id, player_id, description, r#mod, mod_duration, mod_removed } => {
                 event_builder
                     .fill(EventBuilderUpdate {
-                        r#type: if mod_removed { EventType::RemovedMod } else { EventType::AddedMod },
+                        r#type: if *mod_removed { EventType::RemovedMod } else { EventType::AddedMod },
                         category: EventCategory::Changes,
-                        description,
-                        team_tags: vec![team_id],
-                        player_tags: player_id.into_iter().collect(),
+                        description: description.to_string(),
+                        team_tags: vec![*team_id],
+                        player_tags: player_id.iter().copied().collect(),
                         ..Default::default()
                     })
                     .metadata(json!({
                         "mod": r#mod,
-                        "type": mod_duration as i64,
+                        "type": *mod_duration as i64,
                     }))
                     .build()
             }
-            FedEventData::BecomeTripleThreat { ref game, ref pitchers } => {
-                let children = pitchers.iter()
+            FedEventData::BecomeTripleThreat { game, pitchers } => {
+                let children = || pitchers.iter()
                     .map(|pitcher| {
                         EventBuilderChild::new(&pitcher.sub_event)
                             .update(EventBuilderUpdate {
@@ -1251,8 +1242,8 @@ impl FedEvent {
                                 ..Default::default()
                             })
                             .metadata(json!({
-                                "mod": "TRIPLE_THREAT",
-                                "type": 0, // ?
+                                "mod": Mod::TripleThreat,
+                                "type": ModDuration::Permanent as i64,
                             }))
                     });
                 event_builder.for_game(game)
@@ -1272,74 +1263,74 @@ impl FedEvent {
                     .children(children)
                     .build()
             }
-            FedEventData::UnderOver { ref game, team_id, player_id, ref player_name, on, ref sub_event } => {
-                let description = format!("{player_name}, Under Over, {}.", if on { "On" } else { "Off" });
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::UnderOver { game, team_id, player_id, player_name, on, sub_event } => {
+                let description = format!("{player_name}, Under Over, {}.", if *on { "On" } else { "Off" });
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         category: EventCategory::Changes,
-                        r#type: if on { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod },
+                        r#type: if *on { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod },
                         description: description.clone(),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "OVERPERFORMING",
-                        "source": "UNDEROVER",
-                        "type": 0, // ?
+                        "mod": Mod::Overperforming,
+                        "source": Mod::UnderOver,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         category: EventCategory::Special,
                         r#type: EventType::UnderOver,
-                        description,
+                        description: description.clone(),
                         ..Default::default()
                     })
                     .child(child)
                     .build()
             }
-            FedEventData::OverUnder { ref game, team_id, player_id, ref player_name, on, ref sub_event } => {
-                let description = format!("{player_name}, Over Under, {}.", if on { "On" } else { "Off" });
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::OverUnder { game, team_id, player_id, player_name, on, sub_event } => {
+                let description = format!("{player_name}, Over Under, {}.", if *on { "On" } else { "Off" });
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         category: EventCategory::Changes,
-                        r#type: if on { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod },
+                        r#type: if *on { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod },
                         description: description.clone(),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "UNDERPERFORMING",
-                        "source": "OVERUNDER",
-                        "type": 0, // ?
+                        "mod": Mod::Underperforming,
+                        "source": Mod::OverUnder,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         category: EventCategory::Special,
                         r#type: EventType::OverUnder,
-                        description,
+                        description: description.clone(),
                         ..Default::default()
                     })
                     .child(child)
                     .build()
             }
-            FedEventData::TasteTheInfinite { ref game, sheller_id, ref sheller_name, shellee_team_id, shellee_id, ref shellee_name, ref sub_event } => {
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::TasteTheInfinite { game, sheller_id, sheller_name, shellee_team_id, shellee_id, shellee_name, sub_event } => {
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         category: EventCategory::Changes,
                         r#type: EventType::AddedMod,
                         description: format!("{shellee_name} is Shelled!"),
-                        team_tags: vec![shellee_team_id],
+                        team_tags: vec![*shellee_team_id],
                         // Yes this makes no sense! but, it appears to be that way
-                        player_tags: vec![sheller_id],
+                        player_tags: vec![*sheller_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "SHELLED",
-                        "type": 0, // ?
+                        "mod": Mod::Shelled,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
                 event_builder.for_game(game)
@@ -1347,14 +1338,14 @@ impl FedEvent {
                         r#type: EventType::TasteTheInfinite,
                         category: EventCategory::Special,
                         description: format!("{sheller_name} tastes the infinite!\n{shellee_name} is Shelled!"),
-                        player_tags: vec![sheller_id, shellee_id],
+                        player_tags: vec![*sheller_id, *shellee_id],
                         ..Default::default()
                     })
                     .child(child)
                     .build()
             }
             FedEventData::BatterSkipped { game, batter_name, reason } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::BatterSkipped,
                         description: match reason {
@@ -1363,7 +1354,7 @@ impl FedEvent {
                         },
                         // Bizarrely, the player tag is on elsewhere players but not shelled ones
                         player_tags: if let BatterSkippedReason::Elsewhere(id) = reason {
-                            vec![id]
+                            vec![*id]
                         } else {
                             Vec::new()
                         },
@@ -1371,20 +1362,20 @@ impl FedEvent {
                     })
                     .build()
             }
-            FedEventData::FeedbackBlocked { ref game, resisted_id, ref resisted_name, tangled_id, tangled_team_id, ref tangled_name, tangled_rating_before, tangled_rating_after, ref sub_event } => {
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::FeedbackBlocked { game, resisted_id, resisted_name, tangled_id, tangled_team_id, tangled_name, tangled_rating_before, tangled_rating_after, sub_event } => {
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         category: EventCategory::Changes,
                         r#type: EventType::PlayerStatDecrease,
                         description: format!("{tangled_name} is tangled in the flicker!"),
-                        team_tags: vec![tangled_team_id],
-                        player_tags: vec![tangled_id],
+                        team_tags: vec![*tangled_team_id],
+                        player_tags: vec![*tangled_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "before": tangled_rating_before,
                         "after": tangled_rating_after,
-                        "type": 4, // ?
+                        "type": StatChangeCategory::All as i64,
                     }));
 
                 event_builder.for_game(game)
@@ -1392,14 +1383,14 @@ impl FedEvent {
                         r#type: EventType::FeedbackBlocked,
                         category: EventCategory::Special,
                         description: format!("Reality begins to flicker ...\nBut {resisted_name} resists!\n{tangled_name} is tangled in the flicker!"),
-                        player_tags: vec![resisted_id, tangled_id],
+                        player_tags: vec![*resisted_id, *tangled_id],
                         ..Default::default()
                     })
                     .child(child)
                     .build()
             }
             FedEventData::FlagPlanted { team_id, team_nickname, ballpark_name, prefab_name, renovation_id, votes, is_first } => {
-                let flag_planted_str = if is_first {
+                let flag_planted_str = if *is_first {
                     "!\nTHE FLAG IS PLANTED"
                 } else {
                     ".\nAnother flag is planted!"
@@ -1409,7 +1400,7 @@ impl FedEvent {
                         r#type: EventType::FlagPlanted,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} break ground on {ballpark_name}, selecting to build the {prefab_name} prefab{flag_planted_str}"),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
@@ -1424,8 +1415,8 @@ impl FedEvent {
                     .fill(EventBuilderUpdate {
                         r#type: EventType::EmergencyAlert,
                         category: EventCategory::Outcomes,
-                        description: message,
-                        team_tags,
+                        description: message.to_string(),
+                        team_tags: team_tags.clone(),
                         ..Default::default()
                     })
                     .build()
@@ -1436,7 +1427,7 @@ impl FedEvent {
                         r#type: EventType::TeamDivisionMove,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} have joined the ILB!\nThey will play in the {division_name} division."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
@@ -1447,30 +1438,14 @@ impl FedEvent {
                     }))
                     .build()
             }
-            FedEventData::FloodingSwept { ref game, ref effects, ref free_refills, flood_pumps } => {
+            FedEventData::FloodingSwept { game, effects, free_refills, flood_pumps } => {
                 // I'm being uncharacteristically imperative with this one
-                let mut children = Vec::new();
                 let mut player_tags = Vec::new();
                 let mut description = "A surge of Immateria rushes up from Under!\nBaserunners are swept from play!".to_string();
 
                 for effect in effects {
                     match effect {
-                        FloodingSweptEffect::Elsewhere(ModChangeSubEventWithNamedPlayer { sub_event, team_id, player_id, player_name }) => {
-                            children.push(
-                                EventBuilderChild::new(&sub_event)
-                                    .update(EventBuilderUpdate {
-                                        r#type: EventType::AddedMod,
-                                        category: EventCategory::Changes,
-                                        description: format!("{player_name} is swept Elsewhere!"),
-                                        team_tags: vec![*team_id],
-                                        player_tags: vec![*player_id],
-                                        ..Default::default()
-                                    })
-                                    .metadata(json!({
-                                        "mod": "ELSEWHERE",
-                                        "type": 0, // ?
-                                    }))
-                            );
+                        FloodingSweptEffect::Elsewhere(ModChangeSubEventWithNamedPlayer { player_name, .. }) => {
                             write!(description, "\n{player_name} is swept Elsewhere!").unwrap();
                         }
                         FloodingSweptEffect::Flippers(PlayerInfo { player_name, player_id }) => {
@@ -1484,15 +1459,35 @@ impl FedEvent {
                     }
                 }
 
+                if *flood_pumps {
+                    write!(description, "\nThe Flood Pumps activate!").unwrap();
+                }
+
                 for refill in free_refills {
                     write!(description, "\n{} used their Free Refill.\n{} Refills the In!",
                            refill.player_name, refill.player_name).unwrap();
-                    children.push(make_free_refill_child(refill));
                 }
 
-                if flood_pumps {
-                    write!(description, "\nThe Flood Pumps activate!").unwrap();
-                }
+                let children = || effects.iter()
+                    .filter_map(|effect| match effect {
+                        FloodingSweptEffect::Elsewhere(ModChangeSubEventWithNamedPlayer { sub_event, team_id, player_id, player_name }) => {
+                            Some(EventBuilderChild::new(sub_event)
+                                .update(EventBuilderUpdate {
+                                    r#type: EventType::AddedMod,
+                                    category: EventCategory::Changes,
+                                    description: format!("{player_name} is swept Elsewhere!"),
+                                    team_tags: vec![*team_id],
+                                    player_tags: vec![*player_id],
+                                    ..Default::default()
+                                })
+                                .metadata(json!({
+                                    "mod": Mod::Elsewhere,
+                                    "type": ModDuration::Permanent as i64,
+                                })))
+                        }
+                        _ => None,
+                    })
+                    .chain(free_refills.iter().map(make_free_refill_child));
 
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
@@ -1505,15 +1500,15 @@ impl FedEvent {
                     .children(children)
                     .build()
             }
-            FedEventData::ReturnFromElsewhere { ref game, ref player_name, ref flavor } => {
-                let (description, children) = match flavor {
-                    ReturnFromElsewhereFlavor::Full { team_id, player_id, is_peanut, sub_event, time_elsewhere, scattered, recongealed_differently } => {
+            FedEventData::ReturnFromElsewhere { game, player_name, flavor } => {
+                let description = match flavor {
+                    ReturnFromElsewhereFlavor::Full { is_peanut, time_elsewhere, .. } => {
                         let returned_text = if *is_peanut {
                             "rolled back"
                         } else {
                             "returned"
                         };
-                        let description = match time_elsewhere {
+                        match time_elsewhere {
                             TimeElsewhere::Days(days) => {
                                 let s = if *days == 1 { "" } else { "s" };
                                 format!("{player_name} has {returned_text} from Elsewhere after {days} day{s}!")
@@ -1524,23 +1519,34 @@ impl FedEvent {
                             TimeElsewhere::Seasons(seasons) => {
                                 format!("{player_name} has {returned_text} from Elsewhere after {seasons} seasons!")
                             }
-                        };
-                        let elsewhere_child = EventBuilderChild::new(sub_event)
-                            .update(EventBuilderUpdate {
-                                category: EventCategory::Changes,
-                                r#type: EventType::RemovedMod,
-                                description: description.clone(),
-                                team_tags: vec![*team_id],
-                                player_tags: vec![*player_id],
-                                ..Default::default()
-                            })
-                            .metadata(json!({
-                                "mod": "ELSEWHERE",
-                                "type": 0, // ?
-                            }));
+                        }
+                    }
+                    ReturnFromElsewhereFlavor::Short { is_peanut, .. } | ReturnFromElsewhereFlavor::False { is_peanut } => {
+                        format!("{player_name} has {} from Elsewhere!",
+                                if *is_peanut { "rolled back" } else { "returned" })
+                    }
+                };
+
+                let elsewhere_child = |sub_event, team_id: &uuid::Uuid, player_id: &uuid::Uuid| {
+                    EventBuilderChild::new(sub_event)
+                        .update(EventBuilderUpdate {
+                            category: EventCategory::Changes,
+                            r#type: EventType::RemovedMod,
+                            description: description.clone(),
+                            team_tags: vec![*team_id],
+                            player_tags: vec![*player_id],
+                            ..Default::default()
+                        })
+                        .metadata(json!({
+                            "mod": Mod::Elsewhere,
+                            "type": ModDuration::Permanent as i64,
+                        }))
+                };
 
-                        let mut children = if let Some(Scattered { scattered_name, sub_event }) = scattered {
-                            let scattered_child = EventBuilderChild::new(sub_event)
+                let children = || match flavor {
+                    ReturnFromElsewhereFlavor::Full { team_id, player_id, sub_event, scattered, recongealed_differently, .. } => {
+                        let mut children = if let Some(Scattered { scattered_name, sub_event: scattered_sub_event }) = scattered {
+                            let scattered_child = EventBuilderChild::new(scattered_sub_event)
                                 .update(EventBuilderUpdate {
                                     category: EventCategory::Changes,
                                     r#type: EventType::AddedMod,
@@ -1550,13 +1556,13 @@ impl FedEvent {
                                     ..Default::default()
                                 })
                                 .metadata(json!({
-                                    "mod": "SCATTERED",
-                                    "type": 0, // ?
+                                    "mod": Mod::Scattered,
+                                    "type": ModDuration::Permanent as i64,
                                 }));
 
-                            vec![scattered_child, elsewhere_child]
+                            vec![scattered_child, elsewhere_child(sub_event, team_id, player_id)]
                         } else {
-                            vec![elsewhere_child]
+                            vec![elsewhere_child(sub_event, team_id, player_id)]
                         };
 
                         if let Some(recongeal) = recongealed_differently {
@@ -1577,163 +1583,149 @@ impl FedEvent {
                                     .metadata(json!({
                                     "after": recongeal.rating_after,
                                     "before": recongeal.rating_before,
-                                    "type": 4,
+                                    "type": StatChangeCategory::All as i64,
                                 }))
                             );
                         }
 
-                        (description, children)
+                        children
                     }
-                    ReturnFromElsewhereFlavor::Short { team_id, player_id, is_peanut, sub_event } => {
-                        let description = format!("{player_name} has {} from Elsewhere!",
-                                                  if *is_peanut { "rolled back" } else { "returned" });
-                        let elsewhere_child = EventBuilderChild::new(sub_event)
-                            .update(EventBuilderUpdate {
-                                category: EventCategory::Changes,
-                                r#type: EventType::RemovedMod,
-                                description: description.clone(),
-                                team_tags: vec![*team_id],
-                                player_tags: vec![*player_id],
-                                ..Default::default()
-                            })
-                            .metadata(json!({
-                                "mod": "ELSEWHERE",
-                                "type": 0, // ?
-                            }));
-
-                        (description, vec![elsewhere_child])
-                    }
-                    ReturnFromElsewhereFlavor::False { is_peanut} => {
-                        let description = format!("{player_name} has {} from Elsewhere!",
-                                                  if *is_peanut { "rolled back" } else { "returned" });
-                        (description, vec![])
+                    ReturnFromElsewhereFlavor::Short { team_id, player_id, sub_event, .. } => {
+                        vec![elsewhere_child(sub_event, team_id, player_id)]
                     }
+                    ReturnFromElsewhereFlavor::False { .. } => vec![],
                 };
 
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::ReturnFromElsewhere,
-                        description,
+                        description: description.clone(),
                         ..Default::default()
                     })
                     .children(children)
                     .build()
             }
-            FedEventData::Incineration { ref game, team_id, ref team_nickname, victim_id, ref victim_name, replacement_id, ref replacement_name, location, ref unstable_chain, ref sub_events } => {
+            FedEventData::Incineration { game, team_id, team_nickname, victim_id, victim_name, replacement_id, replacement_name, location, unstable_chain, sub_events } => {
                 let (incin_child, enter_hall_child, hatch_child, replace_child) = sub_events;
-                let location_int: i64 = location.into();
-                let mut prefix = String::new();
-                let mut suffix = String::new();
-                let mut children = vec![
-                    EventBuilderChild::new(incin_child)
-                        .update(EventBuilderUpdate {
-                            category: EventCategory::Changes,
-                            r#type: EventType::Incineration,
-                            description: format!("Rogue Umpire incinerated {victim_name}!"),
-                            team_tags: vec![team_id],
-                            player_tags: vec![victim_id],
-                            ..Default::default()
-                        }),
-                    EventBuilderChild::new(enter_hall_child)
-                        .update(EventBuilderUpdate {
-                            category: EventCategory::Changes,
-                            r#type: EventType::EnterHallOfFlame,
-                            description: format!("{victim_name} entered the Hall of Flame."),
-                            player_tags: vec![victim_id],
-                            ..Default::default()
-                        }),
-                    EventBuilderChild::new(hatch_child)
-                        .update(EventBuilderUpdate {
-                            category: EventCategory::Changes,
-                            r#type: EventType::PlayerHatched,
-                            description: format!("{replacement_name} has been hatched from the field of eggs."),
-                            player_tags: vec![replacement_id],
-                            ..Default::default()
-                        })
-                        .metadata(json!({ "id": replacement_id })),
-                    EventBuilderChild::new(replace_child)
-                        .update(EventBuilderUpdate {
-                            category: EventCategory::Changes,
-                            r#type: EventType::PlayerBornFromIncineration,
-                            description: format!("{replacement_name} replaced the incinerated {victim_name}."),
-                            team_tags: vec![team_id],
-                            player_tags: vec![victim_id, replacement_id],
-                            ..Default::default()
-                        })
-                        .metadata(json!({
-                            "inPlayerId": replacement_id,
-                            "inPlayerName": replacement_name,
-                            "location": location_int,
-                            "outPlayerId": victim_id,
-                            "outPlayerName": victim_name,
-                            "teamId": team_id,
-                            "teamName": team_nickname,
-                        })),
-                ];
-
-                if let Some(chain) = unstable_chain {
-                    prefix = format!("{victim_name} is Unstable!\nA Debt was collected.\n");
-                    suffix = format!("\nThe Instability chains to {}!", chain.player_name);
-                    children.push(
-                        EventBuilderChild::new(&chain.sub_event)
+                let location_int: i64 = (*location).into();
+                let (prefix, suffix) = match unstable_chain {
+                    Some(chain) => (format!("{victim_name} is Unstable!\nA Debt was collected.\n"),
+                                    format!("\nThe Instability chains to {}!", chain.player_name)),
+                    None => (String::new(), String::new()),
+                };
+
+                let children = || {
+                    let mut children = vec![
+                        EventBuilderChild::new(incin_child)
                             .update(EventBuilderUpdate {
                                 category: EventCategory::Changes,
-                                r#type: EventType::AddedMod,
-                                description: format!("The Instability chains to {}!", chain.player_name),
-                                team_tags: vec![chain.team_id],
-                                player_tags: vec![chain.player_id],
+                                r#type: EventType::Incineration,
+                                description: format!("Rogue Umpire incinerated {victim_name}!"),
+                                team_tags: vec![*team_id],
+                                player_tags: vec![*victim_id],
+                                ..Default::default()
+                            }),
+                        EventBuilderChild::new(enter_hall_child)
+                            .update(EventBuilderUpdate {
+                                category: EventCategory::Changes,
+                                r#type: EventType::EnterHallOfFlame,
+                                description: format!("{victim_name} entered the Hall of Flame."),
+                                player_tags: vec![*victim_id],
+                                ..Default::default()
+                            }),
+                        EventBuilderChild::new(hatch_child)
+                            .update(EventBuilderUpdate {
+                                category: EventCategory::Changes,
+                                r#type: EventType::PlayerHatched,
+                                description: format!("{replacement_name} has been hatched from the field of eggs."),
+                                player_tags: vec![*replacement_id],
+                                ..Default::default()
+                            })
+                            .metadata(json!({ "id": replacement_id })),
+                        EventBuilderChild::new(replace_child)
+                            .update(EventBuilderUpdate {
+                                category: EventCategory::Changes,
+                                r#type: EventType::PlayerBornFromIncineration,
+                                description: format!("{replacement_name} replaced the incinerated {victim_name}."),
+                                team_tags: vec![*team_id],
+                                player_tags: vec![*victim_id, *replacement_id],
                                 ..Default::default()
                             })
                             .metadata(json!({
-                                "mod": "MARKED",
-                                "type": ModDuration::Weekly as i64,
-                            }))
-                    )
-                }
+                                "inPlayerId": replacement_id,
+                                "inPlayerName": replacement_name,
+                                "location": location_int,
+                                "outPlayerId": victim_id,
+                                "outPlayerName": victim_name,
+                                "teamId": team_id,
+                                "teamName": team_nickname,
+                            })),
+                    ];
+
+                    if let Some(chain) = unstable_chain {
+                        children.push(
+                            EventBuilderChild::new(&chain.sub_event)
+                                .update(EventBuilderUpdate {
+                                    category: EventCategory::Changes,
+                                    r#type: EventType::AddedMod,
+                                    description: format!("The Instability chains to {}!", chain.player_name),
+                                    team_tags: vec![chain.team_id],
+                                    player_tags: vec![chain.player_id],
+                                    ..Default::default()
+                                })
+                                .metadata(json!({
+                                    "mod": Mod::Marked,
+                                    "type": ModDuration::Weekly as i64,
+                                }))
+                        )
+                    }
+
+                    children
+                };
 
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Incineration,
                         category: EventCategory::Special,
                         description: format!("{prefix}Rogue Umpire incinerated {victim_name}!\nThey're replaced by {replacement_name}.{suffix}"),
-                        player_tags: vec![victim_id, replacement_id],
+                        player_tags: vec![*victim_id, *replacement_id],
                         ..Default::default()
                     })
                     .children(children)
                     .build()
             }
             FedEventData::PitcherChange { game, team_nickname: team_name, pitcher_id, pitcher_name } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::PitcherChange,
                         description: format!("{pitcher_name} is now pitching for the {team_name}."),
-                        player_tags: vec![pitcher_id],
+                        player_tags: vec![*pitcher_id],
                         ..Default::default()
                     })
                     .build()
             }
-            FedEventData::Party { ref game, team_id, player_id, ref player_name, ref sub_event, rating_before, rating_after } => {
+            FedEventData::Party { game, team_id, player_id, player_name, sub_event, rating_before, rating_after } => {
                 let description = format!("{player_name} is Partying!");
-                let child = EventBuilderChild::new(sub_event)
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         category: EventCategory::Changes,
                         r#type: EventType::PlayerStatIncrease,
                         description: description.clone(),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "before": rating_before,
                         "after": rating_after,
-                        "type": 4, // ?
+                        "type": StatChangeCategory::All as i64,
                     }));
 
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Party,
-                        description,
-                        player_tags: vec![player_id],
+                        description: description.clone(),
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .child(child)
@@ -1745,21 +1737,21 @@ impl FedEvent {
                         r#type: EventType::PlayerHatched,
                         category: EventCategory::Changes,
                         description: format!("{player_name} has been hatched from the field of eggs."),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({ "id": player_id }))
                     .build()
             }
             FedEventData::PostseasonBirth { team_id, team_nickname, player_id, player_name, location } => {
-                let location_int: i64 = location.into();
+                let location_int: i64 = (*location).into();
                 event_builder
                     .fill(EventBuilderUpdate {
                         r#type: EventType::PlayerAddedToTeam,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} earn a Postseason Birth!"),
-                        player_tags: vec![player_id],
-                        team_tags: vec![team_id],
+                        player_tags: vec![*player_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
@@ -1783,7 +1775,7 @@ impl FedEvent {
                         r#type: EventType::FinalStandings,
                         category: EventCategory::Outcomes,
                         description: format!("The {team_nickname} finished {place_str} in the {division_name}."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({ "place": place }))
@@ -1795,12 +1787,12 @@ impl FedEvent {
                         r#type: EventType::RemovedMod,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} have been removed from Party Time to join the Postseason!"),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "PARTY_TIME",
-                        "type": 1, // ?
+                        "mod": Mod::PartyTime,
+                        "type": ModDuration::Seasonal as i64,
                     }))
                     .build()
             }
@@ -1810,7 +1802,7 @@ impl FedEvent {
                         r#type: EventType::EarnedPostseasonSlot,
                         category: EventCategory::Outcomes,
                         description: format!("The {team_nickname} earned a spot in the Season {} Postseason.", self.season + 1),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .build()
@@ -1826,7 +1818,7 @@ impl FedEvent {
                         r#type: EventType::PostseasonAdvance,
                         category: EventCategory::Outcomes,
                         description: format!("The {team_nickname} advanced to {round_str} of the Season {season} Postseason."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .build()
@@ -1837,7 +1829,7 @@ impl FedEvent {
                         r#type: EventType::PostseasonEliminated,
                         category: EventCategory::Outcomes,
                         description: format!("The {team_nickname} have been eliminated from the Season {season} Postseason."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .build()
@@ -1848,14 +1840,14 @@ impl FedEvent {
                         r#type: EventType::PlayerStatIncrease,
                         category: EventCategory::Changes,
                         description: format!("{player_name} was boosted."),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "before": rating_before,
                         "after": rating_after,
-                        "type": 4, // ?
+                        "type": StatChangeCategory::All as i64,
                     }))
                     .build()
             }
@@ -1865,12 +1857,12 @@ impl FedEvent {
                         r#type: EventType::AddedMod,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} have entered Party Time!"),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "PARTY_TIME",
-                        "type": 1
+                        "mod": Mod::PartyTime,
+                        "type": ModDuration::Seasonal as i64,
                     }))
                     .build()
             }
@@ -1880,7 +1872,7 @@ impl FedEvent {
                         r#type: EventType::TeamWonInternetSeries,
                         category: EventCategory::Outcomes,
                         description: format!("The {team_nickname} won the Season {} Internet Series!", self.season + 1),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
@@ -1894,13 +1886,13 @@ impl FedEvent {
                         r#type: EventType::PlayerStatIncrease,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} are Bottom Dwellers."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "before": rating_before,
                         "after": rating_after,
-                        "type": 5, // ?
+                        "type": StatChangeCategory::Team as i64,
                     }))
                     .build()
             }
@@ -1910,10 +1902,10 @@ impl FedEvent {
                         r#type: EventType::WillRecieved,
                         category: EventCategory::Outcomes,
                         description: format!("Will Received: {will_title}"),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
-                    .full_metadata(metadata)
+                    .full_metadata(metadata.clone())
                     .build()
             }
             FedEventData::BlessingWon { team_tags, blessing_title, metadata } => {
@@ -1922,25 +1914,25 @@ impl FedEvent {
                         r#type: EventType::BlessingOrGiftWon,
                         category: EventCategory::Outcomes,
                         description: format!("Blessing Won: {blessing_title}"),
-                        team_tags,
+                        team_tags: team_tags.clone(),
                         ..Default::default()
                     })
-                    .full_metadata(metadata)
+                    .full_metadata(metadata.clone())
                     .build()
             }
-            FedEventData::EarlbirdsAddedToTeam { ref game, team_id, ref team_nickname, ref sub_event } => {
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::EarlbirdsAddedToTeam { game, team_id, team_nickname, sub_event } => {
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::AddedModFromOtherMod,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} are Earlbirds!"),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "OVERPERFORMING",
-                        "source": "EARLBIRDS",
-                        "type": 0, // ?
+                        "mod": Mod::Overperforming,
+                        "source": Mod::Earlbirds,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
                 event_builder.for_game(game)
@@ -1961,7 +1953,7 @@ impl FedEvent {
                         description: format!("Decree Passed: {decree_title}"),
                         ..Default::default()
                     })
-                    .full_metadata(metadata)
+                    .full_metadata(metadata.clone())
                     .build()
             }
             FedEventData::PlayerJoinedILB { player_id, player_name } => {
@@ -1970,7 +1962,7 @@ impl FedEvent {
                         r#type: EventType::PlayerDivisionMove,
                         category: EventCategory::Changes,
                         description: format!("{player_name} has joined the ILB."),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({ "id": player_id }))
@@ -1982,18 +1974,18 @@ impl FedEvent {
                         r#type: EventType::PlayerPermittedToStay,
                         category: EventCategory::Special,
                         description: format!("{player_name} has been permitted to stay."),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .build()
             }
             FedEventData::FireproofIncineration { game, player_id, player_name } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::IncinerationBlocked,
                         category: EventCategory::Special,
                         description: format!("Rogue Umpire tried to incinerate {player_name}, but they're Fireproof! The Umpire was incinerated instead!"),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .build()
@@ -2003,25 +1995,25 @@ impl FedEvent {
                     .fill(EventBuilderUpdate {
                         r#type: EventType::LineupSorted,
                         category: EventCategory::Changes,
-                        description: format!("The {} lineup has been optimized.", possessive(team_nickname)),
-                        team_tags: vec![team_id],
+                        description: format!("The {} lineup has been optimized.", possessive(team_nickname.to_string())),
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .build()
             }
-            FedEventData::EarlbirdsRemovedFromTeam { ref game, team_id, ref sub_event } => {
-                let child = EventBuilderChild::new(sub_event)
+            FedEventData::EarlbirdsRemovedFromTeam { game, team_id, sub_event } => {
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::RemovedModFromOtherMod,
                         category: EventCategory::Changes,
                         description: format!("Earlbirds wears off for the [object Object]."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "OVERPERFORMING",
-                        "source": "EARLBIRDS",
-                        "type": 0, // ?
+                        "mod": Mod::Overperforming,
+                        "source": Mod::Earlbirds,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
                 event_builder.for_game(game)
@@ -2034,26 +2026,26 @@ impl FedEvent {
                     .child(child)
                     .build()
             }
-            FedEventData::Undersea { ref game, ref team_name, team_id, ref sub_event } => {
+            FedEventData::Undersea { game, team_name, team_id, sub_event } => {
                 let description = format!("The {team_name} go Undersea. They're now Overperforming!");
-                let child = EventBuilderChild::new(sub_event)
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::AddedModFromOtherMod,
                         category: EventCategory::Changes,
                         description: description.clone(),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "OVERPERFORMING",
-                        "source": "UNDERSEA",
-                        "type": 3, // ?
+                        "mod": Mod::Overperforming,
+                        "source": Mod::Undersea,
+                        "type": ModDuration::Game as i64,
                     }));
 
                 event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Undersea,
-                        description,
+                        description: description.clone(),
                         ..Default::default()
                     })
                     .child(child)
@@ -2065,8 +2057,8 @@ impl FedEvent {
                     .fill(EventBuilderUpdate {
                         r#type: EventType::RenovationBuilt,
                         category: EventCategory::Changes,
-                        description,
-                        team_tags: vec![team_id],
+                        description: description.to_string(),
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
@@ -2076,20 +2068,20 @@ impl FedEvent {
                     }))
                     .build()
             }
-            FedEventData::LateToThePartyAdded { ref game, team_id, ref team_nickname, ref sub_event } => {
-                let children = if let Some(sub_event) = sub_event {
+            FedEventData::LateToThePartyAdded { game, team_id, team_nickname, sub_event } => {
+                let children = || if let Some(sub_event) = sub_event {
                     vec![EventBuilderChild::new(sub_event)
                         .update(EventBuilderUpdate {
                             r#type: EventType::AddedModFromOtherMod,
                             category: EventCategory::Changes,
                             description: format!("The {team_nickname} are Late to the Party!"),
-                            team_tags: team_id.into_iter().collect(),
+                            team_tags: team_id.iter().copied().collect(),
                             ..Default::default()
                         })
                         .metadata(json!({
-                            "mod": "OVERPERFORMING",
-                            "source": "LATE_TO_PARTY",
-                            "type": 0, // ?
+                            "mod": Mod::Overperforming,
+                            "source": Mod::LateToParty,
+                            "type": ModDuration::Permanent as i64,
                         }))]
                 } else {
                     vec![]
@@ -2113,28 +2105,28 @@ impl FedEvent {
                     "has been cured of their peanut allergy"
                 };
 
-                let child = superallergy.map(|superallergy| {
+                let child = || superallergy.as_ref().map(|superallergy| {
                     EventBuilderChild::new(&superallergy.sub_event)
                         .update(EventBuilderUpdate {
                             r#type: EventType::RemovedMod,
                             category: EventCategory::Changes,
                             description: format!("{player_name} lost the Superallergic mod."),
-                            player_tags: vec![player_id],
+                            player_tags: vec![*player_id],
                             team_tags: vec![superallergy.team_id],
                             ..Default::default()
                         })
                         .metadata(json!({
-                            "mod": "SUPERALLERGIC",
-                            "type": 0, // ?
+                            "mod": Mod::Superallergic,
+                            "type": ModDuration::Permanent as i64,
                         }))
                 });
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::PeanutMister,
                         category: EventCategory::Special,
                         description: format!("The Peanut Mister activates!\n{player_name} {effect_str}!"),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .children(child)
@@ -2142,19 +2134,19 @@ impl FedEvent {
             }
             FedEventData::PlayerNamedMvp { team_id, player_id, player_name, level } => {
                 let mod_name = format!("EGO{level}");
-                if level == 1 {
+                if *level == 1 {
                     event_builder
                         .fill(EventBuilderUpdate {
                             r#type: EventType::AddedMod,
                             category: EventCategory::Changes,
                             description: format!("{player_name} is named an MVP."),
-                            team_tags: vec![team_id],
-                            player_tags: vec![player_id],
+                            team_tags: vec![*team_id],
+                            player_tags: vec![*player_id],
                             ..Default::default()
                         })
                         .metadata(json!({
                             "mod": mod_name,
-                            "type": 0,
+                            "type": ModDuration::Permanent as i64,
                         }))
                         .build()
                 } else {
@@ -2165,21 +2157,21 @@ impl FedEvent {
                             category: EventCategory::Changes,
                             description: format!("{player_name} is named a {level}-Time MVP{}",
                                                  // i dont like this
-                                                 if level == 2 { "." } else { "!" }),
-                            team_tags: vec![team_id],
-                            player_tags: vec![player_id],
+                                                 if *level == 2 { "." } else { "!" }),
+                            team_tags: vec![*team_id],
+                            player_tags: vec![*player_id],
                             ..Default::default()
                         })
                         .metadata(json!({
                             "from": prev_mod_name,
                             "to": mod_name,
-                            "type": 0,
+                            "type": ModDuration::Permanent as i64,
                         }))
                         .build()
                 }
             }
             FedEventData::LateToThePartyRemoved { game, team_nickname } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::LateToTheParty,
                         category: EventCategory::Special,
@@ -2189,40 +2181,40 @@ impl FedEvent {
                     .build()
             }
             FedEventData::BirdsUnshell { game, team_id, player_id, player_name, pecked_free_event, superallergy_event } => {
-                let pecked_free_child = EventBuilderChild::new(&pecked_free_event)
+                let pecked_free_child = || EventBuilderChild::new(pecked_free_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::RemovedMod,
                         category: EventCategory::Changes,
                         description: format!("The Birds pecked {player_name} free!"),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "SHELLED",
-                        "type": 0, // ?
+                        "mod": Mod::Shelled,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
-                let superallergy_child = EventBuilderChild::new(&superallergy_event)
+                let superallergy_child = || EventBuilderChild::new(superallergy_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::AddedMod,
                         category: EventCategory::Changes,
                         description: format!("{player_name} emerges from the shell with a Superallergy!"),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "SUPERALLERGIC",
-                        "type": 0, // ?
+                        "mod": Mod::Superallergic,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::BirdsUnshell,
                         category: EventCategory::Special,
                         description: format!("The Birds circle...\nThe Birds pecked {player_name} free!"),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .child(pecked_free_child)
@@ -2235,15 +2227,15 @@ impl FedEvent {
                         r#type: EventType::PlayerReplacesReturned,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} cut a player and promoted another from the shadows."),
-                        player_tags: vec![removed_player_id, promoted_player_id],
-                        team_tags: vec![team_id],
+                        player_tags: vec![*removed_player_id, *promoted_player_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "promoteLocation": promoted_location as i64,
+                        "promoteLocation": *promoted_location as i64,
                         "promotePlayerId": promoted_player_id,
                         "promotePlayerName": promoted_player_name,
-                        "removeLocation": removed_location as i64,
+                        "removeLocation": *removed_location as i64,
                         "removePlayerId": removed_player_id,
                         "removePlayerName": removed_player_name,
                         "teamId": team_id,
@@ -2257,7 +2249,7 @@ impl FedEvent {
                         r#type: EventType::EnterHallOfFlame,
                         category: EventCategory::Changes,
                         description: format!("{player_name} entered the Hall of Flame."),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .build()
@@ -2268,12 +2260,12 @@ impl FedEvent {
                         r#type: EventType::RemovedMod,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} used their Free Will."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "FREE_WILL",
-                        "type": 0, // ?
+                        "mod": Mod::FreeWill,
+                        "type": ModDuration::Permanent as i64,
                     }))
                     .build()
             }
@@ -2283,13 +2275,13 @@ impl FedEvent {
                         r#type: EventType::RemovedMod,
                         category: EventCategory::Changes,
                         description: format!("{player_name} lost the {mod_name} mod."),
-                        team_tags: vec![team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "mod": r#mod,
-                        "type": 0, // ?
+                        "type": ModDuration::Permanent as i64,
                     }))
                     .build()
             }
@@ -2298,37 +2290,37 @@ impl FedEvent {
                     .fill(EventBuilderUpdate {
                         r#type: EventType::InvestigationMessage,
                         category: EventCategory::Special,
-                        description: message,
-                        player_tags: vec![player_id],
+                        description: message.to_string(),
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .build()
             }
             FedEventData::HighPressure { game, team_id, team_nickname, is_on, sub_event } => {
-                let description = if is_on {
+                let description = if *is_on {
                     format!("The pressure is on! The {team_nickname} are Overperforming.")
                 } else {
                     format!("The pressure is off. The {team_nickname} are no longer Overperforming.")
                 };
 
-                let child = EventBuilderChild::new(&sub_event)
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
-                        r#type: if is_on { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod },
+                        r#type: if *is_on { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod },
                         category: EventCategory::Changes,
                         description: description.clone(),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "OVERPERFORMING",
-                        "source": "HIGH_PRESSURE",
-                        "type": 3, // ?
+                        "mod": Mod::Overperforming,
+                        "source": Mod::HighPressure,
+                        "type": ModDuration::Game as i64,
                     }));
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::HighPressure,
-                        description,
+                        description: description.clone(),
                         ..Default::default()
                     })
                     .child(child)
@@ -2340,7 +2332,7 @@ impl FedEvent {
                         r#type: EventType::PlayerDivisionMove,
                         category: EventCategory::Changes,
                         description: format!("{player_name} was pulled through the Rift."),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({ "id": player_id }))
@@ -2348,14 +2340,14 @@ impl FedEvent {
             }
 
             FedEventData::PlayerLocalized { team_id, team_nickname, player_id, player_name, location } => {
-                let location_int: i64 = location.into();
+                let location_int: i64 = (*location).into();
                 event_builder
                     .fill(EventBuilderUpdate {
                         r#type: EventType::PlayerAddedToTeam,
                         category: EventCategory::Changes,
                         description: format!("{player_name} Localized into the {} {}.", possessive(team_nickname.clone()), location.location()),
-                        player_tags: vec![player_id],
-                        team_tags: vec![team_id],
+                        player_tags: vec![*player_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
@@ -2368,10 +2360,10 @@ impl FedEvent {
                     .build()
             }
             FedEventData::Echo { game, echoee_name, primary_echo: main_echo, receiver_echos: sub_echos, } => {
-                let make_children_for_echo = |echo: Echo, mod_type: i64, source: &str, echo_description: &str| {
+                let make_children_for_echo = |echo: Echo, mod_duration: ModDuration, source: &Mod, echo_description: &str| {
                     let child_removed = echo.mods_removed.map(|mods_removed| {
                         let removes: Vec<_> = mods_removed.mod_ids.into_iter()
-                            .map(|mod_id| json!({ "type": mod_type, "mod": mod_id }))
+                            .map(|mod_id| json!({ "type": mod_duration as i64, "mod": mod_id }))
                             .collect();
 
                         EventBuilderChild::new(&mods_removed.sub_event)
@@ -2379,7 +2371,7 @@ impl FedEvent {
                                 r#type: EventType::RemovedModsFromAnotherMod,
                                 category: EventCategory::Changes,
                                 description: format!("{}'s {}Echo faded.", echo.receiver_name,
-                                                     if mod_type == 0 { "" } else { "Echoed " }),
+                                                     if mod_duration == ModDuration::Permanent { "" } else { "Echoed " }),
                                 player_tags: vec![echo.receiver_id],
                                 team_tags: vec![echo.receiver_team_id],
                                 ..Default::default()
@@ -2391,7 +2383,7 @@ impl FedEvent {
                     });
                     let child_added = {
                         let adds: Vec<_> = echo.mods_added.mod_ids.into_iter()
-                            .map(|mod_id| json!({ "type": mod_type, "mod": mod_id }))
+                            .map(|mod_id| json!({ "type": mod_duration as i64, "mod": mod_id }))
                             .collect();
 
                         EventBuilderChild::new(&echo.mods_added.sub_event)
@@ -2413,21 +2405,24 @@ impl FedEvent {
                 };
 
                 let receiver_echo_description = format!("'s Echoed an Echo from {}", main_echo.receiver_name);
-                let main_echo_children = make_children_for_echo(main_echo, 0, "ECHO",
-                                                                &format!(" Echoed {echoee_name}"));
-                let sub_echo_children = sub_echos.into_iter()
-                    .map(|sub_echo| make_children_for_echo(sub_echo, 1, "RECEIVER",
-                                                           &receiver_echo_description));
-
-                let description = main_echo_children.1.update.description.clone();
-                let children = iter::once(main_echo_children)
-                    .chain(sub_echo_children)
-                    .map(|(removed, added)| [removed, Some(added)])
-                    .flatten() // This one should flatten the array
-                    .flatten() // This one should flatten the options
-                    .collect_vec(); // for debugging
-
-                event_builder.for_game(&game)
+                let main_echo_description = format!(" Echoed {echoee_name}");
+                // Same as the description of the main echo's added mods child
+                let description = format!("{}{main_echo_description}!", main_echo.receiver_name);
+                let children = || {
+                    let main_echo_children = make_children_for_echo(main_echo.clone(), ModDuration::Permanent, &Mod::Echo,
+                                                                    &main_echo_description);
+                    let sub_echo_children = sub_echos.iter()
+                        .map(|sub_echo| make_children_for_echo(sub_echo.clone(), ModDuration::Seasonal, &Mod::Receiver,
+                                                               &receiver_echo_description));
+
+                    iter::once(main_echo_children)
+                        .chain(sub_echo_children)
+                        .map(|(removed, added)| [removed, Some(added)])
+                        .flatten() // This one should flatten the array
+                        .flatten() // This one should flatten the options
+                };
+
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Echo,
                         category: EventCategory::Special,
@@ -2438,7 +2433,7 @@ impl FedEvent {
                     .build()
             }
             FedEventData::SolarPanelsAwait { game } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::SolarPanelsAwait,
                         category: EventCategory::Special,
@@ -2470,48 +2465,48 @@ impl FedEvent {
                         }))
                     } else {
                         child.metadata(json!({
-                            "from": "ECHO",
-                            "to": "STATIC",
-                            "type": 0,
+                            "from": Mod::Echo,
+                            "to": Mod::Static,
+                            "type": ModDuration::Permanent as i64,
                         }))
                     }
                 };
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::EchoIntoStatic,
                         category: EventCategory::Special,
                         description: description.clone(),
                         ..Default::default()
                     })
-                    .child(make_sub_event(&echoer, &echoer.removed_from_team_sub_event,
-                                          EventType::PlayerRemovedFromTeam))
-                    .child(make_sub_event(&echoee, &echoee.removed_from_team_sub_event,
-                                          EventType::PlayerRemovedFromTeam))
-                    .child(make_sub_event(&echoer, &echoer.mod_changed_sub_event,
-                                          EventType::ModChange))
-                    .child(make_sub_event(&echoee, &echoee.mod_changed_sub_event,
-                                          EventType::ModChange))
+                    .child(|| make_sub_event(echoer, &echoer.removed_from_team_sub_event,
+                                             EventType::PlayerRemovedFromTeam))
+                    .child(|| make_sub_event(echoee, &echoee.removed_from_team_sub_event,
+                                             EventType::PlayerRemovedFromTeam))
+                    .child(|| make_sub_event(echoer, &echoer.mod_changed_sub_event,
+                                             EventType::ModChange))
+                    .child(|| make_sub_event(echoee, &echoee.mod_changed_sub_event,
+                                             EventType::ModChange))
                     .build()
             }
             FedEventData::ConsumerAttack { game, team_id, player_id, player_name_all_caps: player_name, effect, sensed_something_fishy, scattered } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
-                eb.push_player_tag(player_id);
+                eb.push_player_tag(*player_id);
                 eb.push_description("CONSUMERS ATTACK");
-                if scattered {
+                if *scattered {
                     eb.push_description("SCATTERED");
                 }
 
                 match effect {
                     ConsumerAttackEffect::Chomp { rating_before, rating_after, sub_event } => {
-                        eb.push_description(&player_name);
+                        eb.push_description(player_name);
                         let description = eb.description().to_string();
-                        eb.push_child(sub_event, |mut child| {
-                            child.push_player_tag(player_id);
-                            child.push_team_tag(team_id);
+                        eb.push_child(*sub_event, |mut child| {
+                            child.push_player_tag(*player_id);
+                            child.push_team_tag(*team_id);
                             child.set_description(description);
-                            child.build_player_stat_changed(rating_before, rating_after, StatChangeCategory::All as i64)
+                            child.build_player_stat_changed(*rating_before, *rating_after, StatChangeCategory::All)
                         });
                     }
                     ConsumerAttackEffect::DefendedWithItem(damage) => {
@@ -2523,7 +2518,7 @@ impl FedEvent {
                         let description = eb.description().to_string();
                         eb.push_child(damage.sub_event, |mut child| {
                             child.set_description(description);
-                            child.build_item_damaged(damage)
+                            child.build_item_damaged(damage.clone())
                         });
                     }
                 }
@@ -2531,7 +2526,7 @@ impl FedEvent {
                 if let Some(fishy) = sensed_something_fishy {
                     eb.push_child(fishy.sub_event, |mut child| {
                         child.push_description(&format!("{} sensed something fishy.", fishy.detective_name));
-                        child.build_detective_activity(fishy)
+                        child.build_detective_activity(fishy.clone())
                     });
                 }
 
@@ -2540,28 +2535,28 @@ impl FedEvent {
             FedEventData::Psychoacoustics { game, stadium_name, team_id, team_nickname, mod_name, mod_id, sub_event } => {
                 let description = format!("{stadium_name} is Resonating.\nPsychoAcoustics Echo {mod_name} {} the {team_nickname}.",
                                           if (self.season, self.day) < (15, 33) { "at" } else { "to" });
-                let child = EventBuilderChild::new(&sub_event)
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::AddedModFromOtherMod,
                         category: EventCategory::Changes,
                         description: description.clone(),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "mod": mod_id,
-                        "source": "PSYCHOACOUSTICS",
-                        "type": 3,
+                        "source": Mod::Psychoacoustics,
+                        "type": ModDuration::Game as i64,
                     }));
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Psychoacoustics,
                         category: EventCategory::Special,
                         description: if (self.season, self.day) < (15, 33) {
                             String::new() // tgb did a whoopsie
                         } else {
-                            description
+                            description.clone()
                         },
                         ..Default::default()
                     })
@@ -2570,26 +2565,26 @@ impl FedEvent {
             }
             FedEventData::EchoReceiver { game, echoer_name, echoee_name, echoee_id, echoee_team_id, sub_event } => {
                 let description = format!("ECHO {echoer_name} ECHO {echoee_name} ECHO");
-                let child = EventBuilderChild::new(&sub_event)
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         r#type: EventType::ModChange,
                         category: EventCategory::Changes,
                         description: description.clone(),
-                        player_tags: vec![echoee_id],
-                        team_tags: vec![echoee_team_id],
+                        player_tags: vec![*echoee_id],
+                        team_tags: vec![*echoee_team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "from": "RECEIVER",
-                        "to": "ECHO",
-                        "type": 0,
+                        "from": Mod::Receiver,
+                        "to": Mod::Echo,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::EchoReciever,
                         category: EventCategory::Special,
-                        description,
+                        description: description.clone(),
                         ..Default::default()
                     })
                     .child(child)
@@ -2601,12 +2596,12 @@ impl FedEvent {
                         r#type: EventType::AddedMod,
                         category: EventCategory::Changes,
                         description: format!("The {team_nickname} gain Free Will."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "FREE_WILL",
-                        "type": 0,
+                        "mod": Mod::FreeWill,
+                        "type": ModDuration::Permanent as i64,
                     }))
                     .build()
             }
@@ -2615,35 +2610,33 @@ impl FedEvent {
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Tidings,
                         category: EventCategory::Outcomes,
-                        description: message,
-                        player_tags,
+                        description: message.to_string(),
+                        player_tags: player_tags.clone(),
                         ..Default::default()
                     })
-                    .full_metadata(metadata)
+                    .full_metadata(metadata.clone())
                     .build()
             }
             FedEventData::HomebodyGameStart { game, homebodies } => {
-                let (descriptions, children): (Vec<_>, Vec<_>) = homebodies.into_iter()
-                    .map(|toggle| {
-                        let description = format!("{} is {}.", toggle.player_name,
-                                                  if toggle.is_overperforming { "happy to be home" } else { "homesick" });
-                        let change_event = make_switch_performing_child(&toggle, &description, "HOMEBODY");
-                        (description, change_event)
-                    })
-                    .unzip();
+                let homebody_description = |toggle: &TogglePerforming| {
+                    format!("{} is {}.", toggle.player_name,
+                            if toggle.is_overperforming { "happy to be home" } else { "homesick" })
+                };
+                let children = || homebodies.iter()
+                    .map(|toggle| make_switch_performing_child(toggle, &homebody_description(toggle), Mod::Homebody));
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         category: EventCategory::Special,
                         r#type: EventType::Homebody,
-                        description: descriptions.into_iter().join("\n"),
+                        description: homebodies.iter().map(homebody_description).join("\n"),
                         ..Default::default()
                     })
                     .children(children)
                     .build()
             }
             FedEventData::SalmonSwim { game, inning_num, run_losses, item_restored, player_expelled } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.push_description("The Salmon swim upstream!");
                 eb.push_description(&format!("Inning {inning_num} begins again."));
                 eb.push_description(&run_losses.to_string());
@@ -2659,7 +2652,7 @@ impl FedEvent {
                         // Yes, the parent says swim and the child says swam
                         child.push_description("The Salmon swam upstream!");
                         child.push_description(&restored_description);
-                        child.build_item_repaired(item_restored)
+                        child.build_item_repaired(item_restored.clone())
                     });
                 }
 
@@ -2671,7 +2664,7 @@ impl FedEvent {
                         child.push_description(&format!("Salmon Cannons expelled {} Elsewhere.", mod_change.player_name));
                         child.push_player_tag(mod_change.player_id);
                         child.push_team_tag(mod_change.team_id);
-                        child.push_metadata_str("mod", "ELSEWHERE");
+                        child.push_metadata_str("mod", Mod::Elsewhere);
                         child.push_metadata_i64("type", ModDuration::Permanent as i64);
                         child.build(EventType::AddedMod)
                     });
@@ -2680,34 +2673,26 @@ impl FedEvent {
                 eb.build(EventType::SalmonSwim)
             }
             FedEventData::HitByPitch { game, pitcher_id, pitcher_name, batter_team_id, batter_id, batter_name, sub_event, scores } => {
-                let child = EventBuilderChild::new(&sub_event)
-                    .update(EventBuilderUpdate {
-                        category: EventCategory::Changes,
-                        r#type: EventType::AddedMod,
-                        description: format!("{batter_name} is now being Observed..."),
-                        team_tags: vec![batter_team_id],
-                        player_tags: vec![batter_id],
-                        ..Default::default()
-                    })
-                    .metadata(json!({
-                        "mod": "COFFEE_PERIL",
-                        "type": 2, // ?
-                    }));
-
-                event_builder.for_game(&game)
-                    .fill(EventBuilderUpdate {
-                        r#type: EventType::HitByPitch,
-                        category: EventCategory::Special,
-                        description: format!("{pitcher_name} hits {batter_name} with a pitch!\n{batter_name} is now being Observed..."),
-                        player_tags: vec![pitcher_id, batter_id],
-                        ..Default::default()
-                    })
-                    .scores(&scores, " scores!")
-                    .child(child)
-                    .build()
+                eb.set_game(game.clone());
+                eb.set_category(EventCategory::Special);
+                eb.push_description(&format!("{pitcher_name} hits {batter_name} with a pitch!"));
+                eb.push_player_tag(*pitcher_id);
+                eb.push_player_tag(*batter_id);
+                let observed_description = format!("{batter_name} is now being Observed...");
+                eb.push_description(&observed_description);
+                eb.push_child(*sub_event, |mut child| {
+                    child.push_description(&observed_description);
+                    child.push_player_tag(*batter_id);
+                    child.push_team_tag(*batter_team_id);
+                    child.push_metadata_str("mod", Mod::CoffeePeril);
+                    child.push_metadata_i64("type", ModDuration::Weekly as i64);
+                    child.build(EventType::AddedMod)
+                });
+                eb.push_scores(scores.clone(), "scores!");
+                eb.build(EventType::HitByPitch)
             }
             FedEventData::SolarPanelsActivate { game, num_runs, team_nickname } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::SolarPanelsActivation,
                         category: EventCategory::Special,
@@ -2717,16 +2702,16 @@ impl FedEvent {
                     .build()
             }
             FedEventData::RunsOverflowing { game, team_nickname, num_runs } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::RunsOverflowing,
                         category: EventCategory::Special,
                         description: format!("Runs are Overflowing!\n{team_nickname} gain {}.",
-                                             if num_runs == -1. {
+                                             if *num_runs == -1. {
                                                  format!("1 Unrun")
-                                             } else if num_runs == 1. {
+                                             } else if *num_runs == 1. {
                                                  format!("1 Run")
-                                             } else if num_runs < 0. {
+                                             } else if *num_runs < 0. {
                                                  format!("{} Unruns", -num_runs)
                                              } else {
                                                  format!("{num_runs} Runs")
@@ -2736,27 +2721,27 @@ impl FedEvent {
                     .build()
             }
             FedEventData::TeamMiddling { game, team_nickname, change_event, is_middling } => {
-                let child_description = if is_middling {
+                let child_description = if *is_middling {
                     format!("The {team_nickname} are Middling!")
                 } else {
                     format!("Middling wears off for the {team_nickname}.")
                 };
                 let parent_description = format!("Happy Midseason!\n{child_description}");
-                let child = EventBuilderChild::new(&change_event.sub_event)
+                let child = || EventBuilderChild::new(&change_event.sub_event)
                     .update(EventBuilderUpdate {
                         category: EventCategory::Changes,
-                        r#type: if is_middling { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod },
+                        r#type: if *is_middling { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod },
                         description: child_description,
                         team_tags: vec![change_event.team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "OVERPERFORMING",
-                        "source": "MIDDLING",
-                        "type": 0, // ?
+                        "mod": Mod::Overperforming,
+                        "source": Mod::Middling,
+                        "type": ModDuration::Permanent as i64,
                     }));
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::Middling,
                         category: EventCategory::Special,
@@ -2767,17 +2752,17 @@ impl FedEvent {
                     .build()
             }
             FedEventData::EnterCrimeScene { game, player_id, player_name, previous_team_id, previous_team_name, previous_location, new_team_id, new_team_name, stadium_name, rating_before, rating_after, enter_crime_scene_sub_event: crime_scene_sub_event, enter_shadows_sub_event } => {
-                let crime_child = EventBuilderChild::new(&crime_scene_sub_event)
+                let crime_child = || EventBuilderChild::new(crime_scene_sub_event)
                     .update(EventBuilderUpdate {
                         category: EventCategory::Changes,
                         r#type: EventType::PlayerMoved,
                         description: format!("{player_name} entered the Crime Scene at {stadium_name} to Investigate..."),
-                        team_tags: vec![previous_team_id, new_team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*previous_team_id, *new_team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "location": previous_location as i64,
+                        "location": *previous_location as i64,
                         "playerId": player_id,
                         "playerName": player_name,
                         "receiveLocation": 3,
@@ -2786,22 +2771,22 @@ impl FedEvent {
                         "sendTeamId": previous_team_id,
                         "sendTeamName": previous_team_name,
                     }));
-                let shadows_child = EventBuilderChild::new(&enter_shadows_sub_event)
+                let shadows_child = || EventBuilderChild::new(enter_shadows_sub_event)
                     .update(EventBuilderUpdate {
                         category: EventCategory::Changes,
                         r#type: EventType::PlayerStatIncrease,
                         description: format!("{player_name} entered the Shadows."),
-                        team_tags: vec![new_team_id],
-                        player_tags: vec![player_id],
+                        team_tags: vec![*new_team_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "before": rating_before,
                         "after": rating_after,
-                        "type": 4, // ?
+                        "type": StatChangeCategory::All as i64,
                     }));
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::EnterCrimeScene,
                         category: EventCategory::Special,
@@ -2818,16 +2803,16 @@ impl FedEvent {
                         r#type: EventType::PlayerMoved,
                         category: EventCategory::Changes,
                         description: format!("{player_name} returns from the Investigation{}.",
-                                             if emptyhanded { " emptyhanded" } else { "" }),
-                        player_tags: vec![player_id],
-                        team_tags: vec![previous_team_id, new_team_id],
+                                             if *emptyhanded { " emptyhanded" } else { "" }),
+                        player_tags: vec![*player_id],
+                        team_tags: vec![*previous_team_id, *new_team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "location": 3,
                         "playerId": player_id,
                         "playerName": player_name,
-                        "receiveLocation": new_location as i64,
+                        "receiveLocation": *new_location as i64,
                         "receiveTeamId": new_team_id,
                         "receiveTeamName": new_team_name,
                         "sendTeamId": previous_team_id,
@@ -2841,74 +2826,74 @@ impl FedEvent {
                         r#type: EventType::RemovedMod,
                         category: EventCategory::Changes,
                         description: format!("The Crime Scene Investigation at {stadium_name} has concluded."),
-                        team_tags: vec![team_id],
+                        team_tags: vec![*team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "CRIME_SCENE",
-                        "type": 0, // ?
+                        "mod": Mod::CrimeScene,
+                        "type": ModDuration::Permanent as i64,
                     }))
                     .build()
             }
             FedEventData::GrindRail { game, player_id, player_name, first_trick, success } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::GrindRail,
                         category: EventCategory::Special,
                         description: format!("{player_name} hops on the Grind Rail toward third base.\nThey do a {first_trick}!\n{success}"),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .build()
             }
             FedEventData::EnterSecretBase { game, player_id, player_name } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::EnterSecretBase,
                         category: EventCategory::Special,
                         description: format!("{player_name} enters the Secret Base..."),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .build()
             }
             FedEventData::ExitSecretBase { game, player_id, player_name } => {
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::ExitSecretBase,
                         category: EventCategory::Special,
                         description: format!("{player_name} exits the Secret Base to Second Base!"),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .build()
             }
             FedEventData::EchoChamber { game, team_id, player_id, player_name, which_mod, sub_event } => {
                 let mod_id = match which_mod {
-                    EchoChamberModAdded::Repeating => { "REPEATING" }
-                    EchoChamberModAdded::Reverberating => { "REVERBERATING" }
+                    EchoChamberModAdded::Repeating => { Mod::Repeating }
+                    EchoChamberModAdded::Reverberating => { Mod::Reverberating }
                 };
-                let child = EventBuilderChild::new(&sub_event)
+                let child = || EventBuilderChild::new(sub_event)
                     .update(EventBuilderUpdate {
                         category: EventCategory::Changes,
                         r#type: EventType::AddedMod,
                         description: "The Echo Chamber traps a wave.".to_string(),
-                        team_tags: team_id.into_iter().collect(),
-                        player_tags: vec![player_id],
+                        team_tags: team_id.iter().copied().collect(),
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .metadata(json!({
                         "mod": mod_id,
-                        "type": 3, // ?
+                        "type": ModDuration::Game as i64,
                     }));
 
 
-                event_builder.for_game(&game)
+                event_builder.for_game(game)
                     .fill(EventBuilderUpdate {
                         r#type: EventType::EchoChamber,
                         category: EventCategory::Special,
                         description: format!("The Echo Chamber traps a wave.\n{player_name} is temporarily {which_mod}!"),
-                        player_tags: vec![player_id],
+                        player_tags: vec![*player_id],
                         ..Default::default()
                     })
                     .child(child)
@@ -2920,15 +2905,15 @@ impl FedEvent {
                         r#type: EventType::PlayerMoved,
                         category: EventCategory::Changes,
                         description: format!("{player_name} wandered to a new team."),
-                        player_tags: vec![player_id],
-                        team_tags: vec![previous_team_id, new_team_id],
+                        player_tags: vec![*player_id],
+                        team_tags: vec![*previous_team_id, *new_team_id],
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "location": location as i64,
+                        "location": *location as i64,
                         "playerId": player_id,
                         "playerName": player_name,
-                        "receiveLocation": location as i64,
+                        "receiveLocation": *location as i64,
                         "receiveTeamId": new_team_id,
                         "receiveTeamName": new_team_nickname,
                         "sendTeamId": previous_team_id,
@@ -2937,17 +2922,17 @@ impl FedEvent {
                     .build()
             }
             FedEventData::GlitterCrate { game, player_name, gained_item } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.push_description("A shimmering Crate descends.");
-                eb.push_gained_item(player_name, gained_item);
+                eb.push_gained_item(player_name.to_string(), gained_item.clone());
                 eb.build(EventType::GlitterCrateDrop)
             }
             FedEventData::ModsFromAnotherModRemoved { team_id, player_id, player_name, mods_removed, source_mod_name, source_mod_id } => {
                 eb.set_category(EventCategory::Changes);
                 eb.push_description(&format!("{player_name}'s mods caused by {source_mod_name} were removed."));
-                eb.push_player_tag(player_id);
-                eb.push_team_tag(team_id);
-                eb.push_metadata_str("source", source_mod_id);
+                eb.push_player_tag(*player_id);
+                eb.push_team_tag(*team_id);
+                eb.push_metadata_str("source", source_mod_id.clone());
                 eb.push_metadata_json_vec("removes", mods_removed.iter()
                     .map(|r| json!({ "mod": r.mod_id, "type": r.mod_duration as i64 }))
                     .collect());
@@ -2955,71 +2940,71 @@ impl FedEvent {
                 eb.build(EventType::RemovedModsFromAnotherMod)
             }
             FedEventData::ConsumerExpelled { game, player_id } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description("SALMON CANNONS FIRE");
                 eb.push_description("CONSUMER EXPELLED");
-                eb.push_player_tag(player_id);
+                eb.push_player_tag(*player_id);
                 eb.build(EventType::ConsumersAttack)
             }
             FedEventData::EarlbirdsAddedToPlayer { game, team_id, player_id, player_name, sub_event } => {
                 let description = format!("{player_name} is an Earlbird.");
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description(&description);
-                eb.push_player_tag(player_id);
-                eb.push_child(sub_event, |mut child| {
+                eb.push_player_tag(*player_id);
+                eb.push_child(*sub_event, |mut child| {
                     child.push_description(&description);
-                    child.push_player_tag(player_id);
-                    child.push_team_tag(team_id);
-                    child.push_metadata_str("mod", "OVERPERFORMING");
-                    child.push_metadata_str("source", "EARLBIRDS");
+                    child.push_player_tag(*player_id);
+                    child.push_team_tag(*team_id);
+                    child.push_metadata_str("mod", Mod::Overperforming);
+                    child.push_metadata_str("source", Mod::Earlbirds);
                     child.push_metadata_i64("type", ModDuration::Permanent as i64);
                     child.build(EventType::AddedModFromOtherMod)
                 });
                 eb.build(EventType::Earlbird)
             }
             FedEventData::MindTrickWalk { game, strikeout_type, batter_id, batter_name, scores } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description(&format!("{batter_name} strikes out {strikeout_type}."));
                 eb.push_description(&format!("{batter_name} uses a Mind Trick!"));
                 eb.push_description("The umpire sends them to first base.");
-                eb.push_player_tag(batter_id);
-                eb.push_scores(scores, "scores!");
+                eb.push_player_tag(*batter_id);
+                eb.push_scores(scores.clone(), "scores!");
                 eb.build(EventType::Walk)
             }
             FedEventData::MindTrickStrikeout { game, batter_id, batter_name, pitcher_name } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description(&format!("{batter_name} draws a walk."));
                 eb.push_description(&format!("{pitcher_name} uses a Mind Trick!"));
                 eb.push_description(&format!("{batter_name} strikes out thinking."));
-                eb.push_player_tag(batter_id);
-                eb.push_player_tag(batter_id); // batter twice, apparently
+                eb.push_player_tag(*batter_id);
+                eb.push_player_tag(*batter_id); // batter twice, apparently
                 eb.build(EventType::Walk) // ugh
             }
             FedEventData::BlooddrainBlocked { game, sipper_id, sipper_name, sippee_id, sippee_name } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description("The Blooddrain gurgled!");
                 eb.push_description(&format!("{sipper_name} tried to siphon blood from {sippee_name}, but they were Sealed!"));
-                eb.push_player_tag(sipper_id);
-                eb.push_player_tag(sippee_id); // batter twice, apparently
+                eb.push_player_tag(*sipper_id);
+                eb.push_player_tag(*sippee_id); // batter twice, apparently
                 eb.build(EventType::BlooddrainBlocked)
             }
             FedEventData::EarlbirdsRemovedFromPlayer { game, team_id, player_id, player_name, sub_event } => {
                 let description = format!("{player_name} is no longer an Earlbird.");
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description(&description);
-                eb.push_player_tag(player_id);
-                eb.push_child(sub_event, |mut child| {
+                eb.push_player_tag(*player_id);
+                eb.push_child(*sub_event, |mut child| {
                     child.push_description(&description);
-                    child.push_player_tag(player_id);
-                    child.push_team_tag(team_id);
-                    child.push_metadata_str("mod", "OVERPERFORMING");
-                    child.push_metadata_str("source", "EARLBIRDS");
+                    child.push_player_tag(*player_id);
+                    child.push_team_tag(*team_id);
+                    child.push_metadata_str("mod", Mod::Overperforming);
+                    child.push_metadata_str("source", Mod::Earlbirds);
                     child.push_metadata_i64("type", ModDuration::Permanent as i64);
                     child.build(EventType::RemovedModFromOtherMod)
                 });
@@ -3027,24 +3012,24 @@ impl FedEvent {
             }
             FedEventData::TarotReadingAddedOrRemovedItem { description, item_id, item_name, item_mods, player_item_rating_before, player_item_rating_after, player_rating, team_id, player_id, item_gained } => {
                 eb.set_category(EventCategory::Changes);
-                eb.set_description(description);
-                eb.push_team_tag(team_id);
-                eb.push_player_tag(player_id);
-                eb.push_metadata_uuid("itemId", item_id);
+                eb.set_description(description.to_string());
+                eb.push_team_tag(*team_id);
+                eb.push_player_tag(*player_id);
+                eb.push_metadata_uuid("itemId", *item_id);
                 eb.push_metadata_str("itemName", item_name);
-                eb.push_metadata_str_vec("mods", item_mods);
-                eb.push_metadata_f64("playerItemRatingAfter", player_item_rating_after);
-                eb.push_metadata_f64("playerItemRatingBefore", player_item_rating_before);
-                eb.push_metadata_f64("playerRating", player_rating);
-                eb.build(if item_gained { EventType::PlayerGainedItem } else { EventType::PlayerLostItem })
+                eb.push_metadata_mod_vec("mods", item_mods.clone());
+                eb.push_metadata_f64("playerItemRatingAfter", *player_item_rating_after);
+                eb.push_metadata_f64("playerItemRatingBefore", *player_item_rating_before);
+                eb.push_metadata_f64("playerRating", *player_rating);
+                eb.build(if *item_gained { EventType::PlayerGainedItem } else { EventType::PlayerLostItem })
             }
             FedEventData::PlayerMiddling { game, is_middling, change_event } => {
-                let description = if is_middling {
+                let description = if *is_middling {
                     format!("{} is Middling.", change_event.player_name)
                 } else {
                     format!("{} is no longer Middling.", change_event.player_name)
                 };
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description(&description);
                 eb.push_player_tag(change_event.player_id);
@@ -3052,41 +3037,41 @@ impl FedEvent {
                     child.push_description(&description);
                     child.push_player_tag(change_event.player_id);
                     child.push_team_tag(change_event.team_id);
-                    child.push_metadata_str("mod", "OVERPERFORMING");
-                    child.push_metadata_str("source", "MIDDLING");
+                    child.push_metadata_str("mod", Mod::Overperforming);
+                    child.push_metadata_str("source", Mod::Middling);
                     child.push_metadata_i64("type", ModDuration::Permanent as i64);
-                    child.build(if is_middling { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod })
+                    child.build(if *is_middling { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod })
                 });
                 eb.build(EventType::Middling)
             }
             FedEventData::CommunityChestOpens { item_id, item_name, item_mods, player_item_rating_before, player_item_rating_after, player_rating, team_id, player_name, player_id } => {
                 eb.set_category(EventCategory::Special);
                 eb.push_description(&format!("The Community Chest Opens! {player_name} gained {item_name}."));
-                eb.push_team_tag(team_id);
-                eb.push_player_tag(player_id);
-                eb.push_metadata_uuid("itemId", item_id);
+                eb.push_team_tag(*team_id);
+                eb.push_player_tag(*player_id);
+                eb.push_metadata_uuid("itemId", *item_id);
                 eb.push_metadata_str("itemName", item_name);
-                eb.push_metadata_str_vec("mods", item_mods);
-                eb.push_metadata_f64_opt("playerItemRatingAfter", player_item_rating_after);
-                eb.push_metadata_f64_opt("playerItemRatingBefore", player_item_rating_before);
-                eb.push_metadata_f64("playerRating", player_rating);
+                eb.push_metadata_mod_vec("mods", item_mods.clone());
+                eb.push_metadata_f64_opt("playerItemRatingAfter", *player_item_rating_after);
+                eb.push_metadata_f64_opt("playerItemRatingBefore", *player_item_rating_before);
+                eb.push_metadata_f64("playerRating", *player_rating);
                 eb.build(EventType::PlayerGainedItem)
             }
             FedEventData::PlayerDropsItem { item_id, item_name, item_mods, player_item_rating_before, player_item_rating_after, player_rating, team_id, player_name, player_id } => {
                 eb.set_category(EventCategory::Changes);
                 eb.push_description(&format!("{player_name} dropped {item_name}."));
-                eb.push_team_tag(team_id);
-                eb.push_player_tag(player_id);
-                eb.push_metadata_uuid("itemId", item_id);
+                eb.push_team_tag(*team_id);
+                eb.push_player_tag(*player_id);
+                eb.push_metadata_uuid("itemId", *item_id);
                 eb.push_metadata_str("itemName", item_name);
-                eb.push_metadata_str_vec("mods", item_mods);
-                eb.push_metadata_f64("playerItemRatingAfter", player_item_rating_after);
-                eb.push_metadata_f64("playerItemRatingBefore", player_item_rating_before);
-                eb.push_metadata_f64("playerRating", player_rating);
+                eb.push_metadata_mod_vec("mods", item_mods.clone());
+                eb.push_metadata_f64("playerItemRatingAfter", *player_item_rating_after);
+                eb.push_metadata_f64("playerItemRatingBefore", *player_item_rating_before);
+                eb.push_metadata_f64("playerRating", *player_rating);
                 eb.build(EventType::PlayerLostItem)
             }
             FedEventData::CommunityChestGameMessage { game, first_player_name, first_player_item_name, first_player_dropped_item, second_player_name, second_player_item_name, second_player_dropped_item } => {
-                eb.set_game(game);
+                eb.set_game(game.clone());
                 eb.set_category(EventCategory::Special);
                 eb.push_description("The Community Chest Opens!");
                 if let Some(dropped_item) = first_player_dropped_item {
@@ -3105,13 +3090,14 @@ impl FedEvent {
     }
 
     #[deprecated = "This is part of the old event builder"]
-    fn make_mod_change_sub_events<'a>(&self, mod_changes: &[ModChangeSubEventWithNamedPlayer], event_type: EventType, message: &str, mod_name: &str) -> (Vec<EventBuilderChildFull>, String) {
+    /// Returns the description suffix, and an iterator that makes the children when it's consumed
+    fn make_mod_change_sub_events<'a>(&self, mod_changes: &'a [ModChangeSubEventWithNamedPlayer], event_type: EventType, message: &'a str, mod_id: Mod) -> (impl Iterator<Item=EventBuilderChildFull> + 'a, String) {
         let suffix = mod_changes.iter()
             .map(|e| format!("\n{} {message}", e.player_name))
             .join("");
 
         let children = mod_changes.iter()
-            .map(|e| {
+            .map(move |e| {
                 EventBuilderChild::new(&e.sub_event)
                     .update(EventBuilderUpdate {
                         r#type: event_type,
@@ -3122,23 +3108,20 @@ impl FedEvent {
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": mod_name,
-                        "type": 0, // ?
+                        "mod": mod_id,
+                        "type": ModDuration::Permanent as i64,
                     }))
-            })
-            .collect();
+            });
 
         (children, suffix)
     }
 }
 
-#[deprecated = "Use a base enum instead"]
-fn base_name(base_stolen: i32) -> &'static str {
-    match base_stolen {
-        2 => "second",
-        3 => "third",
-        4 => "fourth",
-        5 => "fifth",
-        _ => panic!("What base is this")
+/// Writes the event's feed description. This shares its templates with
+/// [`FedEvent::into_feed_event`] but works from a reference and skips building children, tags, and
+/// metadata, so it's much cheaper when only the text is needed.
+impl Display for FedEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.build_feed_event(true).description)
     }
 }
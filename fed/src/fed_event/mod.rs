@@ -1,8 +1,14 @@
 mod fed_event_impl;
+mod compare;
+mod mods;
+mod named_players;
+mod team_ids;
 
 pub use fed_event_impl::*;
+pub use compare::*;
+pub use mods::*;
+pub use named_players::*;
 
-use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, Write};
 use chrono::{DateTime, Utc};
 use enum_access::EnumDisplay;
@@ -77,7 +83,8 @@ pub struct GamePitch {
 
 // This contains only the event properties that will differ from the parent, including id, created,
 // and nuts; but not properties that will be the same, like day, season, and tournament.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, WithStructure)]
+// Serialize is implemented in compare.rs, so that comparisons can leave out sub-event metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, JsonSchema, WithStructure)]
 #[serde(rename_all = "camelCase")]
 pub struct SubEvent {
     /// Uuid of sub-event
@@ -92,7 +99,8 @@ pub struct SubEvent {
 }
 
 impl SubEvent {
-    // For use when you are generating Fed events and don't care about the SubEvent data
+    // For use when you are generating Fed events and don't care about the SubEvent data. Compare
+    // them to measured events with FedEventComparison::IgnoringSubEvents.
     pub fn nil() -> Self {
         Self {
             id: Uuid::nil(),
@@ -102,14 +110,6 @@ impl SubEvent {
     }
 }
 
-// I am doing this crime because i want to compare measured events to generated events and i don't
-// care about the non-generatable data. i am sure this will bite me in the ass eventually
-impl PartialEq for SubEvent {
-    fn eq(&self, other: &Self) -> bool {
-        true
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FreeRefill {
@@ -164,9 +164,8 @@ impl Scores {
         let mut output = String::new();
         for score in &self.scores {
             if let Some(damage) = &score.item_damage {
-                write!(output, "\n{}{} {} {}", if extra_space { " " } else { "" },
-                       possessive(score.player_name.clone()), damage.item_name,
-                       if damage.health == 0 { "broke!" } else { "was damaged." }).unwrap();
+                write!(output, "\n{}{} {}", if extra_space { " " } else { "" },
+                       possessive(score.player_name.clone()), damage).unwrap();
             }
 
             write!(output, "\n{}{}", score.player_name, score_text).unwrap();
@@ -325,14 +324,8 @@ impl Display for AttrCategory {
 }
 
 impl AttrCategory {
-    // TODO This should be a repr on the enum
-    pub fn metadata_type(&self) -> i32 {
-        match self {
-            AttrCategory::Batting => { 0 }
-            AttrCategory::Pitching => { 1 }
-            AttrCategory::Defense => { 2 }
-            AttrCategory::Baserunning => { 3 }
-        }
+    pub fn metadata_type(&self) -> i64 {
+        StatChangeCategory::from(*self).into()
     }
 }
 
@@ -448,6 +441,42 @@ pub enum SpicyStatus {
     RedHot(Option<ModChangeSubEvent>),
 }
 
+/// A team mod that adds Over- or Underperforming for part of the season. Serialized as its internal
+/// ID, like [`Mod`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubseasonalMod {
+    EarlyToParty,
+    Middling,
+    LateToParty,
+}
+
+impl SubseasonalMod {
+    const ALL: [SubseasonalMod; 3] = [SubseasonalMod::EarlyToParty, SubseasonalMod::Middling, SubseasonalMod::LateToParty];
+
+    /// Looks up a subseasonal mod by the name that's shown in event descriptions
+    pub fn from_display_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|subseasonal_mod| subseasonal_mod.source_mod().display_name() == name)
+    }
+
+    /// The mod itself
+    pub fn source_mod(&self) -> Mod {
+        match self {
+            SubseasonalMod::EarlyToParty => { Mod::EarlyToParty }
+            SubseasonalMod::Middling => { Mod::Middling }
+            SubseasonalMod::LateToParty => { Mod::LateToParty }
+        }
+    }
+
+    /// The mod that this mod adds to or removes from its team
+    pub fn performing_mod(&self) -> Mod {
+        match self {
+            SubseasonalMod::Middling | SubseasonalMod::LateToParty => { Mod::Overperforming }
+            SubseasonalMod::EarlyToParty => { Mod::Underperforming }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamPerformingChanged {
@@ -457,10 +486,9 @@ pub struct TeamPerformingChanged {
     /// Uuid of the team who gained or lost Over or Underperforming
     pub team_id: Uuid,
 
-    /// Internal ID of the mod which caused the addition or removal. Which mod was added or removed
-    /// is not stored, but is inferred from this ID.
-    // TODO: Make this an enum?
-    pub source_mod_id: String,
+    /// The mod which caused the addition or removal. Which mod was added or removed is not stored,
+    /// but is inferred from this.
+    pub source_mod_id: SubseasonalMod,
 
     /// Name of the mod which caused the addition or removal
     pub source_mod_name: String,
@@ -487,6 +515,51 @@ impl SpicyStatus {
     }
 }
 
+/// Why an event is in the Special category. The feed only records that an event is Special, so
+/// these are worked out from the rest of the event.
+///
+/// Events that can be Special have a `special_reason` field, which is only set when no other field
+/// of the event explains it (e.g. a Free Refill is recorded in `free_refill`, not here). It's null
+/// if the event wasn't Special or if another field explains it. [`FedEventData::special_reasons`]
+/// collects the reasons from both places.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum SpecialReason {
+    /// The pitcher fired a Double Strike
+    DoubleStrike,
+
+    /// A player used their Free Refill. This also covers Triple Threat: the only way a strikeout
+    /// uses a Free Refill is thanks to Triple Threat, and the feed doesn't show Triple Threat in
+    /// the event any other way, so there's no separate reason for it.
+    FreeRefill,
+
+    /// The batter is Red Hot (see [`SpicyStatus::RedHot`])
+    RedHot,
+
+    /// The batter was Red Hot and cooled off
+    CooledOff,
+
+    /// The batter has Debt and hit the fielder with the ball
+    BatterDebt,
+
+    /// The runner scored with Blaserunning
+    Blaserunning,
+
+    /// The batter took an extra base with Base Instincts
+    BaseInstincts,
+
+    /// Nothing else in the event shows why, but someone scored. In the early Expansion Era this is
+    /// what happened when a player scored with Wired or Tired.
+    WiredOrTired,
+
+    /// Nothing else in the strikeout shows why. In the early Expansion Era this is what happened on
+    /// Unrun strikeouts.
+    UnrunStrikeout,
+
+    /// Nothing else in the event shows why, and there's no known reason for this kind of event
+    Unknown,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerStatChange {
@@ -625,14 +698,28 @@ pub enum BatterSkippedReason {
     Elsewhere(Uuid),
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+/// The "type" in the metadata of player stat change events, which says which ratings changed
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, JsonSchema, TryFromPrimitive, IntoPrimitive)]
 #[repr(i64)]
 pub enum StatChangeCategory {
     Batting = 0,
     Pitching = 1,
-    Baserunning = 2,
-    Defense = 3,
+    Defense = 2,
+    Baserunning = 3,
     All = 4,
+    /// A whole team's ratings. Only seen on Bottom Dwellers.
+    Team = 5,
+}
+
+impl From<AttrCategory> for StatChangeCategory {
+    fn from(category: AttrCategory) -> Self {
+        match category {
+            AttrCategory::Batting => { StatChangeCategory::Batting }
+            AttrCategory::Pitching => { StatChangeCategory::Pitching }
+            AttrCategory::Defense => { StatChangeCategory::Defense }
+            AttrCategory::Baserunning => { StatChangeCategory::Baserunning }
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -685,7 +772,7 @@ pub enum RenovationVotes {
 #[serde(rename_all = "camelCase")]
 pub struct MultipleModsAddedOrRemoved {
     /// Vector of mods that were added/removed. Each mod is represented by its internal ID.
-    pub mod_ids: Vec<String>,
+    pub mod_ids: Vec<Mod>,
 
     /// Metadata for the event associated with adding or removing these mods
     pub sub_event: SubEvent,
@@ -996,7 +1083,7 @@ pub struct ItemDamaged {
     pub item_name_plural: Option<bool>,
 
     /// Mods bestowed by item that was damaged
-    pub item_mods: Vec<String>,
+    pub item_mods: Vec<Mod>,
 
     /// Durability of item. This is its max health.
     pub durability: i64,
@@ -1046,7 +1133,7 @@ pub struct ItemGained {
     pub item_name: String,
 
     /// Mods bestowed by item that was gained
-    pub item_mods: Vec<String>,
+    pub item_mods: Vec<Mod>,
 
     /// The increase or decrease that all the wielding player's items caused to their star rating
     /// before gaining this item
@@ -1081,7 +1168,7 @@ pub struct ItemRepaired {
     pub item_name: String,
 
     /// Mods bestowed by item that was repaired
-    pub item_mods: Vec<String>,
+    pub item_mods: Vec<Mod>,
 
     /// Durability of item. This is its max health.
     pub durability: i64,
@@ -1126,7 +1213,7 @@ pub struct ItemDroppedForNewItem {
     pub item_name: String,
 
     /// Mods bestowed by item that was dropped
-    pub item_mods: Vec<String>,
+    pub item_mods: Vec<Mod>,
 
     /// The increase or decrease that all the wielding player's items caused to their star rating
     /// before dropping this item
@@ -1215,7 +1302,7 @@ pub struct Attraction {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, WithStructure)]
 pub struct ModDesc {
     /// Internal name of the mod
-    pub mod_id: String,
+    pub mod_id: Mod,
 
     /// Duration of the mod
     pub mod_duration: ModDuration,
@@ -1230,6 +1317,18 @@ pub enum HitType {
     Quadruple,
 }
 
+impl HitType {
+    /// Number of bases the batter gets from this hit
+    pub fn num_bases(&self) -> i32 {
+        match self {
+            HitType::Single => { 1 }
+            HitType::Double => { 2 }
+            HitType::Triple(_) => { 3 }
+            HitType::Quadruple => { 4 }
+        }
+    }
+}
+
 impl Display for HitType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1250,6 +1349,19 @@ pub enum Base {
     Fifth,
 }
 
+impl Base {
+    /// Zero-indexed base number, as used in the basesOccupied field of game updates
+    pub fn index(&self) -> i32 {
+        match self {
+            Base::First => { 0 }
+            Base::Second => { 1 }
+            Base::Third => { 2 }
+            Base::Fourth => { 3 }
+            Base::Fifth => { 4 }
+        }
+    }
+}
+
 impl Display for Base {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1575,11 +1687,8 @@ pub enum FedEventData {
         /// Hot mod, otherwise null.
         cooled_off: Option<ModChangeSubEventWithPlayer>,
 
-        /// If the event was a Special type. Usually this can be inferred from other fields.
-        /// However, the early Expansion Era, when players scored with Tired or Wired the event was
-        /// Special but that was the only way of knowing. (It's possible that there are other
-        /// circumstances that cause an otherwise-undetectable Special event.)
-        is_special: bool,
+        /// Why the event was Special, if no other field shows it
+        special_reason: Option<SpecialReason>,
 
         /// If the batter has Debt and hit the fielder with the ball, this contains the information
         /// about adding Unstable/Observed/whatever. Otherwise it will be null.
@@ -1627,11 +1736,8 @@ pub enum FedEventData {
         /// Hot mod, otherwise null.
         cooled_off: Option<ModChangeSubEventWithPlayer>,
 
-        /// If the event was a Special type. Usually this can be inferred from other fields.
-        /// However, the early Expansion Era, when players scored with Tired or Wired the event was
-        /// Special but that was the only way of knowing. (It's possible that there are other
-        /// circumstances that cause an otherwise-undetectable Special event.)
-        is_special: bool,
+        /// Why the event was Special, if no other field shows it
+        special_reason: Option<SpecialReason>,
 
         /// If the batter has Debt and hit the fielder with the ball, this contains the information
         /// about adding Unstable/Observed/whatever. Otherwise it will be null.
@@ -1676,11 +1782,8 @@ pub enum FedEventData {
         /// Hot mod, otherwise null.
         cooled_off: Option<ModChangeSubEventWithPlayer>,
 
-        /// If the event was a Special type. Usually this can be inferred from other fields.
-        /// However, the early Expansion Era, when players scored with Tired or Wired the event was
-        /// Special but that was the only way of knowing. (It's possible that there are other
-        /// circumstances that cause an otherwise-undetectable Special event.)
-        is_special: bool,
+        /// Why the event was Special, if no other field shows it
+        special_reason: Option<SpecialReason>,
 
         /// Items that were damaged, if any. Like home runs there isn't enough information to 
         /// properly attribute the damage to pitchers, batters, fielders, and runners.
@@ -1739,11 +1842,8 @@ pub enum FedEventData {
         /// Otherwise null.
         stopped_inhabiting: Option<StoppedInhabiting>,
 
-        /// If the event was a Special type. Usually this can be inferred from other fields.
-        /// However, the early Expansion Era, when players scored with Tired or Wired the event was
-        /// Special but that was the only way of knowing. (It's possible that there are other
-        /// circumstances that cause an otherwise-undetectable Special event.)
-        is_special: bool,
+        /// Why the event was Special, if no other field shows it
+        special_reason: Option<SpecialReason>,
 
         /// Damage that the pitcher's item took, if any
         pitcher_item_damage: Option<(String, ItemDamaged)>,
@@ -1788,11 +1888,8 @@ pub enum FedEventData {
         /// The Spicy status of the batter
         spicy_status: SpicyStatus,
 
-        /// If the event was a Special type. Usually this can be inferred from other fields.
-        /// However, the early Expansion Era, when players scored with Tired or Wired the event was
-        /// Special but that was the only way of knowing. (It's possible that there are other
-        /// circumstances that cause an otherwise-undetectable Special event.)
-        is_special: bool,
+        /// Why the event was Special, if no other field shows it
+        special_reason: Option<SpecialReason>,
 
         /// True if the ball landed in a Big Bucket and scored an extra Run, false otherwise
         big_bucket: bool,
@@ -1832,11 +1929,8 @@ pub enum FedEventData {
         /// Baserunner item damage if any, otherwise null
         runner_item_damage: Option<ItemDamaged>,
 
-        /// If the event was a Special type. Usually this can be inferred from other fields.
-        /// However, the early Expansion Era, when players scored with Tired or Wired the event was
-        /// Special but that was the only way of knowing. (It's possible that there are other
-        /// circumstances that cause an otherwise-undetectable Special event.)
-        is_special: bool,
+        /// Why the event was Special, if no other field shows it
+        special_reason: Option<SpecialReason>,
     },
 
     /// Caught stealing
@@ -1875,11 +1969,8 @@ pub enum FedEventData {
         /// thanks to Triple Threat.
         free_refill: Option<FreeRefill>,
 
-        /// If the event was a Special type. Usually this can be inferred from other fields.
-        /// However, the early Expansion Era, when players got Unrun strikeouts the event was
-        /// Special but that was the only way of knowing. (It's possible that there are other
-        /// circumstances that cause an otherwise-undetectable Special event.)
-        is_special: bool,
+        /// Why the event was Special, if no other field shows it
+        special_reason: Option<SpecialReason>,
 
         /// If there was a parasite blooddrain on this strikeout, contains information about it.
         /// Otherwise null.
@@ -1909,11 +2000,8 @@ pub enum FedEventData {
         /// thanks to Triple Threat.
         free_refill: Option<FreeRefill>,
 
-        /// If the event was a Special type. Usually this can be inferred from other fields.
-        /// However, the early Expansion Era, when players got Unrun strikeouts the event was
-        /// Special but that was the only way of knowing. (It's possible that there are other
-        /// circumstances that cause an otherwise-undetectable Special event.)
-        is_special: bool,
+        /// Why the event was Special, if no other field shows it
+        special_reason: Option<SpecialReason>,
 
         /// If there was a parasite blooddrain on this strikeout, contains information about it.
         /// Otherwise null.
@@ -1946,11 +2034,8 @@ pub enum FedEventData {
         /// Otherwise null.
         stopped_inhabiting: Option<StoppedInhabiting>,
 
-        /// If the event was a Special type. Usually this can be inferred from other fields.
-        /// However, the early Expansion Era, when players scored with Tired or Wired the event was
-        /// Special but that was the only way of knowing. (It's possible that there are other
-        /// circumstances that cause an otherwise-undetectable Special event.)
-        is_special: bool,
+        /// Why the event was Special, if no other field shows it
+        special_reason: Option<SpecialReason>,
     },
 
     /// Marks the end of the half-inning
@@ -2210,7 +2295,7 @@ pub enum FedEventData {
         player_name: String,
 
         /// The mod(s) that were removed
-        mods: Vec<String>,
+        mods: Vec<Mod>,
 
         /// Duration after which the mod(s) were removed (game, week, or season)
         mod_duration: ModDuration,
@@ -2226,7 +2311,7 @@ pub enum FedEventData {
         team_nickname: String,
 
         /// The mod(s) that were removed
-        mods: Vec<String>,
+        mods: Vec<Mod>,
 
         /// Duration after which the mod(s) were removed (game, week, or season)
         mod_duration: ModDuration,
@@ -2531,7 +2616,7 @@ pub enum FedEventData {
         description: String,
 
         /// Internal ID of the mod that was gained/lost
-        r#mod: String,
+        r#mod: Mod,
 
         /// Duration of the mod that was gained/lost
         mod_duration: ModDuration,
@@ -3284,7 +3369,7 @@ pub enum FedEventData {
         player_name: String,
 
         /// Internal ID of the mod that was lost
-        r#mod: String,
+        r#mod: Mod,
 
         /// User-facing name of the mod that was lost
         mod_name: String,
@@ -3405,7 +3490,7 @@ pub enum FedEventData {
         mod_name: String,
 
         /// Internal ID of mod that was echoed
-        mod_id: String,
+        mod_id: Mod,
 
         /// Metadata for the sub-event associated with adding the mod
         sub_event: SubEvent,
@@ -3760,7 +3845,7 @@ pub enum FedEventData {
         source_mod_name: String,
 
         /// Internal name of the mod that had originally added the removed mods
-        source_mod_id: String,
+        source_mod_id: Mod,
     },
 
     /// A Consumer was expelled by Salmon Cannons
@@ -3884,7 +3969,7 @@ pub enum FedEventData {
         item_name: String,
 
         /// Mods bestowed by item that was gained/lost
-        item_mods: Vec<String>,
+        item_mods: Vec<Mod>,
 
         /// The increase/decrease that all the wielding player's items caused to their star rating
         /// before gaining/losing this item
@@ -3931,7 +4016,7 @@ pub enum FedEventData {
         item_name: String,
 
         /// Mods bestowed by item that was gained
-        item_mods: Vec<String>,
+        item_mods: Vec<Mod>,
 
         /// The increase or decrease that all the wielding player's items caused to their star rating
         /// before gaining this item. Sometimes this is null for no reason I can discern.
@@ -3965,7 +4050,7 @@ pub enum FedEventData {
         item_name: String,
 
         /// Mods bestowed by item that was gained
-        item_mods: Vec<String>,
+        item_mods: Vec<Mod>,
 
         /// The increase or decrease that all the wielding player's items caused to their star rating
         /// before gaining this item
@@ -4041,9 +4126,11 @@ pub enum SimPhase {
 }
 
 /// Represents the parsed data for any Feed event
-#[derive(Clone, Debug, Builder, JsonSchema, Serialize, Deserialize, WithStructure, EnumFlatten)]
+#[derive(Clone, Debug, PartialEq, Builder, JsonSchema, Serialize, Deserialize, WithStructure, EnumFlatten)]
 #[serde(rename_all = "camelCase")]
 #[enum_flatten(data)]
+#[enum_flatten_derive(Serialize, JsonSchema)]
+#[enum_flatten_propagate(serde)]
 pub struct FedEvent {
     /// Uuid of the event itself
     pub id: Uuid,
@@ -4236,22 +4323,124 @@ impl FedEventData {
     }
 }
 
-impl Eq for FedEvent {}
+impl FedEventData {
+    /// Every reason this event is Special. Empty if it isn't Special, or if it's a kind of event
+    /// that doesn't record why.
+    pub fn special_reasons(&self) -> Vec<SpecialReason> {
+        let mut reasons = self.shown_special_reasons();
+        reasons.extend(self.special_reason());
+        reasons
+    }
 
-impl PartialEq<Self> for FedEvent {
-    fn eq(&self, other: &Self) -> bool {
-        self.created.eq(&other.created)
+    /// The `special_reason` field, for the kinds of event that have one
+    pub fn special_reason(&self) -> Option<SpecialReason> {
+        match self {
+            FedEventData::Flyout { special_reason, .. } |
+            FedEventData::GroundOut { special_reason, .. } |
+            FedEventData::FieldersChoice { special_reason, .. } |
+            FedEventData::Hit { special_reason, .. } |
+            FedEventData::HomeRun { special_reason, .. } |
+            FedEventData::StolenBase { special_reason, .. } |
+            FedEventData::StrikeoutSwinging { special_reason, .. } |
+            FedEventData::StrikeoutLooking { special_reason, .. } |
+            FedEventData::Walk { special_reason, .. } => { *special_reason }
+            _ => { None }
+        }
     }
-}
 
-impl PartialOrd<Self> for FedEvent {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.created.partial_cmp(&other.created)
+    fn special_reason_mut(&mut self) -> Option<&mut Option<SpecialReason>> {
+        match self {
+            FedEventData::Flyout { special_reason, .. } |
+            FedEventData::GroundOut { special_reason, .. } |
+            FedEventData::FieldersChoice { special_reason, .. } |
+            FedEventData::Hit { special_reason, .. } |
+            FedEventData::HomeRun { special_reason, .. } |
+            FedEventData::StolenBase { special_reason, .. } |
+            FedEventData::StrikeoutSwinging { special_reason, .. } |
+            FedEventData::StrikeoutLooking { special_reason, .. } |
+            FedEventData::Walk { special_reason, .. } => { Some(special_reason) }
+            _ => { None }
+        }
     }
-}
 
-impl Ord for FedEvent {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.created.cmp(&other.created)
+    /// Reasons for being Special that other fields of the event show. Building an event makes it
+    /// Special for any of these, so they're never stored in `special_reason`.
+    fn shown_special_reasons(&self) -> Vec<SpecialReason> {
+        let reasons = match self {
+            FedEventData::Flyout { pitch, scores, cooled_off, batter_debt, .. } |
+            FedEventData::GroundOut { pitch, scores, cooled_off, batter_debt, .. } => vec![
+                (SpecialReason::DoubleStrike, pitch.double_strike.is_some()),
+                (SpecialReason::FreeRefill, scores.used_refill()),
+                (SpecialReason::CooledOff, cooled_off.is_some()),
+                (SpecialReason::BatterDebt, batter_debt.is_some()),
+            ],
+            FedEventData::FieldersChoice { pitch, scores, cooled_off, .. } => vec![
+                (SpecialReason::DoubleStrike, pitch.double_strike.is_some()),
+                (SpecialReason::FreeRefill, scores.used_refill()),
+                (SpecialReason::CooledOff, cooled_off.is_some()),
+            ],
+            FedEventData::Hit { pitch, scores, spicy_status, .. } => vec![
+                (SpecialReason::DoubleStrike, pitch.double_strike.is_some()),
+                (SpecialReason::FreeRefill, scores.used_refill()),
+                (SpecialReason::RedHot, spicy_status.is_special()),
+            ],
+            FedEventData::HomeRun { pitch, free_refills, spicy_status, .. } => vec![
+                (SpecialReason::DoubleStrike, pitch.double_strike.is_some()),
+                (SpecialReason::FreeRefill, !free_refills.is_empty()),
+                (SpecialReason::RedHot, spicy_status.is_special()),
+            ],
+            FedEventData::StolenBase { blaserunning, free_refill, .. } => vec![
+                (SpecialReason::Blaserunning, *blaserunning),
+                (SpecialReason::FreeRefill, free_refill.is_some()),
+            ],
+            FedEventData::StrikeoutSwinging { pitch, free_refill, .. } |
+            FedEventData::StrikeoutLooking { pitch, free_refill, .. } => vec![
+                (SpecialReason::DoubleStrike, pitch.double_strike.is_some()),
+                (SpecialReason::FreeRefill, free_refill.is_some()),
+            ],
+            FedEventData::Walk { base_instincts, scores, .. } => vec![
+                (SpecialReason::BaseInstincts, base_instincts.is_some()),
+                (SpecialReason::FreeRefill, scores.used_refill()),
+            ],
+            _ => { vec![] }
+        };
+
+        reasons.into_iter()
+            .filter_map(|(reason, applies)| applies.then_some(reason))
+            .collect()
+    }
+
+    /// The likeliest reason this event is Special when no other field shows why
+    fn hidden_special_reason(&self) -> SpecialReason {
+        match self {
+            FedEventData::Flyout { scores, .. } |
+            FedEventData::GroundOut { scores, .. } |
+            FedEventData::FieldersChoice { scores, .. } |
+            FedEventData::Hit { scores, .. } |
+            FedEventData::Walk { scores, .. } if !scores.scores.is_empty() => { SpecialReason::WiredOrTired }
+            // The batter always scores on a home run
+            FedEventData::HomeRun { .. } => { SpecialReason::WiredOrTired }
+            FedEventData::StrikeoutSwinging { .. } |
+            FedEventData::StrikeoutLooking { .. } => { SpecialReason::UnrunStrikeout }
+            _ => { SpecialReason::Unknown }
+        }
+    }
+
+    /// The parser can only tell whether an event is Special, which it records as
+    /// `Some(SpecialReason::Unknown)`. This clears that if another field explains it, and otherwise
+    /// replaces it with the likeliest hidden reason.
+    pub(crate) fn resolve_special_reason(&mut self) {
+        if self.special_reason().is_none() {
+            return;
+        }
+
+        let reason = if self.shown_special_reasons().is_empty() {
+            Some(self.hidden_special_reason())
+        } else {
+            None
+        };
+        if let Some(special_reason) = self.special_reason_mut() {
+            *special_reason = reason;
+        }
     }
-}
\ No newline at end of file
+}
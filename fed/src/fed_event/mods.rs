@@ -0,0 +1,179 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use with_structure::{MonostateStructure, WithStructure};
+
+macro_rules! mods {
+    ($($(#[$attr:meta])* $variant:ident => $id:literal, $name:literal;)*) => {
+        /// A mod (short for modification) that a player, team, item, or stadium can have. Serialized
+        /// as its internal ID, e.g. "ON_FIRE", so it's compatible with the feed's metadata and with
+        /// previously saved events.
+        ///
+        /// Mods that aren't in this list are kept as [`Mod::Unknown`] with their internal ID.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum Mod {
+            $($(#[$attr])* $variant,)*
+            /// A mod that isn't in this list. Contains the mod's internal ID.
+            Unknown(String),
+        }
+
+        impl Mod {
+            /// Internal ID of the mod, as it appears in event metadata
+            pub fn id(&self) -> &str {
+                match self {
+                    $(Mod::$variant => { $id })*
+                    Mod::Unknown(id) => { id }
+                }
+            }
+
+            /// Name of the mod as it's shown on the site. For unknown mods this is the internal ID.
+            pub fn display_name(&self) -> &str {
+                match self {
+                    $(Mod::$variant => { $name })*
+                    Mod::Unknown(id) => { id }
+                }
+            }
+
+            /// Looks up a mod by its internal ID. IDs that aren't known become [`Mod::Unknown`].
+            pub fn from_id(id: &str) -> Self {
+                match id {
+                    $($id => { Mod::$variant })*
+                    _ => { Mod::Unknown(id.to_string()) }
+                }
+            }
+        }
+    };
+}
+
+mods! {
+    Acidic => "ACIDIC", "Acidic";
+    AffinityForCrows => "AFFINITY_FOR_CROWS", "Affinity for Crows";
+    AfterParty => "AFTER_PARTY", "After Party";
+    Alternate => "ALTERNATE", "Alternate";
+    BaseInstincts => "BASE_INSTINCTS", "Base Instincts";
+    Blaserunning => "BLASERUNNING", "Blaserunning";
+    Chunky => "CHUNKY", "Chunky";
+    /// Shown on the site as Observed
+    CoffeePeril => "COFFEE_PERIL", "Observed";
+    /// Shown on the site as Free Refill
+    CoffeeRally => "COFFEE_RALLY", "Free Refill";
+    CrimeScene => "CRIME_SCENE", "Crime Scene";
+    Earlbirds => "EARLBIRDS", "Earlbirds";
+    EarlyToParty => "EARLY_TO_PARTY", "Early to the Party";
+    Echo => "ECHO", "Echo";
+    Elsewhere => "ELSEWHERE", "Elsewhere";
+    Fiery => "FIERY", "Fiery";
+    FireEater => "FIRE_EATER", "Fire Eater";
+    Fireproof => "FIREPROOF", "Fireproof";
+    Flickering => "FLICKERING", "Flickering";
+    FreeWill => "FREE_WILL", "Free Will";
+    FriendOfCrows => "FRIEND_OF_CROWS", "Friend of Crows";
+    Haunted => "HAUNTED", "Haunted";
+    HighPressure => "HIGH_PRESSURE", "High Pressure";
+    Homebody => "HOMEBODY", "Homebody";
+    Inhabiting => "INHABITING", "Inhabiting";
+    LateToParty => "LATE_TO_PARTY", "Late to the Party";
+    Magmatic => "MAGMATIC", "Magmatic";
+    Marked => "MARKED", "Marked";
+    Middling => "MIDDLING", "Middling";
+    /// Shown on the site as Red Hot
+    OnFire => "ON_FIRE", "Red Hot";
+    Overperforming => "OVERPERFORMING", "Overperforming";
+    OverUnder => "OVERUNDER", "Over Under";
+    Parasite => "PARASITE", "Parasite";
+    PartyTime => "PARTY_TIME", "Party Time";
+    Perk => "PERK", "Perk";
+    Psychic => "PSYCHIC", "Psychic";
+    Psychoacoustics => "PSYCHOACOUSTICS", "Psychoacoustics";
+    Receiver => "RECEIVER", "Receiver";
+    Repeating => "REPEATING", "Repeating";
+    Reverberating => "REVERBERATING", "Reverberating";
+    Scattered => "SCATTERED", "Scattered";
+    Shelled => "SHELLED", "Shelled";
+    SinkingShip => "SINKING_SHIP", "Sinking Ship";
+    Siphon => "SIPHON", "Siphon";
+    Smooth => "SMOOTH", "Smooth";
+    Spicy => "SPICY", "Spicy";
+    Static => "STATIC", "Static";
+    Superallergic => "SUPERALLERGIC", "Superallergic";
+    Superyummy => "SUPERYUMMY", "Superyummy";
+    /// Added to a player who's temporarily on another team, e.g. after a Consumer attack. Not shown
+    /// on the site.
+    TempStolen => "TEMP_STOLEN", "Temporarily Stolen";
+    Tired => "TIRED", "Tired";
+    TripleThreat => "TRIPLE_THREAT", "Triple Threat";
+    Underperforming => "UNDERPERFORMING", "Underperforming";
+    UnderOver => "UNDEROVER", "Under Over";
+    Undersea => "UNDERSEA", "Undersea";
+    Unstable => "UNSTABLE", "Unstable";
+    Wired => "WIRED", "Wired";
+}
+
+impl Mod {
+    /// Whether this mod is in the catalog, i.e. isn't [`Mod::Unknown`]
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Mod::Unknown(_))
+    }
+}
+
+impl Display for Mod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl FromStr for Mod {
+    type Err = Infallible;
+
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        Ok(Mod::from_id(id))
+    }
+}
+
+impl From<&str> for Mod {
+    fn from(id: &str) -> Self {
+        Mod::from_id(id)
+    }
+}
+
+impl From<String> for Mod {
+    fn from(id: String) -> Self {
+        match Mod::from_id(&id) {
+            // Reuse the allocation
+            Mod::Unknown(_) => { Mod::Unknown(id) }
+            known => { known }
+        }
+    }
+}
+
+impl From<Mod> for String {
+    fn from(value: Mod) -> Self {
+        match value {
+            Mod::Unknown(id) => { id }
+            known => { known.id().to_string() }
+        }
+    }
+}
+
+// Mods are serialized as their internal ID, so the schema is the same as a string's
+impl JsonSchema for Mod {
+    fn schema_name() -> String {
+        "Mod".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        String::json_schema(generator)
+    }
+}
+
+impl WithStructure for Mod {
+    type Structure = MonostateStructure;
+
+    fn structure(&self) -> Self::Structure { MonostateStructure }
+}
@@ -0,0 +1,504 @@
+use uuid::Uuid;
+
+use crate::{
+    Echo,
+    EchoIntoStatic,
+    FedEventData,
+    FeedbackPlayerData,
+    FloodingSweptEffect,
+    FreeRefill,
+    ItemDamaged,
+    ModChangeSubEventWithNamedPlayer,
+    PlayerInfo,
+    PlayerMovedTeams,
+    PlayerReverb,
+    PlayerStatChange,
+    ReturnFromElsewhereFlavor,
+    ReverbType,
+    Scores,
+    StoppedInhabiting,
+    TogglePerforming,
+};
+
+/// A player that an event names, with the uuid the event gives them
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NamedPlayer<'a> {
+    pub player_id: Uuid,
+    pub player_name: &'a str,
+}
+
+/// Collects named players in the order they're pushed
+#[derive(Default)]
+struct NamedPlayers<'a>(Vec<NamedPlayer<'a>>);
+
+impl<'a> NamedPlayers<'a> {
+    fn push(&mut self, player_id: Uuid, player_name: &'a str) {
+        self.0.push(NamedPlayer { player_id, player_name });
+    }
+
+    fn info(&mut self, player: &'a PlayerInfo) {
+        self.push(player.player_id, &player.player_name);
+    }
+
+    fn named(&mut self, change: &'a ModChangeSubEventWithNamedPlayer) {
+        self.push(change.player_id, &change.player_name);
+    }
+
+    fn stat_change(&mut self, change: &'a PlayerStatChange) {
+        self.push(change.player_id, &change.player_name);
+    }
+
+    fn free_refill(&mut self, free_refill: &'a FreeRefill) {
+        self.push(free_refill.player_id, &free_refill.player_name);
+    }
+
+    fn scores(&mut self, scores: &'a Scores) {
+        for score in &scores.scores {
+            self.push(score.player_id, &score.player_name);
+        }
+        for free_refill in &scores.free_refills {
+            self.free_refill(free_refill);
+        }
+    }
+
+    fn stopped_inhabiting(&mut self, stopped_inhabiting: &'a Option<StoppedInhabiting>) {
+        if let Some(stopped) = stopped_inhabiting {
+            self.push(stopped.inhabiting_player_id, &stopped.inhabiting_player_name);
+        }
+    }
+
+    /// Damage to the item of a player whose name is elsewhere in the event
+    fn item_damage(&mut self, damage: &Option<ItemDamaged>, player_name: &'a str) {
+        if let Some(damage) = damage {
+            self.push(damage.player_id, player_name);
+        }
+    }
+
+    fn named_item_damages(&mut self, damages: impl IntoIterator<Item=&'a (String, ItemDamaged)>) {
+        for (player_name, damage) in damages {
+            self.push(damage.player_id, player_name);
+        }
+    }
+
+    fn moved(&mut self, moved: &'a PlayerMovedTeams) {
+        self.push(moved.player_id, &moved.player_name);
+    }
+
+    fn toggle(&mut self, toggle: &'a TogglePerforming) {
+        self.push(toggle.player_id, &toggle.player_name);
+    }
+
+    fn feedback(&mut self, player: &'a FeedbackPlayerData) {
+        self.push(player.player_id, &player.player_name);
+    }
+
+    fn echo(&mut self, echo: &'a Echo) {
+        self.push(echo.receiver_id, &echo.receiver_name);
+    }
+
+    fn echo_into_static(&mut self, echo: &'a EchoIntoStatic) {
+        self.push(echo.player_id, &echo.player_name);
+    }
+}
+
+impl FedEventData {
+    /// Every player this event names along with their uuid, in the order the event's fields list
+    /// them. A player can appear more than once. Names the event has without a uuid, like the
+    /// batter in BatterUp, aren't included, and neither are uuids without a name.
+    pub fn named_players(&self) -> Vec<NamedPlayer<'_>> {
+        let mut players = NamedPlayers::default();
+
+        if let Some(game) = self.game() {
+            if let Some(unscatter) = &game.unscatter {
+                players.push(unscatter.player_id, &unscatter.player_name);
+            }
+            if let Some(attractor) = &game.attractor_secret_base {
+                players.info(attractor);
+            }
+        }
+
+        match self {
+            FedEventData::BeingSpeech { .. } => {}
+            FedEventData::LetsGo { .. } => {}
+            FedEventData::PlayBall { .. } => {}
+            FedEventData::HalfInningStart { .. } => {}
+            FedEventData::BatterUp { batter_name, inhabiting, .. } => {
+                if let Some(inhabiting) = inhabiting {
+                    players.push(inhabiting.inhabiting_player_id, batter_name);
+                    players.push(inhabiting.inhabited_player_id, &inhabiting.inhabited_player_name);
+                }
+            }
+            FedEventData::SuperyummyGameStart { toggle, .. } => {
+                players.toggle(toggle);
+            }
+            FedEventData::EchoedSuperyummyGameStart { .. } => {}
+            FedEventData::Ball { batter_item_damage, .. } |
+            FedEventData::FoulBall { batter_item_damage, .. } => {
+                players.named_item_damages(batter_item_damage);
+            }
+            FedEventData::StrikeSwinging { pitcher_item_damage, .. } |
+            FedEventData::StrikeLooking { pitcher_item_damage, .. } |
+            FedEventData::StrikeFlinching { pitcher_item_damage, .. } => {
+                players.named_item_damages(pitcher_item_damage);
+            }
+            FedEventData::Flyout { batter_name, fielder_name, scores, stopped_inhabiting, cooled_off, batter_debt, batter_item_damage, fielder_item_damage, other_player_item_damage, .. } => {
+                players.scores(scores);
+                players.stopped_inhabiting(stopped_inhabiting);
+                if let Some(cooled_off) = cooled_off {
+                    players.push(cooled_off.player_id, batter_name);
+                }
+                if let Some(batter_debt) = batter_debt {
+                    players.push(batter_debt.batter_id, batter_name);
+                    players.push(batter_debt.fielder_id, fielder_name);
+                }
+                players.item_damage(batter_item_damage, batter_name);
+                players.item_damage(fielder_item_damage, fielder_name);
+                players.named_item_damages(other_player_item_damage);
+            }
+            FedEventData::GroundOut { batter_name, fielder_name, scores, stopped_inhabiting, cooled_off, batter_debt, batter_item_damage, pitcher_item_damage, fielder_item_damage, .. } => {
+                players.scores(scores);
+                players.stopped_inhabiting(stopped_inhabiting);
+                if let Some(cooled_off) = cooled_off {
+                    players.push(cooled_off.player_id, batter_name);
+                }
+                if let Some(batter_debt) = batter_debt {
+                    players.push(batter_debt.batter_id, batter_name);
+                    players.push(batter_debt.fielder_id, fielder_name);
+                }
+                players.item_damage(batter_item_damage, batter_name);
+                players.named_item_damages(pitcher_item_damage);
+                players.item_damage(fielder_item_damage, fielder_name);
+            }
+            FedEventData::FieldersChoice { batter_name, scores, stopped_inhabiting, cooled_off, damaged_items, .. } => {
+                players.scores(scores);
+                players.stopped_inhabiting(stopped_inhabiting);
+                if let Some(cooled_off) = cooled_off {
+                    players.push(cooled_off.player_id, batter_name);
+                }
+                players.named_item_damages(damaged_items);
+            }
+            FedEventData::DoublePlay { batter_name, scores, stopped_inhabiting, cooled_off, .. } => {
+                players.scores(scores);
+                players.stopped_inhabiting(stopped_inhabiting);
+                if let Some(cooled_off) = cooled_off {
+                    players.push(cooled_off.player_id, batter_name);
+                }
+            }
+            FedEventData::Hit { batter_name, batter_id, scores, stopped_inhabiting, pitcher_item_damage, batter_item_damage, other_player_item_damage, .. } => {
+                players.push(*batter_id, batter_name);
+                players.scores(scores);
+                players.stopped_inhabiting(stopped_inhabiting);
+                players.named_item_damages(pitcher_item_damage);
+                players.item_damage(batter_item_damage, batter_name);
+                players.named_item_damages(other_player_item_damage);
+            }
+            FedEventData::HomeRun { batter_name, batter_id, stopped_inhabiting, free_refills, attraction, damaged_items, .. } => {
+                players.push(*batter_id, batter_name);
+                players.stopped_inhabiting(stopped_inhabiting);
+                for free_refill in free_refills {
+                    players.free_refill(free_refill);
+                }
+                if let Some(attraction) = attraction {
+                    players.push(attraction.player_id, &attraction.player_name);
+                }
+                players.named_item_damages(damaged_items);
+            }
+            FedEventData::StolenBase { runner_name, runner_id, free_refill, runner_item_damage, .. } => {
+                players.push(*runner_id, runner_name);
+                if let Some(free_refill) = free_refill {
+                    players.free_refill(free_refill);
+                }
+                players.item_damage(runner_item_damage, runner_name);
+            }
+            FedEventData::CaughtStealing { .. } => {}
+            FedEventData::StrikeoutSwinging { stopped_inhabiting, pitcher_item_damage, free_refill, parasite, .. } |
+            FedEventData::StrikeoutLooking { stopped_inhabiting, pitcher_item_damage, free_refill, parasite, .. } => {
+                players.stopped_inhabiting(stopped_inhabiting);
+                players.named_item_damages(pitcher_item_damage);
+                if let Some(free_refill) = free_refill {
+                    players.free_refill(free_refill);
+                }
+                if let Some(parasite) = parasite {
+                    players.push(parasite.pitcher_id, &parasite.pitcher_name);
+                    players.push(parasite.batter_id, &parasite.batter_name);
+                }
+            }
+            FedEventData::Walk { batter_name, batter_id, scores, batter_item_damage, stopped_inhabiting, .. } => {
+                players.push(*batter_id, batter_name);
+                players.scores(scores);
+                players.item_damage(batter_item_damage, batter_name);
+                players.stopped_inhabiting(stopped_inhabiting);
+            }
+            FedEventData::InningEnd { lost_triple_threat, .. } => {
+                for change in lost_triple_threat {
+                    players.named(change);
+                }
+            }
+            FedEventData::CharmStrikeout { charmer_id, charmer_name, charmed_id, charmed_name, stopped_inhabiting, .. } => {
+                players.push(*charmer_id, charmer_name);
+                players.push(*charmed_id, charmed_name);
+                players.stopped_inhabiting(stopped_inhabiting);
+            }
+            FedEventData::StrikeZapped { .. } => {}
+            FedEventData::PeanutFlavorText { .. } => {}
+            FedEventData::GameEnd { temp_stolen_player_returned, .. } => {
+                if let Some(returned) = temp_stolen_player_returned {
+                    players.moved(returned);
+                }
+            }
+            FedEventData::MildPitch { pitcher_id, pitcher_name, scores, .. } => {
+                players.push(*pitcher_id, pitcher_name);
+                players.scores(scores);
+            }
+            FedEventData::MildPitchWalk { pitcher_id, pitcher_name, batter_id, batter_name, scores, .. } => {
+                players.push(*pitcher_id, pitcher_name);
+                players.push(*batter_id, batter_name);
+                players.scores(scores);
+            }
+            FedEventData::CoffeeBean { player_id, player_name, .. } |
+            FedEventData::BecameMagmatic { player_id, player_name, .. } |
+            FedEventData::PlayerModExpires { player_id, player_name, .. } |
+            FedEventData::GainFreeRefill { player_id, player_name, .. } |
+            FedEventData::AllergicReaction { player_id, player_name, .. } |
+            FedEventData::BestowReverberating { player_id, player_name, .. } |
+            FedEventData::UnderOver { player_id, player_name, .. } |
+            FedEventData::OverUnder { player_id, player_name, .. } |
+            FedEventData::Party { player_id, player_name, .. } |
+            FedEventData::PlayerHatched { player_id, player_name } |
+            FedEventData::PostseasonBirth { player_id, player_name, .. } |
+            FedEventData::PlayerBoosted { player_id, player_name, .. } |
+            FedEventData::PlayerJoinedILB { player_id, player_name } |
+            FedEventData::PlayerPermittedToStay { player_id, player_name } |
+            FedEventData::FireproofIncineration { player_id, player_name, .. } |
+            FedEventData::PeanutMister { player_id, player_name, .. } |
+            FedEventData::PlayerNamedMvp { player_id, player_name, .. } |
+            FedEventData::BirdsUnshell { player_id, player_name, .. } |
+            FedEventData::PlayerCalledBackToHall { player_id, player_name } |
+            FedEventData::PlayerLostMod { player_id, player_name, .. } |
+            FedEventData::PlayerPulledThroughRift { player_id, player_name } |
+            FedEventData::PlayerLocalized { player_id, player_name, .. } |
+            FedEventData::EnterCrimeScene { player_id, player_name, .. } |
+            FedEventData::ReturnFromInvestigation { player_id, player_name, .. } |
+            FedEventData::GrindRail { player_id, player_name, .. } |
+            FedEventData::EnterSecretBase { player_id, player_name, .. } |
+            FedEventData::ExitSecretBase { player_id, player_name, .. } |
+            FedEventData::EchoChamber { player_id, player_name, .. } |
+            FedEventData::Roam { player_id, player_name, .. } |
+            FedEventData::ModsFromAnotherModRemoved { player_id, player_name, .. } |
+            FedEventData::EarlbirdsAddedToPlayer { player_id, player_name, .. } |
+            FedEventData::EarlbirdsRemovedFromPlayer { player_id, player_name, .. } |
+            FedEventData::CommunityChestOpens { player_id, player_name, .. } |
+            FedEventData::PlayerDropsItem { player_id, player_name, .. } => {
+                players.push(*player_id, player_name);
+            }
+            FedEventData::Blooddrain { sipper, sipped, .. } => {
+                players.stat_change(sipper);
+                players.stat_change(sipped);
+            }
+            FedEventData::SpecialBlooddrain { sipper_id, sipper_name, sipped_id, sipped_name, .. } => {
+                players.push(*sipper_id, sipper_name);
+                players.push(*sipped_id, sipped_name);
+            }
+            FedEventData::TeamModExpires { .. } => {}
+            FedEventData::BirdsCircle { .. } => {}
+            FedEventData::AmbushedByCrows { batter_id, batter_name, friend_of_crows, .. } => {
+                players.push(*batter_id, batter_name);
+                if let Some(pitcher) = friend_of_crows {
+                    players.push(pitcher.pitcher_id, &pitcher.pitcher_name);
+                }
+            }
+            FedEventData::Sun2SetWin { .. } => {}
+            FedEventData::BlackHoleSwallowedWin { .. } => {}
+            FedEventData::Sun2 { caught_some_rays, .. } => {
+                if let Some(change) = caught_some_rays {
+                    players.stat_change(change);
+                }
+            }
+            FedEventData::BlackHole { carcinization, compressed_by_gamma, .. } => {
+                if let Some(carcinization) = carcinization {
+                    players.moved(&carcinization.mv);
+                }
+                if let Some(change) = compressed_by_gamma {
+                    players.stat_change(change);
+                }
+            }
+            FedEventData::TeamDidShame { .. } => {}
+            FedEventData::TeamWasShamed { .. } => {}
+            FedEventData::CharmWalk { batter_id, batter_name, pitcher_name, pitcher_item_damage, batter_item_damage, scores, .. } => {
+                players.push(*batter_id, batter_name);
+                players.item_damage(pitcher_item_damage, pitcher_name);
+                players.item_damage(batter_item_damage, batter_name);
+                players.scores(scores);
+            }
+            FedEventData::PerkUp { players: perked_up, .. } => {
+                for change in perked_up {
+                    players.named(change);
+                }
+            }
+            FedEventData::Feedback { players: (player_a, player_b), .. } => {
+                players.feedback(player_a);
+                players.feedback(player_b);
+            }
+            FedEventData::Reverb { reverb_type, gravity_players, .. } => {
+                if let ReverbType::SeveralPlayers(reverbs) = reverb_type {
+                    for reverb in reverbs {
+                        if let PlayerReverb::Reverb { first_player_id, first_player_name, second_player_id, second_player_name, .. } = reverb {
+                            players.push(*first_player_id, first_player_name);
+                            players.push(*second_player_id, second_player_name);
+                        }
+                    }
+                }
+                for player in gravity_players {
+                    players.info(player);
+                }
+            }
+            FedEventData::TarotReading { .. } => {}
+            FedEventData::TarotReadingAddedOrRemovedMod { .. } => {}
+            FedEventData::TeamEnteredPartyTime { .. } => {}
+            FedEventData::BecomeTripleThreat { pitchers, .. } => {
+                for change in pitchers {
+                    players.named(change);
+                }
+            }
+            FedEventData::TasteTheInfinite { sheller_id, sheller_name, shellee_id, shellee_name, .. } => {
+                players.push(*sheller_id, sheller_name);
+                players.push(*shellee_id, shellee_name);
+            }
+            FedEventData::BatterSkipped { .. } => {}
+            FedEventData::FeedbackBlocked { resisted_id, resisted_name, tangled_id, tangled_name, .. } => {
+                players.push(*resisted_id, resisted_name);
+                players.push(*tangled_id, tangled_name);
+            }
+            FedEventData::FlagPlanted { .. } => {}
+            FedEventData::EmergencyAlert { .. } => {}
+            FedEventData::TeamJoinedILB { .. } => {}
+            FedEventData::FloodingSwept { effects, free_refills, .. } => {
+                for effect in effects {
+                    match effect {
+                        FloodingSweptEffect::Elsewhere(change) => { players.named(change) }
+                        FloodingSweptEffect::Flippers(player) => { players.info(player) }
+                        FloodingSweptEffect::Ego(player) => { players.info(player) }
+                    }
+                }
+                for free_refill in free_refills {
+                    players.free_refill(free_refill);
+                }
+            }
+            FedEventData::ReturnFromElsewhere { player_name, flavor, .. } => {
+                match flavor {
+                    ReturnFromElsewhereFlavor::Full { player_id, recongealed_differently, .. } => {
+                        players.push(*player_id, player_name);
+                        if let Some(change) = recongealed_differently {
+                            players.stat_change(change);
+                        }
+                    }
+                    ReturnFromElsewhereFlavor::Short { player_id, .. } => {
+                        players.push(*player_id, player_name);
+                    }
+                    ReturnFromElsewhereFlavor::False { .. } => {}
+                }
+            }
+            FedEventData::Incineration { victim_id, victim_name, replacement_id, replacement_name, unstable_chain, .. } => {
+                players.push(*victim_id, victim_name);
+                players.push(*replacement_id, replacement_name);
+                if let Some(change) = unstable_chain {
+                    players.named(change);
+                }
+            }
+            FedEventData::PitcherChange { pitcher_id, pitcher_name, .. } => {
+                players.push(*pitcher_id, pitcher_name);
+            }
+            FedEventData::FinalStandings { .. } => {}
+            FedEventData::TeamLeftPartyTimeForPostseason { .. } => {}
+            FedEventData::EarnedPostseasonSlot { .. } => {}
+            FedEventData::PostseasonAdvance { .. } => {}
+            FedEventData::PostseasonEliminated { .. } => {}
+            FedEventData::TeamWonInternetSeries { .. } => {}
+            FedEventData::BottomDwellers { .. } => {}
+            FedEventData::WillReceived { .. } => {}
+            FedEventData::BlessingWon { .. } => {}
+            FedEventData::EarlbirdsAddedToTeam { .. } => {}
+            FedEventData::DecreePassed { .. } => {}
+            FedEventData::LineupSorted { .. } => {}
+            FedEventData::EarlbirdsRemovedFromTeam { .. } => {}
+            FedEventData::Undersea { .. } => {}
+            FedEventData::RenovationBuilt { .. } => {}
+            FedEventData::LateToThePartyAdded { .. } => {}
+            FedEventData::LateToThePartyRemoved { .. } => {}
+            FedEventData::ReplaceReturnedPlayerFromShadows { promoted_player_id, promoted_player_name, removed_player_id, removed_player_name, .. } => {
+                players.push(*promoted_player_id, promoted_player_name);
+                players.push(*removed_player_id, removed_player_name);
+            }
+            FedEventData::TeamUsedFreeWill { .. } => {}
+            FedEventData::InvestigationMessage { .. } => {}
+            FedEventData::HighPressure { .. } => {}
+            FedEventData::Echo { primary_echo, receiver_echos, .. } => {
+                players.echo(primary_echo);
+                for echo in receiver_echos {
+                    players.echo(echo);
+                }
+            }
+            FedEventData::SolarPanelsAwait { .. } => {}
+            FedEventData::EchoIntoStatic { echoer, echoee, .. } => {
+                players.echo_into_static(echoer);
+                players.echo_into_static(echoee);
+            }
+            FedEventData::Psychoacoustics { .. } => {}
+            FedEventData::EchoReceiver { echoee_name, echoee_id, .. } => {
+                players.push(*echoee_id, echoee_name);
+            }
+            FedEventData::ConsumerAttack { sensed_something_fishy, .. } => {
+                // The attacked player's name is only given in all caps
+                if let Some(detective) = sensed_something_fishy {
+                    players.push(detective.detective_id, &detective.detective_name);
+                }
+            }
+            FedEventData::TeamGainedFreeWill { .. } => {}
+            FedEventData::Tidings { .. } => {}
+            FedEventData::HomebodyGameStart { homebodies, .. } => {
+                for toggle in homebodies {
+                    players.toggle(toggle);
+                }
+            }
+            FedEventData::SalmonSwim { item_restored, player_expelled, .. } => {
+                if let Some(item) = item_restored {
+                    players.push(item.player_id, &item.player_name);
+                }
+                if let Some(change) = player_expelled {
+                    players.named(change);
+                }
+            }
+            FedEventData::HitByPitch { pitcher_id, pitcher_name, batter_id, batter_name, scores, .. } => {
+                players.push(*pitcher_id, pitcher_name);
+                players.push(*batter_id, batter_name);
+                players.scores(scores);
+            }
+            FedEventData::SolarPanelsActivate { .. } => {}
+            FedEventData::RunsOverflowing { .. } => {}
+            FedEventData::TeamMiddling { .. } => {}
+            FedEventData::InvestigationConcluded { .. } => {}
+            FedEventData::GlitterCrate { player_name, gained_item, .. } => {
+                players.push(gained_item.player_id, player_name);
+            }
+            FedEventData::ConsumerExpelled { .. } => {}
+            FedEventData::MindTrickWalk { batter_id, batter_name, scores, .. } => {
+                players.push(*batter_id, batter_name);
+                players.scores(scores);
+            }
+            FedEventData::MindTrickStrikeout { batter_id, batter_name, .. } => {
+                players.push(*batter_id, batter_name);
+            }
+            FedEventData::BlooddrainBlocked { sipper_id, sipper_name, sippee_id, sippee_name, .. } => {
+                players.push(*sipper_id, sipper_name);
+                players.push(*sippee_id, sippee_name);
+            }
+            FedEventData::TarotReadingAddedOrRemovedItem { .. } => {}
+            FedEventData::PlayerMiddling { change_event, .. } => {
+                players.named(change_event);
+            }
+            FedEventData::CommunityChestGameMessage { .. } => {}
+        }
+
+        players.0
+    }
+}
@@ -0,0 +1,436 @@
+use uuid::Uuid;
+
+use crate::{
+    ConsumerAttackEffect,
+    FedEventData,
+    FloodingSweptEffect,
+    FreeRefill,
+    ItemDamaged,
+    PlayerMovedTeams,
+    ReturnFromElsewhereFlavor,
+    Scores,
+    StoppedInhabiting,
+};
+
+/// Collects team uuids in the order they're pushed
+#[derive(Default)]
+struct TeamIds(Vec<Uuid>);
+
+impl TeamIds {
+    fn push(&mut self, team_id: Uuid) {
+        self.0.push(team_id);
+    }
+
+    fn extend(&mut self, team_ids: impl IntoIterator<Item=Uuid>) {
+        self.0.extend(team_ids);
+    }
+
+    fn free_refill(&mut self, free_refill: &FreeRefill) {
+        self.extend(free_refill.team_id);
+    }
+
+    fn scores(&mut self, scores: &Scores) {
+        for score in &scores.scores {
+            if let Some(damage) = &score.item_damage {
+                self.push(damage.team_id);
+            }
+            if let Some(attraction) = &score.attraction {
+                self.push(attraction.team_id);
+            }
+        }
+        for free_refill in &scores.free_refills {
+            self.free_refill(free_refill);
+        }
+    }
+
+    fn stopped_inhabiting(&mut self, stopped_inhabiting: &Option<StoppedInhabiting>) {
+        if let Some(stopped) = stopped_inhabiting {
+            self.extend(stopped.inhabiting_player_team_id);
+        }
+    }
+
+    fn item_damages<'a>(&mut self, damages: impl IntoIterator<Item=&'a ItemDamaged>) {
+        for damage in damages {
+            self.push(damage.team_id);
+        }
+    }
+
+    fn named_item_damages<'a>(&mut self, damages: impl IntoIterator<Item=&'a (String, ItemDamaged)>) {
+        self.item_damages(damages.into_iter().map(|(_, damage)| damage));
+    }
+
+    fn moved(&mut self, moved: &PlayerMovedTeams) {
+        self.push(moved.previous_team_id);
+        self.push(moved.new_team_id);
+    }
+}
+
+impl FedEventData {
+    /// The uuid of every team this event refers to, in the order the event's fields list them,
+    /// starting with the game's home and away teams for game events. A team can appear more than
+    /// once.
+    pub fn team_ids(&self) -> Vec<Uuid> {
+        let mut teams = TeamIds::default();
+
+        if let Some(game) = self.game() {
+            teams.push(game.home_team);
+            teams.push(game.away_team);
+            if let Some(unscatter) = &game.unscatter {
+                teams.push(unscatter.team_id);
+            }
+        }
+
+        match self {
+            FedEventData::BeingSpeech { .. } => {}
+            FedEventData::LetsGo { .. } => {}
+            FedEventData::PlayBall { .. } => {}
+            FedEventData::HalfInningStart { subseasonal_mod_effects, .. } => {
+                teams.extend(subseasonal_mod_effects.iter().map(|effect| effect.team_id));
+            }
+            FedEventData::BatterUp { inhabiting, .. } => {
+                if let Some(inhabiting) = inhabiting {
+                    teams.extend(inhabiting.inhabiting_player_team_id);
+                }
+            }
+            FedEventData::SuperyummyGameStart { toggle, .. } => {
+                teams.push(toggle.team_id);
+            }
+            FedEventData::EchoedSuperyummyGameStart { .. } => {}
+            FedEventData::Ball { batter_item_damage, .. } |
+            FedEventData::FoulBall { batter_item_damage, .. } => {
+                teams.named_item_damages(batter_item_damage);
+            }
+            FedEventData::StrikeSwinging { pitcher_item_damage, .. } |
+            FedEventData::StrikeLooking { pitcher_item_damage, .. } |
+            FedEventData::StrikeFlinching { pitcher_item_damage, .. } => {
+                teams.named_item_damages(pitcher_item_damage);
+            }
+            FedEventData::Flyout { scores, stopped_inhabiting, cooled_off, batter_debt, batter_item_damage, fielder_item_damage, other_player_item_damage, parasite, .. } => {
+                teams.scores(scores);
+                teams.stopped_inhabiting(stopped_inhabiting);
+                if let Some(cooled_off) = cooled_off {
+                    teams.push(cooled_off.team_id);
+                }
+                if let Some(sub_event) = batter_debt.as_ref().and_then(|debt| debt.sub_event.as_ref()) {
+                    teams.push(sub_event.team_id);
+                }
+                teams.item_damages(batter_item_damage);
+                teams.item_damages(fielder_item_damage);
+                teams.named_item_damages(other_player_item_damage);
+                if let Some(parasite) = parasite {
+                    teams.push(parasite.pitcher_team_id);
+                    teams.push(parasite.batter_team_id);
+                }
+            }
+            FedEventData::GroundOut { scores, stopped_inhabiting, cooled_off, batter_debt, batter_item_damage, pitcher_item_damage, fielder_item_damage, .. } => {
+                teams.scores(scores);
+                teams.stopped_inhabiting(stopped_inhabiting);
+                if let Some(cooled_off) = cooled_off {
+                    teams.push(cooled_off.team_id);
+                }
+                if let Some(sub_event) = batter_debt.as_ref().and_then(|debt| debt.sub_event.as_ref()) {
+                    teams.push(sub_event.team_id);
+                }
+                teams.item_damages(batter_item_damage);
+                teams.named_item_damages(pitcher_item_damage);
+                teams.item_damages(fielder_item_damage);
+            }
+            FedEventData::FieldersChoice { scores, stopped_inhabiting, cooled_off, damaged_items, .. } => {
+                teams.scores(scores);
+                teams.stopped_inhabiting(stopped_inhabiting);
+                if let Some(cooled_off) = cooled_off {
+                    teams.push(cooled_off.team_id);
+                }
+                teams.named_item_damages(damaged_items);
+            }
+            FedEventData::DoublePlay { scores, stopped_inhabiting, cooled_off, .. } => {
+                teams.scores(scores);
+                teams.stopped_inhabiting(stopped_inhabiting);
+                if let Some(cooled_off) = cooled_off {
+                    teams.push(cooled_off.team_id);
+                }
+            }
+            FedEventData::Hit { scores, stopped_inhabiting, pitcher_item_damage, batter_item_damage, other_player_item_damage, .. } => {
+                teams.scores(scores);
+                teams.stopped_inhabiting(stopped_inhabiting);
+                teams.named_item_damages(pitcher_item_damage);
+                teams.item_damages(batter_item_damage);
+                teams.named_item_damages(other_player_item_damage);
+            }
+            FedEventData::HomeRun { magmatic, stopped_inhabiting, free_refills, attraction, damaged_items, .. } => {
+                if let Some(magmatic) = magmatic {
+                    teams.push(magmatic.team_id);
+                }
+                teams.stopped_inhabiting(stopped_inhabiting);
+                for free_refill in free_refills {
+                    teams.free_refill(free_refill);
+                }
+                if let Some(attraction) = attraction {
+                    teams.push(attraction.team_id);
+                }
+                teams.named_item_damages(damaged_items);
+            }
+            FedEventData::StolenBase { free_refill, runner_item_damage, .. } => {
+                if let Some(free_refill) = free_refill {
+                    teams.free_refill(free_refill);
+                }
+                teams.item_damages(runner_item_damage);
+            }
+            FedEventData::CaughtStealing { .. } => {}
+            FedEventData::StrikeoutSwinging { stopped_inhabiting, pitcher_item_damage, free_refill, parasite, .. } |
+            FedEventData::StrikeoutLooking { stopped_inhabiting, pitcher_item_damage, free_refill, parasite, .. } => {
+                teams.stopped_inhabiting(stopped_inhabiting);
+                teams.named_item_damages(pitcher_item_damage);
+                if let Some(free_refill) = free_refill {
+                    teams.free_refill(free_refill);
+                }
+                if let Some(parasite) = parasite {
+                    teams.push(parasite.pitcher_team_id);
+                    teams.push(parasite.batter_team_id);
+                }
+            }
+            FedEventData::Walk { scores, batter_item_damage, stopped_inhabiting, .. } => {
+                teams.scores(scores);
+                teams.item_damages(batter_item_damage);
+                teams.stopped_inhabiting(stopped_inhabiting);
+            }
+            FedEventData::InningEnd { lost_triple_threat, .. } => {
+                teams.extend(lost_triple_threat.iter().map(|change| change.team_id));
+            }
+            FedEventData::CharmStrikeout { stopped_inhabiting, .. } => {
+                teams.stopped_inhabiting(stopped_inhabiting);
+            }
+            FedEventData::StrikeZapped { .. } => {}
+            FedEventData::PeanutFlavorText { .. } => {}
+            FedEventData::GameEnd { winner_id, temp_stolen_player_returned, .. } => {
+                if !winner_id.is_nil() {
+                    teams.push(*winner_id);
+                }
+                if let Some(returned) = temp_stolen_player_returned {
+                    teams.moved(returned);
+                }
+            }
+            FedEventData::MildPitch { scores, .. } |
+            FedEventData::MildPitchWalk { scores, .. } |
+            FedEventData::MindTrickWalk { scores, .. } => {
+                teams.scores(scores);
+            }
+            FedEventData::CoffeeBean { team_id, .. } |
+            FedEventData::GainFreeRefill { team_id, .. } |
+            FedEventData::LateToThePartyAdded { team_id, .. } |
+            FedEventData::EchoChamber { team_id, .. } => {
+                teams.extend(*team_id);
+            }
+            FedEventData::BecameMagmatic { magmatic_mod_added, .. } => {
+                if let Some(change) = magmatic_mod_added {
+                    teams.push(change.team_id);
+                }
+            }
+            FedEventData::Blooddrain { sipper, sipped, .. } => {
+                teams.push(sipper.team_id);
+                teams.push(sipped.team_id);
+            }
+            FedEventData::SpecialBlooddrain { sipped_team_id, .. } => {
+                teams.push(*sipped_team_id);
+            }
+            FedEventData::PlayerModExpires { team_id, .. } |
+            FedEventData::TeamModExpires { team_id, .. } |
+            FedEventData::Sun2SetWin { team_id, .. } |
+            FedEventData::BlackHoleSwallowedWin { team_id, .. } |
+            FedEventData::AllergicReaction { team_id, .. } |
+            FedEventData::BestowReverberating { team_id, .. } |
+            FedEventData::Reverb { team_id, .. } |
+            FedEventData::TarotReadingAddedOrRemovedMod { team_id, .. } |
+            FedEventData::TeamEnteredPartyTime { team_id, .. } |
+            FedEventData::UnderOver { team_id, .. } |
+            FedEventData::OverUnder { team_id, .. } |
+            FedEventData::FlagPlanted { team_id, .. } |
+            FedEventData::TeamJoinedILB { team_id, .. } |
+            FedEventData::Party { team_id, .. } |
+            FedEventData::PostseasonBirth { team_id, .. } |
+            FedEventData::FinalStandings { team_id, .. } |
+            FedEventData::TeamLeftPartyTimeForPostseason { team_id, .. } |
+            FedEventData::EarnedPostseasonSlot { team_id, .. } |
+            FedEventData::PostseasonAdvance { team_id, .. } |
+            FedEventData::PostseasonEliminated { team_id, .. } |
+            FedEventData::PlayerBoosted { team_id, .. } |
+            FedEventData::TeamWonInternetSeries { team_id, .. } |
+            FedEventData::BottomDwellers { team_id, .. } |
+            FedEventData::WillReceived { team_id, .. } |
+            FedEventData::EarlbirdsAddedToTeam { team_id, .. } |
+            FedEventData::LineupSorted { team_id, .. } |
+            FedEventData::EarlbirdsRemovedFromTeam { team_id, .. } |
+            FedEventData::Undersea { team_id, .. } |
+            FedEventData::RenovationBuilt { team_id, .. } |
+            FedEventData::PlayerNamedMvp { team_id, .. } |
+            FedEventData::BirdsUnshell { team_id, .. } |
+            FedEventData::ReplaceReturnedPlayerFromShadows { team_id, .. } |
+            FedEventData::TeamUsedFreeWill { team_id, .. } |
+            FedEventData::PlayerLostMod { team_id, .. } |
+            FedEventData::HighPressure { team_id, .. } |
+            FedEventData::PlayerLocalized { team_id, .. } |
+            FedEventData::Psychoacoustics { team_id, .. } |
+            FedEventData::TeamGainedFreeWill { team_id, .. } |
+            FedEventData::InvestigationConcluded { team_id, .. } |
+            FedEventData::ModsFromAnotherModRemoved { team_id, .. } |
+            FedEventData::EarlbirdsAddedToPlayer { team_id, .. } |
+            FedEventData::EarlbirdsRemovedFromPlayer { team_id, .. } |
+            FedEventData::TarotReadingAddedOrRemovedItem { team_id, .. } |
+            FedEventData::CommunityChestOpens { team_id, .. } |
+            FedEventData::PlayerDropsItem { team_id, .. } => {
+                teams.push(*team_id);
+            }
+            FedEventData::BirdsCircle { .. } => {}
+            FedEventData::AmbushedByCrows { .. } => {}
+            FedEventData::Sun2 { caught_some_rays, .. } => {
+                if let Some(change) = caught_some_rays {
+                    teams.push(change.team_id);
+                }
+            }
+            FedEventData::BlackHole { carcinization, compressed_by_gamma, .. } => {
+                if let Some(carcinization) = carcinization {
+                    teams.moved(&carcinization.mv);
+                }
+                if let Some(change) = compressed_by_gamma {
+                    teams.push(change.team_id);
+                }
+            }
+            FedEventData::TeamDidShame { shaming_team_id, .. } => {
+                teams.push(*shaming_team_id);
+            }
+            FedEventData::TeamWasShamed { shamed_team_id, .. } => {
+                teams.push(*shamed_team_id);
+            }
+            FedEventData::CharmWalk { pitcher_item_damage, batter_item_damage, scores, .. } => {
+                teams.item_damages(pitcher_item_damage);
+                teams.item_damages(batter_item_damage);
+                teams.scores(scores);
+            }
+            FedEventData::PerkUp { players, .. } |
+            FedEventData::BecomeTripleThreat { pitchers: players, .. } => {
+                teams.extend(players.iter().map(|change| change.team_id));
+            }
+            FedEventData::Feedback { players: (player_a, player_b), .. } => {
+                teams.push(player_a.team_id);
+                teams.push(player_b.team_id);
+            }
+            FedEventData::TarotReading { team_tags, .. } |
+            FedEventData::EmergencyAlert { team_tags, .. } |
+            FedEventData::BlessingWon { team_tags, .. } => {
+                teams.extend(team_tags.iter().copied());
+            }
+            FedEventData::TasteTheInfinite { shellee_team_id, .. } => {
+                teams.push(*shellee_team_id);
+            }
+            FedEventData::BatterSkipped { .. } => {}
+            FedEventData::FeedbackBlocked { tangled_team_id, .. } => {
+                teams.push(*tangled_team_id);
+            }
+            FedEventData::FloodingSwept { effects, free_refills, .. } => {
+                for effect in effects {
+                    if let FloodingSweptEffect::Elsewhere(change) = effect {
+                        teams.push(change.team_id);
+                    }
+                }
+                for free_refill in free_refills {
+                    teams.free_refill(free_refill);
+                }
+            }
+            FedEventData::ReturnFromElsewhere { flavor, .. } => {
+                match flavor {
+                    ReturnFromElsewhereFlavor::Full { team_id, recongealed_differently, .. } => {
+                        teams.push(*team_id);
+                        if let Some(change) = recongealed_differently {
+                            teams.push(change.team_id);
+                        }
+                    }
+                    ReturnFromElsewhereFlavor::Short { team_id, .. } => {
+                        teams.push(*team_id);
+                    }
+                    ReturnFromElsewhereFlavor::False { .. } => {}
+                }
+            }
+            FedEventData::Incineration { team_id, unstable_chain, .. } => {
+                teams.push(*team_id);
+                if let Some(change) = unstable_chain {
+                    teams.push(change.team_id);
+                }
+            }
+            FedEventData::PitcherChange { .. } => {}
+            FedEventData::PlayerHatched { .. } => {}
+            FedEventData::DecreePassed { .. } => {}
+            FedEventData::PlayerJoinedILB { .. } => {}
+            FedEventData::PlayerPermittedToStay { .. } => {}
+            FedEventData::FireproofIncineration { .. } => {}
+            FedEventData::PeanutMister { superallergy, .. } => {
+                if let Some(change) = superallergy {
+                    teams.push(change.team_id);
+                }
+            }
+            FedEventData::LateToThePartyRemoved { .. } => {}
+            FedEventData::PlayerCalledBackToHall { .. } => {}
+            FedEventData::InvestigationMessage { .. } => {}
+            FedEventData::PlayerPulledThroughRift { .. } => {}
+            FedEventData::Echo { primary_echo, receiver_echos, .. } => {
+                teams.push(primary_echo.receiver_team_id);
+                teams.extend(receiver_echos.iter().map(|echo| echo.receiver_team_id));
+            }
+            FedEventData::SolarPanelsAwait { .. } => {}
+            FedEventData::EchoIntoStatic { echoer, echoee, .. } => {
+                teams.push(echoer.team_id);
+                teams.push(echoee.team_id);
+            }
+            FedEventData::EchoReceiver { echoee_team_id, .. } => {
+                teams.push(*echoee_team_id);
+            }
+            FedEventData::ConsumerAttack { team_id, effect, .. } => {
+                teams.push(*team_id);
+                if let ConsumerAttackEffect::DefendedWithItem(damage) = effect {
+                    teams.push(damage.team_id);
+                }
+            }
+            FedEventData::Tidings { .. } => {}
+            FedEventData::HomebodyGameStart { homebodies, .. } => {
+                teams.extend(homebodies.iter().map(|toggle| toggle.team_id));
+            }
+            FedEventData::SalmonSwim { item_restored, player_expelled, .. } => {
+                if let Some(item) = item_restored {
+                    teams.push(item.team_id);
+                }
+                if let Some(change) = player_expelled {
+                    teams.push(change.team_id);
+                }
+            }
+            FedEventData::HitByPitch { batter_team_id, scores, .. } => {
+                teams.push(*batter_team_id);
+                teams.scores(scores);
+            }
+            FedEventData::SolarPanelsActivate { .. } => {}
+            FedEventData::RunsOverflowing { .. } => {}
+            FedEventData::TeamMiddling { change_event, .. } => {
+                teams.push(change_event.team_id);
+            }
+            FedEventData::EnterCrimeScene { previous_team_id, new_team_id, .. } |
+            FedEventData::ReturnFromInvestigation { previous_team_id, new_team_id, .. } |
+            FedEventData::Roam { previous_team_id, new_team_id, .. } => {
+                teams.push(*previous_team_id);
+                teams.push(*new_team_id);
+            }
+            FedEventData::GrindRail { .. } => {}
+            FedEventData::EnterSecretBase { .. } => {}
+            FedEventData::ExitSecretBase { .. } => {}
+            FedEventData::GlitterCrate { gained_item, .. } => {
+                teams.push(gained_item.team_id);
+            }
+            FedEventData::ConsumerExpelled { .. } => {}
+            FedEventData::MindTrickStrikeout { .. } => {}
+            FedEventData::BlooddrainBlocked { .. } => {}
+            FedEventData::PlayerMiddling { change_event, .. } => {
+                teams.push(change_event.team_id);
+            }
+            FedEventData::CommunityChestGameMessage { .. } => {}
+        }
+
+        teams.0
+    }
+}
@@ -0,0 +1,65 @@
+use serde_json::Value;
+use thiserror::Error;
+use uuid::Uuid;
+use eventually_api::{EventType, EventuallyEvent};
+
+use crate::Mod;
+
+/// A field that the trackers need is missing from (or has the wrong type in) a feed event's
+/// metadata
+#[derive(Error, Debug)]
+#[error("{event_type:?} event {event_id} is missing metadata field {field}")]
+pub struct MissingMetadata {
+    pub event_type: EventType,
+    pub event_id: Uuid,
+    pub field: &'static str,
+}
+
+pub(crate) fn missing(feed_event: &EventuallyEvent, field: &'static str) -> MissingMetadata {
+    MissingMetadata {
+        event_type: feed_event.r#type,
+        event_id: feed_event.id,
+        field,
+    }
+}
+
+/// Gets a field from `metadata`, which is the event's own metadata or an object nested inside it.
+/// `feed_event` is only used to describe the error.
+pub(crate) fn value_in<'a>(feed_event: &EventuallyEvent, metadata: &'a Value, field: &'static str) -> Result<&'a Value, MissingMetadata> {
+    metadata.get(field)
+        .ok_or_else(|| missing(feed_event, field))
+}
+
+pub(crate) fn metadata_value<'a>(feed_event: &'a EventuallyEvent, field: &'static str) -> Result<&'a Value, MissingMetadata> {
+    value_in(feed_event, &feed_event.metadata.other, field)
+}
+
+pub(crate) fn metadata_str<'a>(feed_event: &'a EventuallyEvent, field: &'static str) -> Result<&'a str, MissingMetadata> {
+    metadata_value(feed_event, field)?.as_str()
+        .ok_or_else(|| missing(feed_event, field))
+}
+
+pub(crate) fn metadata_i64(feed_event: &EventuallyEvent, field: &'static str) -> Result<i64, MissingMetadata> {
+    metadata_value(feed_event, field)?.as_i64()
+        .ok_or_else(|| missing(feed_event, field))
+}
+
+pub(crate) fn metadata_uuid(feed_event: &EventuallyEvent, field: &'static str) -> Result<Uuid, MissingMetadata> {
+    metadata_str(feed_event, field)
+        .ok()
+        .and_then(|value| Uuid::parse_str(value).ok())
+        .ok_or_else(|| missing(feed_event, field))
+}
+
+pub(crate) fn metadata_array<'a>(feed_event: &'a EventuallyEvent, field: &'static str) -> Result<&'a [Value], MissingMetadata> {
+    metadata_value(feed_event, field)?.as_array()
+        .map(|array| array.as_slice())
+        .ok_or_else(|| missing(feed_event, field))
+}
+
+/// Gets a mod id from `metadata`, which is the event's own metadata or an object nested inside it
+pub(crate) fn metadata_mod(feed_event: &EventuallyEvent, metadata: &Value, field: &'static str) -> Result<Mod, MissingMetadata> {
+    value_in(feed_event, metadata, field)?.as_str()
+        .map(Mod::from_id)
+        .ok_or_else(|| missing(feed_event, field))
+}
@@ -0,0 +1,698 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+use eventually_api::Weather;
+
+use crate::{BlooddrainAction, FedEvent, FedEventData, FloodingSweptEffect, GameEvent, GrindRailSuccess, HomeRunType, RunLossesFromSalmon, Scores};
+
+/// Most outs there can be in a half-inning
+const MAX_OUTS: i32 = 3;
+
+/// Number of bases, including home, without the Fifth Base
+const DEFAULT_NUM_BASES: i32 = 4;
+
+/// Runs taken away by Sun 2 and the Black Hole
+const SUN_2_RUNS: f32 = 10.;
+
+/// Runs scored by a player with Blaserunning when they steal a base
+const BLASERUNNING_RUNS: f32 = 0.2;
+
+#[derive(Error, Debug)]
+pub enum GameStateError {
+    #[error("{event_type} event from game {actual} applied to the state of game {expected}")]
+    WrongGame {
+        event_type: String,
+        expected: Uuid,
+        actual: Uuid,
+    },
+
+    #[error("{event_type} event for play {play} came after play {previous}")]
+    PlayOutOfOrder {
+        event_type: String,
+        previous: i64,
+        play: i64,
+    },
+
+    #[error("{event_type} event came after the game ended")]
+    GameAlreadyOver {
+        event_type: String,
+    },
+
+    #[error("Expected half-inning {expected_inning} (top: {expected_top_of_inning}) to start but half-inning {inning} (top: {top_of_inning}) started")]
+    HalfInningOutOfOrder {
+        expected_inning: i32,
+        expected_top_of_inning: bool,
+        inning: i32,
+        top_of_inning: bool,
+    },
+
+    #[error("Inning {inning_num} ended during inning {current_inning_num}")]
+    InningEndOutOfOrder {
+        inning_num: i32,
+        current_inning_num: i32,
+    },
+
+    #[error("{event_type} event left the count at {balls}-{strikes}, which can't follow {previous_balls}-{previous_strikes}")]
+    InconsistentCount {
+        event_type: String,
+        balls: i32,
+        strikes: i32,
+        previous_balls: i32,
+        previous_strikes: i32,
+    },
+
+    #[error("{event_type} event would make {outs} outs")]
+    TooManyOuts {
+        event_type: String,
+        outs: i32,
+    },
+
+    #[error("{event_type} event refers to baserunner {name}, who is not on base")]
+    MissingBaserunner {
+        event_type: String,
+        name: String,
+    },
+
+    #[error("{event_type} event needs a baserunner, but the bases are empty")]
+    NoBaserunners {
+        event_type: String,
+    },
+
+    #[error("{event_type} event moves {name} to base {base}, which is occupied by {occupant}")]
+    BaseOccupied {
+        event_type: String,
+        name: String,
+        base: i32,
+        occupant: String,
+    },
+
+    #[error("{event_type} event forces {name} home, but they're not listed as scoring")]
+    UnlistedScore {
+        event_type: String,
+        name: String,
+    },
+
+    #[error("Hit a {home_run_type} with {num_runners} runner(s) on base")]
+    InconsistentHomeRun {
+        home_run_type: HomeRunType,
+        num_runners: usize,
+    },
+
+    #[error("{event_type} event refers to team {team_id}, which is not playing in this game")]
+    TeamNotInGame {
+        event_type: String,
+        team_id: Uuid,
+    },
+
+    #[error("{event_type} event refers to team {name}, which can't be matched to either team in this game")]
+    UnknownTeam {
+        event_type: String,
+        name: String,
+    },
+}
+
+/// A player as known to the game state. Many game events only name players, so the uuid is filled
+/// in once some event provides it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerRef {
+    pub id: Option<Uuid>,
+    pub name: String,
+}
+
+impl PlayerRef {
+    pub(crate) fn named(name: &str) -> Self {
+        Self { id: None, name: name.to_string() }
+    }
+
+    pub(crate) fn with_id(id: Uuid, name: &str) -> Self {
+        Self { id: Some(id), name: name.to_string() }
+    }
+
+    /// Compares by uuid if both sides have one, otherwise by name
+    pub(crate) fn is(&self, id: Option<Uuid>, name: &str) -> bool {
+        match (self.id, id) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.name == name,
+        }
+    }
+
+    pub(crate) fn learn_id(&mut self, id: Uuid) {
+        self.id.get_or_insert(id);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Baserunner {
+    pub player: PlayerRef,
+
+    /// Zero-indexed base, as in the basesOccupied field of game updates
+    pub base: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamState {
+    pub id: Uuid,
+
+    /// Full name of the team, once a HalfInningStart event has named it
+    pub name: Option<String>,
+
+    /// Nickname of the team, once any event has named it
+    pub nickname: Option<String>,
+
+    pub score: f32,
+
+    /// The team's current pitcher. The feed doesn't announce starting pitchers, so this is only
+    /// known after an event names the pitcher or if the caller fills it in.
+    pub pitcher: Option<PlayerRef>,
+
+    /// Number of bases including home when this team bats. This is only known to be 5 once one of
+    /// its players hits a Quadruple or steals fifth base, so steals of fourth base before that are
+    /// counted as steals of home.
+    pub num_bases: i32,
+}
+
+impl TeamState {
+    fn new(id: Uuid) -> Self {
+        Self { id, name: None, nickname: None, score: 0., pitcher: None, num_bases: DEFAULT_NUM_BASES }
+    }
+
+    fn is_named(&self, name: &str) -> bool {
+        self.nickname.as_deref() == Some(name) || self.name.as_deref() == Some(name)
+    }
+}
+
+/// The state of a game, built up by applying that game's FedEvents in order.
+///
+/// The feed only says who scored on each play, not where the other runners ended up, so baserunner
+/// positions are a best guess: on a hit, runners who didn't score advance by as many bases as the
+/// batter did without passing home or each other, and on walks runners only move if they're forced.
+/// Every score is counted as one run; GameEnd events overwrite the scores with the final ones.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameState {
+    pub game_id: Uuid,
+    pub home: TeamState,
+    pub away: TeamState,
+
+    /// Weather, once the LetsGo event has announced it
+    pub weather: Option<Weather>,
+
+    /// Zero-indexed inning
+    pub inning: i32,
+    pub top_of_inning: bool,
+
+    pub balls: i32,
+    pub strikes: i32,
+    pub outs: i32,
+
+    /// Runners on base, furthest along first
+    pub baserunners: Vec<Baserunner>,
+
+    /// The player in the Secret Base, if any
+    pub secret_base: Option<PlayerRef>,
+
+    /// The player currently at bat, if any
+    pub batter: Option<PlayerRef>,
+
+    /// Play number of the most recent event applied
+    pub last_play: Option<i64>,
+
+    pub game_complete: bool,
+
+    #[serde(skip)]
+    next_half_inning: (i32, bool),
+}
+
+impl GameState {
+    pub fn new(game: &GameEvent) -> Self {
+        Self {
+            game_id: game.game_id,
+            home: TeamState::new(game.home_team),
+            away: TeamState::new(game.away_team),
+            weather: None,
+            inning: 0,
+            top_of_inning: true,
+            balls: 0,
+            strikes: 0,
+            outs: 0,
+            baserunners: Vec::new(),
+            secret_base: None,
+            batter: None,
+            last_play: None,
+            game_complete: false,
+            next_half_inning: (0, true),
+        }
+    }
+
+    pub fn batting_team(&self) -> &TeamState {
+        if self.top_of_inning { &self.away } else { &self.home }
+    }
+
+    pub fn fielding_team(&self) -> &TeamState {
+        if self.top_of_inning { &self.home } else { &self.away }
+    }
+
+    fn batting_team_mut(&mut self) -> &mut TeamState {
+        if self.top_of_inning { &mut self.away } else { &mut self.home }
+    }
+
+    fn fielding_team_mut(&mut self) -> &mut TeamState {
+        if self.top_of_inning { &mut self.home } else { &mut self.away }
+    }
+
+    /// Number of bases including home for the batting team
+    pub fn num_bases(&self) -> i32 {
+        self.batting_team().num_bases
+    }
+
+    /// The fielding team's current pitcher, if known
+    pub fn pitcher(&self) -> Option<&PlayerRef> {
+        self.fielding_team().pitcher.as_ref()
+    }
+
+    pub fn team(&self, team_id: Uuid) -> Option<&TeamState> {
+        if self.home.id == team_id {
+            Some(&self.home)
+        } else if self.away.id == team_id {
+            Some(&self.away)
+        } else {
+            None
+        }
+    }
+
+    /// Applies one event to the state. Events that aren't game events are ignored.
+    pub fn apply(&mut self, event: &FedEvent) -> Result<(), GameStateError> {
+        let Some(game) = event.data.game() else { return Ok(()) };
+        let event_type: &str = event.data.as_ref();
+
+        if game.game_id != self.game_id {
+            return Err(GameStateError::WrongGame {
+                event_type: event_type.to_string(),
+                expected: self.game_id,
+                actual: game.game_id,
+            });
+        }
+
+        if let Some(previous) = self.last_play {
+            if game.play < previous {
+                return Err(GameStateError::PlayOutOfOrder {
+                    event_type: event_type.to_string(),
+                    previous,
+                    play: game.play,
+                });
+            }
+
+            // Events that are part of the game-ending play are fine
+            if self.game_complete && game.play > previous {
+                return Err(GameStateError::GameAlreadyOver { event_type: event_type.to_string() });
+            }
+        }
+        self.last_play = Some(game.play);
+
+        if let Some(attractor) = &game.attractor_secret_base {
+            self.secret_base = Some(PlayerRef::with_id(attractor.player_id, &attractor.player_name));
+        }
+
+        match &event.data {
+            FedEventData::LetsGo { weather, .. } => {
+                self.weather = Some(*weather);
+            }
+            FedEventData::HalfInningStart { top_of_inning, inning, batting_team_name, .. } => {
+                let (expected_inning, expected_top_of_inning) = self.next_half_inning;
+                if *inning != expected_inning || *top_of_inning != expected_top_of_inning {
+                    return Err(GameStateError::HalfInningOutOfOrder {
+                        expected_inning,
+                        expected_top_of_inning,
+                        inning: *inning,
+                        top_of_inning: *top_of_inning,
+                    });
+                }
+
+                self.inning = *inning;
+                self.top_of_inning = *top_of_inning;
+                self.next_half_inning = if *top_of_inning { (*inning, false) } else { (*inning + 1, true) };
+                self.outs = 0;
+                self.baserunners.clear();
+                self.end_plate_appearance();
+                self.batting_team_mut().name = Some(batting_team_name.clone());
+            }
+            FedEventData::BatterUp { batter_name, team_nickname, .. } => {
+                self.batting_team_mut().nickname = Some(team_nickname.clone());
+                self.balls = 0;
+                self.strikes = 0;
+                self.batter = Some(PlayerRef::named(batter_name));
+            }
+            FedEventData::Ball { balls, strikes, .. } => {
+                self.update_count(event_type, *balls, *strikes, 1, 0..=0)?;
+            }
+            FedEventData::FoulBall { pitch, balls, strikes, .. } => {
+                let max_strikes = if pitch.double_strike.is_some() { 2 } else { 1 };
+                self.update_count(event_type, *balls, *strikes, 0, 0..=max_strikes)?;
+            }
+            FedEventData::StrikeSwinging { pitch, balls, strikes, .. } |
+            FedEventData::StrikeLooking { pitch, balls, strikes, .. } |
+            FedEventData::StrikeFlinching { pitch, balls, strikes, .. } => {
+                let num_strikes = if pitch.double_strike.is_some() { 2 } else { 1 };
+                self.update_count(event_type, *balls, *strikes, 0, num_strikes..=num_strikes)?;
+            }
+            FedEventData::StrikeZapped { .. } => {
+                self.strikes = (self.strikes - 1).max(0);
+            }
+            FedEventData::Flyout { scores, .. } |
+            FedEventData::GroundOut { scores, .. } => {
+                self.score_runners(event_type, scores)?;
+                self.add_outs(event_type, 1)?;
+                self.end_plate_appearance();
+            }
+            FedEventData::FieldersChoice { batter_name, runner_out_name, scores, .. } => {
+                self.remove_runner(event_type, None, runner_out_name)?;
+                self.score_runners(event_type, scores)?;
+                self.add_outs(event_type, 1)?;
+                self.force_runner(event_type, PlayerRef::named(batter_name), 0)?;
+                self.end_plate_appearance();
+            }
+            FedEventData::DoublePlay { scores, .. } => {
+                self.score_runners(event_type, scores)?;
+                // The feed doesn't say who the second out was. The sim gets the runner closest to
+                // first.
+                if self.baserunners.pop().is_none() {
+                    return Err(GameStateError::NoBaserunners { event_type: event_type.to_string() });
+                }
+                self.add_outs(event_type, 2)?;
+                self.end_plate_appearance();
+            }
+            FedEventData::Hit { batter_name, batter_id, hit_type, scores, .. } => {
+                let hit_bases = hit_type.num_bases();
+                let batting_team = self.batting_team_mut();
+                batting_team.num_bases = batting_team.num_bases.max(hit_bases + 1);
+
+                self.score_runners(event_type, scores)?;
+                self.advance_runners(hit_bases);
+                self.force_runner(event_type, PlayerRef::with_id(*batter_id, batter_name), hit_bases - 1)?;
+                self.end_plate_appearance();
+            }
+            FedEventData::HomeRun { home_run_type, .. } => {
+                let num_runners = self.baserunners.len();
+                let consistent = match home_run_type {
+                    HomeRunType::Solo => num_runners == 0,
+                    HomeRunType::TwoRun => num_runners == 1,
+                    HomeRunType::ThreeRun => num_runners == 2,
+                    HomeRunType::GrandSlam => num_runners >= 3,
+                };
+                if !consistent {
+                    return Err(GameStateError::InconsistentHomeRun { home_run_type: home_run_type.clone(), num_runners });
+                }
+
+                self.baserunners.clear();
+                self.batting_team_mut().score += (num_runners + 1) as f32;
+                self.end_plate_appearance();
+            }
+            FedEventData::StolenBase { runner_name, runner_id, base_stolen, blaserunning, .. } => {
+                let mut runner = self.remove_runner(event_type, Some(*runner_id), runner_name)?.player;
+                runner.learn_id(*runner_id);
+
+                if *blaserunning {
+                    self.batting_team_mut().score += BLASERUNNING_RUNS;
+                }
+
+                let base = base_stolen.index();
+                let batting_team = self.batting_team_mut();
+                batting_team.num_bases = batting_team.num_bases.max(base + 1);
+                if base == batting_team.num_bases - 1 {
+                    batting_team.score += 1.;
+                } else {
+                    self.put_runner(event_type, runner, base)?;
+                }
+            }
+            FedEventData::CaughtStealing { runner_name, .. } => {
+                self.remove_runner(event_type, None, runner_name)?;
+                self.add_outs(event_type, 1)?;
+            }
+            FedEventData::StrikeoutSwinging { .. } |
+            FedEventData::StrikeoutLooking { .. } |
+            FedEventData::CharmStrikeout { .. } |
+            FedEventData::MindTrickStrikeout { .. } |
+            FedEventData::AmbushedByCrows { .. } => {
+                self.add_outs(event_type, 1)?;
+                self.end_plate_appearance();
+            }
+            FedEventData::Walk { batter_name, batter_id, scores, base_instincts, .. } => {
+                self.score_runners(event_type, scores)?;
+                let base = base_instincts.map_or(0, |base| base - 1);
+                self.force_runner(event_type, PlayerRef::with_id(*batter_id, batter_name), base)?;
+                self.end_plate_appearance();
+            }
+            FedEventData::CharmWalk { batter_name, batter_id, scores, .. } |
+            FedEventData::MindTrickWalk { batter_name, batter_id, scores, .. } => {
+                self.score_runners(event_type, scores)?;
+                self.force_runner(event_type, PlayerRef::with_id(*batter_id, batter_name), 0)?;
+                self.end_plate_appearance();
+            }
+            FedEventData::MildPitchWalk { pitcher_id, pitcher_name, batter_id, batter_name, scores, .. } |
+            FedEventData::HitByPitch { pitcher_id, pitcher_name, batter_id, batter_name, scores, .. } => {
+                self.fielding_team_mut().pitcher = Some(PlayerRef::with_id(*pitcher_id, pitcher_name));
+                self.score_runners(event_type, scores)?;
+                self.force_runner(event_type, PlayerRef::with_id(*batter_id, batter_name), 0)?;
+                self.end_plate_appearance();
+            }
+            FedEventData::MildPitch { pitcher_id, pitcher_name, balls, strikes, runners_advance, scores, .. } => {
+                self.fielding_team_mut().pitcher = Some(PlayerRef::with_id(*pitcher_id, pitcher_name));
+                self.update_count(event_type, *balls, *strikes, 1, 0..=0)?;
+                self.score_runners(event_type, scores)?;
+                if *runners_advance {
+                    for runner in &mut self.baserunners {
+                        runner.base += 1;
+                    }
+                }
+            }
+            FedEventData::BatterSkipped { .. } => {
+                self.end_plate_appearance();
+            }
+            FedEventData::SpecialBlooddrain { action, .. } => {
+                match action {
+                    BlooddrainAction::AddBall => { self.balls += 1 }
+                    BlooddrainAction::RemoveBall => { self.balls = (self.balls - 1).max(0) }
+                    BlooddrainAction::AddStrike(None) => { self.strikes += 1 }
+                    BlooddrainAction::AddStrike(Some(_)) => {
+                        self.add_outs(event_type, 1)?;
+                        self.end_plate_appearance();
+                    }
+                    BlooddrainAction::RemoveStrike => { self.strikes = (self.strikes - 1).max(0) }
+                    BlooddrainAction::AddOut => { self.add_outs(event_type, 1)? }
+                    BlooddrainAction::RemoveOut => { self.outs = (self.outs - 1).max(0) }
+                }
+            }
+            FedEventData::InningEnd { inning_num, .. } => {
+                if *inning_num != self.inning + 1 {
+                    return Err(GameStateError::InningEndOutOfOrder {
+                        inning_num: *inning_num,
+                        current_inning_num: self.inning + 1,
+                    });
+                }
+            }
+            FedEventData::PitcherChange { team_nickname, pitcher_id, pitcher_name, .. } => {
+                self.team_by_name_mut(event_type, team_nickname)?.pitcher = Some(PlayerRef::with_id(*pitcher_id, pitcher_name));
+            }
+            FedEventData::Sun2 { team_nickname, .. } => {
+                self.team_by_name_mut(event_type, team_nickname)?.score -= SUN_2_RUNS;
+            }
+            FedEventData::BlackHole { scoring_team_nickname, .. } => {
+                self.team_by_name_mut(event_type, scoring_team_nickname)?.score -= SUN_2_RUNS;
+            }
+            FedEventData::SolarPanelsActivate { team_nickname, num_runs, .. } => {
+                self.team_by_name_mut(event_type, team_nickname)?.score -= num_runs;
+            }
+            FedEventData::RunsOverflowing { team_nickname, num_runs, .. } => {
+                self.team_by_name_mut(event_type, team_nickname)?.score += num_runs;
+            }
+            FedEventData::SalmonSwim { inning_num, run_losses, .. } => {
+                let losses = match run_losses {
+                    RunLossesFromSalmon::None => vec![],
+                    RunLossesFromSalmon::OneTeam(loss) => vec![loss],
+                    RunLossesFromSalmon::BothTeams((a, b)) => vec![a, b],
+                };
+                for loss in losses {
+                    self.team_by_name_mut(event_type, &loss.team_name)?.score -= loss.runs_lost;
+                }
+                self.next_half_inning = (inning_num - 1, true);
+            }
+            FedEventData::FloodingSwept { effects, .. } => {
+                let mut kept = Vec::new();
+                for effect in effects {
+                    match effect {
+                        FloodingSweptEffect::Elsewhere(_) => {}
+                        FloodingSweptEffect::Flippers(_) => {
+                            self.batting_team_mut().score += 1.;
+                        }
+                        FloodingSweptEffect::Ego(player) => {
+                            kept.push(self.remove_runner(event_type, Some(player.player_id), &player.player_name)?);
+                        }
+                    }
+                }
+                // Everyone without an Ego is swept off the bases
+                self.baserunners.clear();
+                for runner in kept {
+                    self.baserunners.push(runner);
+                }
+                self.sort_baserunners();
+            }
+            FedEventData::EnterSecretBase { player_id, player_name, .. } => {
+                let mut player = self.remove_runner(event_type, Some(*player_id), player_name)?.player;
+                player.learn_id(*player_id);
+                self.secret_base = Some(player);
+            }
+            FedEventData::ExitSecretBase { player_id, player_name, .. } => {
+                self.secret_base = None;
+                self.put_runner(event_type, PlayerRef::with_id(*player_id, player_name), 1)?;
+            }
+            FedEventData::GrindRail { player_id, player_name, success, .. } => {
+                let mut player = self.remove_runner(event_type, Some(*player_id), player_name)?.player;
+                if let GrindRailSuccess::Safe(_) = success {
+                    player.learn_id(*player_id);
+                    self.put_runner(event_type, player, 2)?;
+                } else {
+                    self.add_outs(event_type, 1)?;
+                }
+            }
+            FedEventData::GameEnd { winner_id, winning_team_score, losing_team_score, .. } => {
+                let (winner, loser) = if *winner_id == self.home.id {
+                    (&mut self.home, &mut self.away)
+                } else if *winner_id == self.away.id {
+                    (&mut self.away, &mut self.home)
+                } else {
+                    return Err(GameStateError::TeamNotInGame { event_type: event_type.to_string(), team_id: *winner_id });
+                };
+                winner.score = *winning_team_score;
+                loser.score = *losing_team_score;
+                self.baserunners.clear();
+                self.batter = None;
+                self.game_complete = true;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn end_plate_appearance(&mut self) {
+        self.balls = 0;
+        self.strikes = 0;
+        self.batter = None;
+    }
+
+    fn update_count(&mut self, event_type: &str, balls: i32, strikes: i32, added_balls: i32, added_strikes: std::ops::RangeInclusive<i32>) -> Result<(), GameStateError> {
+        if balls != self.balls + added_balls || !added_strikes.contains(&(strikes - self.strikes)) {
+            return Err(GameStateError::InconsistentCount {
+                event_type: event_type.to_string(),
+                balls,
+                strikes,
+                previous_balls: self.balls,
+                previous_strikes: self.strikes,
+            });
+        }
+
+        self.balls = balls;
+        self.strikes = strikes;
+        Ok(())
+    }
+
+    fn add_outs(&mut self, event_type: &str, outs: i32) -> Result<(), GameStateError> {
+        self.outs += outs;
+        if self.outs > MAX_OUTS {
+            return Err(GameStateError::TooManyOuts { event_type: event_type.to_string(), outs: self.outs });
+        }
+
+        Ok(())
+    }
+
+    fn team_by_name_mut(&mut self, event_type: &str, name: &str) -> Result<&mut TeamState, GameStateError> {
+        if self.home.is_named(name) {
+            return Ok(&mut self.home);
+        } else if self.away.is_named(name) {
+            return Ok(&mut self.away);
+        }
+
+        // If only one team's nickname is known and this isn't it, it must be the other team
+        let team = match (&self.home.nickname, &self.away.nickname) {
+            (None, Some(_)) => &mut self.home,
+            (Some(_), None) => &mut self.away,
+            _ => return Err(GameStateError::UnknownTeam { event_type: event_type.to_string(), name: name.to_string() }),
+        };
+        team.nickname = Some(name.to_string());
+        Ok(team)
+    }
+
+    fn sort_baserunners(&mut self) {
+        self.baserunners.sort_by_key(|runner| -runner.base);
+    }
+
+    fn remove_runner(&mut self, event_type: &str, id: Option<Uuid>, name: &str) -> Result<Baserunner, GameStateError> {
+        let position = self.baserunners.iter()
+            .position(|runner| runner.player.is(id, name))
+            .ok_or_else(|| GameStateError::MissingBaserunner {
+                event_type: event_type.to_string(),
+                name: name.to_string(),
+            })?;
+
+        Ok(self.baserunners.remove(position))
+    }
+
+    fn score_runners(&mut self, event_type: &str, scores: &Scores) -> Result<(), GameStateError> {
+        for score in &scores.scores {
+            self.remove_runner(event_type, Some(score.player_id), &score.player_name)?;
+            self.batting_team_mut().score += 1.;
+        }
+
+        Ok(())
+    }
+
+    /// Puts a runner on a base that must be empty
+    fn put_runner(&mut self, event_type: &str, player: PlayerRef, base: i32) -> Result<(), GameStateError> {
+        if let Some(occupant) = self.baserunners.iter().find(|runner| runner.base == base) {
+            return Err(GameStateError::BaseOccupied {
+                event_type: event_type.to_string(),
+                name: player.name,
+                base,
+                occupant: occupant.player.name.clone(),
+            });
+        }
+
+        self.baserunners.push(Baserunner { player, base });
+        self.sort_baserunners();
+        Ok(())
+    }
+
+    /// Puts a runner on a base, pushing any runners in the way forward like a walk does
+    fn force_runner(&mut self, event_type: &str, player: PlayerRef, base: i32) -> Result<(), GameStateError> {
+        let mut base_needed = base;
+        for runner in self.baserunners.iter_mut().rev() {
+            if runner.base > base_needed { break; }
+            if runner.base == base_needed {
+                runner.base += 1;
+                base_needed += 1;
+            }
+        }
+
+        let num_bases = self.num_bases();
+        if let Some(runner) = self.baserunners.iter().find(|runner| runner.base >= num_bases - 1) {
+            return Err(GameStateError::UnlistedScore {
+                event_type: event_type.to_string(),
+                name: runner.player.name.clone(),
+            });
+        }
+
+        self.put_runner(event_type, player, base)
+    }
+
+    /// Advances every runner by up to `num_bases` without passing home or another runner
+    fn advance_runners(&mut self, num_bases: i32) {
+        let mut limit = self.num_bases() - 2;
+        for runner in &mut self.baserunners {
+            runner.base = (runner.base + num_bases).min(limit).max(runner.base);
+            limit = runner.base - 1;
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{FedEvent, GameEvent, GameState, GameStateError, TeamState};
+
+/// Scores and other numbers in game updates are floats, so they're compared with this tolerance
+const SCORE_EPSILON: f64 = 1e-6;
+
+/// The parts of a Chronicler game update that can be derived from the feed. Field names match the
+/// game update's, so serializing this gives a subset of the game update JSON.
+///
+/// Fields that the game state doesn't know (e.g. a team's name before any event has mentioned it)
+/// are None and left out when serialized, so they aren't compared by [`GameUpdate::diff`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameUpdate {
+    /// Game uuid
+    pub id: Uuid,
+    pub season: i32,
+    pub day: i32,
+
+    /// One more than the play number of the event that produced this update
+    pub play_count: i64,
+    pub last_update: String,
+
+    /// Zero-indexed inning
+    pub inning: i32,
+    pub top_of_inning: bool,
+
+    /// Outs in the current half-inning. This goes back to 0 on the play that makes the third out.
+    pub half_inning_outs: i32,
+    pub at_bat_balls: i32,
+    pub at_bat_strikes: i32,
+
+    pub home_team: Uuid,
+    pub away_team: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_team_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub away_team_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_team_nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub away_team_nickname: Option<String>,
+
+    pub home_score: f32,
+    pub away_score: f32,
+
+    /// Zero-indexed bases of each runner, in the same order as `base_runner_names`
+    pub bases_occupied: Vec<i32>,
+    pub base_runner_names: Vec<String>,
+
+    /// Uuids of each runner, if every runner's uuid is known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_runners: Option<Vec<Uuid>>,
+    pub baserunner_count: i32,
+
+    /// Number of bases the home team has, if they're batting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_bases: Option<i32>,
+
+    /// Number of bases the away team has, if they're batting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub away_bases: Option<i32>,
+
+    /// The home team's batter, if they're batting and someone is at bat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_batter_name: Option<String>,
+
+    /// The away team's batter, if they're batting and someone is at bat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub away_batter_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_pitcher_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub away_pitcher_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weather: Option<i32>,
+
+    pub game_complete: bool,
+}
+
+/// One field where a generated game update disagrees with a recorded one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameUpdateMismatch {
+    pub field: String,
+    /// The value in the generated game update
+    pub generated: Value,
+    /// The value in the recorded game update
+    pub recorded: Value,
+}
+
+impl GameUpdate {
+    /// Builds the game update that should follow a play, given the game state after applying all
+    /// of `events`. `events` are every event of the play (several when an event was split into
+    /// siblings), in order. Returns None if `events` is empty.
+    pub fn from_state(state: &GameState, events: &[&FedEvent], play: i64) -> Option<Self> {
+        let event = events.last()?;
+        let batting_home = !state.top_of_inning;
+        // The game update after the third out already shows the bases cleared and no outs, even
+        // though the next half-inning hasn't started
+        let inning_over = state.outs >= 3;
+        let baserunners = if inning_over { &[][..] } else { &state.baserunners[..] };
+        let batter_name = |batting: bool| {
+            if batting { state.batter.as_ref().map(|batter| batter.name.clone()) } else { None }
+        };
+        let bases = |team: &TeamState, batting: bool| {
+            batting.then_some(team.num_bases)
+        };
+        let pitcher_name = |team: &TeamState| {
+            team.pitcher.as_ref().map(|pitcher| pitcher.name.clone())
+        };
+
+        Some(Self {
+            id: state.game_id,
+            season: event.season,
+            day: event.day,
+            play_count: play + 1,
+            last_update: FedEvent::play_last_update(events.iter().copied()),
+            inning: state.inning,
+            top_of_inning: state.top_of_inning,
+            half_inning_outs: if inning_over { 0 } else { state.outs },
+            at_bat_balls: state.balls,
+            at_bat_strikes: state.strikes,
+            home_team: state.home.id,
+            away_team: state.away.id,
+            home_team_name: state.home.name.clone(),
+            away_team_name: state.away.name.clone(),
+            home_team_nickname: state.home.nickname.clone(),
+            away_team_nickname: state.away.nickname.clone(),
+            home_score: state.home.score,
+            away_score: state.away.score,
+            bases_occupied: baserunners.iter().map(|runner| runner.base).collect(),
+            base_runner_names: baserunners.iter().map(|runner| runner.player.name.clone()).collect(),
+            base_runners: baserunners.iter().map(|runner| runner.player.id).collect(),
+            baserunner_count: baserunners.len() as i32,
+            home_bases: bases(&state.home, batting_home),
+            away_bases: bases(&state.away, !batting_home),
+            home_batter_name: batter_name(batting_home),
+            away_batter_name: batter_name(!batting_home),
+            home_pitcher_name: pitcher_name(&state.home),
+            away_pitcher_name: pitcher_name(&state.away),
+            weather: state.weather.map(|weather| weather.into()),
+            game_complete: state.game_complete,
+        })
+    }
+
+    /// Builds one game update per play from every event of one game, in order. When several events
+    /// share a play, they're sibling events, and the update reflects the state after all of them
+    /// and shows the text of each.
+    pub fn from_events<'a>(events: impl IntoIterator<Item=&'a FedEvent>) -> Result<Vec<Self>, GameStateError> {
+        let mut builder: Option<GameUpdateBuilder> = None;
+        let mut updates: Vec<GameUpdate> = Vec::new();
+        let mut play_events: Vec<&FedEvent> = Vec::new();
+        let mut events = events.into_iter()
+            .filter_map(|event| Some((event.data.game()?, event)))
+            .peekable();
+        while let Some((game, event)) = events.next() {
+            play_events.push(event);
+            if events.peek().is_some_and(|(next_game, _)| next_game.play == game.play) {
+                continue;
+            }
+
+            let builder = builder.get_or_insert_with(|| GameUpdateBuilder::new(game));
+            if let Some(update) = builder.push_play(&play_events)? {
+                updates.push(update);
+            }
+            play_events.clear();
+        }
+
+        Ok(updates)
+    }
+
+    /// Compares this update with a recorded game update, which should be the game update object
+    /// itself (the `data` field of a Chronicler entity). Only fields that both updates have are
+    /// compared, since older game updates are missing some fields, and numbers are compared with a
+    /// small tolerance.
+    pub fn diff(&self, recorded: &Value) -> Vec<GameUpdateMismatch> {
+        let Ok(Value::Object(generated)) = serde_json::to_value(self) else {
+            panic!("GameUpdate should serialize to a JSON object")
+        };
+
+        generated.into_iter()
+            .filter_map(|(field, generated)| {
+                let recorded = recorded.get(&field)?;
+                (!values_match(&generated, recorded))
+                    .then(|| GameUpdateMismatch { field, generated, recorded: recorded.clone() })
+            })
+            .collect()
+    }
+}
+
+fn values_match(generated: &Value, recorded: &Value) -> bool {
+    match (generated, recorded) {
+        (Value::Number(a), Value::Number(b)) => {
+            match (a.as_f64(), b.as_f64()) {
+                (Some(a), Some(b)) => (a - b).abs() < SCORE_EPSILON,
+                _ => a == b,
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| values_match(a, b))
+        }
+        _ => generated == recorded,
+    }
+}
+
+/// Replays one game's events and produces the game update after each one
+pub struct GameUpdateBuilder {
+    state: GameState,
+}
+
+impl GameUpdateBuilder {
+    pub fn new(game: &GameEvent) -> Self {
+        Self { state: GameState::new(game) }
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    /// Applies every event of one play from this builder's game, in order, and returns the game
+    /// update that should follow the play. Events that aren't game events are skipped, and if none
+    /// of them are, this returns None.
+    pub fn push_play(&mut self, events: &[&FedEvent]) -> Result<Option<GameUpdate>, GameStateError> {
+        let game_events: Vec<_> = events.iter()
+            .copied()
+            .filter(|event| event.data.game().is_some())
+            .collect();
+        let Some(game) = game_events.last().and_then(|event| event.data.game()) else { return Ok(None) };
+
+        for event in &game_events {
+            self.state.apply(event)?;
+        }
+        Ok(GameUpdate::from_state(&self.state, &game_events, game.play))
+    }
+}
@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+use eventually_api::{EventType, EventuallyEvent};
+
+use crate::{FedEvent, Mod};
+use crate::feed_metadata::{metadata_i64, metadata_str, metadata_uuid, MissingMetadata};
+
+#[derive(Error, Debug)]
+pub enum ItemLedgerError {
+    #[error(transparent)]
+    MissingMetadata(#[from] MissingMetadata),
+
+    #[error("{event_type:?} event {event_id} changes an item but isn't tagged with a player")]
+    NoPlayer {
+        event_type: EventType,
+        event_id: Uuid,
+    },
+}
+
+/// The player holding an item, and the team they were on when the ledger last saw them with it
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemOwner {
+    pub player_id: Uuid,
+    pub team_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum ItemHistoryChange {
+    Gained { owner: ItemOwner },
+    Dropped { owner: ItemOwner },
+    #[serde(rename_all = "camelCase")]
+    Damaged { health_before: i64, health_after: i64 },
+    #[serde(rename_all = "camelCase")]
+    Broke { health_before: i64 },
+    #[serde(rename_all = "camelCase")]
+    Repaired { health_before: i64, health_after: i64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemHistoryEntry {
+    /// Position of the event that caused this change in the sequence of events given to the ledger
+    pub sequence: usize,
+    pub event_id: Uuid,
+    pub created: DateTime<Utc>,
+    pub change: ItemHistoryChange,
+}
+
+/// Something in the feed that doesn't agree with what the ledger already knew about an item.
+/// These usually mean the ledger was started partway through the item's life, but can also point
+/// to missing or misparsed events.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum ItemInconsistency {
+    /// The item was damaged, broken, or repaired while the ledger didn't know who held it
+    #[serde(rename_all = "camelCase")]
+    ChangedWithUnknownOwner { player_id: Uuid },
+
+    /// The item was dropped while the ledger didn't know who held it
+    #[serde(rename_all = "camelCase")]
+    DroppedWithUnknownOwner { player_id: Uuid },
+
+    /// The event's player isn't the one the ledger thinks holds the item
+    #[serde(rename_all = "camelCase")]
+    OwnerMismatch { expected: Uuid, actual: Uuid },
+
+    /// The item was gained by a player while another player still held it
+    #[serde(rename_all = "camelCase")]
+    GainedWhileOwned { previous_owner: Uuid },
+
+    /// The item's health before this event isn't what the ledger last saw
+    #[serde(rename_all = "camelCase")]
+    HealthMismatch { expected: i64, actual: i64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemInconsistencyEntry {
+    pub item_id: Uuid,
+    pub event_id: Uuid,
+    pub created: DateTime<Utc>,
+    pub inconsistency: ItemInconsistency,
+}
+
+/// Everything the ledger knows about one item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemRecord {
+    pub item_id: Uuid,
+
+    /// Most recent name of the item. Item names change when their mods change.
+    pub item_name: String,
+
+    /// Mods bestowed by the item, as of the most recent event that listed them
+    pub item_mods: Vec<Mod>,
+
+    /// Max health of the item, if any event has reported it. Gain and drop events don't.
+    pub durability: Option<i64>,
+
+    /// Current health of the item, if any event has reported it
+    pub health: Option<i64>,
+
+    /// Who holds the item, or None if it was dropped or the ledger hasn't seen it gained
+    pub owner: Option<ItemOwner>,
+
+    /// Every change to the item, in order
+    pub history: Vec<ItemHistoryEntry>,
+}
+
+impl ItemRecord {
+    fn new(item_id: Uuid, item_name: String) -> Self {
+        Self {
+            item_id,
+            item_name,
+            item_mods: Vec::new(),
+            durability: None,
+            health: None,
+            owner: None,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.health == Some(0)
+    }
+
+    /// Number of times the item has broken
+    pub fn times_broken(&self) -> usize {
+        self.history.iter()
+            .filter(|entry| matches!(entry.change, ItemHistoryChange::Broke { .. }))
+            .count()
+    }
+
+    /// Number of times the item has been repaired
+    pub fn times_repaired(&self) -> usize {
+        self.history.iter()
+            .filter(|entry| matches!(entry.change, ItemHistoryChange::Repaired { .. }))
+            .count()
+    }
+
+    /// The item's health just after each event that reported it, in order
+    pub fn health_history(&self) -> impl Iterator<Item=(&ItemHistoryEntry, i64)> {
+        self.history.iter()
+            .filter_map(|entry| match entry.change {
+                ItemHistoryChange::Damaged { health_after, .. } => Some((entry, health_after)),
+                ItemHistoryChange::Broke { .. } => Some((entry, 0)),
+                ItemHistoryChange::Repaired { health_after, .. } => Some((entry, health_after)),
+                _ => None,
+            })
+    }
+}
+
+/// Tracks the ownership and condition of every item from a stream of FedEvents, which must be in
+/// chronological order.
+///
+/// Like the mod tracker, this reads the item events that the event builder generates for each
+/// FedEvent, so it picks up item damage from every game event that can cause it as well as
+/// ItemGained, ItemRepaired, ItemDroppedForNewItem, PlayerDropsItem, CommunityChestOpens, and
+/// GlitterCrate. Events that disagree with what the ledger already knows are still applied, and
+/// the disagreement is recorded in `inconsistencies`.
+#[derive(Debug, Default)]
+pub struct ItemLedger {
+    items: HashMap<Uuid, ItemRecord>,
+    inconsistencies: Vec<ItemInconsistencyEntry>,
+    num_events: usize,
+}
+
+struct EventContext<'a> {
+    sequence: usize,
+    event: &'a FedEvent,
+}
+
+impl ItemLedger {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Applies one event. On error, item changes from earlier in the same event may already have
+    /// been applied.
+    pub fn apply(&mut self, event: &FedEvent) -> Result<(), ItemLedgerError> {
+        let ctx = EventContext {
+            sequence: self.num_events,
+            event,
+        };
+        self.num_events += 1;
+
+        self.apply_feed_event(&ctx, &event.clone().into_feed_event())
+    }
+
+    fn apply_feed_event(&mut self, ctx: &EventContext, feed_event: &EventuallyEvent) -> Result<(), ItemLedgerError> {
+        match feed_event.r#type {
+            EventType::PlayerGainedItem => {
+                let owner = owner(feed_event)?;
+                let record = self.record_for(feed_event)?;
+                let previous_owner = record.owner
+                    .filter(|previous| previous.player_id != owner.player_id);
+                record.owner = Some(owner);
+                self.push_history(ctx, feed_event, ItemHistoryChange::Gained { owner })?;
+                if let Some(previous) = previous_owner {
+                    self.push_inconsistency(ctx, feed_event, ItemInconsistency::GainedWhileOwned {
+                        previous_owner: previous.player_id,
+                    })?;
+                }
+            }
+            EventType::PlayerLostItem => {
+                let owner = owner(feed_event)?;
+                let record = self.record_for(feed_event)?;
+                let inconsistency = owner_inconsistency(record.owner, owner, true);
+                record.owner = None;
+                self.push_history(ctx, feed_event, ItemHistoryChange::Dropped { owner })?;
+                if let Some(inconsistency) = inconsistency {
+                    self.push_inconsistency(ctx, feed_event, inconsistency)?;
+                }
+            }
+            EventType::ItemDamaged | EventType::ItemBreaks |
+            EventType::DamagedItemRepaired | EventType::BrokenItemRepaired => {
+                let owner = owner(feed_event)?;
+                let health_before = metadata_i64(feed_event, "itemHealthBefore")?;
+                let health_after = metadata_i64(feed_event, "itemHealthAfter")?;
+                let durability = metadata_i64(feed_event, "itemDurability")?;
+
+                let record = self.record_for(feed_event)?;
+                let mut inconsistencies: Vec<_> = owner_inconsistency(record.owner, owner, false)
+                    .into_iter()
+                    .collect();
+                if let Some(expected) = record.health && expected != health_before {
+                    inconsistencies.push(ItemInconsistency::HealthMismatch { expected, actual: health_before });
+                }
+                // Damage and repair happen to items the player is holding, so this is the best
+                // information there is about the owner even if it disagrees with the ledger
+                record.owner = Some(owner);
+                record.durability = Some(durability);
+                record.health = Some(health_after);
+
+                let change = match feed_event.r#type {
+                    EventType::ItemBreaks => ItemHistoryChange::Broke { health_before },
+                    EventType::ItemDamaged => ItemHistoryChange::Damaged { health_before, health_after },
+                    _ => ItemHistoryChange::Repaired { health_before, health_after },
+                };
+                self.push_history(ctx, feed_event, change)?;
+                for inconsistency in inconsistencies {
+                    self.push_inconsistency(ctx, feed_event, inconsistency)?;
+                }
+            }
+            _ => {}
+        }
+
+        for child in &feed_event.metadata.children {
+            self.apply_feed_event(ctx, child)?;
+        }
+
+        Ok(())
+    }
+
+    /// Gets the record for the event's item, creating it if necessary, and updates its name and
+    /// mods to the ones in the event
+    fn record_for(&mut self, feed_event: &EventuallyEvent) -> Result<&mut ItemRecord, ItemLedgerError> {
+        let item_id = metadata_uuid(feed_event, "itemId")?;
+        let item_name = metadata_str(feed_event, "itemName")?.to_string();
+        let item_mods = feed_event.metadata.other.get("mods")
+            .and_then(|value| value.as_array())
+            .map(|mods| mods.iter().filter_map(|m| m.as_str()).map(Mod::from_id).collect());
+
+        let record = self.items.entry(item_id)
+            .or_insert_with(|| ItemRecord::new(item_id, item_name.clone()));
+        record.item_name = item_name;
+        if let Some(item_mods) = item_mods {
+            record.item_mods = item_mods;
+        }
+        Ok(record)
+    }
+
+    fn push_history(&mut self, ctx: &EventContext, feed_event: &EventuallyEvent, change: ItemHistoryChange) -> Result<(), ItemLedgerError> {
+        let item_id = metadata_uuid(feed_event, "itemId")?;
+        self.items.get_mut(&item_id)
+            .expect("push_history must be called after record_for")
+            .history
+            .push(ItemHistoryEntry {
+                sequence: ctx.sequence,
+                event_id: ctx.event.id,
+                created: ctx.event.created,
+                change,
+            });
+        Ok(())
+    }
+
+    fn push_inconsistency(&mut self, ctx: &EventContext, feed_event: &EventuallyEvent, inconsistency: ItemInconsistency) -> Result<(), ItemLedgerError> {
+        self.inconsistencies.push(ItemInconsistencyEntry {
+            item_id: metadata_uuid(feed_event, "itemId")?,
+            event_id: ctx.event.id,
+            created: ctx.event.created,
+            inconsistency,
+        });
+        Ok(())
+    }
+
+    pub fn item(&self, item_id: Uuid) -> Option<&ItemRecord> {
+        self.items.get(&item_id)
+    }
+
+    /// Every item whose most recent name is `item_name`. Item names aren't unique, so there may be
+    /// more than one.
+    pub fn items_named<'a>(&'a self, item_name: &'a str) -> impl Iterator<Item=&'a ItemRecord> + 'a {
+        self.items.values()
+            .filter(move |record| record.item_name == item_name)
+    }
+
+    /// Items the player currently holds, as far as the ledger knows
+    pub fn items_held_by(&self, player_id: Uuid) -> impl Iterator<Item=&ItemRecord> + '_ {
+        self.items.values()
+            .filter(move |record| record.owner.is_some_and(|owner| owner.player_id == player_id))
+    }
+
+    pub fn items(&self) -> impl Iterator<Item=&ItemRecord> {
+        self.items.values()
+    }
+
+    /// Every inconsistency found so far, in the order they were found
+    pub fn inconsistencies(&self) -> &[ItemInconsistencyEntry] {
+        &self.inconsistencies
+    }
+}
+
+fn owner_inconsistency(known: Option<ItemOwner>, actual: ItemOwner, is_drop: bool) -> Option<ItemInconsistency> {
+    match known {
+        None if is_drop => Some(ItemInconsistency::DroppedWithUnknownOwner { player_id: actual.player_id }),
+        None => Some(ItemInconsistency::ChangedWithUnknownOwner { player_id: actual.player_id }),
+        Some(known) if known.player_id != actual.player_id => Some(ItemInconsistency::OwnerMismatch {
+            expected: known.player_id,
+            actual: actual.player_id,
+        }),
+        Some(_) => None,
+    }
+}
+
+fn owner(feed_event: &EventuallyEvent) -> Result<ItemOwner, ItemLedgerError> {
+    let player_id = *feed_event.player_tags.first()
+        .ok_or_else(|| ItemLedgerError::NoPlayer {
+            event_type: feed_event.r#type,
+            event_id: feed_event.id,
+        })?;
+
+    Ok(ItemOwner {
+        player_id,
+        team_id: feed_event.team_tags.first().copied(),
+    })
+}
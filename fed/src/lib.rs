@@ -1,9 +1,33 @@
 #![feature(let_chains)]
 mod parse;
 mod fed_event;
+mod game_state;
+mod feed_metadata;
+mod stats;
+mod box_score;
+mod mod_tracker;
+mod item_ledger;
+mod roster_tracker;
+mod player_registry;
+mod standings;
+mod game_update;
+mod render;
+mod round_trip;
 
 pub use parse::stream::{expansion_era_events, EXPANSION_ERA_START, EXPANSION_ERA_END};
 pub use eventually_api::Weather;
 pub use fed_event::*;
-pub use parse::{parse_feed_event, feed_event_from_json};
+pub use parse::{parse_feed_event, feed_event_from_json, parse_game_update};
 pub use parse::error::FeedParseError;
+pub use feed_metadata::MissingMetadata;
+pub use game_state::{GameState, GameStateError, TeamState, Baserunner, PlayerRef};
+pub use stats::{BattingStats, PitchingStats, PlayerSeasonStats, StatsAccumulator};
+pub use box_score::{BoxScore, BoxScoreBuilder, TeamBoxScore, BattingLine, PitchingLine, PlayByPlayEntry};
+pub use mod_tracker::{ModTracker, ModTrackerError, ModHolder, ActiveMod, ModHistoryChange, ModHistoryEntry};
+pub use item_ledger::{ItemLedger, ItemLedgerError, ItemRecord, ItemOwner, ItemHistoryChange, ItemHistoryEntry, ItemInconsistency, ItemInconsistencyEntry};
+pub use roster_tracker::{RosterTracker, RosterTrackerError, TeamRoster, RosterChange, RosterHistoryEntry, RosterInconsistency, RosterInconsistencyEntry};
+pub use player_registry::{PlayerRegistry, PlayerRecord, PlayerNameEntry, PlayerNameKind, PlayerInconsistency, PlayerInconsistencyEntry};
+pub use standings::{Standings, TeamRecord, PostseasonBracket, PostseasonTeam, PostseasonGame};
+pub use game_update::{GameUpdate, GameUpdateBuilder, GameUpdateMismatch};
+pub use render::{EventRenderer, LinkTemplates};
+pub use round_trip::{verify_round_trip, diff_feed_events, RoundTripReport, RoundTripError, RoundTripDifference, TagKind};
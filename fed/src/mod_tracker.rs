@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use uuid::Uuid;
+use eventually_api::{EventType, EventuallyEvent};
+
+use crate::{FedEvent, FedEventData, Mod, ModDuration};
+use crate::feed_metadata::{metadata_array, metadata_mod, missing, value_in, MissingMetadata};
+
+#[derive(Error, Debug)]
+pub enum ModTrackerError {
+    #[error(transparent)]
+    MissingMetadata(#[from] MissingMetadata),
+
+    #[error("{event_type:?} event {event_id} has unknown mod duration {duration}")]
+    UnknownModDuration {
+        event_type: EventType,
+        event_id: Uuid,
+        duration: i64,
+    },
+
+    #[error("{event_type:?} event {event_id} changes a mod but isn't tagged with a player or team")]
+    NoModHolder {
+        event_type: EventType,
+        event_id: Uuid,
+    },
+}
+
+/// Something that can have mods
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id")]
+pub enum ModHolder {
+    Player(Uuid),
+    Team(Uuid),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveMod {
+    /// The mod, e.g. [`Mod::OnFire`]
+    pub mod_id: Mod,
+    pub duration: ModDuration,
+
+    /// The game the mod was gained in, if it was gained in a game. Game mods wear off when this
+    /// game ends.
+    pub game_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum ModHistoryChange {
+    Added(ActiveMod),
+    #[serde(rename_all = "camelCase")]
+    Removed {
+        mod_id: Mod,
+        /// Which duration of the mod was removed, or None to remove it at every duration. If the
+        /// holder doesn't have the mod at this duration, it's removed at every duration.
+        duration: Option<ModDuration>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModHistoryEntry {
+    /// Position of the event that caused this change in the sequence of events given to the tracker
+    pub sequence: usize,
+    pub event_id: Uuid,
+    pub created: DateTime<Utc>,
+    pub change: ModHistoryChange,
+}
+
+/// Applies a change to a holder's active mods
+fn apply_change(mods: &mut Vec<ActiveMod>, change: &ModHistoryChange) {
+    match change {
+        ModHistoryChange::Added(active) => {
+            if !mods.iter().any(|m| m.mod_id == active.mod_id && m.duration == active.duration) {
+                mods.push(active.clone());
+            }
+        }
+        ModHistoryChange::Removed { mod_id, duration } => {
+            // The duration a mod is added with doesn't always match the one it expires with (some
+            // mods that wear off after the game are added with type 0), so fall back to removing
+            // it at any duration
+            let exact_match = mods.iter().any(|m| m.mod_id == *mod_id && Some(m.duration) == *duration);
+            mods.retain(|m| m.mod_id != *mod_id || (exact_match && Some(m.duration) != *duration));
+        }
+    }
+}
+
+/// Reconstructs the mods of every player and team from a stream of FedEvents, which must be in
+/// chronological order.
+///
+/// Which mods an event adds or removes is read from the mod change events that the event builder
+/// generates for it, so this covers every variant the builder knows about. Mods wear off when the
+/// feed says they do (PlayerModExpires and TeamModExpires). In addition, seasonal, weekly, and game
+/// mods are dropped when a new season starts, and game mods are dropped at the end of the game they
+/// were gained in.
+///
+/// Weekly mods stay active until the feed says they expire or the season ends. The tracker doesn't
+/// work out when a week ends on its own, so a weekly mod whose expiry is missing from the events
+/// is reported as active for the rest of the season.
+#[derive(Debug, Default)]
+pub struct ModTracker {
+    current: HashMap<ModHolder, Vec<ActiveMod>>,
+    history: HashMap<ModHolder, Vec<ModHistoryEntry>>,
+    event_sequence: HashMap<Uuid, usize>,
+    num_events: usize,
+    season: Option<i32>,
+}
+
+struct EventContext<'a> {
+    sequence: usize,
+    event: &'a FedEvent,
+    game_id: Option<Uuid>,
+}
+
+impl ModTracker {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Applies one event. On error, mod changes from earlier in the same event may already have
+    /// been applied.
+    pub fn apply(&mut self, event: &FedEvent) -> Result<(), ModTrackerError> {
+        let ctx = EventContext {
+            sequence: self.num_events,
+            event,
+            game_id: event.data.game().map(|game| game.game_id),
+        };
+        self.event_sequence.insert(event.id, self.num_events);
+        self.num_events += 1;
+
+        if self.season.is_some_and(|season| event.season > season) {
+            self.expire_where(&ctx, |m| m.duration != ModDuration::Permanent);
+        }
+        self.season = Some(event.season);
+
+        self.apply_feed_event(&ctx, &event.clone().into_feed_event())?;
+
+        if let FedEventData::GameEnd { game, .. } = &event.data {
+            self.expire_where(&ctx, |m| m.duration == ModDuration::Game && m.game_id == Some(game.game_id));
+        }
+
+        Ok(())
+    }
+
+    fn apply_feed_event(&mut self, ctx: &EventContext, feed_event: &EventuallyEvent) -> Result<(), ModTrackerError> {
+        let changes = match feed_event.r#type {
+            EventType::AddedMod | EventType::AddedModFromOtherMod => {
+                vec![self.added(ctx, feed_event, &feed_event.metadata.other)?]
+            }
+            EventType::RemovedMod | EventType::RemovedModFromOtherMod => {
+                vec![removed(feed_event, &feed_event.metadata.other)?]
+            }
+            EventType::ModChange | EventType::ChangedModFromOtherMod => {
+                let other = &feed_event.metadata.other;
+                let duration = Some(duration(feed_event, other)?);
+                vec![
+                    ModHistoryChange::Removed { mod_id: metadata_mod(feed_event, other, "from")?, duration },
+                    self.added_named(ctx, feed_event, metadata_mod(feed_event, other, "to")?, other)?,
+                ]
+            }
+            EventType::ModExpires => {
+                let duration = Some(duration(feed_event, &feed_event.metadata.other)?);
+                metadata_array(feed_event, "mods")?.iter()
+                    .map(|mod_id| {
+                        let mod_id = mod_id.as_str()
+                            .ok_or_else(|| missing(feed_event, "mods"))?;
+                        Ok(ModHistoryChange::Removed { mod_id: Mod::from_id(mod_id), duration })
+                    })
+                    .collect::<Result<_, MissingMetadata>>()?
+            }
+            EventType::AddedModsFromAnotherMod => {
+                metadata_array(feed_event, "adds")?.iter()
+                    .map(|added| self.added(ctx, feed_event, added))
+                    .collect::<Result<_, _>>()?
+            }
+            EventType::RemovedModsFromAnotherMod => {
+                metadata_array(feed_event, "removes")?.iter()
+                    .map(|removed_mod| removed(feed_event, removed_mod))
+                    .collect::<Result<_, _>>()?
+            }
+            _ => vec![],
+        };
+
+        if !changes.is_empty() {
+            let holder = if let Some(&player_id) = feed_event.player_tags.first() {
+                ModHolder::Player(player_id)
+            } else if let Some(&team_id) = feed_event.team_tags.first() {
+                ModHolder::Team(team_id)
+            } else {
+                return Err(ModTrackerError::NoModHolder {
+                    event_type: feed_event.r#type,
+                    event_id: feed_event.id,
+                });
+            };
+
+            for change in changes {
+                self.record(ctx, holder, change);
+            }
+        }
+
+        for child in &feed_event.metadata.children {
+            self.apply_feed_event(ctx, child)?;
+        }
+
+        Ok(())
+    }
+
+    fn added(&self, ctx: &EventContext, feed_event: &EventuallyEvent, metadata: &Value) -> Result<ModHistoryChange, ModTrackerError> {
+        let mod_id = metadata_mod(feed_event, metadata, "mod")?;
+        self.added_named(ctx, feed_event, mod_id, metadata)
+    }
+
+    fn added_named(&self, ctx: &EventContext, feed_event: &EventuallyEvent, mod_id: Mod, metadata: &Value) -> Result<ModHistoryChange, ModTrackerError> {
+        Ok(ModHistoryChange::Added(ActiveMod {
+            mod_id,
+            duration: duration(feed_event, metadata)?,
+            game_id: ctx.game_id,
+        }))
+    }
+
+    fn record(&mut self, ctx: &EventContext, holder: ModHolder, change: ModHistoryChange) {
+        apply_change(self.current.entry(holder).or_default(), &change);
+        self.history.entry(holder).or_default()
+            .push(ModHistoryEntry {
+                sequence: ctx.sequence,
+                event_id: ctx.event.id,
+                created: ctx.event.created,
+                change,
+            });
+    }
+
+    fn expire_where(&mut self, ctx: &EventContext, predicate: impl Fn(&ActiveMod) -> bool) {
+        let expired: Vec<_> = self.current.iter()
+            .flat_map(|(holder, mods)| {
+                mods.iter()
+                    .filter(|m| predicate(m))
+                    .map(|m| (*holder, ModHistoryChange::Removed { mod_id: m.mod_id.clone(), duration: Some(m.duration) }))
+            })
+            .collect();
+
+        for (holder, change) in expired {
+            self.record(ctx, holder, change);
+        }
+    }
+
+    /// Mods the holder has after every event applied so far
+    pub fn current_mods(&self, holder: ModHolder) -> &[ActiveMod] {
+        self.current.get(&holder).map_or(&[], |mods| mods.as_slice())
+    }
+
+    /// Every change to the holder's mods, in order
+    pub fn history(&self, holder: ModHolder) -> &[ModHistoryEntry] {
+        self.history.get(&holder).map_or(&[], |history| history.as_slice())
+    }
+
+    fn mods_where(&self, holder: ModHolder, include: impl Fn(&ModHistoryEntry) -> bool) -> Vec<ActiveMod> {
+        let mut mods = Vec::new();
+        for entry in self.history(holder).iter().take_while(|entry| include(entry)) {
+            apply_change(&mut mods, &entry.change);
+        }
+        mods
+    }
+
+    /// Mods the holder had just after the given event was applied, or None if that event hasn't
+    /// been applied
+    pub fn mods_at_event(&self, holder: ModHolder, event_id: Uuid) -> Option<Vec<ActiveMod>> {
+        let sequence = *self.event_sequence.get(&event_id)?;
+        Some(self.mods_where(holder, |entry| entry.sequence <= sequence))
+    }
+
+    /// Mods the holder had at the given time, including changes from events created at exactly
+    /// that time
+    pub fn mods_at(&self, holder: ModHolder, time: DateTime<Utc>) -> Vec<ActiveMod> {
+        self.mods_where(holder, |entry| entry.created <= time)
+    }
+}
+
+fn duration(feed_event: &EventuallyEvent, metadata: &Value) -> Result<ModDuration, ModTrackerError> {
+    let duration = value_in(feed_event, metadata, "type")?.as_i64()
+        .ok_or_else(|| missing(feed_event, "type"))?;
+
+    ModDuration::try_from(duration)
+        .map_err(|_| ModTrackerError::UnknownModDuration {
+            event_type: feed_event.r#type,
+            event_id: feed_event.id,
+            duration,
+        })
+}
+
+fn removed(feed_event: &EventuallyEvent, metadata: &Value) -> Result<ModHistoryChange, ModTrackerError> {
+    Ok(ModHistoryChange::Removed {
+        mod_id: metadata_mod(feed_event, metadata, "mod")?,
+        duration: metadata.get("type")
+            .map(|_| duration(feed_event, metadata))
+            .transpose()?,
+    })
+}
@@ -5,7 +5,7 @@ use eventually_api::{EventCategory, EventMetadata, EventType, EventuallyEvent};
 use std::fmt::Write;
 use crate::ItemDamaged;
 
-use crate::fed_event::{FreeRefill, GameEvent, ModChangeSubEvent, ModChangeSubEventWithPlayer, Scores, SpicyStatus, StoppedInhabiting, SubEvent};
+use crate::fed_event::{FreeRefill, GameEvent, Mod, ModChangeSubEvent, ModChangeSubEventWithPlayer, ModDuration, Scores, SpicyStatus, StoppedInhabiting, SubEvent};
 
 pub struct EventBuilderCommon {
     pub id: Uuid,
@@ -16,6 +16,9 @@ pub struct EventBuilderCommon {
     pub season: i32,
     pub tournament: i32,
     pub nuts: i32,
+
+    /// Only the description is needed, so children aren't built
+    pub description_only: bool,
 }
 
 impl EventBuilderCommon {
@@ -266,18 +269,23 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
         }
     }
 
-    pub fn child(self, child: impl Into<EventBuilderChildFull>) -> Self {
+    /// Adds a child. The child is only made if the whole event is being built, not just its
+    /// description.
+    pub fn child<T: Into<EventBuilderChildFull>>(self, make_child: impl FnOnce() -> T) -> Self {
+        if self.common.description_only { return self; }
         let mut children = self.children;
-        children.push(child.into());
+        children.push(make_child().into());
         Self {
             children,
             ..self
         }
     }
 
-    pub fn children<T: Into<EventBuilderChildFull>>(self, new_children: impl IntoIterator<Item=T>) -> Self {
+    /// Adds children. Like [`Self::child`], they're only made if the whole event is being built.
+    pub fn children<T: Into<EventBuilderChildFull>, I: IntoIterator<Item=T>>(self, make_children: impl FnOnce() -> I) -> Self {
+        if self.common.description_only { return self; }
         let mut children = self.children;
-        children.extend(new_children.into_iter().map(Into::into));
+        children.extend(make_children().into_iter().map(Into::into));
         Self {
             children,
             ..self
@@ -285,20 +293,21 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
     }
 
     pub fn build(self) -> EventuallyEvent {
+        // Children aren't made at all when only the description is wanted
+        let with_children = !self.common.description_only;
         let mut children_builders = Vec::new();
         let mut description = String::new();
         let mut player_tags = Vec::new();
 
         // Just guessing that attractor is before unscatter
-        let has_attractor = if let Some(attractor) = self.game.as_ref().and_then(|game| game.attractor_secret_base.as_ref()) {
+        let attractor_id = if let Some(attractor) = self.game.as_ref().and_then(|game| game.attractor_secret_base.as_ref()) {
             push_description!(description, "{} enters the Secret Base...", attractor.player_name);
-            player_tags.push(attractor.player_id);
-            true
+            Some(attractor.player_id)
         } else {
-            false
+            None
         };
 
-        if let Some(unscatter) = self.game.as_ref().and_then(|game| game.unscatter.as_ref()) {
+        if let Some(unscatter) = self.game.as_ref().and_then(|game| game.unscatter.as_ref()) && with_children {
             children_builders.push(
                 EventBuilderChild::new(&unscatter.sub_event)
                     .update(EventBuilderUpdate {
@@ -310,8 +319,8 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "SCATTERED",
-                        "type": 0, // ?
+                        "mod": Mod::Scattered,
+                        "type": ModDuration::Permanent as i64,
                     }))
             );
         }
@@ -323,19 +332,15 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
         self.build_item_damage(&self.item_damage_before_score, &mut description, &mut children_builders);
 
         if let Some((scores, score_text)) = self.scores {
+            // Each scorer's children are together, in the same order as their lines in the
+            // description
             for score in &scores.scores {
-                if let Some(item_damage) = &score.item_damage {
+                if let Some(item_damage) = &score.item_damage && with_children {
                     children_builders.push(make_item_damage_child(
                         possessive(score.player_name.clone()), item_damage, true)
                     )
                 }
-            }
-            description += &*scores.to_description_with_text_between(score_text,
-                                                                     &self.update.description_after_score,
-                                                                     (self.common.season, self.common.day) < (15, 3));
-            for score in &scores.scores {
-                if let Some(attraction) = &score.attraction {
-                    player_tags.push(score.player_id);
+                if let Some(attraction) = &score.attraction && with_children {
                     children_builders.push(EventBuilderChild::new(&attraction.sub_event)
                         .update(EventBuilderUpdate {
                             r#type: EventType::PlayerAddedToTeam,
@@ -354,12 +359,14 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
                         })))
                 }
             }
-
+            description += &*scores.to_description_with_text_between(score_text,
+                                                                     &self.update.description_after_score,
+                                                                     (self.common.season, self.common.day) < (15, 3));
         } else {
             description += &*self.update.description_after_score;
         }
 
-        if let Some(inh) = self.stopped_inhabiting {
+        if let Some(inh) = self.stopped_inhabiting && with_children {
             children_builders.push(
                 EventBuilderChild::new(&inh.sub_event)
                     .update(EventBuilderUpdate {
@@ -371,16 +378,24 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
                         ..Default::default()
                     })
                     .metadata(json!({
-                        "mod": "INHABITING",
-                        "type": 0, // ?
+                        "mod": Mod::Inhabiting,
+                        "type": ModDuration::Permanent as i64,
                     }))
             )
         }
 
         if let Some((scores, _)) = self.scores {
-            children_builders.extend(scores.free_refills.iter()
-                .map(|free_refill| make_free_refill_child(free_refill)));
-            player_tags.extend(scores.scorer_ids());
+            if with_children {
+                children_builders.extend(scores.free_refills.iter()
+                    .map(|free_refill| make_free_refill_child(free_refill)));
+            }
+            for score in &scores.scores {
+                player_tags.push(score.player_id);
+                // Attracted players are tagged again
+                if score.attraction.is_some() {
+                    player_tags.push(score.player_id);
+                }
+            }
         }
 
         self.build_item_damage(&self.item_damage_after_score, &mut description, &mut children_builders);
@@ -392,7 +407,7 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
                 push_description!(description, "{player_name} is Heating Up!");
             }
             SpicyChange::RedHot { red_hot, player_id, player_name } => {
-                if let Some(red_hot) = red_hot {
+                if let Some(red_hot) = red_hot && with_children {
                     children_builders.push(
                         EventBuilderChild::new(&red_hot.sub_event)
                             .update(EventBuilderUpdate {
@@ -404,8 +419,8 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
                                 ..Default::default()
                             })
                             .metadata(json!({
-                                "mod": "ON_FIRE",
-                                "type": 0, // ?
+                                "mod": Mod::OnFire,
+                                "type": ModDuration::Permanent as i64,
                             })),
                     );
                 }
@@ -413,21 +428,23 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
                 push_description!(description, "{player_name} is Red Hot!");
             }
             SpicyChange::CooledOff { cooled_off, player_name } => {
-                children_builders.push(
-                    EventBuilderChild::new(&cooled_off.sub_event)
-                        .update(EventBuilderUpdate {
-                            r#type: EventType::RemovedMod,
-                            category: EventCategory::Changes,
-                            description: format!("{player_name} cooled off."),
-                            team_tags: vec![cooled_off.team_id],
-                            player_tags: vec![cooled_off.player_id],
-                            ..Default::default()
-                        })
-                        .metadata(json!({
-                            "mod": "ON_FIRE",
-                            "type": 0, // ?
-                        }))
-                );
+                if with_children {
+                    children_builders.push(
+                        EventBuilderChild::new(&cooled_off.sub_event)
+                            .update(EventBuilderUpdate {
+                                r#type: EventType::RemovedMod,
+                                category: EventCategory::Changes,
+                                description: format!("{player_name} cooled off."),
+                                team_tags: vec![cooled_off.team_id],
+                                player_tags: vec![cooled_off.player_id],
+                                ..Default::default()
+                            })
+                            .metadata(json!({
+                                "mod": Mod::OnFire,
+                                "type": ModDuration::Permanent as i64,
+                            }))
+                    );
+                }
 
                 player_tags.push(cooled_off.player_id);
                 push_description!(description, "{player_name} cooled off.")
@@ -482,7 +499,13 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
             ..Default::default()
         };
 
-        build_final(self.common, self.game, self.update, metadata, description, player_tags, has_attractor)
+        // The attractor's tag comes before the event's own tags
+        let mut update = self.update;
+        if let Some(attractor_id) = attractor_id {
+            update.player_tags.insert(0, attractor_id);
+        }
+
+        build_final(self.common, self.game, update, metadata, description, player_tags, attractor_id.is_some())
     }
 
     fn build_item_damage(&self, v: &Vec<(&ItemDamaged, &str)>, description: &mut String, children_builders: &mut Vec<EventBuilderChildFull>) {
@@ -490,8 +513,10 @@ impl<'ts, 'ti, 'tc, 'tt> EventBuilderFull<'ts, 'ti, 'tc, 'tt> {
             let player_name_possessive = possessive(player_name.to_string());
             push_description!(description, "{}{player_name_possessive} {item_damage}",
                               if (self.common.season, self.common.day) < (15, 3) { " " } else { "" });
-            children_builders.push(make_item_damage_child(player_name_possessive, item_damage,
-                                                          (self.common.season, self.common.day) < (15, 3)));
+            if !self.common.description_only {
+                children_builders.push(make_item_damage_child(player_name_possessive, item_damage,
+                                                              (self.common.season, self.common.day) < (15, 3)));
+            }
         }
     }
 }
@@ -507,8 +532,8 @@ pub fn make_free_refill_child(free_refill: &FreeRefill) -> EventBuilderChildFull
             ..Default::default()
         })
         .metadata(json!({
-                "mod": "COFFEE_RALLY",
-                "type": 0, // ?
+                "mod": Mod::CoffeeRally,
+                "type": ModDuration::Permanent as i64,
             }))
 }
 
@@ -529,7 +554,7 @@ fn make_item_damage_child(player_name_possessive: String, item_damage: &ItemDama
             "itemHealthBefore": item_damage.health + 1,
             "itemId": item_damage.item_id,
             "itemName": item_damage.item_name,
-            "mods": Vec::<String>::new(), // TODO vec of what?
+            "mods": item_damage.item_mods,
             "playerItemRatingAfter": zero_int(item_damage.player_item_rating_after),
             "playerItemRatingBefore": zero_int(item_damage.player_item_rating_before),
             "playerRating": zero_int(item_damage.player_rating),
@@ -1,8 +1,9 @@
+use strum_macros::AsRefStr;
 use thiserror::Error;
 use uuid::Uuid;
 use eventually_api::EventType;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, AsRefStr)]
 pub enum FeedParseError {
     #[error(transparent)]
     EventuallyEventJsonParseFailed(#[from] serde_json::Error),
@@ -99,6 +100,12 @@ pub enum FeedParseError {
         value: String,
     },
 
+    #[error("Unexpected subseasonal mod \"{mod_name}\" in {event_type:?} event")]
+    UnexpectedSubseasonalMod {
+        event_type: EventType,
+        mod_name: String,
+    },
+
     #[error("Unknown phase {phase} for {event_type:?} event")]
     UnknownPhase {
         phase: i32,
@@ -111,6 +118,22 @@ pub enum FeedParseError {
     #[error("Unknown weather {0}")]
     UnknownWeather(i32),
 
+    #[error("Expected field \"{field}\" in game update")]
+    MissingGameUpdateField {
+        field: &'static str,
+    },
+
+    #[error("Game update didn't match any known event: {description}")]
+    UnrecognizedGameUpdate {
+        description: String,
+    },
+
+    #[error("More item damage lines for {player_name} than a {event_type:?} event can hold")]
+    TooManyItemDamages {
+        event_type: EventType,
+        player_name: String,
+    },
+
     #[error("Expected location to be one of {expected:?} but it was {actual}")]
     InvalidLocation {
         expected: &'static [i64],
@@ -14,6 +14,7 @@ use crate::{
     ItemDamaged,
     ItemGained,
     ItemRepaired,
+    Mod,
     ModChangeSubEvent,
     ModChangeSubEventWithPlayer,
     ModDuration,
@@ -22,11 +23,18 @@ use crate::{
     Scores,
     ScoringPlayer,
     SpicyStatus,
+    StatChangeCategory,
     StoppedInhabiting,
     SubEvent,
 };
 
-pub struct EventBuilder(EventuallyEvent);
+pub struct EventBuilder {
+    event: EventuallyEvent,
+
+    // When set, only the description is built. Children aren't built at all, and tags and metadata
+    // are dropped.
+    description_only: bool,
+}
 
 
 // Newtype with Display implementation that prints the string using grammatically correct possessive
@@ -45,131 +53,153 @@ impl Display for Possessive<'_> {
 
 impl EventBuilder {
     pub fn new(id: Uuid, created: DateTime<Utc>, sim: String, day: i32, season: i32, tournament: i32, phase: i32, nuts: i32) -> Self {
-        let mut builder = Self(EventuallyEvent {
-            id,
-            created,
-            r#type: Default::default(),
-            category: Default::default(),
-            metadata: Default::default(),
-            blurb: "".to_string(),
-            description: "".to_string(),
-            player_tags: vec![],
-            game_tags: vec![],
-            team_tags: vec![],
-            sim,
-            day,
-            season,
-            tournament,
-            phase,
-            nuts,
-        });
-
-        builder.0.metadata.other = serde_json::json!({});
+        let mut builder = Self {
+            event: EventuallyEvent {
+                id,
+                created,
+                r#type: Default::default(),
+                category: Default::default(),
+                metadata: Default::default(),
+                blurb: "".to_string(),
+                description: "".to_string(),
+                player_tags: vec![],
+                game_tags: vec![],
+                team_tags: vec![],
+                sim,
+                day,
+                season,
+                tournament,
+                phase,
+                nuts,
+            },
+            description_only: false,
+        };
+
+        builder.event.metadata.other = serde_json::json!({});
 
         builder
     }
 
+    /// Makes this builder skip everything but the description. Use this when only the text of the
+    /// event is needed, since building children and metadata is most of the cost.
+    pub fn set_description_only(&mut self, description_only: bool) {
+        self.description_only = description_only;
+    }
+
     pub fn description(&self) -> &str {
-        &self.0.description
+        &self.event.description
     }
 
     pub fn set_description(&mut self, description: String) {
-        self.0.description = description;
+        self.event.description = description;
     }
 
     pub fn set_category(&mut self, category: EventCategory) {
-        self.0.category = category;
+        self.event.category = category;
     }
 
     pub fn set_game(&mut self, game: GameEvent) {
-        self.0.game_tags = vec![game.game_id];
-        self.0.team_tags = vec![game.away_team, game.home_team];
-        self.0.metadata.play = Some(game.play);
+        self.event.game_tags = vec![game.game_id];
+        self.event.team_tags = vec![game.away_team, game.home_team];
+        self.event.metadata.play = Some(game.play);
         // Root events of games are always -1, non-games are null
-        self.0.metadata.sub_play = Some(-1);
+        self.event.metadata.sub_play = Some(-1);
 
         if let Some(unscatter) = game.unscatter {
             self.push_child(unscatter.sub_event, |mut child| {
                 child.push_description(&format!("{} was Unscattered.", unscatter.player_name));
                 child.push_player_tag(unscatter.player_id);
                 child.push_team_tag(unscatter.team_id);
-                child.push_metadata_str("mod", "SCATTERED");
-                child.push_metadata_i64("type", 0);
+                child.push_metadata_str("mod", Mod::Scattered);
+                child.push_metadata_i64("type", ModDuration::Permanent as i64);
                 child.build(EventType::RemovedMod)
             });
         }
 
         if let Some(attractor) = game.attractor_secret_base {
             self.push_description(&format!("{} enters the Secret Base...", attractor.player_name));
-            self.push_player_tag(attractor.player_id)
+            // The attractor's tag comes first even if the event's own tags were pushed already
+            if !self.description_only {
+                self.event.player_tags.insert(0, attractor.player_id);
+            }
         }
     }
 
     pub fn push_child<F>(&mut self, sub_event: SubEvent, build_func: F) where F: FnOnce(Self) -> EventuallyEvent {
-        let mut child_builder = Self::new(sub_event.id, sub_event.created, self.0.sim.clone(), self.0.day, self.0.season, self.0.tournament, self.0.phase, sub_event.nuts);
+        if self.description_only { return; }
+        let mut child_builder = Self::new(sub_event.id, sub_event.created, self.event.sim.clone(), self.event.day, self.event.season, self.event.tournament, self.event.phase, sub_event.nuts);
         // Childrens' categories are usually Changes
-        child_builder.0.category = EventCategory::Changes;
-        child_builder.0.metadata.parent = Some(self.0.id);
-        child_builder.0.game_tags = self.0.game_tags.clone();
-        child_builder.0.metadata.play = self.0.metadata.play;
-        child_builder.0.metadata.sub_play = Some(self.0.metadata.children.len() as i64);
-        self.0.metadata.children.push(build_func(child_builder))
+        child_builder.event.category = EventCategory::Changes;
+        child_builder.event.metadata.parent = Some(self.event.id);
+        child_builder.event.game_tags = self.event.game_tags.clone();
+        child_builder.event.metadata.play = self.event.metadata.play;
+        child_builder.event.metadata.sub_play = Some(self.event.metadata.children.len() as i64);
+        self.event.metadata.children.push(build_func(child_builder))
     }
 
     pub fn push_description(&mut self, desc: &str) {
-        if !self.0.description.is_empty() {
-            self.0.description.push('\n');
+        if !self.event.description.is_empty() {
+            self.event.description.push('\n');
         }
-        self.0.description += desc.into();
+        self.event.description += desc.into();
+    }
+
+    /// Adds to the last line of the description, rather than starting a new line
+    pub fn append_description(&mut self, desc: &str) {
+        self.event.description += desc;
     }
 
     pub fn push_player_tag(&mut self, player_id: Uuid) {
-        self.0.player_tags.push(player_id)
+        if self.description_only { return; }
+        self.event.player_tags.push(player_id)
     }
 
     pub fn push_team_tag(&mut self, team_id: Uuid) {
-        self.0.team_tags.push(team_id)
+        if self.description_only { return; }
+        self.event.team_tags.push(team_id)
     }
 
     fn metadata_mut(&mut self) -> &mut Map<String, Value> {
-        self.0.metadata.other
+        self.event.metadata.other
             .as_object_mut()
             .expect("Internal error: This metadata should always be an object")
     }
 
+    fn insert_metadata(&mut self, key: String, value: Value) {
+        if self.description_only { return; }
+        self.metadata_mut().insert(key, value);
+    }
+
     pub fn push_metadata_null(&mut self, key: impl Into<String>) {
-        self.metadata_mut()
-            .insert(key.into(), Value::Null);
+        self.insert_metadata(key.into(), Value::Null);
     }
 
     pub fn push_metadata_str(&mut self, key: impl Into<String>, value: impl Into<String>) {
-        self.metadata_mut()
-            .insert(key.into(), Value::String(value.into()));
+        self.insert_metadata(key.into(), Value::String(value.into()));
     }
 
     pub fn push_metadata_str_vec(&mut self, key: impl Into<String>, value: Vec<String>) {
-        self.metadata_mut()
-            .insert(key.into(), value.into());
+        self.insert_metadata(key.into(), value.into());
+    }
+
+    pub fn push_metadata_mod_vec(&mut self, key: impl Into<String>, value: Vec<Mod>) {
+        self.insert_metadata(key.into(), value.into_iter().map(String::from).collect::<Vec<_>>().into());
     }
 
     pub fn push_metadata_json_vec(&mut self, key: impl Into<String>, value: Vec<Value>) {
-        self.metadata_mut()
-            .insert(key.into(), value.into());
+        self.insert_metadata(key.into(), value.into());
     }
 
     pub fn push_metadata_uuid(&mut self, key: impl Into<String>, value: Uuid) {
-        self.metadata_mut()
-            .insert(key.into(), Value::String(value.to_string()));
+        self.insert_metadata(key.into(), Value::String(value.to_string()));
     }
 
     pub fn push_metadata_i64(&mut self, key: impl Into<String>, value: impl Into<i64>) {
-        self.metadata_mut()
-            .insert(key.into(), value.into().into());
+        self.insert_metadata(key.into(), value.into().into());
     }
 
     pub fn push_metadata_f64_forced(&mut self, key: impl Into<String>, value: f64) {
-        self.metadata_mut()
-            .insert(key.into(), value.into());
+        self.insert_metadata(key.into(), value.into());
     }
 
     pub fn push_metadata_f64(&mut self, key: impl Into<String>, value: f64) {
@@ -202,7 +232,7 @@ impl EventBuilder {
                 child.push_team_tag(gained_item.team_id);
                 child.push_metadata_uuid("itemId", lost_item.item_id);
                 child.push_metadata_str("itemName", lost_item.item_name);
-                child.push_metadata_str_vec("mods", lost_item.item_mods);
+                child.push_metadata_mod_vec("mods", lost_item.item_mods);
                 child.push_metadata_f64("playerItemRatingAfter", lost_item.player_item_rating_after);
                 child.push_metadata_f64("playerItemRatingBefore", lost_item.player_item_rating_before);
                 child.push_metadata_f64("playerRating", gained_item.player_rating);
@@ -219,7 +249,7 @@ impl EventBuilder {
             child.push_team_tag(gained_item.team_id);
             child.push_metadata_uuid("itemId", gained_item.item_id);
             child.push_metadata_str("itemName", gained_item.item_name);
-            child.push_metadata_str_vec("mods", gained_item.item_mods);
+            child.push_metadata_mod_vec("mods", gained_item.item_mods);
             child.push_metadata_f64("playerItemRatingAfter", gained_item.player_item_rating_after);
             child.push_metadata_f64("playerItemRatingBefore", gained_item.player_item_rating_before);
             child.push_metadata_f64("playerRating", gained_item.player_rating);
@@ -248,7 +278,7 @@ impl EventBuilder {
     fn push_item_damage_impl(&mut self, dmg: ItemDamaged, player_name: &str) {
         let description = format!("{}{} {dmg}",
                                   // bug-for-bug compatibility :)
-                                  if (self.0.season, self.0.day) < (15, 3) { " " } else { "" },
+                                  if (self.event.season, self.event.day) < (15, 3) { " " } else { "" },
                                   Possessive(player_name));
         self.push_description(&description);
         self.push_child(dmg.sub_event, |mut child| {
@@ -260,7 +290,7 @@ impl EventBuilder {
             child.push_metadata_i64("itemHealthBefore", dmg.health + 1);
             child.push_metadata_uuid("itemId", dmg.item_id);
             child.push_metadata_str("itemName", dmg.item_name);
-            child.push_metadata_str_vec("mods", dmg.item_mods);
+            child.push_metadata_mod_vec("mods", dmg.item_mods);
             child.push_metadata_f64("playerItemRatingAfter", dmg.player_item_rating_after);
             child.push_metadata_f64("playerItemRatingBefore", dmg.player_item_rating_before);
             child.push_metadata_f64("playerRating", dmg.player_rating);
@@ -276,7 +306,7 @@ impl EventBuilder {
             if let Some(team_id) = si.inhabiting_player_team_id {
                 child.push_team_tag(team_id);
             }
-            child.push_metadata_str("mod", "INHABITING");
+            child.push_metadata_str("mod", Mod::Inhabiting);
             child.push_metadata_i64("type", ModDuration::Permanent as i64);
             child.build(EventType::RemovedMod)
         })
@@ -291,7 +321,7 @@ impl EventBuilder {
                 child.push_description(&common_description);
                 child.push_player_tag(fr.player_id);
                 if let Some(t) = fr.team_id { child.push_team_tag(t) };
-                child.push_metadata_str("mod", "COFFEE_RALLY");
+                child.push_metadata_str("mod", Mod::CoffeeRally);
                 child.push_metadata_i64("type", ModDuration::Permanent as i64);
                 child.build(EventType::RemovedMod)
             });
@@ -372,7 +402,7 @@ impl EventBuilder {
                         child.push_description(&description);
                         child.push_player_tag(player_id);
                         child.push_team_tag(mod_added.team_id);
-                        child.push_metadata_str("mod", "ON_FIRE");
+                        child.push_metadata_str("mod", Mod::OnFire);
                         child.push_metadata_i64("type", ModDuration::Permanent as i64);
                         child.build(EventType::AddedMod)
                     })
@@ -391,7 +421,7 @@ impl EventBuilder {
                 child.push_description(&description);
                 child.push_player_tag(co.player_id);
                 child.push_team_tag(co.team_id);
-                child.push_metadata_str("mod", "ON_FIRE");
+                child.push_metadata_str("mod", Mod::OnFire);
                 child.push_metadata_i64("type", ModDuration::Permanent as i64);
                 child.build(EventType::RemovedMod)
             })
@@ -413,7 +443,7 @@ impl EventBuilder {
                     child.push_description(&common_description);
                     child.push_player_tag(bd.fielder_id);
                     child.push_team_tag(mod_change.team_id);
-                    child.push_metadata_str("mod", "COFFEE_PERIL");
+                    child.push_metadata_str("mod", Mod::CoffeePeril);
                     child.push_metadata_i64("type", ModDuration::Weekly as i64);
                     child.build(EventType::AddedMod)
                 })
@@ -436,7 +466,7 @@ impl EventBuilder {
                 child.push_description(&description);
                 child.push_player_tag(batter_id);
                 child.push_team_tag(charge.team_id);
-                child.push_metadata_str("mod", "OVERPERFORMING");
+                child.push_metadata_str("mod", Mod::Overperforming);
                 child.push_metadata_str("source", a.to_ascii_uppercase());
                 child.push_metadata_i64("type", ModDuration::Game as i64);
                 child.build(EventType::AddedModFromOtherMod)
@@ -480,7 +510,7 @@ impl EventBuilder {
                 child.push_description(&format!("{batter_name} hit a Magmatic home run!"));
                 child.push_player_tag(batter_id);
                 child.push_team_tag(mod_change.team_id);
-                child.push_metadata_str("mod", "MAGMATIC");
+                child.push_metadata_str("mod", Mod::Magmatic);
                 child.push_metadata_i64("type", ModDuration::Permanent as i64);
                 child.build(EventType::RemovedMod)
             });
@@ -502,7 +532,7 @@ impl EventBuilder {
         self.push_metadata_i64("itemHealthBefore", item_repaired.health - 1);
         self.push_metadata_uuid("itemId", item_repaired.item_id);
         self.push_metadata_str("itemName", item_repaired.item_name);
-        self.push_metadata_str_vec("mods", item_repaired.item_mods);
+        self.push_metadata_mod_vec("mods", item_repaired.item_mods);
         self.push_metadata_f64("playerItemRatingAfter", item_repaired.player_item_rating_after);
         self.push_metadata_f64("playerItemRatingBefore", item_repaired.player_item_rating_before);
         self.push_metadata_f64("playerRating", item_repaired.player_rating);
@@ -521,7 +551,7 @@ impl EventBuilder {
         self.push_metadata_i64("itemHealthBefore", item_damaged.health + 1);
         self.push_metadata_uuid("itemId", item_damaged.item_id);
         self.push_metadata_str("itemName", item_damaged.item_name);
-        self.push_metadata_str_vec("mods", item_damaged.item_mods);
+        self.push_metadata_mod_vec("mods", item_damaged.item_mods);
         self.push_metadata_f64("playerItemRatingAfter", item_damaged.player_item_rating_after);
         self.push_metadata_f64("playerItemRatingBefore", item_damaged.player_item_rating_before);
         self.push_metadata_f64("playerRating", item_damaged.player_rating);
@@ -532,10 +562,10 @@ impl EventBuilder {
         })
     }
 
-    pub fn build_player_stat_changed(mut self, rating_before: f64, rating_after: f64, attribute_type: i64) -> EventuallyEvent {
+    pub fn build_player_stat_changed(mut self, rating_before: f64, rating_after: f64, category: StatChangeCategory) -> EventuallyEvent {
         self.push_metadata_f64("before", rating_before);
         self.push_metadata_f64("after", rating_after);
-        self.push_metadata_i64("type", attribute_type);
+        self.push_metadata_i64("type", category);
         self.build(if rating_after > rating_before {
             EventType::PlayerStatIncrease
         } else {
@@ -562,7 +592,7 @@ impl EventBuilder {
     }
 
     pub fn build(mut self, event_type: EventType) -> EventuallyEvent {
-        self.0.r#type = event_type;
-        self.0
+        self.event.r#type = event_type;
+        self.event
     }
 }
\ No newline at end of file
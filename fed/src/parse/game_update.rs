@@ -0,0 +1,190 @@
+use serde_json::Value;
+use uuid::Uuid;
+use eventually_api::{EventType, Weather};
+
+use crate::fed_event::{FedEventData, GameEvent};
+use crate::parse::error::FeedParseError;
+use crate::parse::parse_event_data;
+use crate::parse::parse_wrapper::EventParseWrapper;
+
+/// What game updates show for a GameEnd. The feed's final score is in the update's own fields.
+const GAME_OVER: &str = "Game over.";
+
+/// The event types a game update's lastUpdate is tried as, in order. GameEnd is handled separately
+/// because its text isn't the feed's.
+const GAME_UPDATE_EVENT_TYPES: [EventType; 16] = [
+    EventType::LetsGo,
+    EventType::PlayBall,
+    EventType::HalfInning,
+    EventType::PitcherChange,
+    EventType::BatterUp,
+    EventType::Ball,
+    EventType::FoulBall,
+    EventType::Strike,
+    EventType::Strikeout,
+    EventType::Walk,
+    EventType::FlyOut,
+    EventType::GroundOut,
+    EventType::Hit,
+    EventType::HomeRun,
+    EventType::StolenBase,
+    EventType::InningEnd,
+];
+
+/// Pairs of (name field, uuid field) in a game update that identify a player
+const GAME_UPDATE_PLAYER_FIELDS: [(&str, &str); 4] = [
+    ("homeBatterName", "homeBatter"),
+    ("awayBatterName", "awayBatter"),
+    ("homePitcherName", "homePitcher"),
+    ("awayPitcherName", "awayPitcher"),
+];
+
+/// Parses the `lastUpdate` of a game update, for periods where game updates were recorded but feed
+/// events weren't. `update` is the game update object itself (the `data` field of a Chronicler
+/// entity).
+///
+/// The description is read by the feed event parser, given only the description, so a play is
+/// recognized here exactly when its feed description would be. Game updates have no tags, children,
+/// or metadata, so whatever the feed event would have taken from them is a placeholder (see
+/// `EventParseWrapper::description_only`): sub-events have nil ids, metadata values are zero or
+/// empty, and uuids are nil. The exceptions are the game, which comes from the update's own fields,
+/// and players' uuids, which are found by matching the player's name against the update's fields
+/// (and `previous`, the update before it, if given). Players that no field names keep a nil uuid.
+///
+/// "Game over." becomes a GameEnd with the teams and scores from the update's fields and a nil
+/// winner if the scores are tied. Only the core gameplay events are recognized; anything else is
+/// an [`FeedParseError::UnrecognizedGameUpdate`].
+pub fn parse_game_update(update: &Value, previous: Option<&Value>) -> Result<FedEventData, FeedParseError> {
+    let description = update.get("lastUpdate")
+        .and_then(Value::as_str)
+        .ok_or(FeedParseError::MissingGameUpdateField { field: "lastUpdate" })?;
+    let play_count = update.get("playCount")
+        .and_then(Value::as_i64)
+        .ok_or(FeedParseError::MissingGameUpdateField { field: "playCount" })?;
+    let game = GameEvent {
+        game_id: uuid_field(update, "id")?,
+        home_team: uuid_field(update, "homeTeam")?,
+        away_team: uuid_field(update, "awayTeam")?,
+        play: play_count - 1,
+        unscatter: None,
+        attractor_secret_base: None,
+    };
+
+    if description == GAME_OVER {
+        return game_end(update, game);
+    }
+
+    // Only used to tell whether the update is from the early Expansion Era
+    let season = update.get("season").and_then(Value::as_i64).map_or(i32::MAX, |season| season as i32);
+    let day = update.get("day").and_then(Value::as_i64).map_or(i32::MAX, |day| day as i32);
+    let mut data = GAME_UPDATE_EVENT_TYPES.into_iter()
+        .find_map(|event_type| {
+            let mut event = EventParseWrapper::description_only(description, event_type, &game, season, day);
+            let data = parse_event_data(&mut event).ok()?;
+            event.description().trim().is_empty().then_some(data)
+        })
+        .ok_or_else(|| FeedParseError::UnrecognizedGameUpdate {
+            description: description.to_string(),
+        })?;
+
+    if let FedEventData::LetsGo { weather, stadium_id, .. } = &mut data {
+        let weather_number = update.get("weather")
+            .and_then(Value::as_i64)
+            .ok_or(FeedParseError::MissingGameUpdateField { field: "weather" })?;
+        *weather = Weather::try_from(weather_number as i32)
+            .map_err(|err| FeedParseError::UnknownWeather(err.number))?;
+        *stadium_id = update.get("stadiumId")
+            .and_then(Value::as_str)
+            .and_then(|id| Uuid::parse_str(id).ok());
+    }
+
+    Ok(fill_player_ids(data, update, previous))
+}
+
+fn uuid_field(update: &Value, field: &'static str) -> Result<Uuid, FeedParseError> {
+    update.get(field)
+        .and_then(Value::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or(FeedParseError::MissingGameUpdateField { field })
+}
+
+fn game_end(update: &Value, game: GameEvent) -> Result<FedEventData, FeedParseError> {
+    let score = |field: &'static str| {
+        update.get(field)
+            .and_then(Value::as_f64)
+            .map(|score| score as f32)
+            .ok_or(FeedParseError::MissingGameUpdateField { field })
+    };
+    let team_name = |side: &str| {
+        [format!("{side}TeamName"), format!("{side}TeamNickname")].iter()
+            .find_map(|field| update.get(field).and_then(Value::as_str))
+            .unwrap_or_default()
+            .to_string()
+    };
+    let home = (game.home_team, team_name("home"), score("homeScore")?);
+    let away = (game.away_team, team_name("away"), score("awayScore")?);
+    let ((winner_id, winning_team_name, winning_team_score), (_, losing_team_name, losing_team_score)) =
+        if away.2 > home.2 { (away, home) } else { (home, away) };
+
+    Ok(FedEventData::GameEnd {
+        game,
+        winner_id: if winning_team_score == losing_team_score { Uuid::nil() } else { winner_id },
+        winning_team_name,
+        winning_team_score,
+        losing_team_name,
+        losing_team_score,
+        temp_stolen_player_returned: None,
+    })
+}
+
+/// Replaces the nil uuid of every player in `data` with the one found for their name in `update`
+/// or `previous`. A player is any `<x>Name` field with a matching `<x>Id` field, at any depth.
+fn fill_player_ids(data: FedEventData, update: &Value, previous: Option<&Value>) -> FedEventData {
+    fn fill(value: &mut Value, find_id: &impl Fn(&str) -> Option<Uuid>) {
+        match value {
+            Value::Object(object) => {
+                let found_ids = object.iter()
+                    .filter_map(|(key, name)| {
+                        let id_key = format!("{}Id", key.strip_suffix("Name")?);
+                        let id = Uuid::parse_str(object.get(&id_key)?.as_str()?).ok()?;
+                        if !id.is_nil() {
+                            return None;
+                        }
+                        Some((id_key, find_id(name.as_str()?)?))
+                    })
+                    .collect::<Vec<_>>();
+                for (id_key, id) in found_ids {
+                    object.insert(id_key, Value::String(id.to_string()));
+                }
+                object.values_mut().for_each(|child| fill(child, find_id));
+            }
+            Value::Array(array) => {
+                array.iter_mut().for_each(|child| fill(child, find_id));
+            }
+            _ => {}
+        }
+    }
+
+    let find_id = |name: &str| {
+        find_player_id(update, name)
+            .or_else(|| previous.and_then(|previous| find_player_id(previous, name)))
+    };
+    let mut value = serde_json::to_value(&data)
+        .expect("FedEventData should serialize");
+    fill(&mut value, &find_id);
+    serde_json::from_value(value)
+        .expect("Filling in uuids shouldn't change the shape of FedEventData")
+}
+
+fn find_player_id(update: &Value, name: &str) -> Option<Uuid> {
+    let str_field = |field: &str| update.get(field).and_then(Value::as_str);
+    let runners = update.get("baseRunnerNames").and_then(Value::as_array).into_iter().flatten()
+        .zip(update.get("baseRunners").and_then(Value::as_array).into_iter().flatten())
+        .filter_map(|(name, id)| Some((name.as_str()?, id.as_str()?)));
+    let players = GAME_UPDATE_PLAYER_FIELDS.into_iter()
+        .filter_map(|(name_field, id_field)| Some((str_field(name_field)?, str_field(id_field)?)));
+
+    runners.chain(players)
+        .filter(|(player_name, _)| *player_name == name)
+        .find_map(|(_, id)| Uuid::parse_str(id).ok())
+}
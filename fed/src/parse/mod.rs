@@ -4,6 +4,8 @@ pub mod event_builder_new;
 mod parsers;
 pub mod stream;
 mod parse_wrapper;
+pub mod game_update;
+pub(crate) mod template;
 
 use serde::Deserialize;
 // the second one is a macro
@@ -13,9 +15,11 @@ use eventually_api::{EventCategory, EventType, EventuallyEvent, Weather};
 use crate::parse::error::FeedParseError;
 use crate::parse::parsers::*;
 use crate::parse::parse_wrapper::EventParseWrapper;
+use crate::parse::template::{HitTemplate, StrikeTemplate, WalkTemplate};
 use crate::fed_event::*;
 
 pub use stream::expansion_era_events;
+pub use game_update::parse_game_update;
 
 const KNOWN_TEAM_NICKNAMES: [&'static str; 24] = [
     "Fridays", "Moist Talkers", "Lovers", "Jazz Hands", "Sunbeams", "Tigers", "Wild Wings",
@@ -46,9 +50,19 @@ fn ParseOk<T>(v: T) -> Result<T, FeedParseError> {
     Ok(v)
 }
 
+fn game_pitch(double_strike: Option<&str>) -> GamePitch {
+    GamePitch {
+        double_strike: double_strike.map(str::to_string),
+    }
+}
+
 pub fn parse_feed_event(feed_event: &EventuallyEvent) -> Result<FedEvent, FeedParseError> {
     if feed_event.metadata.siblings.is_empty() {
-        parse_single_feed_event(feed_event)
+        let mut event = parse_single_feed_event(feed_event)?;
+        // Parsing only records whether the event is Special. Why can only be worked out once the
+        // rest of the event is known.
+        event.data.resolve_special_reason();
+        Ok(event)
     } else {
         todo!()
     }
@@ -60,8 +74,16 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
     // representation of the Uuid type is to low-level to copy-paste
     let _id_string = event.id.to_string();
 
+    let data = parse_event_data(&mut event)?;
+    event.to_fed(data)
+}
+
+/// Parses the event's data according to its type. This is also how game updates are parsed, with a
+/// description-only wrapper.
+fn parse_event_data(event: &mut EventParseWrapper) -> Result<FedEventData, FeedParseError> {
+
     // This can happen on the majority of events, so I handle it outside
-    let unscatter = event.next_child_if_mod_effect(EventType::RemovedMod, "SCATTERED")?.map(|mut child| {
+    let unscatter = event.next_child_if_mod_effect(EventType::RemovedMod, Mod::Scattered)?.map(|mut child| {
         let player_name = child.next_parse(parse_terminated(" was Unscattered."))?;
         ParseOk(Unscatter {
             sub_event: child.as_sub_event(),
@@ -105,30 +127,45 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             // theoretically possible that there would be some starting and others ending in the
             // same event (the days coincide) but it didn't happen in Beta, so I don't know the
             // order it would apply in. I'm assuming it would be interleaved.
-            let mut subseasonal_mod_effects = Vec::new();
-            while let Some(mut child) = event.next_child_any_opt(&[EventType::AddedModFromOtherMod, EventType::RemovedModFromOtherMod])? {
-                // The nickname and mod name are on both child and parent, but we need to consume
-                // the description from the parent anyway, so it's better to parse it from there
-                let (team_nickname, source_mod_name) = event.next_parse(parse_subseasonal_mod_change)?;
-                assert!(is_known_team_nickname(team_nickname));
-                subseasonal_mod_effects.push(TeamPerformingChanged {
-                    team_id: child.next_team_id()?,
-                    team_nickname: team_nickname.to_string(),
-                    source_mod_id: child.metadata_str("source")?.to_string(),
-                    source_mod_name: source_mod_name.to_string(),
-                    was_added: child.event_type == EventType::AddedModFromOtherMod,
-                    sub_event: child.as_sub_event(),
+            let play = event.next_parse(parse_half_inning_play)?;
+            // The nickname and mod name are on both child and parent, but we need to consume the
+            // description from the parent anyway, so it's better to parse it from there
+            let subseasonal_mod_effects = play.subseasonal_mod_changes.into_iter()
+                .map(|(team_nickname, source_mod_name, was_added)| {
+                    assert!(is_known_team_nickname(team_nickname));
+                    let mut child = event.next_child(if was_added { EventType::AddedModFromOtherMod } else { EventType::RemovedModFromOtherMod })?;
+                    // Game updates have no metadata, so the mod has to come from its name
+                    let source_mod_id = SubseasonalMod::from_display_name(source_mod_name)
+                        .ok_or_else(|| FeedParseError::UnexpectedSubseasonalMod {
+                            event_type: child.event_type,
+                            mod_name: source_mod_name.to_string(),
+                        })?;
+                    let metadata_source = child.metadata_mod("source")?;
+                    if !child.is_description_only() && metadata_source != source_mod_id.source_mod() {
+                        return Err(FeedParseError::UnexpectedMetadataValue {
+                            event_type: child.event_type,
+                            field: "source",
+                            value: metadata_source.id().to_string(),
+                        });
+                    }
+                    ParseOk(TeamPerformingChanged {
+                        team_id: child.next_team_id()?,
+                        team_nickname: team_nickname.to_string(),
+                        source_mod_id,
+                        source_mod_name: source_mod_name.to_string(),
+                        was_added,
+                        sub_event: child.as_sub_event(),
+                    })
                 })
-            }
+                .collect::<Result<_, _>>()?;
 
-            let (top_of_inning, inning, team_name) = event.next_parse(parse_half_inning)?;
-            assert!(is_known_team_name(team_name));
+            assert!(is_known_team_name(play.batting_team_name));
 
             FedEventData::HalfInningStart {
                 game: event.game(unscatter, attractor_secret_base)?,
-                top_of_inning,
-                inning,
-                batting_team_name: team_name.to_string(),
+                top_of_inning: play.top_of_inning,
+                inning: play.inning,
+                batting_team_name: play.batting_team_name.to_string(),
                 subseasonal_mod_effects,
             }
         }
@@ -145,72 +182,54 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             }
         }
         EventType::StolenBase => {
-            let (runner_name, base_stolen, is_successful, blaserunning, free_refiller) = event.next_parse(parse_stolen_base)?;
-            if is_successful {
+            let play = event.next_parse(parse_stolen_base_play(event.is_early_expansion_era()))?;
+            if play.is_successful {
                 let runner_id = event.next_player_id()?;
 
-                let runner_item_damage = event.parse_item_damage(runner_name)?;
+                let runner_item_damage = event.next_player_item_damage(play.runner_item_damage)?;
 
                 FedEventData::StolenBase {
                     game: event.game(unscatter, attractor_secret_base)?,
-                    runner_name: runner_name.to_string(),
+                    runner_name: play.runner_name.to_string(),
                     runner_id,
-                    base_stolen,
-                    blaserunning,
-                    free_refill: free_refiller.map(|refiller_name| {
-                        let mut sub_event = event.next_child(EventType::RemovedMod)?;
-                        ParseOk(FreeRefill {
-                            sub_event: sub_event.as_sub_event(),
-                            player_name: refiller_name.to_string(),
-                            player_id: sub_event.next_player_id()?,
-                            team_id: sub_event.next_team_id_opt(),
-                        })
-                    }).transpose()?,
+                    base_stolen: play.base,
+                    blaserunning: play.blaserunning,
+                    free_refill: event.next_free_refill(play.free_refill)?,
                     runner_item_damage,
-                    is_special: event.category == EventCategory::Special,
+                    special_reason: (event.category == EventCategory::Special).then_some(SpecialReason::Unknown),
                 }
             } else {
                 FedEventData::CaughtStealing {
                     game: event.game(unscatter, attractor_secret_base)?,
-                    runner_name: runner_name.to_string(),
-                    base_stolen,
+                    runner_name: play.runner_name.to_string(),
+                    base_stolen: play.base,
                 }
             }
         }
         EventType::Walk => {
-            match event.next_parse(parse_walk)? {
-                ParsedWalk::Ordinary((batter_name, base_instincts)) => {
-                    let batter_id = event.next_player_id()?;
-                    let scores = event.parse_scores(" scores!")?;
-
-                    let batter_item_damage = event.parse_item_damage(batter_name)?;
-                    let stopped_inhabiting = event.parse_stopped_inhabiting(Some(batter_id))?;
-                    FedEventData::Walk {
-                        game: event.game(unscatter, attractor_secret_base)?,
-                        batter_name: batter_name.to_string(),
-                        batter_id,
-                        scores,
-                        base_instincts,
-                        batter_item_damage,
-                        stopped_inhabiting,
-                        is_special: event.category == EventCategory::Special,
-                    }
+            // Ordinary walks are a prefix of Mind Trick strikeouts, so they have to be tried last
+            match event.next_parse_opt(parse_unusual_walk_play(event.is_early_expansion_era())) {
+                None => {
+                    WalkTemplate::parse(event)?
+                        .into_data(event.game(unscatter, attractor_secret_base)?)
                 }
-                ParsedWalk::Charm((broken_item, batter_name, pitcher_name)) => {
+                Some(ParsedUnusualWalkPlay::Charm { broken_item, batter_name, pitcher_name, scores }) => {
                     let batter_id = event.next_player_id()?;
                     let charmer_id = event.next_player_id()?;
                     assert_eq!(batter_id, charmer_id);
 
-                    let scores = event.parse_scores(" scores!")?;
+                    // The damage line comes before the charm, so its child comes before the
+                    // scorers'
                     let (batter_item_damage, pitcher_item_damage) = match broken_item {
                         None => { (None, None) }
-                        Some((ActivePositionType::Lineup, _item_name, item_name_damage)) => {
-                            (Some(event.next_item_damage(item_name_damage)?), None)
+                        Some((ActivePositionType::Lineup, item_name, item_name_plural)) => {
+                            (Some(event.next_item_damage(item_name, item_name_plural)?), None)
                         }
-                        Some((ActivePositionType::Rotation, _item_name, item_name_damage)) => {
-                            (None, Some(event.next_item_damage(item_name_damage)?))
+                        Some((ActivePositionType::Rotation, item_name, item_name_plural)) => {
+                            (None, Some(event.next_item_damage(item_name, item_name_plural)?))
                         }
                     };
+                    let scores = event.next_scores(scores)?;
 
                     FedEventData::CharmWalk {
                         game: event.game(unscatter, attractor_secret_base)?,
@@ -222,16 +241,16 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                         scores,
                     }
                 }
-                ParsedWalk::MindTrickStrikeoutIntoWalk((batter_name, strikeout_type)) => {
+                Some(ParsedUnusualWalkPlay::MindTrickWalk { batter_name, strikeout_type, scores }) => {
                     FedEventData::MindTrickWalk {
                         game: event.game(unscatter, attractor_secret_base)?,
                         strikeout_type,
                         batter_id: event.next_player_id()?,
                         batter_name: batter_name.to_string(),
-                        scores: event.parse_scores(" scores!")?,
+                        scores: event.next_scores(scores)?,
                     }
                 }
-                ParsedWalk::MindTrickWalkIntoStrikeout((batter_name, pitcher_name)) => {
+                Some(ParsedUnusualWalkPlay::MindTrickStrikeout { batter_name, pitcher_name }) => {
                     FedEventData::MindTrickStrikeout {
                         game: event.game(unscatter, attractor_secret_base)?,
                         batter_id: event.next_player_id()?,
@@ -242,13 +261,14 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             }
         }
         EventType::Strikeout => {
-            let pitch = event.parse_pitch()?;
-            match event.next_parse(parse_strikeout)? {
+            let play = event.next_parse(parse_strikeout_play(event.is_early_expansion_era()))?;
+            let pitch = game_pitch(play.double_strike);
+            match play.strikeout {
                 ParsedStrikeout::Swinging(batter_name) => {
                     let stopped_inhabiting = event.parse_stopped_inhabiting(None)?;
-                    let pitcher_item_damage = event.parse_item_damage_and_name(true)?;
-                    let free_refill = event.parse_free_refill()?;
-                    let parasite = event.parse_parasite()?;
+                    let pitcher_item_damage = event.next_named_item_damage(play.pitcher_item_damage)?;
+                    let free_refill = event.next_free_refill(play.free_refill)?;
+                    let parasite = event.next_parasite(play.parasite)?;
                     FedEventData::StrikeoutSwinging {
                         game: event.game(unscatter, attractor_secret_base)?,
                         pitch,
@@ -256,15 +276,15 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                         stopped_inhabiting,
                         pitcher_item_damage,
                         free_refill,
-                        is_special: event.category == EventCategory::Special,
+                        special_reason: (event.category == EventCategory::Special).then_some(SpecialReason::Unknown),
                         parasite,
                     }
                 }
                 ParsedStrikeout::Looking(batter_name) => {
                     let stopped_inhabiting = event.parse_stopped_inhabiting(None)?;
-                    let pitcher_item_damage = event.parse_item_damage_and_name(true)?;
-                    let free_refill = event.parse_free_refill()?;
-                    let parasite = event.parse_parasite()?;
+                    let pitcher_item_damage = event.next_named_item_damage(play.pitcher_item_damage)?;
+                    let free_refill = event.next_free_refill(play.free_refill)?;
+                    let parasite = event.next_parasite(play.parasite)?;
                     FedEventData::StrikeoutLooking {
                         game: event.game(unscatter, attractor_secret_base)?,
                         pitch,
@@ -272,7 +292,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                         stopped_inhabiting,
                         pitcher_item_damage,
                         free_refill,
-                        is_special: event.category == EventCategory::Special,
+                        special_reason: (event.category == EventCategory::Special).then_some(SpecialReason::Unknown),
                         parasite,
                     }
                 }
@@ -295,26 +315,26 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             }
         }
         EventType::FlyOut => {
-            // Order matters
-            let pitch = event.parse_pitch()?;
-            let (batter_name, fielder_name) = event.next_parse(parse_flyout)?;
-            let batter_debt = event.parse_batter_debt(batter_name, fielder_name)?;
-            let fielder_item_damage = event.parse_item_damage(fielder_name)?;
-            let scores = event.parse_scores(" tags up and scores!")?;
-            let batter_item_damage = event.parse_item_damage(batter_name)?;
-            let other_player_item_damage = event.parse_item_damage_and_name(true)?;
-            let cooled_off = event.parse_cooled_off(batter_name)?;
+            // The description is all parsed at once, but the tags and children have to be read
+            // in this order
+            let play = event.next_parse(parse_flyout_play(event.is_early_expansion_era()))?;
+            let batter_debt = event.next_batter_debt(play.batter_debt)?;
+            let fielder_item_damage = event.next_player_item_damage(play.fielder_item_damage)?;
+            let scores = event.next_scores(play.scores)?;
+            let batter_item_damage = event.next_player_item_damage(play.batter_item_damage)?;
+            let other_player_item_damage = event.next_named_item_damage(play.other_player_item_damage)?;
+            let cooled_off = event.next_cooled_off(play.cooled_off)?;
             let stopped_inhabiting = event.parse_stopped_inhabiting(None)?; // Not sure about order here
-            let parasite = event.parse_parasite()?;
+            let parasite = event.next_parasite(play.parasite)?;
             FedEventData::Flyout {
                 game: event.game(unscatter, attractor_secret_base)?,
-                pitch,
-                batter_name: batter_name.to_string(),
-                fielder_name: fielder_name.to_string(),
+                pitch: game_pitch(play.double_strike),
+                batter_name: play.batter_name.to_string(),
+                fielder_name: play.fielder_name.to_string(),
                 scores,
                 stopped_inhabiting,
                 cooled_off,
-                is_special: event.category == EventCategory::Special,
+                special_reason: (event.category == EventCategory::Special).then_some(SpecialReason::Unknown),
                 batter_debt,
                 batter_item_damage,
                 fielder_item_damage,
@@ -323,18 +343,17 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             }
         }
         EventType::GroundOut => {
-            let pitch = event.parse_pitch()?;
-            match event.next_parse(parse_ground_out)? {
-                ParsedGroundOut::Simple { batter_name, fielder_name } => {
-                    let batter_debt = event.parse_batter_debt(batter_name, fielder_name)?;
-                    let scores = event.parse_scores(" advances on the sacrifice.")?;
-                    // Damages definitely belong after scores and in this order but not sure if any
-                    // other events come in between
-                    let pitcher_item_damage = event.parse_item_damage_and_name(true)?;
-                    let batter_item_damage = event.parse_item_damage(batter_name)?;
-                    let fielder_item_damage = event.parse_item_damage(fielder_name)?;
+            let (double_strike, play) = event.next_parse(parse_ground_out_play(event.is_early_expansion_era()))?;
+            let pitch = game_pitch(double_strike);
+            match play {
+                ParsedGroundOutPlay::Simple { batter_name, fielder_name, batter_debt, scores, pitcher_item_damage, batter_item_damage, fielder_item_damage, cooled_off } => {
+                    let batter_debt = event.next_batter_debt(batter_debt)?;
+                    let scores = event.next_scores(scores)?;
+                    let pitcher_item_damage = event.next_named_item_damage(pitcher_item_damage)?;
+                    let batter_item_damage = event.next_player_item_damage(batter_item_damage)?;
+                    let fielder_item_damage = event.next_player_item_damage(fielder_item_damage)?;
                     let stopped_inhabiting = event.parse_stopped_inhabiting(None)?;
-                    let cooled_off = event.parse_cooled_off(batter_name)?;
+                    let cooled_off = event.next_cooled_off(cooled_off)?;
                     FedEventData::GroundOut {
                         game: event.game(unscatter, attractor_secret_base)?,
                         pitch,
@@ -343,21 +362,18 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                         scores,
                         stopped_inhabiting,
                         cooled_off,
-                        is_special: event.category == EventCategory::Special,
+                        special_reason: (event.category == EventCategory::Special).then_some(SpecialReason::Unknown),
                         batter_debt,
                         batter_item_damage,
                         pitcher_item_damage,
                         fielder_item_damage,
                     }
                 }
-                ParsedGroundOut::FieldersChoice { runner_out_name, base } => {
-                    let damaged_items = event.parse_item_damages_and_names(true)?;
-                    // Breaking up the call to insert "reaches on fielders choice" in the middle
-                    let scoring_players = event.parse_scoring_players(" scores!")?;
-                    let batter_name = event.next_parse(parse_reaches_on_fielders_choice)?;
-                    let scores = event.parse_scores_with_scoring_players(scoring_players)?;
+                ParsedGroundOutPlay::FieldersChoice { runner_out_name, base, damaged_items, batter_name, scores, cooled_off } => {
+                    let damaged_items = event.next_named_item_damages(damaged_items)?;
+                    let scores = event.next_scores(scores)?;
                     let stopped_inhabiting = event.parse_stopped_inhabiting(None)?;
-                    let cooled_off = event.parse_cooled_off(batter_name)?;
+                    let cooled_off = event.next_cooled_off(cooled_off)?;
                     FedEventData::FieldersChoice {
                         game: event.game(unscatter, attractor_secret_base)?,
                         pitch,
@@ -367,14 +383,14 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                         scores,
                         stopped_inhabiting,
                         cooled_off,
-                        is_special: event.category == EventCategory::Special,
+                        special_reason: (event.category == EventCategory::Special).then_some(SpecialReason::Unknown),
                         damaged_items
                     }
                 }
-                ParsedGroundOut::DoublePlay { batter_name } => {
-                    let scores = event.parse_scores(" scores!")?;
+                ParsedGroundOutPlay::DoublePlay { batter_name, scores, cooled_off } => {
+                    let scores = event.next_scores(scores)?;
                     let stopped_inhabiting = event.parse_stopped_inhabiting(None)?;
-                    let cooled_off = event.parse_cooled_off(batter_name)?;
+                    let cooled_off = event.next_cooled_off(cooled_off)?;
                     FedEventData::DoublePlay {
                         game: event.game(unscatter, attractor_secret_base)?,
                         pitch,
@@ -387,11 +403,11 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             }
         }
         EventType::HomeRun => {
-            let pitch = event.parse_pitch()?;
-            let damaged_items = event.parse_item_damages_and_names(false)?;
+            let play = event.next_parse(parse_home_run_play(event.is_early_expansion_era()))?;
+            let damaged_items = event.next_named_item_damages(play.damaged_items)?;
             // In addition to getting a magmatic event, get a player name and id to check against
             // the batter name and id
-            let magmatic_expanded = event.next_parse(parse_magmatic)?
+            let magmatic_expanded = play.magmatic
                 .map(|player_name| {
                     let mut child = event.next_child(EventType::RemovedMod)?;
                     let magmatic = ModChangeSubEvent {
@@ -403,9 +419,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                 })
                 .transpose()?;
 
-            let (batter_name, home_run_type) = event.next_parse(parse_hr)?;
-
-            let attraction = event.next_parse(parse_attract_player)?
+            let attraction = play.attraction
                 .map(|(team_nickname, player_name)| {
                     assert!(is_known_team_nickname(team_nickname));
 
@@ -420,74 +434,32 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                 })
                 .transpose()?;
 
-            let big_bucket = event.next_parse(parse_big_bucket)?;
-            let free_refills = event.parse_free_refills()?;
-            let spicy_status = event.parse_spicy_status(batter_name)?;
+            let free_refills = event.next_free_refills(play.free_refills)?;
+            let spicy_status = event.next_spicy_status(play.spicy_status)?;
 
             let batter_id = event.next_player_id()?;
             let stopped_inhabiting = event.parse_stopped_inhabiting(Some(batter_id))?;
 
             FedEventData::HomeRun {
                 game: event.game(unscatter, attractor_secret_base)?,
-                pitch,
+                pitch: game_pitch(play.double_strike),
                 // TODO Verify batter name and id against magmatic
                 magmatic: magmatic_expanded.map(|(m, _, _)| m),
-                batter_name: batter_name.to_string(),
+                batter_name: play.batter_name.to_string(),
                 batter_id,
-                home_run_type,
+                home_run_type: play.home_run_type,
                 stopped_inhabiting,
                 free_refills,
                 spicy_status,
-                is_special: event.category == EventCategory::Special,
-                big_bucket,
+                special_reason: (event.category == EventCategory::Special).then_some(SpecialReason::Unknown),
+                big_bucket: play.big_bucket,
                 attraction,
                 damaged_items,
             }
         }
         EventType::Hit => {
-            let pitch = event.parse_pitch()?;
-            let (batter_name, hit_type, batter_item_broke, pitcher_item_broke) = event.next_parse(parse_hit)?;
-            // resim research says pitcher goes first
-            let pitcher_item_damage = pitcher_item_broke
-                .map(|(_item_name, item_name_plural, player_name)| {
-                    event.next_item_damage(item_name_plural).map(|d| (player_name.to_string(), d))
-                })
-                .transpose()?;
-            let batter_item_damage = batter_item_broke
-                .map(|(_item_name, item_name_plural)| {
-                    event.next_item_damage(item_name_plural)
-                })
-                .transpose()?;
-
-            let batter_id = event.next_player_id()?;
-            let stopped_inhabiting = event.parse_stopped_inhabiting(Some(batter_id))?;
-
-            let hit_type = match hit_type {
-                ParsedHitType::Single => { HitType::Single }
-                ParsedHitType::Double => { HitType::Double }
-                ParsedHitType::Triple => {
-                    HitType::Triple(event.parse_charge_blood(batter_name, "aaa")?)
-                }
-                ParsedHitType::Quadruple => { HitType::Quadruple }
-            };
-            let scores = event.parse_scores(" scores!")?;
-            let spicy_status = event.parse_spicy_status(batter_name)?;
-            let other_player_item_damage = event.parse_item_damage_and_name(true)?;
-
-            FedEventData::Hit {
-                game: event.game(unscatter, attractor_secret_base)?,
-                pitch,
-                batter_name: batter_name.to_string(),
-                batter_id,
-                hit_type,
-                scores,
-                spicy_status,
-                stopped_inhabiting,
-                is_special: event.category == EventCategory::Special,
-                pitcher_item_damage,
-                batter_item_damage,
-                other_player_item_damage,
-            }
+            HitTemplate::parse(event)?
+                .into_data(event.game(unscatter, attractor_secret_base)?)?
         }
         EventType::GameEnd => {
             let ((winning_team_name, winning_team_score), (losing_team_name, losing_team_score)) = event.next_parse(parse_game_end)?;
@@ -533,7 +505,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                 inhabiting: inhabited.map(|inhabited| {
                     // Haunting doesn't have a sub-event if the player who Haunted already has the
                     // Inhabiting mod
-                    let child = event.next_child_if_mod_effect(EventType::AddedMod, "INHABITING")?;
+                    let child = event.next_child_if_mod_effect(EventType::AddedMod, Mod::Inhabiting)?;
 
                     // These live on the parent
                     let inhabiting_player_id = event.next_player_id()?;
@@ -551,40 +523,27 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             }
         }
         EventType::Strike => {
-            let pitch = event.parse_pitch()?;
-            let (strike_type, balls, strikes) = event.next_parse(parse_strike(pitch.double_strike.is_some()))?;
-            let pitcher_item_damage = event.parse_item_damage_and_name(true)?;
-            let game = event.game(unscatter, attractor_secret_base)?;
-            match strike_type {
-                StrikeType::Swinging => FedEventData::StrikeSwinging { game, pitch, balls, strikes, pitcher_item_damage },
-                StrikeType::Looking => FedEventData::StrikeLooking { game, pitch, balls, strikes, pitcher_item_damage },
-                StrikeType::Flinching => FedEventData::StrikeFlinching { game, pitch, balls, strikes, pitcher_item_damage },
-            }
+            StrikeTemplate::parse(event)?
+                .into_data(event.game(unscatter, attractor_secret_base)?)
         }
         EventType::Ball => {
-            let (balls, strikes) = event.next_parse(parse_ball)?;
-            let batter_item_damage = event.parse_item_damage_and_name(true)?;
+            let play = event.next_parse(parse_ball_play(event.is_early_expansion_era()))?;
             FedEventData::Ball {
                 game: event.game(unscatter, attractor_secret_base)?,
-                balls,
-                strikes,
-                batter_item_damage,
+                balls: play.balls,
+                strikes: play.strikes,
+                batter_item_damage: event.next_named_item_damage(play.batter_item_damage)?,
             }
         }
         EventType::FoulBall => {
-            let pitch = event.parse_pitch()?;
-            // Eventually this will need very foul support, but I'll get to that when it comes up
-            let (balls, strikes) = event.next_parse(parse_foul_ball(pitch.double_strike.is_some()))?;
-            let batter_item_damage = event.parse_item_damage_and_name(true)?;
-            let birds = event.parse_birds();
-
+            let play = event.next_parse(parse_foul_ball_play(event.is_early_expansion_era()))?;
             FedEventData::FoulBall {
                 game: event.game(unscatter, attractor_secret_base)?,
-                pitch,
-                balls,
-                strikes,
-                batter_item_damage,
-                birds,
+                pitch: game_pitch(play.double_strike),
+                balls: play.balls,
+                strikes: play.strikes,
+                batter_item_damage: event.next_named_item_damage(play.batter_item_damage)?,
+                birds: play.birds,
             }
         }
         EventType::RunsOverflowing => {
@@ -688,7 +647,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             FedEventData::InningEnd {
                 game: event.game(unscatter, attractor_secret_base)?,
                 inning_num,
-                lost_triple_threat: zip_mod_change_events(&mut event, lost_triple_threat_names)?,
+                lost_triple_threat: zip_mod_change_events(event, lost_triple_threat_names)?,
             }
         }
         EventType::BigDeal => {
@@ -854,15 +813,21 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             let (player_name, roast, notes, wired, gained_mod) = event.next_parse(parse_coffee_bean)?;
             let mut sub_event = event.next_child_any(&[EventType::AddedMod, EventType::ModChange, EventType::RemovedMod])?;
             let player_id = event.next_player_id()?;
-            let prev_mod = if sub_event.event_type == EventType::ModChange {
-                let mod_str = sub_event.metadata_str("to")?;
-                // Check that the added mod matches what was parsed
-                assert_eq!(mod_str, if wired { "WIRED" } else { "TIRED" });
+            let is_mod_change = sub_event.event_type == EventType::ModChange;
+            let mod_field = if is_mod_change { "to" } else { "mod" };
+            let added_mod = sub_event.metadata_mod(mod_field)?;
+            // Check that the added mod matches what was parsed. Game updates have no metadata to
+            // check against.
+            if !sub_event.is_description_only() && added_mod != if wired { Mod::Wired } else { Mod::Tired } {
+                return Err(FeedParseError::UnexpectedMetadataValue {
+                    event_type: sub_event.event_type,
+                    field: mod_field,
+                    value: added_mod.id().to_string(),
+                });
+            }
+            let prev_mod = if is_mod_change {
                 Some(sub_event.metadata_str("from")?)
             } else {
-                let mod_str = sub_event.metadata_str("mod")?;
-                // Check that the added mod matches what was parsed
-                assert_eq!(mod_str, if wired { "WIRED" } else { "TIRED" });
                 None
             };
             // The player ID should match in the sub event
@@ -1330,7 +1295,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                     Ok::<_, FeedParseError>(ItemRepaired {
                         item_id: child.metadata_uuid("itemId")?,
                         item_name: child.metadata_str("itemName")?.to_string(),
-                        item_mods: child.metadata_str_vec("mods")?.into_iter().map(|s| s.to_string()).collect(),
+                        item_mods: child.metadata_mod_vec("mods")?,
                         durability: child.metadata_i64("itemDurability")?,
                         health: child.metadata_i64("itemHealthAfter")?,
                         player_item_rating_before: child.metadata_f64("playerItemRatingBefore")?,
@@ -1405,7 +1370,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                             item_id: break_child.metadata_uuid("itemId")?,
                             item_name: break_child.metadata_str("itemName")?.to_string(),
                             item_name_plural: None,
-                            item_mods: vec![],
+                            item_mods: break_child.metadata_mod_vec("mods")?,
                             durability: break_child.metadata_i64("itemDurability")?,
                             health: break_child.metadata_i64("itemHealthAfter")?,
                             player_item_rating_before: break_child.metadata_f64("playerItemRatingBefore")?,
@@ -1577,7 +1542,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
         EventType::ReturnFromElsewhere => {
             let (player_name, flavor) = match event.next_parse(parse_return_from_elsewhere)? {
                 ParsedReturnFromElsewhere::Normal((player_name, time_elsewhere, is_peanut)) => {
-                    let scattered = event.next_child_if_mod_effect(EventType::AddedMod, "SCATTERED")?
+                    let scattered = event.next_child_if_mod_effect(EventType::AddedMod, Mod::Scattered)?
                         .map(|mut scattered_sub_event| {
                             let scattered_name = scattered_sub_event.next_parse(parse_terminated(" was Scattered..."))?;
 
@@ -1615,7 +1580,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                     })
                 }
                 ParsedReturnFromElsewhere::Short((player_name, is_peanut)) => {
-                    if let Some(mut return_sub_event) = event.next_child_if_mod_effect(EventType::RemovedMod, "ELSEWHERE")? {
+                    if let Some(mut return_sub_event) = event.next_child_if_mod_effect(EventType::RemovedMod, Mod::Elsewhere)? {
                         (player_name, ReturnFromElsewhereFlavor::Short {
                             team_id: return_sub_event.next_team_id()?,
                             player_id: return_sub_event.next_player_id()?,
@@ -1799,7 +1764,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                 LateToThePartyChange::Added(team_nickname) => {
                     assert!(is_known_team_nickname(team_nickname));
 
-                    let mut sub_event = event.next_child_if_mod_effect(EventType::AddedModFromOtherMod, "OVERPERFORMING")?;
+                    let mut sub_event = event.next_child_if_mod_effect(EventType::AddedModFromOtherMod, Mod::Overperforming)?;
                     FedEventData::LateToThePartyAdded {
                         game: event.game(unscatter, attractor_secret_base)?,
                         team_id: sub_event.as_mut().map(|e| e.next_team_id()).transpose()?,
@@ -1821,7 +1786,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
         EventType::AddedMod => {
             if TAROT_EVENTS.iter().any(|uuid| uuid == &event.id) {
                 // Then it's a tarot event and we can forget parsing. Thankfully
-                make_mod_tarot_event(&mut event, false)?
+                make_mod_tarot_event(event, false)?
             } else {
                 match event.next_parse(parse_added_mod)? {
                     ParsedAddedMod::EnteredPartyTime(team_nickname) => {
@@ -1852,7 +1817,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
         EventType::RemovedMod => {
             if TAROT_EVENTS.iter().any(|uuid| uuid == &event.id) {
                 // Then it's a tarot event and we can forget parsing. Thankfully
-                make_mod_tarot_event(&mut event, true)?
+                make_mod_tarot_event(event, true)?
             } else {
                 match event.next_parse(parse_removed_mod)? {
                     ParsedRemovedMod::TeamRemovedFromPartyTimeForPostseason(team_nickname) => {
@@ -1874,7 +1839,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                             team_id: event.next_team_id()?,
                             player_id: event.next_player_id()?,
                             player_name: player_name.to_string(),
-                            r#mod: event.metadata_str("mod")?.to_string(),
+                            r#mod: event.metadata_mod("mod")?,
                             mod_name: mod_name.to_string(),
                         }
                     }
@@ -1888,8 +1853,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             }
         }
         EventType::ModExpires => {
-            let mods = event.metadata_str_vec("mods")?
-                .into_iter().map(String::from).collect();
+            let mods = event.metadata_mod_vec("mods")?;
             if let Some(player_id) = event.next_player_id_opt() {
                 let (player_name, mod_duration) = event.next_parse(parse_player_mod_expires)?;
                 FedEventData::PlayerModExpires {
@@ -2021,7 +1985,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
         EventType::PlayerGainedItem => {
             if TAROT_EVENTS.iter().any(|uuid| uuid == &event.id) {
                 // Then it's a tarot event and we can forget parsing. Thankfully
-                make_item_tarot_event(&mut event, true)?
+                make_item_tarot_event(event, true)?
             } else {
                 // So far the only non-tarot root-level PlayerGainedItem event is community chest
                 let (player_name, _item_name) = event.next_parse(parse_community_chest)?;
@@ -2029,7 +1993,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                 FedEventData::CommunityChestOpens {
                     item_id: event.metadata_uuid("itemId")?,
                     item_name: event.metadata_str("itemName")?.to_string(),
-                    item_mods: event.metadata_str_vec("mods")?.iter().map(|s| s.to_string()).collect(),
+                    item_mods: event.metadata_mod_vec("mods")?,
                     player_item_rating_before: event.metadata_f64_opt("playerItemRatingBefore")?,
                     player_item_rating_after: event.metadata_f64_opt("playerItemRatingAfter")?,
                     player_rating: event.metadata_f64("playerRating")?,
@@ -2042,14 +2006,14 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
         EventType::PlayerLostItem => {
             if TAROT_EVENTS.iter().any(|uuid| uuid == &event.id) {
                 // Then it's a tarot event and we can forget parsing. Thankfully
-                make_item_tarot_event(&mut event, false)?
+                make_item_tarot_event(event, false)?
             } else {
                 let (player_name, _item_name) = event.next_parse(parse_player_dropped_item)?;
 
                 FedEventData::PlayerDropsItem {
                     item_id: event.metadata_uuid("itemId")?,
                     item_name: event.metadata_str("itemName")?.to_string(),
-                    item_mods: event.metadata_str_vec("mods")?.iter().map(|s| s.to_string()).collect(),
+                    item_mods: event.metadata_mod_vec("mods")?,
                     player_item_rating_before: event.metadata_f64("playerItemRatingBefore")?,
                     player_item_rating_after: event.metadata_f64("playerItemRatingAfter")?,
                     player_rating: event.metadata_f64("playerRating")?,
@@ -2275,7 +2239,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                             }
                         })?;
 
-                    let mod_id = obj.get("mod")
+                    let mod_id: Mod = obj.get("mod")
                         .ok_or_else(|| {
                             FeedParseError::MissingMetadata {
                                 event_type: event.event_type,
@@ -2290,7 +2254,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                                 ty: "str",
                             }
                         })?
-                        .to_string();
+                        .into();
 
                     let mod_duration = obj.get("type")
                         .ok_or_else(|| {
@@ -2326,7 +2290,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                 player_name: player_name.to_string(),
                 mods_removed,
                 source_mod_name: mod_name.to_string(),
-                source_mod_id: event.metadata_str("source")?.to_string(),
+                source_mod_id: event.metadata_mod("source")?,
             }
         }
         EventType::Psychoacoustics => {
@@ -2342,7 +2306,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                 team_id: child.next_team_id()?,
                 team_nickname: team_nickname.to_string(),
                 mod_name: mod_name.to_string(),
-                mod_id: child.metadata_str("mod")?.to_string(),
+                mod_id: child.metadata_mod("mod")?,
                 sub_event: child.as_sub_event(),
             }
         }
@@ -2382,7 +2346,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
                     Ok::<_, FeedParseError>(ItemDroppedForNewItem {
                         item_id: drop_event.metadata_uuid("itemId")?,
                         item_name: drop_event.metadata_str("itemName")?.to_string(),
-                        item_mods: drop_event.metadata_str_vec("mods")?.into_iter().map(|s| s.to_string()).collect(),
+                        item_mods: drop_event.metadata_mod_vec("mods")?,
                         player_item_rating_before: drop_event.metadata_f64("playerItemRatingBefore")?,
                         player_item_rating_after: drop_event.metadata_f64("playerItemRatingAfter")?,
                         item_was_broken,
@@ -2395,7 +2359,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
             let gained_item = ItemGained {
                 item_id: gain_event.metadata_uuid("itemId")?,
                 item_name: gain_event.metadata_str("itemName")?.to_string(),
-                item_mods: gain_event.metadata_str_vec("mods")?.into_iter().map(|s| s.to_string()).collect(),
+                item_mods: gain_event.metadata_mod_vec("mods")?,
                 player_item_rating_before: gain_event.metadata_f64("playerItemRatingBefore")?,
                 player_item_rating_after: gain_event.metadata_f64("playerItemRatingAfter")?,
                 player_rating: gain_event.metadata_f64("playerRating")?,
@@ -2560,7 +2524,7 @@ fn parse_single_feed_event(event: &EventuallyEvent) -> Result<FedEvent, FeedPars
         }
     };
 
-    event.to_fed(data)
+    Ok(data)
 }
 
 fn make_mod_tarot_event(event: &mut EventParseWrapper, mod_removed: bool) -> Result<FedEventData, FeedParseError> {
@@ -2568,7 +2532,7 @@ fn make_mod_tarot_event(event: &mut EventParseWrapper, mod_removed: bool) -> Res
         team_id: event.next_team_id()?,
         player_id: event.next_player_id_opt(),
         description: event.description().into(),
-        r#mod: event.metadata_str("mod")?.to_string(),
+        r#mod: event.metadata_mod("mod")?,
         mod_duration: event.metadata_enum("type")?,
         mod_removed,
     })
@@ -2579,7 +2543,7 @@ fn make_item_tarot_event(event: &mut EventParseWrapper, item_gained: bool) -> Re
         description: event.description().into(),
         item_id: event.metadata_uuid("itemId")?,
         item_name: event.metadata_str("itemName")?.to_string(),
-        item_mods: event.metadata_str_vec("mods")?.iter().map(|s| s.to_string()).collect(),
+        item_mods: event.metadata_mod_vec("mods")?,
         player_item_rating_before: event.metadata_f64("playerItemRatingBefore")?,
         player_item_rating_after: event.metadata_f64("playerItemRatingAfter")?,
         player_rating: event.metadata_f64("playerRating")?,
@@ -2603,7 +2567,7 @@ fn make_echo(echoer_name: &str, events: (Option<EventParseWrapper>, EventParseWr
 
 #[derive(Deserialize)]
 struct ModAndType {
-    r#mod: String,
+    r#mod: Mod,
     // r#type: i32,
 }
 
@@ -4,12 +4,26 @@ use nom::{Finish, Parser};
 use nom::combinator::opt;
 use nom::error::convert_error;
 use uuid::Uuid;
+use serde_json::Value;
 use eventually_api::{EventCategory, EventMetadata, EventType, EventuallyEvent};
 use crate::fed_event::*;
 use crate::FeedParseError;
-use crate::parse::{is_known_team_nickname, ParseOk};
+use crate::parse::{game_pitch, is_known_team_nickname, ParseOk};
 use crate::parse::parsers::*;
 
+/// Metadata for description-only wrappers, which have none
+static NO_METADATA: EventMetadata = EventMetadata {
+    children: Vec::new(),
+    siblings: Vec::new(),
+    ingest_time: None,
+    ingest_source: None,
+    play: None,
+    sub_play: None,
+    sibling_ids: None,
+    parent: None,
+    other: Value::Null,
+};
+
 #[derive(Debug, Copy, Clone)]
 pub struct EventParseWrapper<'e> {
     pub event_type: EventType,
@@ -37,6 +51,9 @@ pub struct EventParseWrapper<'e> {
 
     consumed_children_count: usize,
     children: &'e [EventuallyEvent],
+
+    /// For description-only wrappers, the game's id, away team, and home team
+    description_only: Option<(Uuid, Uuid, Uuid)>,
 }
 
 impl<'e> EventParseWrapper<'e> {
@@ -67,9 +84,59 @@ impl<'e> EventParseWrapper<'e> {
             game_ids: event.game_tags.as_slice(),
             consumed_children_count: 0,
             children: event.metadata.children.as_slice(),
+            description_only: None,
         })
     }
 
+    /// Makes a wrapper for a game event's description alone, such as the lastUpdate of a game
+    /// update, so that the same parsers can read it. The wrapper has no tags, children, or
+    /// metadata, so parts of the event that come from them get placeholders:
+    ///  - tags are nil uuids, or None where the tag is optional
+    ///  - a child the description says must be there is an empty event with a nil id, created at
+    ///    `DateTime::MIN_UTC`
+    ///  - a child the description doesn't mention, like a Haunting player leaving, is never there
+    ///  - metadata is zero, empty, or nil, except for item names and whether an item broke, which
+    ///    come from the description
+    pub fn description_only(description: &'e str, event_type: EventType, game: &GameEvent, season: i32, day: i32) -> Self {
+        Self {
+            event_type,
+            category: EventCategory::Game,
+            id: Uuid::nil(),
+            created: DateTime::<Utc>::MIN_UTC,
+            sim: "",
+            tournament: -1,
+            season,
+            day,
+            phase: SimPhase::Midseason,
+            nuts: 0,
+            play: Some(game.play),
+            description,
+            metadata: &NO_METADATA,
+            consumed_player_id_count: 0,
+            player_ids: &[],
+            consumed_team_id_count: 0,
+            team_ids: &[],
+            consumed_game_id_count: 0,
+            game_ids: &[],
+            consumed_children_count: 0,
+            children: &[],
+            description_only: Some((game.game_id, game.away_team, game.home_team)),
+        }
+    }
+
+    pub fn is_description_only(&self) -> bool {
+        self.description_only.is_some()
+    }
+
+    /// The placeholder for a child of a description-only wrapper
+    fn description_only_child(&self, event_type: EventType) -> Self {
+        Self {
+            event_type,
+            description: "",
+            ..*self
+        }
+    }
+
     pub fn consume_description(&mut self) -> &'e str {
         let d = self.description;
         self.description = "";
@@ -105,6 +172,9 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn next_player_id(&mut self) -> Result<Uuid, FeedParseError> {
+        if self.is_description_only() {
+            return Ok(Uuid::nil());
+        }
         self.consumed_player_id_count += 1;
         let (&id, rest) = self.player_ids.split_first()
             .ok_or_else(|| {
@@ -123,6 +193,9 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn next_team_id(&mut self) -> Result<Uuid, FeedParseError> {
+        if self.is_description_only() {
+            return Ok(Uuid::nil());
+        }
         let (&id, rest) = self.team_ids.split_first()
             .ok_or_else(|| {
                 FeedParseError::NotEnoughTags {
@@ -169,6 +242,9 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn next_child_any(&mut self, expected_types: &[EventType]) -> Result<Self, FeedParseError> {
+        if self.is_description_only() {
+            return Ok(self.description_only_child(expected_types[0]));
+        }
         let (child, rest) = self.children.split_first()
             .ok_or_else(|| {
                 FeedParseError::NotEnoughChildren {
@@ -212,29 +288,29 @@ impl<'e> EventParseWrapper<'e> {
         self.next_child_if_any(&[expected_type], pred)
     }
 
-    pub fn next_child_if_mod_effect(&mut self, expected_type: EventType, expected_mod: &str) -> Result<Option<Self>, FeedParseError> {
+    pub fn next_child_if_mod_effect(&mut self, expected_type: EventType, expected_mod: Mod) -> Result<Option<Self>, FeedParseError> {
         self.next_child_if_any_mod_effect(&[expected_type], expected_mod)
     }
 
-    pub fn next_child_if_any_mod_effect(&mut self, expected_types: &[EventType], expected_mod: &str) -> Result<Option<Self>, FeedParseError> {
+    pub fn next_child_if_any_mod_effect(&mut self, expected_types: &[EventType], expected_mod: Mod) -> Result<Option<Self>, FeedParseError> {
         self.next_child_if_any(expected_types, |child| {
             expected_types.iter().any(|t| t == &child.event_type) &&
                 child.metadata_str("mod").map_or(false, |m| {
-                    m == expected_mod
+                    m == expected_mod.id()
                 })
         })
     }
 
-    pub fn next_child_if_mod_effect_and<F>(&mut self, expected_type: EventType, expected_mod: &str, pred: F) -> Result<Option<Self>, FeedParseError>
+    pub fn next_child_if_mod_effect_and<F>(&mut self, expected_type: EventType, expected_mod: Mod, pred: F) -> Result<Option<Self>, FeedParseError>
         where F: Fn(Self) -> bool {
         self.next_child_if_any_mod_effect_and(&[expected_type], expected_mod, pred)
     }
 
-    pub fn next_child_if_any_mod_effect_and<F>(&mut self, expected_types: &[EventType], expected_mod: &str, pred: F) -> Result<Option<Self>, FeedParseError>
+    pub fn next_child_if_any_mod_effect_and<F>(&mut self, expected_types: &[EventType], expected_mod: Mod, pred: F) -> Result<Option<Self>, FeedParseError>
         where F: Fn(Self) -> bool {
         self.next_child_if_any(expected_types, |child| {
             expected_types.iter().any(|t| t == &child.event_type) &&
-                child.metadata_str("mod").map_or(false, |m| m == expected_mod) &&
+                child.metadata_str("mod").map_or(false, |m| m == expected_mod.id()) &&
                 pred(child)
         })
     }
@@ -270,7 +346,12 @@ impl<'e> EventParseWrapper<'e> {
         }
     }
 
+    /// For description-only wrappers, every field is the placeholder `Value::Null`, and the typed
+    /// getters below return zero, empty, or nil instead of failing on it
     pub fn get_metadata(&self, key: &'static str) -> Result<&'e serde_json::Value, FeedParseError> {
+        if self.is_description_only() {
+            return Ok(&Value::Null);
+        }
         self.metadata.other
             .as_object()
             .ok_or_else(|| {
@@ -288,6 +369,9 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn metadata_i64(&self, key: &'static str) -> Result<i64, FeedParseError> {
+        if self.is_description_only() {
+            return Ok(0);
+        }
         self.get_metadata(key)?
             .as_i64()
             .ok_or_else(|| {
@@ -300,6 +384,9 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn metadata_f64(&self, key: &'static str) -> Result<f64, FeedParseError> {
+        if self.is_description_only() {
+            return Ok(0.);
+        }
         self.get_metadata(key)?
             .as_f64()
             .ok_or_else(|| {
@@ -329,6 +416,9 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn metadata_str(&self, key: &'static str) -> Result<&'e str, FeedParseError> {
+        if self.is_description_only() {
+            return Ok("");
+        }
         self.get_metadata(key)?
             .as_str()
             .ok_or_else(|| {
@@ -341,6 +431,9 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn metadata_str_vec(&self, key: &'static str) -> Result<Vec<&'e str>, FeedParseError> {
+        if self.is_description_only() {
+            return Ok(Vec::new());
+        }
         self.get_metadata(key)?
             .as_array()
             .ok_or_else(|| {
@@ -367,7 +460,18 @@ impl<'e> EventParseWrapper<'e> {
             })
     }
 
+    pub fn metadata_mod(&self, key: &'static str) -> Result<Mod, FeedParseError> {
+        self.metadata_str(key).map(Mod::from_id)
+    }
+
+    pub fn metadata_mod_vec(&self, key: &'static str) -> Result<Vec<Mod>, FeedParseError> {
+        Ok(self.metadata_str_vec(key)?.into_iter().map(Mod::from_id).collect())
+    }
+
     pub fn metadata_uuid(&self, key: &'static str) -> Result<Uuid, FeedParseError> {
+        if self.is_description_only() {
+            return Ok(Uuid::nil());
+        }
         self.metadata_str(key)?
             .try_into()
             .map_err(|err| {
@@ -412,12 +516,23 @@ impl<'e> EventParseWrapper<'e> {
         self.team_ids
     }
 
+    /// Whether damage lines have an extra space in front of them, which they did early in the
+    /// expansion era
+    pub fn is_early_expansion_era(&self) -> bool {
+        (self.season, self.day) < (15, 3)
+    }
+
     pub fn parse_spicy_status(&mut self, batter_name: &str) -> Result<SpicyStatus, FeedParseError> {
-        Ok(match self.next_parse(parse_spicy_status(batter_name))? {
+        let spicy_status = self.next_parse(parse_spicy_status(batter_name))?;
+        self.next_spicy_status(spicy_status)
+    }
+
+    pub fn next_spicy_status(&mut self, spicy_status: ParsedSpicyStatus) -> Result<SpicyStatus, FeedParseError> {
+        Ok(match spicy_status {
             ParsedSpicyStatus::None => { SpicyStatus::None }
             ParsedSpicyStatus::HeatingUp => { SpicyStatus::HeatingUp }
             ParsedSpicyStatus::RedHot => {
-                let child = self.next_child_if_mod_effect(EventType::AddedMod, "ON_FIRE")?
+                let child = self.next_child_if_mod_effect(EventType::AddedMod, Mod::OnFire)?
                     .map(|mut spicy_event| {
                         ParseOk(ModChangeSubEvent {
                             sub_event: spicy_event.as_sub_event(),
@@ -431,7 +546,12 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn parse_cooled_off(&mut self, batter_name: &str) -> Result<Option<ModChangeSubEventWithPlayer>, FeedParseError> {
-        Ok(match self.next_parse(parse_cooled_off(batter_name))? {
+        let cooled_off = self.next_parse(parse_cooled_off(batter_name))?;
+        self.next_cooled_off(cooled_off)
+    }
+
+    pub fn next_cooled_off(&mut self, cooled_off: bool) -> Result<Option<ModChangeSubEventWithPlayer>, FeedParseError> {
+        Ok(match cooled_off {
             false => { None }
             true => {
                 let mut cooled_off_event = self.next_child(EventType::RemovedMod)?;
@@ -446,22 +566,35 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn parse_free_refills(&mut self) -> Result<Vec<FreeRefill>, FeedParseError> {
-        self.next_parse(parse_free_refills)?.into_iter()
+        let names = self.next_parse(parse_free_refills)?;
+        self.next_free_refills(names)
+    }
+
+    pub fn next_free_refills(&mut self, names: Vec<&str>) -> Result<Vec<FreeRefill>, FeedParseError> {
+        names.into_iter()
             .map(|name| self.build_free_refill(name))
             .collect()
     }
 
     // Use when only one free refill is allowed
     pub fn parse_free_refill(&mut self) -> Result<Option<FreeRefill>, FeedParseError> {
-        self.next_parse(opt(parse_free_refill))?
-            .map(|name| self.build_free_refill(name))
-            .transpose()
+        let name = self.next_parse(opt(parse_free_refill))?;
+        self.next_free_refill(name)
+    }
+
+    pub fn next_free_refill(&mut self, name: Option<&str>) -> Result<Option<FreeRefill>, FeedParseError> {
+        name.map(|name| self.build_free_refill(name)).transpose()
     }
 
     pub fn parse_batter_debt(&mut self, batter_name: &str, fielder_name: &str) -> Result<Option<BatterDebt>, FeedParseError> {
-        self.next_parse_opt(parse_batter_debt(batter_name, fielder_name))
-            .map(|()| {
-                let sub_event = self.next_child_if_mod_effect(EventType::AddedMod, "COFFEE_PERIL")?
+        let batter_debt = self.next_parse_opt(parse_batter_debt(batter_name, fielder_name)).is_some();
+        self.next_batter_debt(batter_debt)
+    }
+
+    pub fn next_batter_debt(&mut self, batter_debt: bool) -> Result<Option<BatterDebt>, FeedParseError> {
+        batter_debt
+            .then(|| {
+                let sub_event = self.next_child_if_mod_effect(EventType::AddedMod, Mod::CoffeePeril)?
                     .map(|mut child| {
                         ParseOk(ModChangeSubEvent {
                             team_id: child.next_team_id()?,
@@ -481,7 +614,7 @@ impl<'e> EventParseWrapper<'e> {
 
     pub fn parse_stopped_inhabiting(&mut self, player_id: Option<Uuid>) -> Result<Option<StoppedInhabiting>, FeedParseError> {
         self
-            .next_child_if_mod_effect_and(EventType::RemovedMod, "INHABITING", |child| {
+            .next_child_if_mod_effect_and(EventType::RemovedMod, Mod::Inhabiting, |child| {
                 player_id.is_none() || child.peek_player_id() == player_id
             })?
             .map(|mut child| {
@@ -497,22 +630,28 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn parse_scores(&mut self, label: &'static str) -> Result<Scores, FeedParseError> {
-        let scoring_players = self.parse_scoring_players(label)?;
-        self.parse_scores_with_scoring_players(scoring_players)
+        let scores = self.next_parse(parse_scores_and_refills(label, self.is_early_expansion_era()))?;
+        self.next_scores(scores)
     }
 
-    pub fn parse_scores_with_scoring_players(&mut self, scoring_players: Vec<(Uuid, Option<(String, Option<bool>)>, String, Option<String>)>) -> Result<Scores, FeedParseError> {
-        let scores = scoring_players.into_iter()
-            .map(|(player_id, item_name, player_name, attraction)| {
-                let item_damage = item_name
-                    .map(|(_name, plural)| self.next_item_damage(plural))
+    pub fn next_scores(&mut self, scores: ParsedScores) -> Result<Scores, FeedParseError> {
+        let ParsedScores { scorers, free_refills } = scores;
+        let scores = scorers.into_iter()
+            .map(|score| {
+                let player_id = self.next_player_id()?;
+                if score.attraction.is_some() {
+                    // Attracted players are tagged again
+                    self.next_player_id()?;
+                }
+                let item_damage = score.damaged_item_name
+                    .map(|(item_name, plural)| self.next_item_damage(item_name, plural))
                     .transpose()?;
-                let attraction = attraction
+                let attraction = score.attraction
                     .map(|team_nickname| {
-                        assert!(is_known_team_nickname(&team_nickname));
+                        assert!(is_known_team_nickname(team_nickname));
                         let mut child = self.next_child(EventType::PlayerAddedToTeam)?;
                         ParseOk(Attraction {
-                            team_nickname,
+                            team_nickname: team_nickname.to_string(),
                             team_id: child.next_team_id()?,
                             sub_event: child.as_sub_event(),
                         })
@@ -520,45 +659,40 @@ impl<'e> EventParseWrapper<'e> {
                     .transpose()?;
                 ParseOk(ScoringPlayer {
                     player_id,
-                    player_name,
+                    player_name: score.player_name.to_string(),
                     item_damage,
                     attraction,
                 })
             })
             .collect::<Result<_, _>>()?;
 
-        let free_refills = self.parse_free_refills()?;
-
         Ok(Scores {
             scores,
-            free_refills,
+            free_refills: self.next_free_refills(free_refills)?,
         })
     }
 
-    pub fn parse_scoring_players(&mut self, label: &'static str) -> Result<Vec<(Uuid, Option<(String, Option<bool>)>, String, Option<String>)>, FeedParseError> {
-        let scorers = self.next_parse(parse_scores(label, (self.season, self.day) < (15, 3)))?;
-        let scoring_players = scorers.into_iter()
-            .map(|score| {
-                ParseOk((self.next_player_id()?,
-                         score.damaged_item_name.map(|(n, p)| (n.to_string(), p)),
-                         score.player_name.to_string(),
-                         score.attraction.map(str::to_string),
-                ))
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(scoring_players)
-    }
-
-    pub fn next_item_damage(&mut self, item_name_plural: Option<bool>) -> Result<ItemDamaged, FeedParseError> {
+    /// `item_name` is the name in the damage line, which is only used when there's no metadata. The
+    /// plural flag is None when the item broke.
+    pub fn next_item_damage(&mut self, item_name: &str, item_name_plural: Option<bool>) -> Result<ItemDamaged, FeedParseError> {
         let mut damage_child = self.next_child_any(&[EventType::ItemDamaged, EventType::ItemBreaks])?;
 
         Ok(ItemDamaged {
             item_id: damage_child.metadata_uuid("itemId")?,
-            item_name: damage_child.metadata_str("itemName")?.to_string(),
+            item_name: if damage_child.is_description_only() {
+                item_name.to_string()
+            } else {
+                damage_child.metadata_str("itemName")?.to_string()
+            },
             item_name_plural,
-            item_mods: vec![],
+            item_mods: damage_child.metadata_mod_vec("mods")?,
             durability: damage_child.metadata_i64("itemDurability")?,
-            health: damage_child.metadata_i64("itemHealthAfter")?,
+            // The description only says whether the item broke
+            health: if damage_child.is_description_only() {
+                if item_name_plural.is_some() { 1 } else { 0 }
+            } else {
+                damage_child.metadata_i64("itemHealthAfter")?
+            },
             player_item_rating_before: damage_child.metadata_f64("playerItemRatingBefore")?,
             player_item_rating_after: damage_child.metadata_f64("playerItemRatingAfter")?,
             player_rating: damage_child.metadata_f64("playerRating")?,
@@ -568,22 +702,40 @@ impl<'e> EventParseWrapper<'e> {
         })
     }
 
-    pub fn parse_item_damage(&mut self, batter_name: &str) -> Result<Option<ItemDamaged>, FeedParseError> {
-        self.next_parse(opt(parse_item_damage(batter_name, (self.season, self.day) < (15, 3))))?
-            .map(|(_item_name, item_name_pural)| {
-                self.next_item_damage(item_name_pural)
-            })
+    /// The child for a damage line of a known player, if there was one
+    pub fn next_player_item_damage(&mut self, damage: Option<(&str, Option<bool>)>) -> Result<Option<ItemDamaged>, FeedParseError> {
+        damage
+            .map(|(item_name, item_name_plural)| self.next_item_damage(item_name, item_name_plural))
             .transpose()
     }
 
-    pub fn parse_item_damage_and_name(&mut self, newline_before: bool) -> Result<Option<(String, ItemDamaged)>, FeedParseError> {
-        self.next_parse(opt(parse_item_damage_unknown_name((self.season, self.day) < (15, 3), newline_before)))?
-            .map(|(_item_name, item_name_plural, player_name)| {
-                Ok((player_name.to_string(), self.next_item_damage(item_name_plural)?))
+    /// The child for a damage line that names its player, if there was one
+    pub fn next_named_item_damage(&mut self, damage: Option<(&str, Option<bool>, &str)>) -> Result<Option<(String, ItemDamaged)>, FeedParseError> {
+        damage
+            .map(|(item_name, item_name_plural, player_name)| {
+                Ok((player_name.to_string(), self.next_item_damage(item_name, item_name_plural)?))
             })
             .transpose()
     }
 
+    pub fn next_named_item_damages(&mut self, damages: Vec<(&str, Option<bool>, &str)>) -> Result<Vec<(String, ItemDamaged)>, FeedParseError> {
+        damages.into_iter()
+            .map(|(item_name, item_name_plural, player_name)| {
+                Ok((player_name.to_string(), self.next_item_damage(item_name, item_name_plural)?))
+            })
+            .collect()
+    }
+
+    pub fn parse_item_damage(&mut self, batter_name: &str) -> Result<Option<ItemDamaged>, FeedParseError> {
+        let damage = self.next_parse(opt(parse_item_damage(batter_name, self.is_early_expansion_era())))?;
+        self.next_player_item_damage(damage)
+    }
+
+    pub fn parse_item_damage_and_name(&mut self, newline_before: bool) -> Result<Option<(String, ItemDamaged)>, FeedParseError> {
+        let damage = self.next_parse(opt(parse_item_damage_unknown_name(self.is_early_expansion_era(), newline_before)))?;
+        self.next_named_item_damage(damage)
+    }
+
     pub fn parse_item_damages_and_names(&mut self, newline_before: bool) -> Result<Vec<(String, ItemDamaged)>, FeedParseError> {
         let mut broken_items = Vec::new();
         while let Some(d) = self.parse_item_damage_and_name(newline_before)? {
@@ -591,19 +743,21 @@ impl<'e> EventParseWrapper<'e> {
         }
         Ok(broken_items)
     }
-    
+
     pub fn parse_pitch(&mut self) -> Result<GamePitch, FeedParseError> {
-        let double_strike = self.next_parse_opt(parse_terminated(" fires a Double Strike!\n"))
-            .map(|player_name| player_name.to_string());
-        
-        Ok(GamePitch {
-            double_strike,
-        })
+        let double_strike = self.next_parse(parse_double_strike)?;
+
+        Ok(game_pitch(double_strike))
     }
 
     pub fn parse_charge_blood(&mut self, batter_name: &str, a: &str) -> Result<Option<ModChangeSubEvent>, FeedParseError> {
-        self.next_parse_opt(parse_charge_blood(batter_name, a))
-            .map(|()| {
+        let charged = self.next_parse_opt(parse_charge_blood(batter_name, a)).is_some();
+        self.next_charge_blood(charged)
+    }
+
+    pub fn next_charge_blood(&mut self, charged: bool) -> Result<Option<ModChangeSubEvent>, FeedParseError> {
+        charged
+            .then(|| {
                 let mut child = self.next_child(EventType::AddedModFromOtherMod)?;
                 ParseOk(ModChangeSubEvent {
                     sub_event: child.as_sub_event(),
@@ -618,7 +772,12 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn parse_parasite(&mut self) -> Result<Option<Parasite>, FeedParseError> {
-        self.next_parse_opt(parse_parasite)
+        let parasite = self.next_parse_opt(parse_parasite);
+        self.next_parasite(parasite)
+    }
+
+    pub fn next_parasite(&mut self, parasite: Option<(&str, &str, &str)>) -> Result<Option<Parasite>, FeedParseError> {
+        parasite
             .map(|(sipper_name, sippee_name, sipped_attribute_name)| {
                 // Both events have to be both increase and decrease because of negative attributes
                 // (unless I want to check against sipped_attribute_name, which I don't)
@@ -645,11 +804,11 @@ impl<'e> EventParseWrapper<'e> {
     }
 
     pub fn game(&mut self, unscatter: Option<Unscatter>, attractor_secret_base: Option<PlayerInfo>) -> Result<GameEvent, FeedParseError> {
-        let game_id = self.next_game_id()?;
-
-        // Order is very important here
-        let away_team = self.next_team_id()?;
-        let home_team = self.next_team_id()?;
+        let (game_id, away_team, home_team) = match self.description_only {
+            Some(game) => game,
+            // Order is very important here
+            None => (self.next_game_id()?, self.next_team_id()?, self.next_team_id()?),
+        };
 
         Ok(GameEvent {
             game_id,
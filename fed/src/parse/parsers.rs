@@ -120,25 +120,13 @@ pub(crate) fn parse_foul_ball(double_strike: bool) -> impl Fn(&str) -> ParserRes
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum StrikeType {
     Swinging,
     Looking,
     Flinching,
 }
 
-pub(crate) fn parse_strike(double_strike: bool) -> impl Fn(&str) -> ParserResult<(StrikeType, i32, i32)> {
-    move |input| {
-        let (input, strike_type) = alt((
-            tag(if double_strike { "Strikes, swinging. "} else { "Strike, swinging. " }).map(|_| StrikeType::Swinging),
-            tag("Strike, looking. ").map(|_| StrikeType::Looking),
-            tag("Strike, flinching. ").map(|_| StrikeType::Flinching),
-        )).parse(input)?;
-        let (input, (balls, strikes)) = parse_count(input)?;
-
-        Ok((input, (strike_type, balls, strikes)))
-    }
-}
-
 pub(crate) fn parse_count(input: &str) -> ParserResult<(i32, i32)> {
     // this should handle double-digit counts because i know how blaseball is
     let (input, balls) = parse_whole_number(input)?;
@@ -219,6 +207,7 @@ pub(crate) fn parse_double_play(input: &str) -> ParserResult<ParsedGroundOut> {
     Ok((input, (ParsedGroundOut::DoublePlay { batter_name })))
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub(crate) enum ParsedHitType {
     Single,
     Double,
@@ -226,34 +215,6 @@ pub(crate) enum ParsedHitType {
     Quadruple,
 }
 
-pub(crate) fn parse_hit(input: &str) -> ParserResult<(&str, ParsedHitType, Option<(&str, Option<bool>)>, Option<(&str, Option<bool>, &str)>)> {
-    let (input, broke) = opt(parse_item_damage_unknown_name(false, false)).parse(input)?;
-    let (input, batter_name, batter_item_broke, pitcher_item_broke) = if let Some((broken_item_name, broken_item_name_plural, player_name)) = broke {
-        let (input, item_was_batters) = opt(tag(player_name)).parse(input)?;
-        let (input, batter_name, batter_item_broke, pitcher_item_broke) = if item_was_batters.is_some() {
-            let (input, _) = tag(" hits a ").parse(input)?;
-            (input, player_name, Some((broken_item_name, broken_item_name_plural)), None)
-        } else {
-            let (input, batter_name) = parse_terminated(" hits a ").parse(input)?;
-            (input, batter_name, None, Some((broken_item_name, broken_item_name_plural, player_name)))
-        };
-
-        (input, batter_name, batter_item_broke, pitcher_item_broke)
-    } else {
-        let (input, batter_name) = parse_terminated(" hits a ").parse(input)?;
-
-        (input, batter_name, None, None)
-    };
-    let (input, num_bases) = alt((
-        tag("Single!").map(|_| ParsedHitType::Single),
-        tag("Double!").map(|_| ParsedHitType::Double),
-        tag("Triple!").map(|_| ParsedHitType::Triple),
-        tag("Quadruple!").map(|_| ParsedHitType::Quadruple),
-    )).parse(input)?;
-
-    Ok((input, (batter_name, num_bases, batter_item_broke, pitcher_item_broke)))
-}
-
 #[derive(PartialEq)]
 pub(crate) enum ParsedSpicyStatus {
     None,
@@ -444,48 +405,29 @@ pub(crate) fn parse_charm_strikeout(input: &str) -> ParserResult<ParsedStrikeout
     Ok((input, ParsedStrikeout::Charm { charmer_name, charmed_name, num_swings }))
 }
 
-pub(crate) enum ParsedWalk<'s> {
-    Ordinary((&'s str, Option<i32>)),
+/// Walks that aren't ordinary walks. Ordinary walks are parsed by `WalkTemplate`.
+pub(crate) enum ParsedUnusualWalk<'s> {
     Charm((Option<(ActivePositionType, &'s str, Option<bool>)>, &'s str, &'s str)),
     MindTrickStrikeoutIntoWalk((&'s str, StrikeoutType)),
     MindTrickWalkIntoStrikeout((&'s str, &'s str)),
 }
 
-pub(crate) fn parse_walk(input: &str) -> ParserResult<ParsedWalk> {
+// An ordinary walk is a prefix of a Mind Trick strikeout, so this has to be tried before
+// WalkTemplate
+pub(crate) fn parse_unusual_walk(input: &str) -> ParserResult<ParsedUnusualWalk> {
     alt((
-        // mind trick strikeout must be before walk, because walk is a prefix of it
-        parse_mind_trick_strikeout.map(|res| ParsedWalk::MindTrickWalkIntoStrikeout(res)),
-        parse_mind_trick_walk.map(|res| ParsedWalk::MindTrickStrikeoutIntoWalk(res)),
-        parse_charm_walk.map(|res| ParsedWalk::Charm(res)),
-        parse_ordinary_walk.map(|res| ParsedWalk::Ordinary(res)),
+        parse_mind_trick_strikeout.map(|res| ParsedUnusualWalk::MindTrickWalkIntoStrikeout(res)),
+        parse_mind_trick_walk.map(|res| ParsedUnusualWalk::MindTrickStrikeoutIntoWalk(res)),
+        parse_charm_walk.map(|res| ParsedUnusualWalk::Charm(res)),
     )).parse(input)
 }
 
-pub(crate) fn parse_base_instincts(input: &str) -> ParserResult<i32> {
-    let (input, _) = tag("\nBase Instincts take them directly to ").parse(input)?;
-    let (input, which) = alt((
-        tag("second").map(|_| 2),
-        tag("third").map(|_| 3),
-        tag("fourth").map(|_| 4), // when fifth base is present
-    )).parse(input)?;
-    let (input, _) = tag(" base!").parse(input)?;
-
-    Ok((input, which))
-}
-
-pub(crate) fn parse_ordinary_walk(input: &str) -> ParserResult<(&str, Option<i32>)> {
-    let (input, batter_name) = parse_terminated(" draws a walk.").parse(input)?;
-
-    let (input, base_instincts) = opt(parse_base_instincts).parse(input)?;
-
-    Ok((input, (batter_name, base_instincts)))
-}
-
 pub(crate) fn parse_charm_walk(input: &str) -> ParserResult<(Option<(ActivePositionType, &str, Option<bool>)>, &str, &str)> {
     // This will need to be updated if anyone charms in a run
     // Resim data makes me think that maybe the pitcher's item could be damaged twice (once as the
     // pitcher and once as the charmer) but I'm not going to worry about that right now
-    let (input, broken_item) = opt(parse_item_damage_unknown_name(false, false)).parse(input)?;
+    // Damage lines have a space in front of them in early s15, even at the start
+    let (input, broken_item) = opt(preceded(opt(tag(" ")), parse_item_damage_unknown_name(false, false))).parse(input)?;
     let (input, broken_item, batter_name, pitcher_name) = if let Some((item_name, item_name_plural, player_name)) = broken_item {
         // We don't yet know which player broke the item
         // Try batter first
@@ -551,6 +493,326 @@ pub(crate) fn parse_stopped_inhabiting(input: &str) -> ParserResult<&str> {
     parse_terminated(" stopped Inhabiting.").parse(input)
 }
 
+// Whole descriptions of the plays that game updates show. The feed event parser and the game update
+// parser both read descriptions with these, so they can't disagree about what a play's description
+// looks like. The feed event parser then reads the tags and children that go with each part.
+// Damage lines for a known player are (item name, plural), and for a player who's only known from
+// the line itself they're (item name, plural, player name).
+
+pub(crate) fn parse_double_strike(input: &str) -> ParserResult<Option<&str>> {
+    opt(parse_terminated(" fires a Double Strike!\n")).parse(input)
+}
+
+/// Players scoring, then any Free Refills they used
+pub(crate) struct ParsedScores<'a> {
+    pub(crate) scorers: Vec<ParsedScore<'a>>,
+    pub(crate) free_refills: Vec<&'a str>,
+}
+
+pub(crate) fn parse_scores_and_refills<'a>(score_label: &'static str, extra_space: bool) -> impl Fn(&'a str) -> ParserResult<ParsedScores<'a>> {
+    move |input| {
+        let (input, scorers) = parse_scores(score_label, extra_space).parse(input)?;
+        let (input, free_refills) = parse_free_refills(input)?;
+
+        Ok((input, ParsedScores { scorers, free_refills }))
+    }
+}
+
+pub(crate) struct ParsedHalfInningPlay<'a> {
+    /// (team nickname, mod name, whether it started) for each subseasonal mod that starts or ends
+    pub(crate) subseasonal_mod_changes: Vec<(&'a str, &'a str, bool)>,
+    pub(crate) top_of_inning: bool,
+    pub(crate) inning: i32,
+    pub(crate) batting_team_name: &'a str,
+}
+
+pub(crate) fn parse_half_inning_play(input: &str) -> ParserResult<ParsedHalfInningPlay> {
+    let (input, subseasonal_mod_changes) = many0(parse_subseasonal_mod_change).parse(input)?;
+    let (input, (top_of_inning, inning, batting_team_name)) = parse_half_inning(input)?;
+
+    Ok((input, ParsedHalfInningPlay { subseasonal_mod_changes, top_of_inning, inning, batting_team_name }))
+}
+
+pub(crate) struct ParsedStolenBasePlay<'a> {
+    pub(crate) runner_name: &'a str,
+    pub(crate) base: Base,
+    pub(crate) is_successful: bool,
+    pub(crate) blaserunning: bool,
+    pub(crate) free_refill: Option<&'a str>,
+    pub(crate) runner_item_damage: Option<(&'a str, Option<bool>)>,
+}
+
+pub(crate) fn parse_stolen_base_play<'a>(extra_space: bool) -> impl Fn(&'a str) -> ParserResult<ParsedStolenBasePlay<'a>> {
+    move |input| {
+        let (input, (runner_name, base, is_successful, blaserunning, free_refill)) = parse_stolen_base(input)?;
+        // Only a successful steal can damage the runner's item
+        let (input, runner_item_damage) = if is_successful {
+            opt(parse_item_damage(runner_name, extra_space)).parse(input)?
+        } else {
+            (input, None)
+        };
+
+        Ok((input, ParsedStolenBasePlay { runner_name, base, is_successful, blaserunning, free_refill, runner_item_damage }))
+    }
+}
+
+pub(crate) enum ParsedUnusualWalkPlay<'a> {
+    Charm {
+        broken_item: Option<(ActivePositionType, &'a str, Option<bool>)>,
+        batter_name: &'a str,
+        pitcher_name: &'a str,
+        scores: ParsedScores<'a>,
+    },
+    MindTrickWalk {
+        batter_name: &'a str,
+        strikeout_type: StrikeoutType,
+        scores: ParsedScores<'a>,
+    },
+    MindTrickStrikeout {
+        batter_name: &'a str,
+        pitcher_name: &'a str,
+    },
+}
+
+pub(crate) fn parse_unusual_walk_play<'a>(extra_space: bool) -> impl Fn(&'a str) -> ParserResult<ParsedUnusualWalkPlay<'a>> {
+    move |input| {
+        Ok(match parse_unusual_walk(input)? {
+            (input, ParsedUnusualWalk::Charm((broken_item, batter_name, pitcher_name))) => {
+                let (input, scores) = parse_scores_and_refills(" scores!", extra_space).parse(input)?;
+                (input, ParsedUnusualWalkPlay::Charm { broken_item, batter_name, pitcher_name, scores })
+            }
+            (input, ParsedUnusualWalk::MindTrickStrikeoutIntoWalk((batter_name, strikeout_type))) => {
+                let (input, scores) = parse_scores_and_refills(" scores!", extra_space).parse(input)?;
+                (input, ParsedUnusualWalkPlay::MindTrickWalk { batter_name, strikeout_type, scores })
+            }
+            (input, ParsedUnusualWalk::MindTrickWalkIntoStrikeout((batter_name, pitcher_name))) => {
+                (input, ParsedUnusualWalkPlay::MindTrickStrikeout { batter_name, pitcher_name })
+            }
+        })
+    }
+}
+
+pub(crate) struct ParsedStrikeoutPlay<'a> {
+    pub(crate) double_strike: Option<&'a str>,
+    pub(crate) strikeout: ParsedStrikeout<'a>,
+    // Charm strikeouts never have these
+    pub(crate) pitcher_item_damage: Option<(&'a str, Option<bool>, &'a str)>,
+    pub(crate) free_refill: Option<&'a str>,
+    pub(crate) parasite: Option<(&'a str, &'a str, &'a str)>,
+}
+
+pub(crate) fn parse_strikeout_play<'a>(extra_space: bool) -> impl Fn(&'a str) -> ParserResult<ParsedStrikeoutPlay<'a>> {
+    move |input| {
+        let (input, double_strike) = parse_double_strike(input)?;
+        let (input, strikeout) = parse_strikeout(input)?;
+        let (input, (pitcher_item_damage, free_refill, parasite)) = if let ParsedStrikeout::Charm { .. } = strikeout {
+            (input, (None, None, None))
+        } else {
+            let (input, pitcher_item_damage) = opt(parse_item_damage_unknown_name(extra_space, true)).parse(input)?;
+            let (input, free_refill) = opt(parse_free_refill).parse(input)?;
+            let (input, parasite) = opt(parse_parasite).parse(input)?;
+            (input, (pitcher_item_damage, free_refill, parasite))
+        };
+
+        Ok((input, ParsedStrikeoutPlay { double_strike, strikeout, pitcher_item_damage, free_refill, parasite }))
+    }
+}
+
+pub(crate) struct ParsedFlyoutPlay<'a> {
+    pub(crate) double_strike: Option<&'a str>,
+    pub(crate) batter_name: &'a str,
+    pub(crate) fielder_name: &'a str,
+    pub(crate) batter_debt: bool,
+    pub(crate) fielder_item_damage: Option<(&'a str, Option<bool>)>,
+    pub(crate) scores: ParsedScores<'a>,
+    pub(crate) batter_item_damage: Option<(&'a str, Option<bool>)>,
+    pub(crate) other_player_item_damage: Option<(&'a str, Option<bool>, &'a str)>,
+    pub(crate) cooled_off: bool,
+    pub(crate) parasite: Option<(&'a str, &'a str, &'a str)>,
+}
+
+pub(crate) fn parse_flyout_play<'a>(extra_space: bool) -> impl Fn(&'a str) -> ParserResult<ParsedFlyoutPlay<'a>> {
+    move |input| {
+        let (input, double_strike) = parse_double_strike(input)?;
+        let (input, (batter_name, fielder_name)) = parse_flyout(input)?;
+        let (input, batter_debt) = opt(parse_batter_debt(batter_name, fielder_name)).parse(input)?;
+        let (input, fielder_item_damage) = opt(parse_item_damage(fielder_name, extra_space)).parse(input)?;
+        let (input, scores) = parse_scores_and_refills(" tags up and scores!", extra_space).parse(input)?;
+        let (input, batter_item_damage) = opt(parse_item_damage(batter_name, extra_space)).parse(input)?;
+        let (input, other_player_item_damage) = opt(parse_item_damage_unknown_name(extra_space, true)).parse(input)?;
+        let (input, cooled_off) = parse_cooled_off(batter_name).parse(input)?;
+        let (input, parasite) = opt(parse_parasite).parse(input)?;
+
+        Ok((input, ParsedFlyoutPlay {
+            double_strike,
+            batter_name,
+            fielder_name,
+            batter_debt: batter_debt.is_some(),
+            fielder_item_damage,
+            scores,
+            batter_item_damage,
+            other_player_item_damage,
+            cooled_off,
+            parasite,
+        }))
+    }
+}
+
+pub(crate) enum ParsedGroundOutPlay<'a> {
+    Simple {
+        batter_name: &'a str,
+        fielder_name: &'a str,
+        batter_debt: bool,
+        scores: ParsedScores<'a>,
+        pitcher_item_damage: Option<(&'a str, Option<bool>, &'a str)>,
+        batter_item_damage: Option<(&'a str, Option<bool>)>,
+        fielder_item_damage: Option<(&'a str, Option<bool>)>,
+        cooled_off: bool,
+    },
+    FieldersChoice {
+        runner_out_name: &'a str,
+        base: Base,
+        damaged_items: Vec<(&'a str, Option<bool>, &'a str)>,
+        batter_name: &'a str,
+        scores: ParsedScores<'a>,
+        cooled_off: bool,
+    },
+    DoublePlay {
+        batter_name: &'a str,
+        scores: ParsedScores<'a>,
+        cooled_off: bool,
+    },
+}
+
+pub(crate) fn parse_ground_out_play<'a>(extra_space: bool) -> impl Fn(&'a str) -> ParserResult<(Option<&'a str>, ParsedGroundOutPlay<'a>)> {
+    move |input| {
+        let (input, double_strike) = parse_double_strike(input)?;
+        let (input, play) = match parse_ground_out(input)? {
+            (input, ParsedGroundOut::Simple { batter_name, fielder_name }) => {
+                let (input, batter_debt) = opt(parse_batter_debt(batter_name, fielder_name)).parse(input)?;
+                let (input, scores) = parse_scores_and_refills(" advances on the sacrifice.", extra_space).parse(input)?;
+                // Damages definitely belong after scores and in this order but not sure if any
+                // other events come in between
+                // The pitcher's line doesn't say it's the pitcher's, so it's any damage line that
+                // isn't the batter's or fielder's
+                let (input, pitcher_item_damage) = opt(verify(
+                    parse_item_damage_unknown_name(extra_space, true),
+                    |(_, _, player_name): &(&str, Option<bool>, &str)| *player_name != batter_name && *player_name != fielder_name,
+                )).parse(input)?;
+                let (input, batter_item_damage) = opt(parse_item_damage(batter_name, extra_space)).parse(input)?;
+                let (input, fielder_item_damage) = opt(parse_item_damage(fielder_name, extra_space)).parse(input)?;
+                let (input, cooled_off) = parse_cooled_off(batter_name).parse(input)?;
+                (input, ParsedGroundOutPlay::Simple {
+                    batter_name,
+                    fielder_name,
+                    batter_debt: batter_debt.is_some(),
+                    scores,
+                    pitcher_item_damage,
+                    batter_item_damage,
+                    fielder_item_damage,
+                    cooled_off,
+                })
+            }
+            (input, ParsedGroundOut::FieldersChoice { runner_out_name, base }) => {
+                let (input, damaged_items) = many0(parse_item_damage_before_scores(extra_space, " scores!")).parse(input)?;
+                // The batter reaching base comes between the scorers and their Free Refills
+                let (input, scorers) = parse_scores(" scores!", extra_space).parse(input)?;
+                let (input, batter_name) = parse_reaches_on_fielders_choice(input)?;
+                let (input, free_refills) = parse_free_refills(input)?;
+                let (input, cooled_off) = parse_cooled_off(batter_name).parse(input)?;
+                (input, ParsedGroundOutPlay::FieldersChoice {
+                    runner_out_name,
+                    base,
+                    damaged_items,
+                    batter_name,
+                    scores: ParsedScores { scorers, free_refills },
+                    cooled_off,
+                })
+            }
+            (input, ParsedGroundOut::DoublePlay { batter_name }) => {
+                let (input, scores) = parse_scores_and_refills(" scores!", extra_space).parse(input)?;
+                let (input, cooled_off) = parse_cooled_off(batter_name).parse(input)?;
+                (input, ParsedGroundOutPlay::DoublePlay { batter_name, scores, cooled_off })
+            }
+        };
+
+        Ok((input, (double_strike, play)))
+    }
+}
+
+pub(crate) struct ParsedHomeRunPlay<'a> {
+    pub(crate) double_strike: Option<&'a str>,
+    pub(crate) damaged_items: Vec<(&'a str, Option<bool>, &'a str)>,
+    pub(crate) magmatic: Option<&'a str>,
+    pub(crate) batter_name: &'a str,
+    pub(crate) home_run_type: HomeRunType,
+    /// (team nickname, player name)
+    pub(crate) attraction: Option<(&'a str, &'a str)>,
+    pub(crate) big_bucket: bool,
+    pub(crate) free_refills: Vec<&'a str>,
+    pub(crate) spicy_status: ParsedSpicyStatus,
+}
+
+pub(crate) fn parse_home_run_play<'a>(extra_space: bool) -> impl Fn(&'a str) -> ParserResult<ParsedHomeRunPlay<'a>> {
+    move |input| {
+        let (input, double_strike) = parse_double_strike(input)?;
+        let (input, damaged_items) = many0(parse_item_damage_unknown_name(extra_space, false)).parse(input)?;
+        let (input, magmatic) = parse_magmatic(input)?;
+        let (input, (batter_name, home_run_type)) = parse_hr(input)?;
+        let (input, attraction) = parse_attract_player(input)?;
+        let (input, big_bucket) = parse_big_bucket(input)?;
+        let (input, free_refills) = parse_free_refills(input)?;
+        let (input, spicy_status) = parse_spicy_status(batter_name).parse(input)?;
+
+        Ok((input, ParsedHomeRunPlay {
+            double_strike,
+            damaged_items,
+            magmatic,
+            batter_name,
+            home_run_type,
+            attraction,
+            big_bucket,
+            free_refills,
+            spicy_status,
+        }))
+    }
+}
+
+pub(crate) struct ParsedBallPlay<'a> {
+    pub(crate) balls: i32,
+    pub(crate) strikes: i32,
+    pub(crate) batter_item_damage: Option<(&'a str, Option<bool>, &'a str)>,
+}
+
+pub(crate) fn parse_ball_play<'a>(extra_space: bool) -> impl Fn(&'a str) -> ParserResult<ParsedBallPlay<'a>> {
+    move |input| {
+        let (input, (balls, strikes)) = parse_ball(input)?;
+        let (input, batter_item_damage) = opt(parse_item_damage_unknown_name(extra_space, true)).parse(input)?;
+
+        Ok((input, ParsedBallPlay { balls, strikes, batter_item_damage }))
+    }
+}
+
+pub(crate) struct ParsedFoulBallPlay<'a> {
+    pub(crate) double_strike: Option<&'a str>,
+    pub(crate) balls: i32,
+    pub(crate) strikes: i32,
+    pub(crate) batter_item_damage: Option<(&'a str, Option<bool>, &'a str)>,
+    pub(crate) birds: Option<i32>,
+}
+
+pub(crate) fn parse_foul_ball_play<'a>(extra_space: bool) -> impl Fn(&'a str) -> ParserResult<ParsedFoulBallPlay<'a>> {
+    move |input| {
+        let (input, double_strike) = parse_double_strike(input)?;
+        // Eventually this will need very foul support, but I'll get to that when it comes up
+        let (input, (balls, strikes)) = parse_foul_ball(double_strike.is_some())(input)?;
+        let (input, batter_item_damage) = opt(parse_item_damage_unknown_name(extra_space, true)).parse(input)?;
+        let (input, birds) = opt(parse_birds).parse(input)?;
+
+        Ok((input, ParsedFoulBallPlay { double_strike, balls, strikes, batter_item_damage, birds }))
+    }
+}
+
 pub(crate) fn parse_game_end(input: &str) -> ParserResult<((&str, f32), (&str, f32))> {
     // This is a bit tricky because it's a string of arbitrary words (a team name) followed by an
     // arbitrary number (score)
@@ -1386,13 +1648,13 @@ pub(crate) fn parse_echo_into_static(input: &str) -> ParserResult<(&str, &str)>
 pub(crate) fn parse_psychoacoustics(at: bool) -> impl Fn(&str) -> ParserResult<(&str, &str, &str)> {
     move |input: &str| {
         let (input, stadium_name) = parse_terminated(" is Resonating.\nPsychoAcoustics Echo ").parse(input)?;
-        // They changed the text in s16
-        let (input, mod_name) = if at {
-            parse_terminated(" at the ").parse(input)?
-        } else {
-            parse_terminated(" to the ").parse(input)?
+        let (input, mod_and_team) = parse_terminated(".").parse(input)?;
+        // They changed the text in s16. Split at the last separator because mod names can contain
+        // it, e.g. "Late to the Party"
+        let separator = if at { " at the " } else { " to the " };
+        let Some((mod_name, team_nickname)) = mod_and_team.rsplit_once(separator) else {
+            return fail(mod_and_team);
         };
-        let (input, team_nickname) = parse_terminated(".").parse(input)?;
 
         Ok((input, (stadium_name, mod_name, team_nickname)))
     }
@@ -1656,6 +1918,22 @@ pub(crate) fn parse_item_damage_unknown_name<'a>(extra_space: bool, newline_befo
     }
 }
 
+/// Parses a damage line on its own line, but not if the damaged player scores on the next line,
+/// because then it's the scorer's damage line
+pub(crate) fn parse_item_damage_before_scores<'a>(extra_space: bool, score_label: &'static str) -> impl FnMut(&'a str) -> ParserResult<(&'a str, Option<bool>, &'a str)> {
+    move |input| {
+        let (rest, (item_name, item_name_plural, player_name)) = parse_item_damage_unknown_name(extra_space, true).parse(input)?;
+        let is_scorer = rest.strip_prefix('\n')
+            .and_then(|rest| rest.strip_prefix(player_name))
+            .is_some_and(|rest| rest.starts_with(score_label));
+        if is_scorer {
+            return fail(input);
+        }
+
+        Ok((rest, (item_name, item_name_plural, player_name)))
+    }
+}
+
 pub(crate) fn parse_item_damage<'a>(player_name: &str, extra_space: bool) -> impl FnMut(&'a str) -> ParserResult<(&'a str, Option<bool>)> + '_ {
     move |input| {
         let (input, _) = if extra_space { tag("\n ") } else { tag("\n") }.parse(input)?;
@@ -1723,8 +2001,11 @@ pub(crate) fn parse_mods_from_other_mod_removed(input: &str) -> ParserResult<(&s
     Ok((input, (player_name, mod_name)))
 }
 
-pub(crate) fn parse_subseasonal_mod_change(input: &str) -> ParserResult<(&str, &str)> {
-    alt((parse_subseasonal_mod_added, parse_subseasonal_mod_removed)).parse(input)
+pub(crate) fn parse_subseasonal_mod_change(input: &str) -> ParserResult<(&str, &str, bool)> {
+    alt((
+        parse_subseasonal_mod_added.map(|(team_name, mod_name)| (team_name, mod_name, true)),
+        parse_subseasonal_mod_removed.map(|(team_name, mod_name)| (team_name, mod_name, false)),
+    )).parse(input)
 }
 
 pub(crate) fn parse_subseasonal_mod_added(input: &str) -> ParserResult<(&str, &str)> {
@@ -0,0 +1,593 @@
+use nom::combinator::fail;
+use nom::Parser;
+use nom::bytes::complete::tag;
+use std::fmt::Write;
+use uuid::Uuid;
+use eventually_api::{EventCategory, EventType};
+
+use crate::parse::error::FeedParseError;
+use crate::parse::event_builder_new::EventBuilder;
+use crate::parse::parse_wrapper::EventParseWrapper;
+use crate::parse::parsers::{parse_terminated, parse_whole_number, ParsedHitType, ParserResult, StrikeType};
+use crate::{FedEventData, GameEvent, GamePitch, HitType, ItemDamaged, ModChangeSubEvent, Scores, SpecialReason, SpicyStatus, StoppedInhabiting};
+
+// Templates describe an event once and are used both to parse it and to build it, so the parser
+// and the builder can't drift apart. There are two layers:
+//  - A `Syntax` is a piece of description grammar that can parse text into a value and print the
+//    value back into the same text.
+//  - A `Part` is one piece of an event -- a line of description, a tag, a child, or some
+//    combination -- that can be consumed from an `EventParseWrapper` and pushed onto an
+//    `EventBuilder`.
+// `event_template!` strings parts together into a struct whose parse and build functions visit the
+// parts in the same order. Parts can refer to the values of earlier parts. Parsing with a
+// description-only `EventParseWrapper` reads a template from its description alone (as for game
+// updates), with placeholders for the tags and children.
+
+/// A piece of description grammar that can both parse and print its value
+pub(crate) trait Syntax {
+    type Value;
+
+    fn parse<'a>(&self, input: &'a str) -> ParserResult<'a, Self::Value>;
+
+    fn print(&self, value: &Self::Value, output: &mut String);
+}
+
+/// A name followed by some literal text. The name can't contain a newline.
+pub(crate) struct NameUntil(pub &'static str);
+
+impl Syntax for NameUntil {
+    type Value = String;
+
+    fn parse<'a>(&self, input: &'a str) -> ParserResult<'a, String> {
+        parse_terminated(self.0).map(str::to_string).parse(input)
+    }
+
+    fn print(&self, value: &String, output: &mut String) {
+        output.push_str(value);
+        output.push_str(self.0);
+    }
+}
+
+/// A whole number
+pub(crate) struct Number;
+
+impl Syntax for Number {
+    type Value = i32;
+
+    fn parse<'a>(&self, input: &'a str) -> ParserResult<'a, i32> {
+        parse_whole_number(input)
+    }
+
+    fn print(&self, value: &i32, output: &mut String) {
+        write!(output, "{value}").unwrap();
+    }
+}
+
+/// One of a fixed set of phrases, each of which stands for a value. Phrases are tried in order, so
+/// a phrase must come before any other phrase that it's a prefix of.
+pub(crate) struct OneOf<T: 'static>(pub &'static [(&'static str, T)]);
+
+impl<T: Copy + PartialEq + 'static> Syntax for OneOf<T> {
+    type Value = T;
+
+    fn parse<'a>(&self, input: &'a str) -> ParserResult<'a, T> {
+        for (phrase, value) in self.0 {
+            if let Some(rest) = input.strip_prefix(*phrase) {
+                return Ok((rest, *value));
+            }
+        }
+
+        fail(input)
+    }
+
+    fn print(&self, value: &T, output: &mut String) {
+        let (phrase, _) = self.0.iter()
+            .find(|(_, v)| v == value)
+            .expect("Value printed with OneOf should be in its phrase table");
+        output.push_str(phrase);
+    }
+}
+
+/// Literal text followed by another syntax, keeping the second syntax's value
+pub(crate) struct Preceded<S>(pub &'static str, pub S);
+
+impl<S: Syntax> Syntax for Preceded<S> {
+    type Value = S::Value;
+
+    fn parse<'a>(&self, input: &'a str) -> ParserResult<'a, S::Value> {
+        let (input, _) = tag(self.0).parse(input)?;
+        self.1.parse(input)
+    }
+
+    fn print(&self, value: &S::Value, output: &mut String) {
+        output.push_str(self.0);
+        self.1.print(value, output);
+    }
+}
+
+/// Another syntax followed by literal text, keeping the first syntax's value
+pub(crate) struct Terminated<S>(pub S, pub &'static str);
+
+impl<S: Syntax> Syntax for Terminated<S> {
+    type Value = S::Value;
+
+    fn parse<'a>(&self, input: &'a str) -> ParserResult<'a, S::Value> {
+        let (input, value) = self.0.parse(input)?;
+        let (input, _) = tag(self.1).parse(input)?;
+        Ok((input, value))
+    }
+
+    fn print(&self, value: &S::Value, output: &mut String) {
+        self.0.print(value, output);
+        output.push_str(self.1);
+    }
+}
+
+fn next_syntax<'e, S: Syntax>(event: &mut EventParseWrapper<'e>, syntax: &S, new_line: bool) -> Result<S::Value, FeedParseError> {
+    event.next_parse(|input: &'e str| {
+        if new_line { parse_line(syntax, input) } else { syntax.parse(input) }
+    })
+}
+
+/// Parses a syntax that starts a new line. The builder separates lines with newlines, but the
+/// first line has nothing before it.
+fn parse_line<'a, S: Syntax>(syntax: &S, input: &'a str) -> ParserResult<'a, S::Value> {
+    syntax.parse(input.strip_prefix('\n').unwrap_or(input))
+}
+
+fn print_syntax<S: Syntax>(syntax: &S, value: &S::Value) -> String {
+    let mut output = String::new();
+    syntax.print(value, &mut output);
+    output
+}
+
+/// One piece of an event. Parsing consumes the piece from the event, and building pushes it onto
+/// the builder.
+pub(crate) trait Part {
+    type Value;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Self::Value, FeedParseError>;
+
+    fn build(&self, value: &Self::Value, builder: &mut EventBuilder);
+}
+
+/// A new line of description
+pub(crate) struct Line<S>(pub S);
+
+impl<S: Syntax> Part for Line<S> {
+    type Value = S::Value;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<S::Value, FeedParseError> {
+        next_syntax(event, &self.0, true)
+    }
+
+    fn build(&self, value: &S::Value, builder: &mut EventBuilder) {
+        builder.push_description(&print_syntax(&self.0, value));
+    }
+}
+
+/// A new line of description that may not be there
+pub(crate) struct OptLine<S>(pub S);
+
+impl<S: Syntax> Part for OptLine<S> {
+    type Value = Option<S::Value>;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Option<S::Value>, FeedParseError> {
+        Ok(event.next_parse_opt(|input| parse_line(&self.0, input)))
+    }
+
+    fn build(&self, value: &Option<S::Value>, builder: &mut EventBuilder) {
+        if let Some(value) = value {
+            builder.push_description(&print_syntax(&self.0, value));
+        }
+    }
+}
+
+/// More description on the same line as the previous part
+pub(crate) struct Text<S>(pub S);
+
+impl<S: Syntax> Part for Text<S> {
+    type Value = S::Value;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<S::Value, FeedParseError> {
+        next_syntax(event, &self.0, false)
+    }
+
+    fn build(&self, value: &S::Value, builder: &mut EventBuilder) {
+        builder.append_description(&print_syntax(&self.0, value));
+    }
+}
+
+/// A player tag
+pub(crate) struct PlayerTag;
+
+impl Part for PlayerTag {
+    type Value = Uuid;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Uuid, FeedParseError> {
+        event.next_player_id()
+    }
+
+    fn build(&self, value: &Uuid, builder: &mut EventBuilder) {
+        builder.push_player_tag(*value);
+    }
+}
+
+/// The Double Strike announcement, if any
+pub(crate) struct Pitch;
+
+impl Part for Pitch {
+    type Value = GamePitch;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<GamePitch, FeedParseError> {
+        event.parse_pitch()
+    }
+
+    fn build(&self, value: &GamePitch, builder: &mut EventBuilder) {
+        builder.push_pitch(value.clone());
+    }
+}
+
+/// Whether the event is Special. Parsing reads the category, and can't tell why, so the reason is
+/// always `Unknown` until `FedEventData::resolve_special_reason` works it out. Building only ever
+/// sets the category to Special, so that it doesn't undo parts that set it themselves. The flag is
+/// for events that are always Special when some other part is present.
+pub(crate) struct SpecialCategory(pub bool);
+
+impl Part for SpecialCategory {
+    type Value = Option<SpecialReason>;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Option<SpecialReason>, FeedParseError> {
+        Ok((event.category == EventCategory::Special).then_some(SpecialReason::Unknown))
+    }
+
+    fn build(&self, value: &Option<SpecialReason>, builder: &mut EventBuilder) {
+        if value.is_some() || self.0 {
+            builder.set_category(EventCategory::Special);
+        }
+    }
+}
+
+/// A part that's only present when the condition is true. Otherwise its value is the default.
+pub(crate) struct When<P>(pub bool, pub P);
+
+impl<P: Part> Part for When<P> where P::Value: Default {
+    type Value = P::Value;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<P::Value, FeedParseError> {
+        if self.0 { self.1.parse(event) } else { Ok(Default::default()) }
+    }
+
+    fn build(&self, value: &P::Value, builder: &mut EventBuilder) {
+        if self.0 {
+            self.1.build(value, builder);
+        }
+    }
+}
+
+/// Item damage lines before the event's main line, with the name of each damaged item's holder
+pub(crate) struct NamedItemDamagesBefore;
+
+impl Part for NamedItemDamagesBefore {
+    type Value = Vec<(String, ItemDamaged)>;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Vec<(String, ItemDamaged)>, FeedParseError> {
+        event.parse_item_damages_and_names(false)
+    }
+
+    fn build(&self, value: &Vec<(String, ItemDamaged)>, builder: &mut EventBuilder) {
+        builder.push_named_item_damages(value.clone());
+    }
+}
+
+/// An item damage line after the event's main line, with the name of the damaged item's holder
+pub(crate) struct NamedItemDamage;
+
+impl Part for NamedItemDamage {
+    type Value = Option<(String, ItemDamaged)>;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Option<(String, ItemDamaged)>, FeedParseError> {
+        event.parse_item_damage_and_name(true)
+    }
+
+    fn build(&self, value: &Option<(String, ItemDamaged)>, builder: &mut EventBuilder) {
+        builder.push_named_item_damage(value.clone());
+    }
+}
+
+/// Damage to a known player's item
+pub(crate) struct ItemDamage<'n>(pub &'n str);
+
+impl Part for ItemDamage<'_> {
+    type Value = Option<ItemDamaged>;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Option<ItemDamaged>, FeedParseError> {
+        event.parse_item_damage(self.0)
+    }
+
+    fn build(&self, value: &Option<ItemDamaged>, builder: &mut EventBuilder) {
+        builder.push_item_damage(value.clone(), self.0);
+    }
+}
+
+/// Players scoring, with the text that follows each scoring player's name
+pub(crate) struct Scoring(pub &'static str);
+
+impl Part for Scoring {
+    type Value = Scores;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Scores, FeedParseError> {
+        event.parse_scores(self.0)
+    }
+
+    fn build(&self, value: &Scores, builder: &mut EventBuilder) {
+        builder.push_scores(value.clone(), self.0.trim_start());
+    }
+}
+
+/// A Haunting player leaving. If a player id is given, only that player's Inhabiting is parsed.
+pub(crate) struct StoppedInhabitingChild(pub Option<Uuid>);
+
+impl Part for StoppedInhabitingChild {
+    type Value = Option<StoppedInhabiting>;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Option<StoppedInhabiting>, FeedParseError> {
+        event.parse_stopped_inhabiting(self.0)
+    }
+
+    fn build(&self, value: &Option<StoppedInhabiting>, builder: &mut EventBuilder) {
+        builder.push_stopped_inhabiting(value.clone());
+    }
+}
+
+/// A player Power Charging, e.g. from hitting a triple with the AAA blood type
+pub(crate) struct ChargeBlood<'n> {
+    pub player_name: &'n str,
+    pub player_id: Uuid,
+    pub a: &'static str,
+}
+
+impl Part for ChargeBlood<'_> {
+    type Value = Option<ModChangeSubEvent>;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<Option<ModChangeSubEvent>, FeedParseError> {
+        event.parse_charge_blood(self.player_name, self.a)
+    }
+
+    fn build(&self, value: &Option<ModChangeSubEvent>, builder: &mut EventBuilder) {
+        builder.push_charge_blood(value.clone(), self.player_name, self.player_id, self.a);
+    }
+}
+
+/// A player Heating Up or becoming Red Hot
+pub(crate) struct Spicy<'n> {
+    pub player_name: &'n str,
+    pub player_id: Uuid,
+}
+
+impl Part for Spicy<'_> {
+    type Value = SpicyStatus;
+
+    fn parse(&self, event: &mut EventParseWrapper<'_>) -> Result<SpicyStatus, FeedParseError> {
+        event.parse_spicy_status(self.player_name)
+    }
+
+    fn build(&self, value: &SpicyStatus, builder: &mut EventBuilder) {
+        builder.push_spicy(value.clone(), self.player_name, self.player_id);
+    }
+}
+
+/// Declares a template: a struct with one field per part, plus `parse` and `build` functions that
+/// visit the parts in the order they're listed. Each part is an expression that may use the fields
+/// before it.
+macro_rules! event_template {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field:ident: $ty:ty = $part:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(pub(crate) $field: $ty,)*
+        }
+
+        impl $name {
+            pub(crate) fn parse(event: &mut EventParseWrapper<'_>) -> Result<Self, FeedParseError> {
+                $(let $field: $ty = Part::parse(&$part, event)?;)*
+                Ok(Self { $($field),* })
+            }
+
+            pub(crate) fn build(self, builder: &mut EventBuilder) {
+                let Self { $($field),* } = self;
+                $(Part::build(&$part, &$field, builder);)*
+            }
+        }
+    };
+}
+
+const STRIKE_TYPES: [(&str, StrikeType); 3] = [
+    ("Strike, swinging. ", StrikeType::Swinging),
+    ("Strike, looking. ", StrikeType::Looking),
+    ("Strike, flinching. ", StrikeType::Flinching),
+];
+
+// Only swinging strikes are plural when there's a Double Strike
+const DOUBLE_STRIKE_TYPES: [(&str, StrikeType); 3] = [
+    ("Strikes, swinging. ", StrikeType::Swinging),
+    ("Strike, looking. ", StrikeType::Looking),
+    ("Strike, flinching. ", StrikeType::Flinching),
+];
+
+event_template! {
+    /// Strike events: StrikeSwinging, StrikeLooking, and StrikeFlinching
+    pub(crate) struct StrikeTemplate {
+        pitch: GamePitch = Pitch,
+        strike_type: StrikeType = Line(OneOf(if pitch.double_strike.is_some() { &DOUBLE_STRIKE_TYPES } else { &STRIKE_TYPES })),
+        balls: i32 = Text(Terminated(Number, "-")),
+        strikes: i32 = Text(Number),
+        pitcher_item_damage: Option<(String, ItemDamaged)> = NamedItemDamage,
+    }
+}
+
+impl StrikeTemplate {
+    pub(crate) fn into_data(self, game: GameEvent) -> FedEventData {
+        let Self { pitch, strike_type, balls, strikes, pitcher_item_damage } = self;
+        match strike_type {
+            StrikeType::Swinging => FedEventData::StrikeSwinging { game, pitch, balls, strikes, pitcher_item_damage },
+            StrikeType::Looking => FedEventData::StrikeLooking { game, pitch, balls, strikes, pitcher_item_damage },
+            StrikeType::Flinching => FedEventData::StrikeFlinching { game, pitch, balls, strikes, pitcher_item_damage },
+        }
+    }
+
+    /// Splits a strike event into its game and template, or returns None if it isn't a strike
+    pub(crate) fn from_data(data: FedEventData) -> Option<(GameEvent, Self)> {
+        let (strike_type, game, pitch, balls, strikes, pitcher_item_damage) = match data {
+            FedEventData::StrikeSwinging { game, pitch, balls, strikes, pitcher_item_damage } => {
+                (StrikeType::Swinging, game, pitch, balls, strikes, pitcher_item_damage)
+            }
+            FedEventData::StrikeLooking { game, pitch, balls, strikes, pitcher_item_damage } => {
+                (StrikeType::Looking, game, pitch, balls, strikes, pitcher_item_damage)
+            }
+            FedEventData::StrikeFlinching { game, pitch, balls, strikes, pitcher_item_damage } => {
+                (StrikeType::Flinching, game, pitch, balls, strikes, pitcher_item_damage)
+            }
+            _ => return None,
+        };
+
+        Some((game, Self { pitch, strike_type, balls, strikes, pitcher_item_damage }))
+    }
+}
+
+const BASE_INSTINCTS_BASES: [(&str, i32); 3] = [
+    ("second", 2),
+    ("third", 3),
+    ("fourth", 4), // when fifth base is present
+];
+
+const BASE_INSTINCTS: Preceded<Terminated<OneOf<i32>>> = Preceded("Base Instincts take them directly to ", Terminated(OneOf(&BASE_INSTINCTS_BASES), " base!"));
+
+event_template! {
+    /// Ordinary walks. Charm and Mind Trick walks are parsed separately.
+    pub(crate) struct WalkTemplate {
+        batter_name: String = Line(NameUntil(" draws a walk.")),
+        base_instincts: Option<i32> = OptLine(BASE_INSTINCTS),
+        batter_id: Uuid = PlayerTag,
+        scores: Scores = Scoring(" scores!"),
+        batter_item_damage: Option<ItemDamaged> = ItemDamage(&batter_name),
+        stopped_inhabiting: Option<StoppedInhabiting> = StoppedInhabitingChild(Some(batter_id)),
+        special_reason: Option<SpecialReason> = SpecialCategory(base_instincts.is_some()),
+    }
+}
+
+impl WalkTemplate {
+    pub(crate) fn into_data(self, game: GameEvent) -> FedEventData {
+        let Self { batter_name, base_instincts, batter_id, scores, batter_item_damage, stopped_inhabiting, special_reason } = self;
+        FedEventData::Walk { game, batter_name, batter_id, scores, base_instincts, batter_item_damage, stopped_inhabiting, special_reason }
+    }
+
+    /// Splits a walk event into its game and template, or returns None if it isn't an ordinary walk
+    pub(crate) fn from_data(data: FedEventData) -> Option<(GameEvent, Self)> {
+        let FedEventData::Walk { game, batter_name, batter_id, scores, base_instincts, batter_item_damage, stopped_inhabiting, special_reason } = data else {
+            return None;
+        };
+
+        Some((game, Self { batter_name, base_instincts, batter_id, scores, batter_item_damage, stopped_inhabiting, special_reason }))
+    }
+}
+
+const HIT_TYPES: [(&str, ParsedHitType); 4] = [
+    ("Single", ParsedHitType::Single),
+    ("Double", ParsedHitType::Double),
+    ("Triple", ParsedHitType::Triple),
+    ("Quadruple", ParsedHitType::Quadruple),
+];
+
+event_template! {
+    /// Hits other than home runs
+    pub(crate) struct HitTemplate {
+        special_reason: Option<SpecialReason> = SpecialCategory(false),
+        pitch: GamePitch = Pitch,
+        // The damage lines come before the batter's name, so which player each one belongs to is
+        // only worked out in into_data
+        item_damages: Vec<(String, ItemDamaged)> = NamedItemDamagesBefore,
+        batter_name: String = Line(NameUntil(" hits a ")),
+        hit_type: ParsedHitType = Text(Terminated(OneOf(&HIT_TYPES), "!")),
+        batter_id: Uuid = PlayerTag,
+        stopped_inhabiting: Option<StoppedInhabiting> = StoppedInhabitingChild(Some(batter_id)),
+        power_charge: Option<ModChangeSubEvent> = When(hit_type == ParsedHitType::Triple, ChargeBlood { player_name: &batter_name, player_id: batter_id, a: "aaa" }),
+        scores: Scores = Scoring(" scores!"),
+        spicy_status: SpicyStatus = Spicy { player_name: &batter_name, player_id: batter_id },
+        other_player_item_damage: Option<(String, ItemDamaged)> = NamedItemDamage,
+    }
+}
+
+impl HitTemplate {
+    /// Fails if there's more than one damage line before the hit for the batter, or more than one
+    /// for other players, because a Hit only has room for one of the batter's items and one of the
+    /// pitcher's
+    pub(crate) fn into_data(self, game: GameEvent) -> Result<FedEventData, FeedParseError> {
+        let Self { special_reason, pitch, item_damages, batter_name, hit_type, batter_id, stopped_inhabiting, power_charge, scores, spicy_status, other_player_item_damage } = self;
+        let (batter_item_damage, pitcher_item_damage) = split_hit_item_damages(item_damages, &batter_name)?;
+
+        Ok(FedEventData::Hit {
+            game,
+            pitch,
+            hit_type: match hit_type {
+                ParsedHitType::Single => HitType::Single,
+                ParsedHitType::Double => HitType::Double,
+                ParsedHitType::Triple => HitType::Triple(power_charge),
+                ParsedHitType::Quadruple => HitType::Quadruple,
+            },
+            batter_name,
+            batter_id,
+            scores,
+            spicy_status,
+            stopped_inhabiting,
+            special_reason,
+            pitcher_item_damage,
+            batter_item_damage,
+            other_player_item_damage,
+        })
+    }
+
+    /// Splits a hit event into its game and template, or returns None if it isn't a hit
+    pub(crate) fn from_data(data: FedEventData) -> Option<(GameEvent, Self)> {
+        let FedEventData::Hit { game, pitch, batter_name, batter_id, hit_type, scores, spicy_status, stopped_inhabiting, special_reason, pitcher_item_damage, batter_item_damage, other_player_item_damage } = data else {
+            return None;
+        };
+
+        // Resim research says the pitcher's item is damaged first
+        let item_damages = pitcher_item_damage.into_iter()
+            .chain(batter_item_damage.map(|damage| (batter_name.clone(), damage)))
+            .collect();
+        let (hit_type, power_charge) = match hit_type {
+            HitType::Single => (ParsedHitType::Single, None),
+            HitType::Double => (ParsedHitType::Double, None),
+            HitType::Triple(power_charge) => (ParsedHitType::Triple, power_charge),
+            HitType::Quadruple => (ParsedHitType::Quadruple, None),
+        };
+
+        Some((game, Self { special_reason, pitch, item_damages, batter_name, hit_type, batter_id, stopped_inhabiting, power_charge, scores, spicy_status, other_player_item_damage }))
+    }
+}
+
+/// The batter's damage, then the pitcher's damage with the pitcher's name
+pub(crate) type HitItemDamages<N, D> = (Option<D>, Option<(N, D)>);
+
+/// Sorts the damage lines before a hit into the batter's and the pitcher's, by whether they name
+/// the batter. Fails if there's more than one of either.
+pub(crate) fn split_hit_item_damages<N: AsRef<str>, D>(item_damages: Vec<(N, D)>, batter_name: &str) -> Result<HitItemDamages<N, D>, FeedParseError> {
+    let (mut batter_item_damages, mut pitcher_item_damages): (Vec<_>, Vec<_>) = item_damages.into_iter()
+        .partition(|(player_name, _)| player_name.as_ref() == batter_name);
+    let extra_damage = [&batter_item_damages, &pitcher_item_damages].into_iter()
+        .find_map(|damages| damages.get(1));
+    if let Some((player_name, _)) = extra_damage {
+        return Err(FeedParseError::TooManyItemDamages {
+            event_type: EventType::Hit,
+            player_name: player_name.as_ref().to_string(),
+        });
+    }
+
+    Ok((batter_item_damages.pop().map(|(_, damage)| damage), pitcher_item_damages.pop()))
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{FedEvent, FedEventData, ReturnFromElsewhereFlavor};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlayerNameKind {
+    /// The player's real name
+    Canonical,
+    /// The name the player had while Scattered, i.e. their real name with some letters replaced
+    /// by dashes
+    Scattered,
+}
+
+/// One name a player went by, and the span of events they were seen with it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerNameEntry {
+    pub name: String,
+    pub kind: PlayerNameKind,
+    pub first_seen: DateTime<Utc>,
+    pub first_event_id: Uuid,
+    /// The last time the player was seen with this name before being seen with a different one
+    pub last_seen: DateTime<Utc>,
+    pub last_event_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerRecord {
+    pub player_id: Uuid,
+    /// Every name the player has been seen with, in order. A name appears again if the player goes
+    /// back to it, e.g. after being unscattered.
+    pub names: Vec<PlayerNameEntry>,
+}
+
+impl PlayerRecord {
+    /// The name the player was most recently seen with
+    pub fn current_name(&self) -> Option<&str> {
+        self.names.last().map(|entry| entry.name.as_str())
+    }
+
+    /// The player's most recent real name, which is the current name unless they're Scattered
+    pub fn canonical_name(&self) -> Option<&str> {
+        self.names.iter().rev()
+            .find(|entry| entry.kind == PlayerNameKind::Canonical)
+            .map(|entry| entry.name.as_str())
+    }
+
+    fn entry_at(&self, time: DateTime<Utc>, kind: Option<PlayerNameKind>) -> Option<&PlayerNameEntry> {
+        self.names.iter().rev()
+            .filter(|entry| kind.is_none_or(|kind| entry.kind == kind))
+            .find(|entry| entry.first_seen <= time)
+    }
+}
+
+/// Something in the feed that doesn't agree with what the registry already knew about a player.
+/// Players really were renamed now and then, so these aren't necessarily errors, but they can also
+/// point to misparsed names.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum PlayerInconsistency {
+    /// A player was seen with a real name other than the one they last had. Scattering and
+    /// unscattering aren't reported.
+    #[serde(rename_all = "camelCase")]
+    NameChanged { player_id: Uuid, previous_name: String, name: String },
+
+    /// A player was seen with the same real name another player currently has
+    #[serde(rename_all = "camelCase")]
+    NameShared { name: String, player_id: Uuid, other_player_id: Uuid },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerInconsistencyEntry {
+    pub event_id: Uuid,
+    pub created: DateTime<Utc>,
+    pub inconsistency: PlayerInconsistency,
+}
+
+/// Collects every player's names from a stream of FedEvents, which must be in chronological order.
+///
+/// Names are read from `FedEventData::named_players`, so this covers every variant that names a
+/// player along with their id. A name that is the player's real name with some letters replaced by dashes is recorded as Scattered, as is the
+/// scattered name given when a player returns from Elsewhere. If a player is first seen while
+/// Scattered, their earlier names are reclassified once they're seen with their real name. Name
+/// changes and names shared by several players are still recorded, and the disagreement is
+/// recorded in `inconsistencies`.
+///
+/// Parsers can use `longest_name_prefix` to find where a player's name ends in text that follows
+/// it with a possessive, for names that themselves contain an apostrophe or "'s".
+#[derive(Debug, Default)]
+pub struct PlayerRegistry {
+    players: HashMap<Uuid, PlayerRecord>,
+    players_by_name: HashMap<String, Vec<Uuid>>,
+    inconsistencies: Vec<PlayerInconsistencyEntry>,
+}
+
+struct EventContext {
+    event_id: Uuid,
+    created: DateTime<Utc>,
+}
+
+impl PlayerRegistry {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Builds a registry from every event in the stream
+    pub fn from_events<'a>(events: impl IntoIterator<Item=&'a FedEvent>) -> Self {
+        let mut registry = Self::new();
+        for event in events {
+            registry.apply(event);
+        }
+        registry
+    }
+
+    pub fn apply(&mut self, event: &FedEvent) {
+        let ctx = EventContext {
+            event_id: event.id,
+            created: event.created,
+        };
+
+        for player in event.data.named_players() {
+            self.observe(&ctx, player.player_id, player.player_name, None);
+        }
+
+        // The scattered name isn't paired with the player's id, and it comes after the real name
+        // in the description
+        if let FedEventData::ReturnFromElsewhere { flavor: ReturnFromElsewhereFlavor::Full { player_id, scattered: Some(scattered), .. }, .. } = &event.data {
+            self.observe(&ctx, *player_id, &scattered.scattered_name, Some(PlayerNameKind::Scattered));
+        }
+    }
+
+    fn observe(&mut self, ctx: &EventContext, player_id: Uuid, name: &str, kind: Option<PlayerNameKind>) {
+        let record = self.players.entry(player_id)
+            .or_insert_with(|| PlayerRecord { player_id, names: Vec::new() });
+
+        if let Some(current) = record.names.last_mut() && current.name == name {
+            current.last_seen = ctx.created;
+            current.last_event_id = ctx.event_id;
+            return;
+        }
+
+        let kind = kind.unwrap_or_else(|| {
+            match record.canonical_name() {
+                Some(canonical) if is_scattered_form(canonical, name) => PlayerNameKind::Scattered,
+                _ => PlayerNameKind::Canonical,
+            }
+        });
+        if kind == PlayerNameKind::Canonical {
+            // Covers players who were first seen while Scattered
+            for entry in &mut record.names {
+                if entry.kind == PlayerNameKind::Canonical && is_scattered_form(name, &entry.name) {
+                    entry.kind = PlayerNameKind::Scattered;
+                }
+            }
+        }
+
+        let previous_name = record.canonical_name().map(str::to_string);
+        record.names.push(PlayerNameEntry {
+            name: name.to_string(),
+            kind,
+            first_seen: ctx.created,
+            first_event_id: ctx.event_id,
+            last_seen: ctx.created,
+            last_event_id: ctx.event_id,
+        });
+
+        let players_with_name = self.players_by_name.entry(name.to_string()).or_default();
+        if !players_with_name.contains(&player_id) {
+            players_with_name.push(player_id);
+        }
+
+        if kind != PlayerNameKind::Canonical {
+            return;
+        }
+
+        if let Some(previous_name) = previous_name && previous_name != name {
+            self.push_inconsistency(ctx, PlayerInconsistency::NameChanged {
+                player_id,
+                previous_name,
+                name: name.to_string(),
+            });
+        }
+
+        let other_player_id = self.players_by_name[name].iter()
+            .copied()
+            .find(|&other_id| {
+                other_id != player_id && self.players[&other_id].canonical_name() == Some(name)
+            });
+        if let Some(other_player_id) = other_player_id {
+            self.push_inconsistency(ctx, PlayerInconsistency::NameShared {
+                name: name.to_string(),
+                player_id,
+                other_player_id,
+            });
+        }
+    }
+
+    fn push_inconsistency(&mut self, ctx: &EventContext, inconsistency: PlayerInconsistency) {
+        self.inconsistencies.push(PlayerInconsistencyEntry {
+            event_id: ctx.event_id,
+            created: ctx.created,
+            inconsistency,
+        });
+    }
+
+    pub fn player(&self, player_id: Uuid) -> Option<&PlayerRecord> {
+        self.players.get(&player_id)
+    }
+
+    /// Every player the registry has seen, in no particular order
+    pub fn players(&self) -> impl Iterator<Item=&PlayerRecord> {
+        self.players.values()
+    }
+
+    /// Every player who has been seen with the given name, real or Scattered, in the order they
+    /// were first seen with it
+    pub fn players_named(&self, name: &str) -> &[Uuid] {
+        self.players_by_name.get(name).map_or(&[], |players| players.as_slice())
+    }
+
+    /// The name the player went by at the given time, including names first seen at exactly that
+    /// time. Returns None if the player hadn't been seen by then.
+    pub fn name_at(&self, player_id: Uuid, time: DateTime<Utc>) -> Option<&str> {
+        self.players.get(&player_id)?
+            .entry_at(time, None)
+            .map(|entry| entry.name.as_str())
+    }
+
+    /// The player's real name at the given time, even if they were Scattered then
+    pub fn canonical_name_at(&self, player_id: Uuid, time: DateTime<Utc>) -> Option<&str> {
+        self.players.get(&player_id)?
+            .entry_at(time, Some(PlayerNameKind::Canonical))
+            .map(|entry| entry.name.as_str())
+    }
+
+    /// The longest known player name that `text` starts with, if it's followed by the end of the
+    /// text or a character that can't continue a name
+    pub fn longest_name_prefix<'a>(&self, text: &'a str) -> Option<&'a str> {
+        self.players_by_name.keys()
+            .filter(|name| {
+                text.strip_prefix(name.as_str())
+                    .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric()))
+            })
+            .map(|name| &text[..name.len()])
+            .max_by_key(|name| name.len())
+    }
+
+    /// Every inconsistency found so far, in the order they were found
+    pub fn inconsistencies(&self) -> &[PlayerInconsistencyEntry] {
+        &self.inconsistencies
+    }
+}
+
+/// True if `candidate` is `canonical` with at least one letter replaced by a dash
+fn is_scattered_form(canonical: &str, candidate: &str) -> bool {
+    canonical.chars().count() == candidate.chars().count() &&
+        canonical.chars().zip(candidate.chars()).all(|(c, s)| c == s || s == '-') &&
+        canonical != candidate
+}
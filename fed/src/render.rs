@@ -0,0 +1,320 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fmt::Write;
+use serde_json::Value;
+use uuid::Uuid;
+use eventually_api::Weather;
+
+use crate::{FedEvent, FedEventData};
+
+/// URL templates for entity links. Every `{id}` in a template is replaced with the entity's uuid.
+#[derive(Debug, Clone)]
+pub struct LinkTemplates {
+    pub player: String,
+    pub team: String,
+
+    /// If set, game events end with a link to their game
+    pub game: Option<String>,
+}
+
+impl LinkTemplates {
+    pub fn new(player: impl Into<String>, team: impl Into<String>) -> Self {
+        Self {
+            player: player.into(),
+            team: team.into(),
+            game: None,
+        }
+    }
+
+    pub fn with_game(self, game: impl Into<String>) -> Self {
+        Self { game: Some(game.into()), ..self }
+    }
+}
+
+fn fill_template(template: &str, id: Uuid) -> String {
+    template.replace("{id}", &id.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MentionKind {
+    Player(Uuid),
+    Team(Uuid),
+    Weather(Weather),
+    Mod,
+    Item,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Mention {
+    text: String,
+    kind: MentionKind,
+}
+
+/// The phrase that refers to the weather behind a weather event, for the events whose description
+/// names it
+fn weather_mention(data: &FedEventData) -> Option<(&'static str, Weather)> {
+    match data {
+        FedEventData::Blooddrain { .. } |
+        FedEventData::SpecialBlooddrain { .. } |
+        FedEventData::BlooddrainBlocked { .. } => Some(("Blooddrain", Weather::Blooddrain)),
+        FedEventData::Feedback { .. } => Some(("Feedback", Weather::Feedback)),
+        FedEventData::Sun2SetWin { .. } |
+        FedEventData::Sun2 { .. } |
+        FedEventData::SolarPanelsAwait { .. } |
+        FedEventData::SolarPanelsActivate { .. } => Some(("Sun 2", Weather::Sun2)),
+        FedEventData::BlackHoleSwallowedWin { .. } |
+        FedEventData::BlackHole { .. } => Some(("Black Hole", Weather::BlackHole)),
+        FedEventData::Reverb { .. } |
+        FedEventData::BestowReverberating { .. } => Some(("Reverberations", Weather::Reverb)),
+        FedEventData::Incineration { .. } |
+        FedEventData::FireproofIncineration { .. } |
+        FedEventData::BecameMagmatic { .. } => Some(("Rogue Umpire", Weather::SolarEclipse)),
+        FedEventData::BirdsCircle { .. } |
+        FedEventData::BirdsUnshell { .. } => Some(("Birds", Weather::Birds)),
+        FedEventData::FloodingSwept { .. } => Some(("Immateria", Weather::Flooding)),
+        FedEventData::SalmonSwim { .. } => Some(("Salmon", Weather::Salmon)),
+        FedEventData::PeanutMister { .. } => Some(("Peanut", Weather::Peanuts)),
+        FedEventData::AllergicReaction { .. } => Some(("peanut", Weather::Peanuts)),
+        _ => None,
+    }
+}
+
+/// Finds the teams, items, and mods in the serialized event data. Players come from
+/// `FedEventData::named_players` instead.
+fn collect_mentions(value: &Value, mentions: &mut Vec<Mention>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                if let Some(prefix) = key.strip_suffix("Id")
+                    && let Some(id) = value.as_str().and_then(|id| Uuid::parse_str(id).ok()) {
+                    let kind = if prefix.ends_with("team") || prefix.ends_with("Team") {
+                        Some(MentionKind::Team(id))
+                    } else if prefix.ends_with("item") || prefix.ends_with("Item") {
+                        Some(MentionKind::Item)
+                    } else {
+                        None
+                    };
+                    if let Some(kind) = kind {
+                        let names = ["Name", "Nickname"].into_iter()
+                            .filter_map(|suffix| map.get(&format!("{prefix}{suffix}")).and_then(Value::as_str));
+                        for name in names {
+                            mentions.push(Mention { text: name.to_string(), kind: kind.clone() });
+                        }
+                    }
+                } else if (key == "itemName" || key.ends_with("ItemName")) && let Some(name) = value.as_str() {
+                    mentions.push(Mention { text: name.to_string(), kind: MentionKind::Item });
+                } else if (key == "modName" || key.ends_with("ModName")) && let Some(name) = value.as_str() {
+                    mentions.push(Mention { text: name.to_string(), kind: MentionKind::Mod });
+                }
+
+                collect_mentions(value, mentions);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_mentions(value, mentions);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Everything the event refers to that could be marked up, longest first so that e.g. a team's
+/// full name wins over a player name inside it
+fn mentions(event: &FedEvent) -> Vec<Mention> {
+    let mut mentions = event.data.named_players().into_iter()
+        .map(|player| Mention { text: player.player_name.to_string(), kind: MentionKind::Player(player.player_id) })
+        .collect::<Vec<_>>();
+    if let Ok(value) = serde_json::to_value(&event.data) {
+        collect_mentions(&value, &mut mentions);
+    }
+
+    // Names whose ids aren't stored next to them
+    match &event.data {
+        FedEventData::GameEnd { game, winner_id, winning_team_name, losing_team_name, .. } => {
+            let loser_id = if *winner_id == game.home_team { game.away_team } else { game.home_team };
+            mentions.push(Mention { text: winning_team_name.clone(), kind: MentionKind::Team(*winner_id) });
+            mentions.push(Mention { text: losing_team_name.clone(), kind: MentionKind::Team(loser_id) });
+        }
+        FedEventData::HalfInningStart { game, top_of_inning, batting_team_name, .. } => {
+            let team_id = if *top_of_inning { game.away_team } else { game.home_team };
+            mentions.push(Mention { text: batting_team_name.clone(), kind: MentionKind::Team(team_id) });
+        }
+        _ => {}
+    }
+
+    if let Some((phrase, weather)) = weather_mention(&event.data) {
+        mentions.push(Mention { text: phrase.to_string(), kind: MentionKind::Weather(weather) });
+    }
+
+    // The first mention of a name wins, since the ones from the event data are the most specific
+    let mut seen = HashSet::new();
+    mentions.retain(|mention| !mention.text.is_empty() && seen.insert(mention.text.clone()));
+    mentions.sort_by_key(|mention| Reverse(mention.text.len()));
+    mentions
+}
+
+enum Segment<'a> {
+    Text(&'a str),
+    Mention(&'a str, &'a MentionKind),
+}
+
+fn is_word_char(c: Option<char>) -> bool {
+    c.is_some_and(char::is_alphanumeric)
+}
+
+/// Splits the description into plain text and mentions. Mentions only match on word boundaries.
+fn segments<'a>(description: &'a str, mentions: &'a [Mention]) -> Vec<Segment<'a>> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+    while i < description.len() {
+        let rest = &description[i..];
+        let before = description[..i].chars().next_back();
+        let found = (!is_word_char(before)).then(|| {
+            mentions.iter().find(|mention| {
+                rest.starts_with(&mention.text) && !is_word_char(rest[mention.text.len()..].chars().next())
+            })
+        }).flatten();
+
+        if let Some(mention) = found {
+            if text_start < i {
+                segments.push(Segment::Text(&description[text_start..i]));
+            }
+            let end = i + mention.text.len();
+            segments.push(Segment::Mention(&description[i..end], &mention.kind));
+            i = end;
+            text_start = end;
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    if text_start < description.len() {
+        segments.push(Segment::Text(&description[text_start..]));
+    }
+
+    segments
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '[' | ']' | '(' | ')' | '#' | '`' | '<' | '>' | '~' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn weather_class(weather: Weather) -> String {
+    format!("weather-{weather:?}").to_ascii_lowercase()
+}
+
+/// Renders event descriptions as Markdown or HTML, with links for the players, teams, and games
+/// the event refers to. Entities are found from the typed ids in the event data, so a name is only
+/// linked when the event knows whose it is. Likewise, a weather is only marked in the events it
+/// caused.
+///
+/// In HTML, links get the classes `player`, `team`, or `game`, and weathers, mods, and items are
+/// wrapped in spans with the classes `weather weather-<name>`, `mod`, and `item`. Markdown has no
+/// classes, so those are left as plain text.
+#[derive(Debug, Clone)]
+pub struct EventRenderer {
+    links: LinkTemplates,
+}
+
+impl EventRenderer {
+    pub fn new(links: LinkTemplates) -> Self {
+        Self { links }
+    }
+
+    fn entity_url(&self, kind: &MentionKind) -> Option<String> {
+        match kind {
+            MentionKind::Player(id) => Some(fill_template(&self.links.player, *id)),
+            MentionKind::Team(id) => Some(fill_template(&self.links.team, *id)),
+            _ => None,
+        }
+    }
+
+    fn game_url(&self, event: &FedEvent) -> Option<String> {
+        let template = self.links.game.as_ref()?;
+        let game = event.data.game()?;
+        Some(fill_template(template, game.game_id))
+    }
+
+    pub fn markdown(&self, event: &FedEvent) -> String {
+        let description = event.to_string();
+        let mentions = mentions(event);
+
+        let mut output = String::new();
+        for segment in segments(&description, &mentions) {
+            match segment {
+                Segment::Text(text) => output += &escape_markdown(text),
+                Segment::Mention(text, kind) => match self.entity_url(kind) {
+                    Some(url) => write!(output, "[{}]({url})", escape_markdown(text)).unwrap(),
+                    None => output += &escape_markdown(text),
+                },
+            }
+        }
+        // Two trailing spaces make a hard line break
+        let mut output = output.replace('\n', "  \n");
+
+        if let Some(url) = self.game_url(event) {
+            write!(output, " ([game]({url}))").unwrap();
+        }
+
+        output
+    }
+
+    pub fn html(&self, event: &FedEvent) -> String {
+        let description = event.to_string();
+        let mentions = mentions(event);
+
+        let mut output = String::new();
+        for segment in segments(&description, &mentions) {
+            match segment {
+                Segment::Text(text) => output += &escape_html(text).replace('\n', "<br>\n"),
+                Segment::Mention(text, kind) => {
+                    let text = escape_html(text);
+                    match kind {
+                        MentionKind::Player(id) => {
+                            let url = escape_html(&fill_template(&self.links.player, *id));
+                            write!(output, r#"<a class="player" href="{url}">{text}</a>"#).unwrap()
+                        }
+                        MentionKind::Team(id) => {
+                            let url = escape_html(&fill_template(&self.links.team, *id));
+                            write!(output, r#"<a class="team" href="{url}">{text}</a>"#).unwrap()
+                        }
+                        MentionKind::Weather(weather) => {
+                            write!(output, r#"<span class="weather {}">{text}</span>"#, weather_class(*weather)).unwrap()
+                        }
+                        MentionKind::Mod => write!(output, r#"<span class="mod">{text}</span>"#).unwrap(),
+                        MentionKind::Item => write!(output, r#"<span class="item">{text}</span>"#).unwrap(),
+                    }
+                }
+            }
+        }
+
+        if let Some(url) = self.game_url(event) {
+            write!(output, r#" <a class="game" href="{}">(game)</a>"#, escape_html(&url)).unwrap();
+        }
+
+        output
+    }
+}
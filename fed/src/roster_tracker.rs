@@ -0,0 +1,565 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+use eventually_api::{EventType, EventuallyEvent};
+
+use crate::{FedEvent, FedEventData, PositionType};
+use crate::feed_metadata::{metadata_i64, metadata_uuid, MissingMetadata};
+
+#[derive(Error, Debug)]
+pub enum RosterTrackerError {
+    #[error(transparent)]
+    MissingMetadata(#[from] MissingMetadata),
+
+    #[error("{event_type:?} event {event_id} has unknown roster location {location}")]
+    UnknownLocation {
+        event_type: EventType,
+        event_id: Uuid,
+        location: i64,
+    },
+
+    #[error("{event_type:?} event {event_id} shuffles a roster but isn't tagged with a team")]
+    NoTeam {
+        event_type: EventType,
+        event_id: Uuid,
+    },
+}
+
+/// One team's players, in order, at each position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamRoster {
+    pub lineup: Vec<Uuid>,
+    pub rotation: Vec<Uuid>,
+    pub bench: Vec<Uuid>,
+    pub bullpen: Vec<Uuid>,
+
+    /// False if the lineup has been shuffled since the last snapshot, so the order of `lineup`
+    /// isn't known. The set of players is still correct.
+    pub lineup_order_known: bool,
+
+    /// False if the rotation has been shuffled since the last snapshot, so the order of `rotation`
+    /// isn't known. The set of players is still correct.
+    pub rotation_order_known: bool,
+
+    /// False if the team has been fully shuffled since the last snapshot, so players may have
+    /// moved between the lineup and the rotation without the tracker knowing. The set of players
+    /// on the team is still correct.
+    pub active_positions_known: bool,
+}
+
+/// An empty roster. The order of every (empty) position is known, the same as a snapshot of a
+/// team with no players.
+impl Default for TeamRoster {
+    fn default() -> Self {
+        Self::new(Vec::new(), Vec::new(), Vec::new(), Vec::new())
+    }
+}
+
+impl TeamRoster {
+    /// Builds a roster from a snapshot, e.g. a team object from Chronicler
+    pub fn new(lineup: Vec<Uuid>, rotation: Vec<Uuid>, bench: Vec<Uuid>, bullpen: Vec<Uuid>) -> Self {
+        Self {
+            lineup,
+            rotation,
+            bench,
+            bullpen,
+            lineup_order_known: true,
+            rotation_order_known: true,
+            active_positions_known: true,
+        }
+    }
+
+    pub fn position(&self, position: PositionType) -> &[Uuid] {
+        match position {
+            PositionType::Lineup => &self.lineup,
+            PositionType::Rotation => &self.rotation,
+            PositionType::Bench => &self.bench,
+            PositionType::Bullpen => &self.bullpen,
+        }
+    }
+
+    fn position_mut(&mut self, position: PositionType) -> &mut Vec<Uuid> {
+        match position {
+            PositionType::Lineup => &mut self.lineup,
+            PositionType::Rotation => &mut self.rotation,
+            PositionType::Bench => &mut self.bench,
+            PositionType::Bullpen => &mut self.bullpen,
+        }
+    }
+
+    /// Players in the Shadows, i.e. on the bench or in the bullpen
+    pub fn shadows(&self) -> impl Iterator<Item=Uuid> + '_ {
+        self.bench.iter().chain(&self.bullpen).copied()
+    }
+
+    /// Every player on the team, in lineup, rotation, bench, bullpen order
+    pub fn players(&self) -> impl Iterator<Item=Uuid> + '_ {
+        self.lineup.iter().chain(&self.rotation).chain(&self.bench).chain(&self.bullpen).copied()
+    }
+
+    /// Where the player is on this team, if they're on it
+    pub fn find(&self, player_id: Uuid) -> Option<(PositionType, usize)> {
+        [PositionType::Lineup, PositionType::Rotation, PositionType::Bench, PositionType::Bullpen]
+            .into_iter()
+            .find_map(|position| {
+                self.position(position).iter()
+                    .position(|&id| id == player_id)
+                    .map(|index| (position, index))
+            })
+    }
+
+    pub fn contains(&self, player_id: Uuid) -> bool {
+        self.find(player_id).is_some()
+    }
+
+    fn remove(&mut self, player_id: Uuid) -> Option<(PositionType, usize)> {
+        let (position, index) = self.find(player_id)?;
+        self.position_mut(position).remove(index);
+        Some((position, index))
+    }
+
+    /// Positions at which this roster and `other` disagree. Order is only compared for positions
+    /// whose order is known in both rosters, and if either roster has had a full shuffle then
+    /// players are allowed to be anywhere in the lineup or rotation.
+    pub fn differing_positions(&self, other: &TeamRoster) -> Vec<PositionType> {
+        let mut differing = Vec::new();
+        let sorted = |players: &[Uuid]| {
+            let mut players = players.to_vec();
+            players.sort();
+            players
+        };
+
+        if self.active_positions_known && other.active_positions_known {
+            let lineup_ordered = self.lineup_order_known && other.lineup_order_known;
+            if (lineup_ordered && self.lineup != other.lineup) ||
+                (!lineup_ordered && sorted(&self.lineup) != sorted(&other.lineup)) {
+                differing.push(PositionType::Lineup);
+            }
+            let rotation_ordered = self.rotation_order_known && other.rotation_order_known;
+            if (rotation_ordered && self.rotation != other.rotation) ||
+                (!rotation_ordered && sorted(&self.rotation) != sorted(&other.rotation)) {
+                differing.push(PositionType::Rotation);
+            }
+        } else {
+            let active = |roster: &TeamRoster| {
+                let mut players: Vec<_> = roster.lineup.iter().chain(&roster.rotation).copied().collect();
+                players.sort();
+                players
+            };
+            if active(self) != active(other) {
+                differing.push(PositionType::Lineup);
+                differing.push(PositionType::Rotation);
+            }
+        }
+        if self.bench != other.bench {
+            differing.push(PositionType::Bench);
+        }
+        if self.bullpen != other.bullpen {
+            differing.push(PositionType::Bullpen);
+        }
+
+        differing
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum RosterChange {
+    /// The roster was set from a snapshot given to the tracker
+    Snapshot { roster: TeamRoster },
+    /// A player joined the team at the end of the given position
+    #[serde(rename_all = "camelCase")]
+    Added { player_id: Uuid, position: PositionType },
+    /// A player left the team
+    #[serde(rename_all = "camelCase")]
+    Removed { player_id: Uuid },
+    /// A player was replaced, taking over the replaced player's slot. If the incoming player was
+    /// already on the team, they are moved from their old slot.
+    #[serde(rename_all = "camelCase")]
+    Replaced { out_player_id: Uuid, in_player_id: Uuid, position: PositionType },
+    /// Two players on the team traded slots
+    #[serde(rename_all = "camelCase")]
+    Swapped { first_player_id: Uuid, second_player_id: Uuid },
+    /// The order of the given positions was randomized. If both the lineup and the rotation are
+    /// listed, players may also have moved between them.
+    Shuffled { positions: Vec<PositionType> },
+}
+
+/// Something in the feed that doesn't agree with what the tracker already knew about a roster.
+/// These usually mean the tracker wasn't given a snapshot of the team before the event, but can
+/// also point to missing or misparsed events.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum RosterInconsistency {
+    /// A player was removed or replaced who the tracker didn't have on the team
+    #[serde(rename_all = "camelCase")]
+    PlayerNotOnTeam { player_id: Uuid },
+
+    /// A player was added who the tracker already had on the team
+    #[serde(rename_all = "camelCase")]
+    PlayerAlreadyOnTeam { player_id: Uuid },
+
+    /// A pitcher took the mound who the tracker didn't have in either team's rotation
+    #[serde(rename_all = "camelCase")]
+    PitcherNotInRotation { player_id: Uuid },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterHistoryEntry {
+    /// Position of the event that caused this change in the sequence of events given to the tracker
+    pub sequence: usize,
+    pub event_id: Option<Uuid>,
+    pub created: DateTime<Utc>,
+    pub season: i32,
+    pub day: i32,
+    pub change: RosterChange,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterInconsistencyEntry {
+    pub team_id: Uuid,
+    pub event_id: Uuid,
+    pub created: DateTime<Utc>,
+    pub inconsistency: RosterInconsistency,
+}
+
+impl RosterChange {
+    /// Whether this change can put the player on the team
+    fn adds(&self, player_id: Uuid) -> bool {
+        match self {
+            RosterChange::Snapshot { roster } => roster.contains(player_id),
+            RosterChange::Added { player_id: added_id, .. } => *added_id == player_id,
+            RosterChange::Replaced { in_player_id, .. } => *in_player_id == player_id,
+            RosterChange::Removed { .. } | RosterChange::Swapped { .. } | RosterChange::Shuffled { .. } => false,
+        }
+    }
+}
+
+fn apply_change(roster: &mut TeamRoster, change: &RosterChange) -> Option<RosterInconsistency> {
+    match change {
+        RosterChange::Snapshot { roster: snapshot } => {
+            *roster = snapshot.clone();
+            None
+        }
+        RosterChange::Added { player_id, position } => {
+            let inconsistency = roster.remove(*player_id)
+                .map(|_| RosterInconsistency::PlayerAlreadyOnTeam { player_id: *player_id });
+            roster.position_mut(*position).push(*player_id);
+            inconsistency
+        }
+        RosterChange::Removed { player_id } => {
+            match roster.remove(*player_id) {
+                Some(_) => None,
+                None => Some(RosterInconsistency::PlayerNotOnTeam { player_id: *player_id }),
+            }
+        }
+        RosterChange::Replaced { out_player_id, in_player_id, position } => {
+            roster.remove(*in_player_id);
+            match roster.find(*out_player_id) {
+                Some((out_position, index)) if out_position == *position => {
+                    roster.position_mut(*position)[index] = *in_player_id;
+                    None
+                }
+                found => {
+                    roster.remove(*out_player_id);
+                    roster.position_mut(*position).push(*in_player_id);
+                    found.is_none()
+                        .then_some(RosterInconsistency::PlayerNotOnTeam { player_id: *out_player_id })
+                }
+            }
+        }
+        RosterChange::Swapped { first_player_id, second_player_id } => {
+            match (roster.find(*first_player_id), roster.find(*second_player_id)) {
+                (Some((first_position, first_index)), Some((second_position, second_index))) => {
+                    roster.position_mut(first_position)[first_index] = *second_player_id;
+                    roster.position_mut(second_position)[second_index] = *first_player_id;
+                    None
+                }
+                (None, _) => Some(RosterInconsistency::PlayerNotOnTeam { player_id: *first_player_id }),
+                (_, None) => Some(RosterInconsistency::PlayerNotOnTeam { player_id: *second_player_id }),
+            }
+        }
+        RosterChange::Shuffled { positions } => {
+            let shuffled_lineup = positions.contains(&PositionType::Lineup);
+            let shuffled_rotation = positions.contains(&PositionType::Rotation);
+            roster.lineup_order_known &= !shuffled_lineup;
+            roster.rotation_order_known &= !shuffled_rotation;
+            roster.active_positions_known &= !(shuffled_lineup && shuffled_rotation);
+            None
+        }
+    }
+}
+
+/// Reconstructs every team's lineup, rotation, and shadows from a stream of FedEvents, which must
+/// be in chronological order.
+///
+/// The feed only describes changes to rosters, so teams should be given a starting snapshot with
+/// `set_roster`. Teams without one start out empty and only contain players the feed has added
+/// to them. Like the mod tracker, this reads the roster change events that the event builder
+/// generates for each FedEvent (PlayerMoved, PlayerTraded, PlayerSwap, PlayerAddedToTeam,
+/// PlayerRemovedFromTeam, PlayerReplacesReturned, PlayerBornFromIncineration, and the Reverb
+/// shuffles), so it covers Incineration, Feedback, Reverb, Roam, Investigations, Attractors, and
+/// so on. Reverb shuffles are random, so after one the tracker only knows which players are in the
+/// shuffled positions and not their order. Events that disagree with what the tracker already
+/// knows are still applied, and the disagreement is recorded in `inconsistencies`.
+#[derive(Debug, Default)]
+pub struct RosterTracker {
+    current: HashMap<Uuid, TeamRoster>,
+    history: HashMap<Uuid, Vec<RosterHistoryEntry>>,
+    inconsistencies: Vec<RosterInconsistencyEntry>,
+    num_events: usize,
+    last_event: Option<(DateTime<Utc>, i32, i32)>,
+}
+
+struct EventContext<'a> {
+    sequence: usize,
+    event: &'a FedEvent,
+}
+
+impl RosterTracker {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the team's roster from a snapshot. The snapshot is considered to be taken just after
+    /// the most recent event given to the tracker, or before the first event if there hasn't been
+    /// one yet.
+    pub fn set_roster(&mut self, team_id: Uuid, roster: TeamRoster) {
+        let (created, season, day) = self.last_event
+            .unwrap_or((DateTime::<Utc>::MIN_UTC, i32::MIN, i32::MIN));
+        let change = RosterChange::Snapshot { roster };
+        apply_change(self.current.entry(team_id).or_default(), &change);
+        self.history.entry(team_id).or_default()
+            .push(RosterHistoryEntry {
+                sequence: self.num_events,
+                event_id: None,
+                created,
+                season,
+                day,
+                change,
+            });
+    }
+
+    /// Applies one event. On error, roster changes from earlier in the same event may already have
+    /// been applied.
+    pub fn apply(&mut self, event: &FedEvent) -> Result<(), RosterTrackerError> {
+        let ctx = EventContext {
+            sequence: self.num_events,
+            event,
+        };
+        self.num_events += 1;
+        self.last_event = Some((event.created, event.season, event.day));
+
+        if let FedEventData::PitcherChange { game, pitcher_id, .. } = &event.data {
+            let known_teams: Vec<_> = [game.home_team, game.away_team].into_iter()
+                .filter(|team_id| self.current.contains_key(team_id))
+                .collect();
+            let in_rotation = known_teams.iter()
+                .any(|team_id| self.current[team_id].rotation.contains(pitcher_id));
+            if !known_teams.is_empty() && !in_rotation {
+                // Attribute it to the first team that knows about this pitcher, if any
+                let team_id = known_teams.iter()
+                    .find(|team_id| self.current[*team_id].contains(*pitcher_id))
+                    .unwrap_or(&known_teams[0]);
+                self.push_inconsistency(&ctx, *team_id, RosterInconsistency::PitcherNotInRotation {
+                    player_id: *pitcher_id,
+                });
+            }
+        }
+
+        self.apply_feed_event(&ctx, &event.clone().into_feed_event())
+    }
+
+    fn apply_feed_event(&mut self, ctx: &EventContext, feed_event: &EventuallyEvent) -> Result<(), RosterTrackerError> {
+        match feed_event.r#type {
+            EventType::PlayerMoved => {
+                let player_id = metadata_uuid(feed_event, "playerId")?;
+                let send_team_id = metadata_uuid(feed_event, "sendTeamId")?;
+                let receive_team_id = metadata_uuid(feed_event, "receiveTeamId")?;
+                let position = location(feed_event, "receiveLocation")?;
+                self.record(ctx, send_team_id, RosterChange::Removed { player_id });
+                self.record(ctx, receive_team_id, RosterChange::Added { player_id, position });
+            }
+            EventType::PlayerAddedToTeam => {
+                let player_id = metadata_uuid(feed_event, "playerId")?;
+                let team_id = metadata_uuid(feed_event, "teamId")?;
+                let position = location(feed_event, "location")?;
+                self.record(ctx, team_id, RosterChange::Added { player_id, position });
+            }
+            EventType::PlayerRemovedFromTeam => {
+                let player_id = metadata_uuid(feed_event, "playerId")?;
+                let team_id = metadata_uuid(feed_event, "teamId")?;
+                self.record(ctx, team_id, RosterChange::Removed { player_id });
+            }
+            EventType::PlayerBornFromIncineration | EventType::PlayerReplacedByNecromancy => {
+                let team_id = metadata_uuid(feed_event, "teamId")?;
+                self.record(ctx, team_id, RosterChange::Replaced {
+                    out_player_id: metadata_uuid(feed_event, "outPlayerId")?,
+                    in_player_id: metadata_uuid(feed_event, "inPlayerId")?,
+                    position: location(feed_event, "location")?,
+                });
+            }
+            EventType::PlayerReplacesReturned => {
+                let team_id = metadata_uuid(feed_event, "teamId")?;
+                self.record(ctx, team_id, RosterChange::Replaced {
+                    out_player_id: metadata_uuid(feed_event, "removePlayerId")?,
+                    in_player_id: metadata_uuid(feed_event, "promotePlayerId")?,
+                    position: location(feed_event, "removeLocation")?,
+                });
+            }
+            EventType::PlayerTraded => {
+                // Feedback: each player takes the other's slot on the other's team
+                let a_player_id = metadata_uuid(feed_event, "aPlayerId")?;
+                let a_team_id = metadata_uuid(feed_event, "aTeamId")?;
+                let b_player_id = metadata_uuid(feed_event, "bPlayerId")?;
+                let b_team_id = metadata_uuid(feed_event, "bTeamId")?;
+                self.record(ctx, a_team_id, RosterChange::Replaced {
+                    out_player_id: a_player_id,
+                    in_player_id: b_player_id,
+                    position: location(feed_event, "aLocation")?,
+                });
+                self.record(ctx, b_team_id, RosterChange::Replaced {
+                    out_player_id: b_player_id,
+                    in_player_id: a_player_id,
+                    position: location(feed_event, "bLocation")?,
+                });
+            }
+            EventType::PlayerSwap => {
+                let team_id = metadata_uuid(feed_event, "teamId")?;
+                self.record(ctx, team_id, RosterChange::Swapped {
+                    first_player_id: metadata_uuid(feed_event, "aPlayerId")?,
+                    second_player_id: metadata_uuid(feed_event, "bPlayerId")?,
+                });
+            }
+            EventType::ReverbLineupShuffle | EventType::ReverbRotationShuffle | EventType::ReverbFullShuffle => {
+                let team_id = *feed_event.team_tags.first()
+                    .ok_or_else(|| RosterTrackerError::NoTeam {
+                        event_type: feed_event.r#type,
+                        event_id: feed_event.id,
+                    })?;
+                let positions = match feed_event.r#type {
+                    EventType::ReverbLineupShuffle => vec![PositionType::Lineup],
+                    EventType::ReverbRotationShuffle => vec![PositionType::Rotation],
+                    _ => vec![PositionType::Lineup, PositionType::Rotation],
+                };
+                self.record(ctx, team_id, RosterChange::Shuffled { positions });
+            }
+            _ => {}
+        }
+
+        for child in &feed_event.metadata.children {
+            self.apply_feed_event(ctx, child)?;
+        }
+
+        Ok(())
+    }
+
+    fn record(&mut self, ctx: &EventContext, team_id: Uuid, change: RosterChange) {
+        let inconsistency = apply_change(self.current.entry(team_id).or_default(), &change);
+        self.history.entry(team_id).or_default()
+            .push(RosterHistoryEntry {
+                sequence: ctx.sequence,
+                event_id: Some(ctx.event.id),
+                created: ctx.event.created,
+                season: ctx.event.season,
+                day: ctx.event.day,
+                change,
+            });
+        if let Some(inconsistency) = inconsistency {
+            self.push_inconsistency(ctx, team_id, inconsistency);
+        }
+    }
+
+    fn push_inconsistency(&mut self, ctx: &EventContext, team_id: Uuid, inconsistency: RosterInconsistency) {
+        self.inconsistencies.push(RosterInconsistencyEntry {
+            team_id,
+            event_id: ctx.event.id,
+            created: ctx.event.created,
+            inconsistency,
+        });
+    }
+
+    /// The team's roster after every event applied so far, or None if the tracker knows nothing
+    /// about the team
+    pub fn current_roster(&self, team_id: Uuid) -> Option<&TeamRoster> {
+        self.current.get(&team_id)
+    }
+
+    /// Every change to the team's roster, in order
+    pub fn history(&self, team_id: Uuid) -> &[RosterHistoryEntry] {
+        self.history.get(&team_id).map_or(&[], |history| history.as_slice())
+    }
+
+    /// Every inconsistency found so far, in the order they were found
+    pub fn inconsistencies(&self) -> &[RosterInconsistencyEntry] {
+        &self.inconsistencies
+    }
+
+    fn roster_where(&self, team_id: Uuid, include: impl Fn(&RosterHistoryEntry) -> bool) -> Option<TeamRoster> {
+        let history = self.history.get(&team_id)?;
+        let mut roster = TeamRoster::default();
+        for entry in history.iter().take_while(|entry| include(entry)) {
+            apply_change(&mut roster, &entry.change);
+        }
+        Some(roster)
+    }
+
+    /// The team's roster at the given time, including changes from events created at exactly that
+    /// time
+    pub fn roster_at(&self, team_id: Uuid, time: DateTime<Utc>) -> Option<TeamRoster> {
+        self.roster_where(team_id, |entry| entry.created <= time)
+    }
+
+    /// The team's roster at the end of the given day, i.e. including changes from every event on
+    /// that day
+    pub fn roster_on_day(&self, team_id: Uuid, season: i32, day: i32) -> Option<TeamRoster> {
+        self.roster_where(team_id, |entry| (entry.season, entry.day) <= (season, day))
+    }
+
+    /// Which team the player was on at the given time, as far as the tracker knows. If the
+    /// tracker has the player on more than one team (which is always an inconsistency), this is
+    /// the team that most recently gained them.
+    pub fn team_of_player_at(&self, player_id: Uuid, time: DateTime<Utc>) -> Option<Uuid> {
+        self.history.iter()
+            .filter(|(team_id, _)| {
+                self.roster_at(**team_id, time)
+                    .is_some_and(|roster| roster.contains(player_id))
+            })
+            .filter_map(|(&team_id, history)| {
+                let joined = history.iter()
+                    .take_while(|entry| entry.created <= time)
+                    .filter(|entry| entry.change.adds(player_id))
+                    .map(|entry| entry.sequence)
+                    .last()?;
+                Some((joined, team_id))
+            })
+            // Break ties between changes from the same event by team id, so the answer doesn't
+            // depend on the map's iteration order
+            .max()
+            .map(|(_, team_id)| team_id)
+    }
+
+    /// Compares a roster snapshot with the tracker's reconstruction of the team at the given time
+    /// and returns the positions where they disagree. Returns None if the tracker knows nothing
+    /// about the team.
+    pub fn verify_snapshot(&self, team_id: Uuid, time: DateTime<Utc>, snapshot: &TeamRoster) -> Option<Vec<PositionType>> {
+        self.roster_at(team_id, time)
+            .map(|roster| roster.differing_positions(snapshot))
+    }
+}
+
+fn location(feed_event: &EventuallyEvent, field: &'static str) -> Result<PositionType, RosterTrackerError> {
+    let location = metadata_i64(feed_event, field)?;
+
+    PositionType::try_from(location)
+        .map_err(|_| RosterTrackerError::UnknownLocation {
+            event_type: feed_event.r#type,
+            event_id: feed_event.id,
+            location,
+        })
+}
@@ -0,0 +1,286 @@
+use std::any::Any;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+
+use eventually_api::EventuallyEvent;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::{parse_feed_event, FedEvent, FeedParseError};
+
+/// Top-level fields of the feed event JSON that get their own [`RoundTripDifference`] variant
+/// instead of being compared as generic fields
+const STRUCTURED_FIELDS: [&str; 5] = ["description", "playerTags", "teamTags", "gameTags", "metadata"];
+
+/// Which of a feed event's tag lists a [`RoundTripDifference::Tags`] refers to
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TagKind {
+    Player,
+    Team,
+    Game,
+}
+
+impl TagKind {
+    /// Name of the tag list in the feed event JSON
+    pub fn field(&self) -> &'static str {
+        match self {
+            TagKind::Player => "playerTags",
+            TagKind::Team => "teamTags",
+            TagKind::Game => "gameTags",
+        }
+    }
+}
+
+/// One way in which a feed event rebuilt from its parsed [`FedEvent`] differs from the original.
+/// `expected` is always the original and `actual` the reconstruction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RoundTripDifference {
+    Description { expected: String, actual: String },
+    Tags { kind: TagKind, expected: Vec<Uuid>, actual: Vec<Uuid> },
+    /// A metadata key that differs. `None` means the key is absent on that side.
+    Metadata { key: String, expected: Option<Value>, actual: Option<Value> },
+    /// Both events have the same children, but not in the same order
+    ChildrenOrder { expected: Vec<Uuid>, actual: Vec<Uuid> },
+    /// A child of the original that the reconstruction doesn't have
+    MissingChild { id: Uuid },
+    /// A child of the reconstruction that the original doesn't have
+    ExtraChild { id: Uuid },
+    /// A child that both events have, but that differs between them
+    Child { id: Uuid, differences: Vec<RoundTripDifference> },
+    /// Any other top-level field, by its name in the feed event JSON
+    Field { field: String, expected: Value, actual: Value },
+}
+
+impl RoundTripDifference {
+    /// Name of the differing field, e.g. `description` or `metadata.mod`. Differences in children
+    /// are all reported as `metadata.children`, so this is suitable for grouping differences.
+    pub fn field(&self) -> String {
+        match self {
+            RoundTripDifference::Description { .. } => "description".to_string(),
+            RoundTripDifference::Tags { kind, .. } => kind.field().to_string(),
+            RoundTripDifference::Metadata { key, .. } => format!("metadata.{key}"),
+            RoundTripDifference::ChildrenOrder { .. } |
+            RoundTripDifference::MissingChild { .. } |
+            RoundTripDifference::ExtraChild { .. } |
+            RoundTripDifference::Child { .. } => "metadata.children".to_string(),
+            RoundTripDifference::Field { field, .. } => field.clone(),
+        }
+    }
+}
+
+impl Display for RoundTripDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn or_missing(value: &Option<Value>) -> String {
+            value.as_ref().map_or_else(|| "(missing)".to_string(), Value::to_string)
+        }
+
+        match self {
+            RoundTripDifference::Description { expected, actual } => {
+                write!(f, "description: expected {expected:?}, got {actual:?}")
+            }
+            RoundTripDifference::Tags { kind, expected, actual } => {
+                write!(f, "{}: expected {expected:?}, got {actual:?}", kind.field())
+            }
+            RoundTripDifference::Metadata { key, expected, actual } => {
+                write!(f, "metadata.{key}: expected {}, got {}", or_missing(expected), or_missing(actual))
+            }
+            RoundTripDifference::ChildrenOrder { expected, actual } => {
+                write!(f, "metadata.children: expected order {expected:?}, got {actual:?}")
+            }
+            RoundTripDifference::MissingChild { id } => write!(f, "metadata.children: missing child {id}"),
+            RoundTripDifference::ExtraChild { id } => write!(f, "metadata.children: extra child {id}"),
+            RoundTripDifference::Child { id, differences } => {
+                write!(f, "metadata.children: child {id} differs")?;
+                for difference in differences {
+                    write!(f, "\n  {difference}")?;
+                }
+                Ok(())
+            }
+            RoundTripDifference::Field { field, expected, actual } => {
+                write!(f, "{field}: expected {expected}, got {actual}")
+            }
+        }
+    }
+}
+
+/// Why a [`RoundTripReport`] has no parsed event
+#[derive(Debug)]
+pub enum RoundTripError {
+    /// The parser returned an error
+    Parse(FeedParseError),
+    /// Parsing or rebuilding the event panicked. Contains the panic message.
+    Panicked(String),
+}
+
+impl Display for RoundTripError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoundTripError::Parse(err) => write!(f, "{err}"),
+            RoundTripError::Panicked(message) => write!(f, "Panicked: {message}"),
+        }
+    }
+}
+
+/// The result of parsing a feed event and turning it back into a feed event
+#[derive(Debug)]
+pub struct RoundTripReport {
+    pub parsed: Result<FedEvent, RoundTripError>,
+    /// The feed event rebuilt from the parsed event. None if parsing failed.
+    pub reconstructed: Option<EventuallyEvent>,
+    /// Every way the reconstructed event differs from the original. Empty if parsing failed.
+    pub differences: Vec<RoundTripDifference>,
+}
+
+impl RoundTripReport {
+    /// True if the event parsed and was reconstructed exactly
+    pub fn is_ok(&self) -> bool {
+        self.parsed.is_ok() && self.differences.is_empty()
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "<non-string panic payload>".to_string())
+}
+
+/// Parses `event`, rebuilds a feed event from the result, and reports how it differs from `event`.
+/// An event that doesn't round-trip exactly has lost information in parsing.
+///
+/// The parser and event builder can panic on events they don't support yet. Those panics are
+/// caught and reported as [`RoundTripError::Panicked`], though the panic hook still runs first.
+pub fn verify_round_trip(event: &EventuallyEvent) -> RoundTripReport {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        parse_feed_event(event)
+            .map(|parsed_event| {
+                let reconstructed = parsed_event.clone().into_feed_event();
+                (parsed_event, reconstructed)
+            })
+    }));
+
+    match result {
+        Ok(Ok((parsed, reconstructed))) => RoundTripReport {
+            differences: diff_feed_events(event, &reconstructed),
+            parsed: Ok(parsed),
+            reconstructed: Some(reconstructed),
+        },
+        Ok(Err(err)) => RoundTripReport {
+            parsed: Err(RoundTripError::Parse(err)),
+            reconstructed: None,
+            differences: Vec::new(),
+        },
+        Err(payload) => RoundTripReport {
+            parsed: Err(RoundTripError::Panicked(panic_message(payload.as_ref()))),
+            reconstructed: None,
+            differences: Vec::new(),
+        },
+    }
+}
+
+/// Every way `actual` differs from `expected`, as [`verify_round_trip`] reports them. Children are
+/// matched by id and compared recursively.
+pub fn diff_feed_events(expected: &EventuallyEvent, actual: &EventuallyEvent) -> Vec<RoundTripDifference> {
+    // Comparing the events directly is much cheaper than building the differences, and almost all
+    // events round-trip
+    if expected == actual {
+        return Vec::new();
+    }
+
+    let mut differences = Vec::new();
+
+    if expected.description != actual.description {
+        differences.push(RoundTripDifference::Description {
+            expected: expected.description.clone(),
+            actual: actual.description.clone(),
+        });
+    }
+
+    let tags = [
+        (TagKind::Player, &expected.player_tags, &actual.player_tags),
+        (TagKind::Team, &expected.team_tags, &actual.team_tags),
+        (TagKind::Game, &expected.game_tags, &actual.game_tags),
+    ];
+    for (kind, expected_tags, actual_tags) in tags {
+        if expected_tags != actual_tags {
+            differences.push(RoundTripDifference::Tags {
+                kind,
+                expected: expected_tags.clone(),
+                actual: actual_tags.clone(),
+            });
+        }
+    }
+
+    let expected_json = to_json_object(expected);
+    let actual_json = to_json_object(actual);
+    differences.extend(diff_objects(&expected_json, &actual_json, &STRUCTURED_FIELDS)
+        .map(|(field, expected, actual)| RoundTripDifference::Field {
+            field,
+            expected: expected.unwrap_or(Value::Null),
+            actual: actual.unwrap_or(Value::Null),
+        }));
+
+    let expected_metadata = to_json_object(&expected.metadata);
+    let actual_metadata = to_json_object(&actual.metadata);
+    differences.extend(diff_objects(&expected_metadata, &actual_metadata, &["children"])
+        .map(|(key, expected, actual)| RoundTripDifference::Metadata { key, expected, actual }));
+
+    diff_children(&expected.metadata.children, &actual.metadata.children, &mut differences);
+
+    differences
+}
+
+fn diff_children(expected: &[EventuallyEvent], actual: &[EventuallyEvent], differences: &mut Vec<RoundTripDifference>) {
+    let expected_ids: Vec<_> = expected.iter().map(|child| child.id).collect();
+    let actual_ids: Vec<_> = actual.iter().map(|child| child.id).collect();
+    let expected_set: HashSet<_> = expected_ids.iter().collect();
+    let actual_set: HashSet<_> = actual_ids.iter().collect();
+
+    if expected_set == actual_set && expected_ids != actual_ids {
+        differences.push(RoundTripDifference::ChildrenOrder {
+            expected: expected_ids.clone(),
+            actual: actual_ids.clone(),
+        });
+    }
+
+    differences.extend(expected_ids.iter()
+        .filter(|id| !actual_set.contains(id))
+        .map(|&id| RoundTripDifference::MissingChild { id }));
+    differences.extend(actual_ids.iter()
+        .filter(|id| !expected_set.contains(id))
+        .map(|&id| RoundTripDifference::ExtraChild { id }));
+
+    // Children are matched by id, so a reordering doesn't also show up as every child differing
+    for expected_child in expected {
+        let Some(actual_child) = actual.iter().find(|child| child.id == expected_child.id) else { continue };
+        let child_differences = diff_feed_events(expected_child, actual_child);
+        if !child_differences.is_empty() {
+            differences.push(RoundTripDifference::Child { id: expected_child.id, differences: child_differences });
+        }
+    }
+}
+
+fn to_json_object<T: serde::Serialize>(value: &T) -> Map<String, Value> {
+    match serde_json::to_value(value) {
+        Ok(Value::Object(object)) => object,
+        _ => panic!("Feed events and their metadata should serialize to JSON objects"),
+    }
+}
+
+/// Yields each key whose value differs between the two objects, in the order they first appear,
+/// along with its value on each side
+fn diff_objects<'a>(
+    expected: &'a Map<String, Value>,
+    actual: &'a Map<String, Value>,
+    skip: &'a [&str],
+) -> impl Iterator<Item=(String, Option<Value>, Option<Value>)> + 'a {
+    let mut seen = HashSet::new();
+    expected.keys().chain(actual.keys())
+        .filter(move |key| !skip.contains(&key.as_str()) && seen.insert(*key))
+        .filter_map(move |key| {
+            let expected_value = expected.get(key);
+            let actual_value = actual.get(key);
+            (expected_value != actual_value)
+                .then(|| (key.clone(), expected_value.cloned(), actual_value.cloned()))
+        })
+}
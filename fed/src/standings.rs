@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{FedEvent, FedEventData, SimPhase};
+
+/// One team's record in the regular season
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamRecord {
+    pub team_id: Uuid,
+
+    /// Most recent name the team was seen under, if any event has named it
+    pub team_nickname: Option<String>,
+
+    /// Regular season games won
+    pub games_won: i32,
+
+    /// Regular season games lost
+    pub games_lost: i32,
+
+    /// Wins set by Sun 2
+    pub sun2_wins: i32,
+
+    /// Wins swallowed by the Black Hole
+    pub black_hole_wins: i32,
+
+    /// Place within the division, from the FinalStandings event. None until the season ends.
+    pub final_place: Option<i32>,
+
+    /// Division name, from the FinalStandings event. None until the season ends.
+    pub division_name: Option<String>,
+}
+
+impl TeamRecord {
+    fn new(team_id: Uuid) -> Self {
+        Self { team_id, ..Default::default() }
+    }
+
+    /// Wins as shown in the standings, i.e. games won plus wins from Sun 2 minus wins swallowed by
+    /// the Black Hole
+    pub fn wins(&self) -> i32 {
+        self.games_won + self.sun2_wins - self.black_hole_wins
+    }
+
+    pub fn losses(&self) -> i32 {
+        self.games_lost
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum StandingsChange {
+    GameWon,
+    GameLost,
+    Sun2Win,
+    BlackHoleWin,
+}
+
+#[derive(Debug, Clone)]
+struct StandingsEntry {
+    day: i32,
+    team_id: Uuid,
+    change: StandingsChange,
+}
+
+/// The result of one postseason game
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostseasonGame {
+    pub game_id: Uuid,
+    pub day: i32,
+    pub winner_id: Uuid,
+    pub loser_id: Uuid,
+    pub winner_score: f32,
+    pub loser_score: f32,
+}
+
+/// One team's run through the postseason
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostseasonTeam {
+    pub team_id: Uuid,
+    pub team_nickname: String,
+
+    /// Highest round the team advanced to, or None if it hasn't advanced past the first round. The
+    /// Internet Series is reported separately, in `reached_internet_series`.
+    pub round_reached: Option<i32>,
+
+    pub reached_internet_series: bool,
+    pub eliminated: bool,
+    pub won_internet_series: bool,
+}
+
+/// The postseason of one season
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostseasonBracket {
+    pub season: i32,
+
+    /// Teams in the order they earned their postseason slots
+    pub teams: Vec<PostseasonTeam>,
+
+    /// Every postseason game, in the order they ended
+    pub games: Vec<PostseasonGame>,
+
+    /// The team that won the Internet Series, if it's been decided
+    pub champion: Option<Uuid>,
+}
+
+impl PostseasonBracket {
+    fn new(season: i32) -> Self {
+        Self { season, ..Default::default() }
+    }
+
+    fn team_mut(&mut self, team_id: Uuid, team_nickname: &str) -> &mut PostseasonTeam {
+        let index = self.teams.iter()
+            .position(|team| team.team_id == team_id)
+            .unwrap_or_else(|| {
+                self.teams.push(PostseasonTeam {
+                    team_id,
+                    team_nickname: team_nickname.to_string(),
+                    round_reached: None,
+                    reached_internet_series: false,
+                    eliminated: false,
+                    won_internet_series: false,
+                });
+                self.teams.len() - 1
+            });
+
+        &mut self.teams[index]
+    }
+
+    pub fn team(&self, team_id: Uuid) -> Option<&PostseasonTeam> {
+        self.teams.iter().find(|team| team.team_id == team_id)
+    }
+
+    /// Teams that haven't been eliminated yet
+    pub fn remaining_teams(&self) -> impl Iterator<Item=&PostseasonTeam> {
+        self.teams.iter().filter(|team| !team.eliminated)
+    }
+}
+
+#[derive(Debug, Default)]
+struct SeasonStandings {
+    nicknames: HashMap<Uuid, String>,
+    entries: Vec<StandingsEntry>,
+    /// Day of the FinalStandings event, place, and division name
+    final_standings: HashMap<Uuid, (i32, i32, String)>,
+}
+
+/// Computes regular season standings and postseason brackets from a stream of FedEvents, which
+/// must be in chronological order.
+///
+/// Regular season records come from GameEnd, Sun2SetWin, and BlackHoleSwallowedWin. Games that end
+/// during the postseason phases go into that season's bracket instead, along with
+/// EarnedPostseasonSlot, PostseasonAdvance, PostseasonEliminated, and TeamWonInternetSeries.
+/// Sun2SetWin and BlackHoleSwallowedWin during the postseason are ignored.
+#[derive(Debug, Default)]
+pub struct Standings {
+    seasons: HashMap<i32, SeasonStandings>,
+    brackets: HashMap<i32, PostseasonBracket>,
+}
+
+fn is_postseason(phase: SimPhase) -> bool {
+    matches!(phase, SimPhase::PrePostseason | SimPhase::Earlpostseason | SimPhase::EarlpostseasonEnd |
+        SimPhase::Latepostseason | SimPhase::PostseasonEnd)
+}
+
+impl Standings {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn apply(&mut self, event: &FedEvent) {
+        match &event.data {
+            FedEventData::GameEnd { game, winner_id, winning_team_name, winning_team_score, losing_team_name, losing_team_score, .. } => {
+                let loser_id = if *winner_id == game.home_team { game.away_team } else { game.home_team };
+                if is_postseason(event.phase) {
+                    self.bracket_mut(event.season).games.push(PostseasonGame {
+                        game_id: game.game_id,
+                        day: event.day,
+                        winner_id: *winner_id,
+                        loser_id,
+                        winner_score: *winning_team_score,
+                        loser_score: *losing_team_score,
+                    });
+                } else {
+                    let season = self.seasons.entry(event.season).or_default();
+                    season.nicknames.insert(*winner_id, winning_team_name.clone());
+                    season.nicknames.insert(loser_id, losing_team_name.clone());
+                    season.entries.push(StandingsEntry { day: event.day, team_id: *winner_id, change: StandingsChange::GameWon });
+                    season.entries.push(StandingsEntry { day: event.day, team_id: loser_id, change: StandingsChange::GameLost });
+                }
+            }
+            FedEventData::Sun2SetWin { team_id, team_nickname } => {
+                self.push_change(event, *team_id, team_nickname, StandingsChange::Sun2Win);
+            }
+            FedEventData::BlackHoleSwallowedWin { team_id, team_nickname } => {
+                self.push_change(event, *team_id, team_nickname, StandingsChange::BlackHoleWin);
+            }
+            FedEventData::FinalStandings { team_id, team_nickname, place, division_name } => {
+                let season = self.seasons.entry(event.season).or_default();
+                season.nicknames.insert(*team_id, team_nickname.clone());
+                season.final_standings.insert(*team_id, (event.day, *place, division_name.clone()));
+            }
+            FedEventData::EarnedPostseasonSlot { team_id, team_nickname } => {
+                self.bracket_mut(event.season).team_mut(*team_id, team_nickname);
+            }
+            FedEventData::PostseasonAdvance { team_id, team_nickname, round, .. } => {
+                let team = self.bracket_mut(event.season).team_mut(*team_id, team_nickname);
+                match round {
+                    Some(round) => { team.round_reached = team.round_reached.max(Some(*round)) }
+                    None => { team.reached_internet_series = true }
+                }
+            }
+            FedEventData::PostseasonEliminated { team_id, team_nickname, .. } => {
+                self.bracket_mut(event.season).team_mut(*team_id, team_nickname).eliminated = true;
+            }
+            FedEventData::TeamWonInternetSeries { team_id, team_nickname, .. } => {
+                let bracket = self.bracket_mut(event.season);
+                bracket.champion = Some(*team_id);
+                let team = bracket.team_mut(*team_id, team_nickname);
+                team.reached_internet_series = true;
+                team.won_internet_series = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn push_change(&mut self, event: &FedEvent, team_id: Uuid, team_nickname: &str, change: StandingsChange) {
+        // Sun 2 and the Black Hole still activate in the postseason, but only regular season wins
+        // count toward the standings
+        if is_postseason(event.phase) {
+            return;
+        }
+        let season = self.seasons.entry(event.season).or_default();
+        season.nicknames.insert(team_id, team_nickname.to_string());
+        season.entries.push(StandingsEntry { day: event.day, team_id, change });
+    }
+
+    fn bracket_mut(&mut self, season: i32) -> &mut PostseasonBracket {
+        self.brackets.entry(season)
+            .or_insert_with(|| PostseasonBracket::new(season))
+    }
+
+    /// Standings at the end of the given day, ordered by wins (most first) and then by losses
+    /// (fewest first). Final places and division names are only filled in if `day` is on or after
+    /// the day of the season's FinalStandings events.
+    pub fn at(&self, season: i32, day: i32) -> Vec<TeamRecord> {
+        let Some(season_standings) = self.seasons.get(&season) else { return Vec::new() };
+
+        let mut records: HashMap<Uuid, TeamRecord> = HashMap::new();
+        for entry in season_standings.entries.iter().filter(|entry| entry.day <= day) {
+            let record = records.entry(entry.team_id)
+                .or_insert_with(|| TeamRecord::new(entry.team_id));
+            match entry.change {
+                StandingsChange::GameWon => { record.games_won += 1 }
+                StandingsChange::GameLost => { record.games_lost += 1 }
+                StandingsChange::Sun2Win => { record.sun2_wins += 1 }
+                StandingsChange::BlackHoleWin => { record.black_hole_wins += 1 }
+            }
+        }
+
+        for (team_id, (standings_day, place, division_name)) in &season_standings.final_standings {
+            if *standings_day > day {
+                continue;
+            }
+            let record = records.entry(*team_id)
+                .or_insert_with(|| TeamRecord::new(*team_id));
+            record.final_place = Some(*place);
+            record.division_name = Some(division_name.clone());
+        }
+
+        for record in records.values_mut() {
+            record.team_nickname = season_standings.nicknames.get(&record.team_id).cloned();
+        }
+
+        records.into_values()
+            .sorted_by_key(|record| (-record.wins(), record.losses(), record.team_nickname.clone()))
+            .collect()
+    }
+
+    /// Standings at the end of the regular season
+    pub fn final_standings(&self, season: i32) -> Vec<TeamRecord> {
+        self.at(season, i32::MAX)
+    }
+
+    /// The postseason bracket for the season, or None if no postseason events have been applied
+    /// for it
+    pub fn bracket(&self, season: i32) -> Option<&PostseasonBracket> {
+        self.brackets.get(&season)
+    }
+
+    /// Every season that has standings or a bracket, in order
+    pub fn seasons(&self) -> Vec<i32> {
+        self.seasons.keys()
+            .chain(self.brackets.keys())
+            .copied()
+            .sorted()
+            .dedup()
+            .collect()
+    }
+}
@@ -0,0 +1,471 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::AddAssign;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{BlooddrainAction, FedEvent, FedEventData, GameState, GameStateError, GrindRailSuccess, PlayerRef, Scores};
+
+/// Counting stats for a batter (and baserunner)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BattingStats {
+    pub plate_appearances: i32,
+    pub at_bats: i32,
+    pub hits: i32,
+    pub doubles: i32,
+    pub triples: i32,
+    pub quadruples: i32,
+    pub home_runs: i32,
+    pub runs: i32,
+    pub runs_batted_in: i32,
+    pub walks: i32,
+    pub hit_by_pitches: i32,
+    pub strikeouts: i32,
+    pub stolen_bases: i32,
+    pub caught_stealing: i32,
+}
+
+impl AddAssign<&BattingStats> for BattingStats {
+    fn add_assign(&mut self, rhs: &BattingStats) {
+        self.plate_appearances += rhs.plate_appearances;
+        self.at_bats += rhs.at_bats;
+        self.hits += rhs.hits;
+        self.doubles += rhs.doubles;
+        self.triples += rhs.triples;
+        self.quadruples += rhs.quadruples;
+        self.home_runs += rhs.home_runs;
+        self.runs += rhs.runs;
+        self.runs_batted_in += rhs.runs_batted_in;
+        self.walks += rhs.walks;
+        self.hit_by_pitches += rhs.hit_by_pitches;
+        self.strikeouts += rhs.strikeouts;
+        self.stolen_bases += rhs.stolen_bases;
+        self.caught_stealing += rhs.caught_stealing;
+    }
+}
+
+/// Counting stats for a pitcher
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PitchingStats {
+    pub batters_faced: i32,
+    pub outs_recorded: i32,
+    pub hits_allowed: i32,
+    pub home_runs_allowed: i32,
+    pub runs_allowed: i32,
+    pub walks: i32,
+    pub hit_batters: i32,
+    pub strikeouts: i32,
+}
+
+impl PitchingStats {
+    /// Innings pitched in the usual notation, where .1 and .2 are one and two outs
+    pub fn innings_pitched(&self) -> String {
+        format!("{}.{}", self.outs_recorded / 3, self.outs_recorded % 3)
+    }
+}
+
+impl AddAssign<&PitchingStats> for PitchingStats {
+    fn add_assign(&mut self, rhs: &PitchingStats) {
+        self.batters_faced += rhs.batters_faced;
+        self.outs_recorded += rhs.outs_recorded;
+        self.hits_allowed += rhs.hits_allowed;
+        self.home_runs_allowed += rhs.home_runs_allowed;
+        self.runs_allowed += rhs.runs_allowed;
+        self.walks += rhs.walks;
+        self.hit_batters += rhs.hit_batters;
+        self.strikeouts += rhs.strikeouts;
+    }
+}
+
+/// Stats earned by one player on one event
+#[derive(Debug, Clone)]
+pub(crate) struct StatChange<S> {
+    pub team_id: Uuid,
+    pub player: PlayerRef,
+    pub stats: S,
+}
+
+/// All the stats earned on one event. Built from the state of the game just before the event.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StatChanges {
+    pub batting: Vec<StatChange<BattingStats>>,
+    /// Stats for the pitcher. The player is None if the game state doesn't know who's pitching.
+    pub pitching: Option<(Uuid, Option<PlayerRef>, PitchingStats)>,
+}
+
+/// How a plate appearance ended, as far as stats are concerned
+enum Outcome {
+    Hit(i32),
+    HomeRun,
+    Walk,
+    HitByPitch,
+    Strikeout,
+    /// An out that counts as an at-bat, along with how many outs it made
+    Out(i32),
+    SacrificeFly,
+}
+
+impl StatChanges {
+    fn batter(&mut self, team_id: Uuid, player: PlayerRef) -> &mut BattingStats {
+        let position = self.batting.iter()
+            .position(|change| change.player == player)
+            .unwrap_or_else(|| {
+                self.batting.push(StatChange { team_id, player, stats: Default::default() });
+                self.batting.len() - 1
+            });
+
+        &mut self.batting[position].stats
+    }
+
+    fn pitcher(&mut self, state: &GameState, named: Option<&str>) -> &mut PitchingStats {
+        let (_, pitcher, stats) = self.pitching
+            .get_or_insert_with(|| (state.fielding_team().id, state.pitcher().cloned(), Default::default()));
+
+        // Some events name the pitcher, which is better than what the game state knows
+        if let Some(name) = named && pitcher.as_ref().is_none_or(|pitcher| pitcher.name != name) {
+            *pitcher = Some(PlayerRef::named(name));
+        }
+
+        stats
+    }
+
+    fn runs(&mut self, state: &GameState, players: impl IntoIterator<Item=PlayerRef>) -> i32 {
+        let team_id = state.batting_team().id;
+        let mut num_runs = 0;
+        for player in players {
+            self.batter(team_id, player).runs += 1;
+            num_runs += 1;
+        }
+
+        if num_runs > 0 {
+            self.pitcher(state, None).runs_allowed += num_runs;
+        }
+
+        num_runs
+    }
+
+    fn scores(&mut self, state: &GameState, scores: &Scores) -> i32 {
+        self.runs(state, scores.scores.iter().map(|score| {
+            // Use the baserunner's entry if there is one, so names and ids stay consistent
+            state.baserunners.iter()
+                .find(|runner| runner.player.is(Some(score.player_id), &score.player_name))
+                .map(|runner| PlayerRef { id: Some(score.player_id), ..runner.player.clone() })
+                .unwrap_or_else(|| PlayerRef::with_id(score.player_id, &score.player_name))
+        }))
+    }
+
+    fn plate_appearance(&mut self, state: &GameState, batter: PlayerRef, outcome: Outcome, runs_batted_in: i32, pitcher_name: Option<&str>) {
+        let batting = self.batter(state.batting_team().id, batter);
+        batting.plate_appearances += 1;
+        batting.runs_batted_in += runs_batted_in;
+        match outcome {
+            Outcome::Hit(bases) => {
+                batting.at_bats += 1;
+                batting.hits += 1;
+                match bases {
+                    2 => { batting.doubles += 1 }
+                    3 => { batting.triples += 1 }
+                    4 => { batting.quadruples += 1 }
+                    _ => {}
+                }
+            }
+            Outcome::HomeRun => {
+                batting.at_bats += 1;
+                batting.hits += 1;
+                batting.home_runs += 1;
+            }
+            Outcome::Walk => { batting.walks += 1 }
+            Outcome::HitByPitch => { batting.hit_by_pitches += 1 }
+            Outcome::Strikeout => {
+                batting.at_bats += 1;
+                batting.strikeouts += 1;
+            }
+            Outcome::Out(_) => { batting.at_bats += 1 }
+            Outcome::SacrificeFly => {}
+        }
+
+        let pitching = self.pitcher(state, pitcher_name);
+        pitching.batters_faced += 1;
+        match outcome {
+            Outcome::Hit(_) => { pitching.hits_allowed += 1 }
+            Outcome::HomeRun => {
+                pitching.hits_allowed += 1;
+                pitching.home_runs_allowed += 1;
+            }
+            Outcome::Walk => { pitching.walks += 1 }
+            Outcome::HitByPitch => { pitching.hit_batters += 1 }
+            Outcome::Strikeout => {
+                pitching.strikeouts += 1;
+                pitching.outs_recorded += 1;
+            }
+            Outcome::Out(outs) => { pitching.outs_recorded += outs }
+            Outcome::SacrificeFly => { pitching.outs_recorded += 1 }
+        }
+    }
+}
+
+fn batter_ref(id: Option<Uuid>, name: &str) -> PlayerRef {
+    PlayerRef { id, name: name.to_string() }
+}
+
+/// Works out the stats earned on an event. `state` must be the game state from just before the
+/// event is applied.
+pub(crate) fn stat_changes(state: &GameState, data: &FedEventData) -> StatChanges {
+    let mut changes = StatChanges::default();
+    match data {
+        FedEventData::Hit { batter_name, batter_id, hit_type, scores, .. } => {
+            let rbi = changes.scores(state, scores);
+            changes.plate_appearance(state, batter_ref(Some(*batter_id), batter_name), Outcome::Hit(hit_type.num_bases()), rbi, None);
+        }
+        FedEventData::HomeRun { batter_name, batter_id, .. } => {
+            let batter = batter_ref(Some(*batter_id), batter_name);
+            let scorers = state.baserunners.iter()
+                .map(|runner| runner.player.clone())
+                .chain(std::iter::once(batter.clone()))
+                .collect::<Vec<_>>();
+            let rbi = changes.runs(state, scorers);
+            changes.plate_appearance(state, batter, Outcome::HomeRun, rbi, None);
+        }
+        FedEventData::Walk { batter_name, batter_id, scores, .. } |
+        FedEventData::MindTrickWalk { batter_name, batter_id, scores, .. } => {
+            let rbi = changes.scores(state, scores);
+            changes.plate_appearance(state, batter_ref(Some(*batter_id), batter_name), Outcome::Walk, rbi, None);
+        }
+        FedEventData::CharmWalk { batter_name, batter_id, pitcher_name, scores, .. } => {
+            let rbi = changes.scores(state, scores);
+            changes.plate_appearance(state, batter_ref(Some(*batter_id), batter_name), Outcome::Walk, rbi, Some(pitcher_name));
+        }
+        FedEventData::MildPitchWalk { batter_name, batter_id, pitcher_name, scores, .. } => {
+            let rbi = changes.scores(state, scores);
+            changes.plate_appearance(state, batter_ref(Some(*batter_id), batter_name), Outcome::Walk, rbi, Some(pitcher_name));
+        }
+        FedEventData::HitByPitch { batter_name, batter_id, pitcher_name, scores, .. } => {
+            let rbi = changes.scores(state, scores);
+            changes.plate_appearance(state, batter_ref(Some(*batter_id), batter_name), Outcome::HitByPitch, rbi, Some(pitcher_name));
+        }
+        FedEventData::StrikeoutSwinging { batter_name, .. } |
+        FedEventData::StrikeoutLooking { batter_name, .. } => {
+            changes.plate_appearance(state, batter_ref(None, batter_name), Outcome::Strikeout, 0, None);
+        }
+        FedEventData::CharmStrikeout { charmed_id, charmed_name, charmer_name, .. } => {
+            changes.plate_appearance(state, batter_ref(Some(*charmed_id), charmed_name), Outcome::Strikeout, 0, Some(charmer_name));
+        }
+        FedEventData::MindTrickStrikeout { batter_name, batter_id, pitcher_name, .. } => {
+            changes.plate_appearance(state, batter_ref(Some(*batter_id), batter_name), Outcome::Strikeout, 0, Some(pitcher_name));
+        }
+        FedEventData::SpecialBlooddrain { action: BlooddrainAction::AddStrike(Some(batter_name)), .. } => {
+            changes.plate_appearance(state, batter_ref(None, batter_name), Outcome::Strikeout, 0, None);
+        }
+        FedEventData::Flyout { batter_name, scores, .. } => {
+            let rbi = changes.scores(state, scores);
+            let outcome = if rbi > 0 { Outcome::SacrificeFly } else { Outcome::Out(1) };
+            changes.plate_appearance(state, batter_ref(None, batter_name), outcome, rbi, None);
+        }
+        FedEventData::GroundOut { batter_name, scores, .. } |
+        FedEventData::FieldersChoice { batter_name, scores, .. } => {
+            let rbi = changes.scores(state, scores);
+            changes.plate_appearance(state, batter_ref(None, batter_name), Outcome::Out(1), rbi, None);
+        }
+        FedEventData::DoublePlay { batter_name, scores, .. } => {
+            // No RBIs on a double play
+            changes.scores(state, scores);
+            changes.plate_appearance(state, batter_ref(None, batter_name), Outcome::Out(2), 0, None);
+        }
+        FedEventData::AmbushedByCrows { batter_name, batter_id, .. } => {
+            changes.plate_appearance(state, batter_ref(Some(*batter_id), batter_name), Outcome::Out(1), 0, None);
+        }
+        FedEventData::MildPitch { pitcher_name, scores, .. } => {
+            changes.pitcher(state, Some(pitcher_name));
+            changes.scores(state, scores);
+        }
+        FedEventData::StolenBase { runner_name, runner_id, base_stolen, .. } => {
+            let runner = state.baserunners.iter()
+                .find(|runner| runner.player.is(Some(*runner_id), runner_name))
+                .map(|runner| PlayerRef { id: Some(*runner_id), ..runner.player.clone() })
+                .unwrap_or_else(|| PlayerRef::with_id(*runner_id, runner_name));
+            changes.batter(state.batting_team().id, runner.clone()).stolen_bases += 1;
+            if base_stolen.index() >= state.num_bases() - 1 {
+                changes.runs(state, [runner]);
+            }
+        }
+        FedEventData::CaughtStealing { runner_name, .. } => {
+            let runner = state.baserunners.iter()
+                .find(|runner| runner.player.name == *runner_name)
+                .map(|runner| runner.player.clone())
+                .unwrap_or_else(|| PlayerRef::named(runner_name));
+            changes.batter(state.batting_team().id, runner).caught_stealing += 1;
+            changes.pitcher(state, None).outs_recorded += 1;
+        }
+        FedEventData::GrindRail { success: GrindRailSuccess::TaggedOut(_) | GrindRailSuccess::Bailed, .. } => {
+            changes.pitcher(state, None).outs_recorded += 1;
+        }
+        _ => {}
+    }
+
+    changes
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PlayerKey {
+    Id(Uuid),
+    /// For players who haven't appeared with their uuid in this game yet. Names are only matched
+    /// up within one team in one game, so players who share a name aren't merged.
+    Named {
+        game_id: Uuid,
+        team_id: Uuid,
+        name: String,
+    },
+}
+
+/// One player's stats for one season
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerSeasonStats {
+    /// Zero-indexed season
+    pub season: i32,
+    pub player_id: Option<Uuid>,
+    pub player_name: String,
+    pub batting: BattingStats,
+    pub pitching: PitchingStats,
+}
+
+impl PlayerSeasonStats {
+    fn new(season: i32, player_id: Option<Uuid>, player_name: String) -> Self {
+        Self { season, player_id, player_name, batting: Default::default(), pitching: Default::default() }
+    }
+}
+
+/// Accumulates per-player, per-season stats from a stream of FedEvents. Events from different games
+/// may be interleaved, but each game's events must be in order.
+///
+/// Stats are keyed by player uuid. Many game events only name the batter, so those stats are
+/// matched to the uuid of the player with that name on the same team in the same game, once any
+/// event in that game reveals it. Players whose uuid never appears in a game get a separate entry,
+/// with no uuid, for each team they appeared on.
+///
+/// The feed doesn't announce starting pitchers (see [`crate::TeamState::pitcher`]), so give them to
+/// [`StatsAccumulator::push_game_start`] when they're known from elsewhere, such as game updates.
+/// Otherwise pitching stats from before an event names the pitcher can't be attributed to anyone
+/// and are dropped.
+#[derive(Debug, Default)]
+pub struct StatsAccumulator {
+    games: HashMap<Uuid, GameState>,
+    /// Starting pitchers given before their game's first event, by game
+    starting_pitchers: HashMap<Uuid, (PlayerRef, PlayerRef)>,
+    finished_games: HashSet<Uuid>,
+    /// Uuids revealed in each game, by team and player name
+    game_player_ids: HashMap<Uuid, HashMap<(Uuid, String), Uuid>>,
+    stats: HashMap<(i32, PlayerKey), PlayerSeasonStats>,
+}
+
+impl StatsAccumulator {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the starting pitchers of a game, so the stats of every batter they face are attributed
+    /// to them. Call this before pushing the game's events. An event that names a different
+    /// pitcher still replaces the starter from then on.
+    pub fn push_game_start(&mut self, game_id: Uuid, home_pitcher: PlayerRef, away_pitcher: PlayerRef) {
+        match self.games.get_mut(&game_id) {
+            Some(state) => {
+                state.home.pitcher = Some(home_pitcher);
+                state.away.pitcher = Some(away_pitcher);
+            }
+            None => {
+                self.starting_pitchers.insert(game_id, (home_pitcher, away_pitcher));
+            }
+        }
+    }
+
+    /// Adds the stats from one event. If the event can't be applied to its game, the error is
+    /// returned and the rest of that game is skipped.
+    pub fn push(&mut self, event: &FedEvent) -> Result<(), GameStateError> {
+        let Some(game) = event.data.game() else { return Ok(()) };
+        if self.finished_games.contains(&game.game_id) {
+            return Ok(());
+        }
+
+        let starting_pitchers = &mut self.starting_pitchers;
+        let state = self.games.entry(game.game_id)
+            .or_insert_with(|| {
+                let mut state = GameState::new(game);
+                if let Some((home_pitcher, away_pitcher)) = starting_pitchers.remove(&game.game_id) {
+                    state.home.pitcher = Some(home_pitcher);
+                    state.away.pitcher = Some(away_pitcher);
+                }
+                state
+            });
+        let changes = stat_changes(state, &event.data);
+        if let Err(err) = state.apply(event) {
+            self.games.remove(&game.game_id);
+            self.finished_games.insert(game.game_id);
+            return Err(err);
+        }
+        if state.game_complete {
+            self.games.remove(&game.game_id);
+            self.finished_games.insert(game.game_id);
+        }
+
+        for change in changes.batting {
+            self.player_stats(event.season, game.game_id, change.team_id, change.player).batting += &change.stats;
+        }
+        if let Some((team_id, Some(pitcher), stats)) = changes.pitching {
+            self.player_stats(event.season, game.game_id, team_id, pitcher).pitching += &stats;
+        }
+
+        Ok(())
+    }
+
+    fn player_stats(&mut self, season: i32, game_id: Uuid, team_id: Uuid, player: PlayerRef) -> &mut PlayerSeasonStats {
+        let game_ids = self.game_player_ids.entry(game_id).or_default();
+        let id = match player.id {
+            Some(id) => {
+                game_ids.insert((team_id, player.name.clone()), id);
+                Some(id)
+            }
+            None => game_ids.get(&(team_id, player.name.clone())).copied(),
+        };
+        let key = match id {
+            Some(id) => PlayerKey::Id(id),
+            None => PlayerKey::Named { game_id, team_id, name: player.name.clone() },
+        };
+
+        self.stats.entry((season, key))
+            .or_insert_with(|| PlayerSeasonStats::new(season, id, player.name))
+    }
+
+    /// Stats for every player and season seen so far, ordered by season and then player name.
+    /// Stats recorded under a player's name before their uuid was revealed in that game are merged
+    /// into the stats for that uuid.
+    pub fn results(&self) -> Vec<PlayerSeasonStats> {
+        #[derive(PartialEq, Eq, Hash)]
+        enum MergedKey<'a> {
+            Id(Uuid),
+            Named { team_id: Uuid, name: &'a str },
+        }
+
+        let mut merged: HashMap<(i32, MergedKey), PlayerSeasonStats> = HashMap::new();
+        for ((season, key), stats) in &self.stats {
+            let (id, key) = match key {
+                PlayerKey::Id(id) => (Some(*id), MergedKey::Id(*id)),
+                PlayerKey::Named { game_id, team_id, name } => {
+                    let id = self.game_player_ids.get(game_id)
+                        .and_then(|ids| ids.get(&(*team_id, name.clone())))
+                        .copied();
+                    (id, id.map_or(MergedKey::Named { team_id: *team_id, name }, MergedKey::Id))
+                }
+            };
+            let entry = merged.entry((*season, key))
+                .or_insert_with(|| PlayerSeasonStats::new(*season, id, stats.player_name.clone()));
+            entry.batting += &stats.batting;
+            entry.pitching += &stats.pitching;
+        }
+
+        merged.into_values()
+            .sorted_by(|a, b| (a.season, &a.player_name).cmp(&(b.season, &b.player_name)))
+            .collect()
+    }
+}
@@ -0,0 +1,175 @@
+//! Tests for `BoxScore`, built from short hand-built games
+
+mod common;
+
+use uuid::Uuid;
+
+use fed::*;
+
+use common::{game, play_event, HOME_TEAM};
+
+const AWAY_BATTER: Uuid = Uuid::from_u128(4);
+const HOME_BATTER: Uuid = Uuid::from_u128(5);
+const HOME_PITCHER: Uuid = Uuid::from_u128(6);
+
+fn half_inning_start(play: i64, top_of_inning: bool, batting_team_name: &str) -> FedEvent {
+    play_event(play, FedEventData::HalfInningStart {
+        game: game(play),
+        top_of_inning,
+        inning: 0,
+        batting_team_name: batting_team_name.to_string(),
+        subseasonal_mod_effects: vec![],
+    })
+}
+
+fn batter_up(play: i64, batter_name: &str, team_nickname: &str) -> FedEvent {
+    play_event(play, FedEventData::BatterUp {
+        game: game(play),
+        batter_name: batter_name.to_string(),
+        team_nickname: team_nickname.to_string(),
+        wielding_item: None,
+        inhabiting: None,
+        is_repeating: false,
+    })
+}
+
+fn pitcher_change(play: i64, team_nickname: &str, pitcher_id: Uuid, pitcher_name: &str) -> FedEvent {
+    play_event(play, FedEventData::PitcherChange {
+        game: game(play),
+        team_nickname: team_nickname.to_string(),
+        pitcher_id,
+        pitcher_name: pitcher_name.to_string(),
+    })
+}
+
+fn hit(play: i64, batter_id: Uuid, batter_name: &str, hit_type: HitType) -> FedEvent {
+    play_event(play, FedEventData::Hit {
+        game: game(play),
+        pitch: GamePitch { double_strike: None },
+        batter_name: batter_name.to_string(),
+        batter_id,
+        hit_type,
+        scores: Scores { scores: vec![], free_refills: vec![] },
+        spicy_status: SpicyStatus::None,
+        stopped_inhabiting: None,
+        special_reason: None,
+        pitcher_item_damage: None,
+        batter_item_damage: None,
+        other_player_item_damage: None,
+    })
+}
+
+fn strikeout(play: i64, batter_name: &str) -> FedEvent {
+    play_event(play, FedEventData::StrikeoutSwinging {
+        game: game(play),
+        pitch: GamePitch { double_strike: None },
+        batter_name: batter_name.to_string(),
+        stopped_inhabiting: None,
+        pitcher_item_damage: None,
+        free_refill: None,
+        special_reason: None,
+        parasite: None,
+    })
+}
+
+fn solo_home_run(play: i64, batter_id: Uuid, batter_name: &str) -> FedEvent {
+    play_event(play, FedEventData::HomeRun {
+        game: game(play),
+        pitch: GamePitch { double_strike: None },
+        magmatic: None,
+        batter_name: batter_name.to_string(),
+        batter_id,
+        home_run_type: HomeRunType::Solo,
+        stopped_inhabiting: None,
+        free_refills: vec![],
+        spicy_status: SpicyStatus::None,
+        special_reason: None,
+        big_bucket: false,
+        attraction: None,
+        damaged_items: vec![],
+    })
+}
+
+fn game_end(play: i64, winner_id: Uuid, winning_team_score: f32, losing_team_score: f32) -> FedEvent {
+    play_event(play, FedEventData::GameEnd {
+        game: game(play),
+        winner_id,
+        winning_team_name: "Home Team".to_string(),
+        winning_team_score,
+        losing_team_name: "Away Team".to_string(),
+        losing_team_score,
+        temp_stolen_player_returned: None,
+    })
+}
+
+fn short_game() -> Vec<FedEvent> {
+    vec![
+        half_inning_start(0, true, "Away Team"),
+        batter_up(1, "Away Batter", "Away"),
+        // "Home" isn't the away team's nickname, so it must be the home team's
+        pitcher_change(2, "Home", HOME_PITCHER, "Home Pitcher"),
+        hit(3, AWAY_BATTER, "Away Batter", HitType::Double),
+        batter_up(4, "Away Batter 2", "Away"),
+        strikeout(5, "Away Batter 2"),
+        half_inning_start(6, false, "Home Team"),
+        batter_up(7, "Home Batter", "Home"),
+        solo_home_run(8, HOME_BATTER, "Home Batter"),
+        game_end(9, HOME_TEAM, 1., 0.),
+    ]
+}
+
+#[test]
+fn box_score_totals_each_team() {
+    let box_score = BoxScore::from_events(&short_game()).unwrap().unwrap();
+
+    assert_eq!(box_score.away.name.as_deref(), Some("Away Team"));
+    assert_eq!(box_score.away.line_score, vec![0.]);
+    assert_eq!(box_score.away.runs, 0.);
+    assert_eq!(box_score.away.hits, 1);
+    assert_eq!(box_score.home.line_score, vec![1.]);
+    assert_eq!(box_score.home.runs, 1.);
+    assert_eq!(box_score.home.hits, 1);
+
+    let away_batting: Vec<_> = box_score.away.batting.iter()
+        .map(|line| (line.player.name.as_str(), line.stats.at_bats, line.stats.doubles, line.stats.strikeouts))
+        .collect();
+    assert_eq!(away_batting, vec![("Away Batter", 1, 1, 0), ("Away Batter 2", 1, 0, 1)]);
+
+    let home_batting = &box_score.home.batting[0];
+    assert_eq!(home_batting.player.id, Some(HOME_BATTER));
+    assert_eq!(home_batting.stats.home_runs, 1);
+    assert_eq!(home_batting.stats.runs, 1);
+    assert_eq!(home_batting.stats.runs_batted_in, 1);
+
+    let home_pitching = &box_score.home.pitching[0];
+    assert_eq!(home_pitching.player.as_ref().and_then(|player| player.id), Some(HOME_PITCHER));
+    assert_eq!(home_pitching.stats.batters_faced, 2);
+    assert_eq!(home_pitching.stats.strikeouts, 1);
+    assert_eq!(home_pitching.stats.hits_allowed, 1);
+
+    // The away team never said who was pitching, so their line has no pitcher
+    assert_eq!(box_score.away.pitching.len(), 1);
+    assert_eq!(box_score.away.pitching[0].player, None);
+}
+
+#[test]
+fn play_by_play_follows_the_game() {
+    let events = short_game();
+    let box_score = BoxScore::from_events(&events).unwrap().unwrap();
+
+    assert_eq!(box_score.play_by_play.len(), events.len());
+    let last = box_score.play_by_play.last().unwrap();
+    assert_eq!((last.away_score, last.home_score), (0., 1.));
+    let strikeout = &box_score.play_by_play[5];
+    assert_eq!(strikeout.outs, 1);
+    assert!(strikeout.top_of_inning);
+}
+
+#[test]
+fn winner_outside_the_game_is_an_error() {
+    let mut events = short_game();
+    *events.last_mut().unwrap() = game_end(9, Uuid::from_u128(99), 1., 0.);
+
+    let err = BoxScore::from_events(&events).unwrap_err();
+    assert!(matches!(err, GameStateError::TeamNotInGame { team_id, .. } if team_id == Uuid::from_u128(99)), "{err}");
+}
@@ -0,0 +1,76 @@
+//! Helpers shared by the tests: replaying feed events stored in the repo, and building events by
+//! hand
+
+// Each test binary only uses some of these
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use uuid::Uuid;
+
+use fed::{feed_event_from_json, parse_feed_event, FedEvent, FedEventData, GameEvent, SimPhase};
+
+pub const GAME_ID: Uuid = Uuid::from_u128(1);
+pub const HOME_TEAM: Uuid = Uuid::from_u128(2);
+pub const AWAY_TEAM: Uuid = Uuid::from_u128(3);
+
+/// When hand-built events happen, unless a test moves them later
+pub fn created() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2021, 3, 1, 16, 0, 0).unwrap()
+}
+
+/// Play `play` of the game between `HOME_TEAM` and `AWAY_TEAM`
+pub fn game(play: i64) -> GameEvent {
+    GameEvent {
+        game_id: GAME_ID,
+        home_team: HOME_TEAM,
+        away_team: AWAY_TEAM,
+        play,
+        unscatter: None,
+        attractor_secret_base: None,
+    }
+}
+
+/// An event in the middle of season 13
+pub fn event(id: u128, created: DateTime<Utc>, data: FedEventData) -> FedEvent {
+    FedEvent {
+        id: Uuid::from_u128(id),
+        created,
+        sim: "thisidisstaticyo".to_string(),
+        tournament: -1,
+        season: 13,
+        day: 20,
+        phase: SimPhase::Midseason,
+        nuts: 0,
+        data,
+    }
+}
+
+/// An event for play `play` of the game, with plays 5 seconds apart
+pub fn play_event(play: i64, data: FedEventData) -> FedEvent {
+    event(1000 + play as u128, created() + Duration::seconds(play * 5), data)
+}
+
+/// Parses the feed event stored at `path` and checks that it turns back into exactly the same
+/// feed event. Returns the parsed event, or a description of what went wrong.
+pub fn check_round_trip(path: &Path) -> Result<FedEvent, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|err| format!("Couldn't read event: {err}"))?;
+    let feed_event = feed_event_from_json(&json)
+        .map_err(|err| format!("Couldn't deserialize event into EventuallyEvent: {err}"))?;
+    let parsed = parse_feed_event(&feed_event)
+        .map_err(|err| format!("Couldn't parse {:?}: {err}", feed_event.description))?;
+
+    let reconstructed = parsed.clone().into_feed_event();
+    if reconstructed != feed_event {
+        return Err(format!(
+            "Event not reconstructed exactly\nExpected: {}\nProduced: {}",
+            serde_json::to_string_pretty(&feed_event).unwrap(),
+            serde_json::to_string_pretty(&reconstructed).unwrap(),
+        ));
+    }
+
+    Ok(parsed)
+}
@@ -0,0 +1,108 @@
+//! Tests for the `FedEvent` comparison modes and `FedEvent::diff`
+
+mod common;
+
+use std::cmp::Ordering;
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use fed::*;
+
+use common::{created, event, game};
+
+fn pitcher(sub_event: SubEvent) -> ModChangeSubEventWithNamedPlayer {
+    ModChangeSubEventWithNamedPlayer {
+        sub_event,
+        team_id: Uuid::from_u128(2),
+        player_id: Uuid::from_u128(3),
+        player_name: "Jessica Telephone".to_string(),
+    }
+}
+
+fn triple_threat(sub_event: SubEvent) -> FedEvent {
+    event(1, created(), FedEventData::BecomeTripleThreat {
+        game: game(0),
+        pitchers: vec![pitcher(sub_event)],
+    })
+}
+
+fn measured_sub_event() -> SubEvent {
+    SubEvent { id: Uuid::from_u128(6), created: created() + Duration::milliseconds(5), nuts: 2 }
+}
+
+#[test]
+fn sub_event_metadata_is_structural() {
+    let generated = triple_threat(SubEvent::nil());
+    let measured = triple_threat(measured_sub_event());
+
+    assert_ne!(generated, measured);
+    assert!(!generated.eq_by(&measured, FedEventComparison::Structural));
+
+    let mut paths: Vec<_> = generated.diff(&measured).iter().map(|difference| difference.path.to_string()).collect();
+    paths.sort();
+    assert_eq!(paths, ["pitchers[0].subEvent.created", "pitchers[0].subEvent.id", "pitchers[0].subEvent.nuts"]);
+}
+
+#[test]
+fn ignoring_sub_events_ignores_only_sub_events() {
+    let generated = triple_threat(SubEvent::nil());
+    let measured = triple_threat(measured_sub_event());
+    assert!(generated.eq_by(&measured, FedEventComparison::IgnoringSubEvents));
+    assert!(generated.diff_by(&measured, FedEventComparison::IgnoringSubEvents).is_empty());
+
+    let mut renamed = measured.clone();
+    let FedEventData::BecomeTripleThreat { pitchers, .. } = &mut renamed.data else { unreachable!() };
+    pitchers[0].player_name = "York Silk".to_string();
+    let differences = generated.diff_by(&renamed, FedEventComparison::IgnoringSubEvents);
+    assert_eq!(differences, [FedEventDifference {
+        path: FedEventPath(vec![
+            FedEventPathSegment::Field("pitchers".to_string()),
+            FedEventPathSegment::Index(0),
+            FedEventPathSegment::Field("playerName".to_string()),
+        ]),
+        expected: Some("Jessica Telephone".into()),
+        actual: Some("York Silk".into()),
+    }]);
+}
+
+#[test]
+fn ignoring_sub_events_leaves_serialization_alone() {
+    let generated = triple_threat(SubEvent::nil());
+    let measured = triple_threat(measured_sub_event());
+    assert!(generated.eq_by(&measured, FedEventComparison::IgnoringSubEvents));
+
+    let json = serde_json::to_value(&measured).unwrap();
+    assert_eq!(json["pitchers"][0]["subEvent"], serde_json::to_value(measured_sub_event()).unwrap());
+    assert_eq!(json["pitchers"][0]["subEvent"]["id"], Uuid::from_u128(6).to_string());
+}
+
+#[test]
+fn extra_list_entries_are_reported() {
+    let one = triple_threat(SubEvent::nil());
+    let mut two = one.clone();
+    let FedEventData::BecomeTripleThreat { pitchers, .. } = &mut two.data else { unreachable!() };
+    pitchers.push(pitcher(SubEvent::nil()));
+
+    let differences = one.diff(&two);
+    assert_eq!(differences.len(), 1);
+    assert_eq!(differences[0].path.to_string(), "pitchers[1]");
+    assert_eq!(differences[0].expected, None);
+    assert!(differences[0].actual.is_some());
+}
+
+#[test]
+fn created_only_ordering() {
+    let earlier = triple_threat(SubEvent::nil());
+    let later = FedEvent { created: created() + Duration::seconds(5), ..triple_threat(measured_sub_event()) };
+    let same_time = FedEvent { nuts: 10, ..triple_threat(measured_sub_event()) };
+
+    assert_eq!(earlier.cmp_created(&later), Ordering::Less);
+    assert_eq!(later.cmp_created(&earlier), Ordering::Greater);
+    assert!(earlier.eq_by(&same_time, FedEventComparison::CreatedOnly));
+    assert!(!earlier.eq_by(&later, FedEventComparison::CreatedOnly));
+
+    let differences = earlier.diff_by(&later, FedEventComparison::CreatedOnly);
+    assert_eq!(differences.len(), 1);
+    assert_eq!(differences[0].path.to_string(), "created");
+}
@@ -0,0 +1,122 @@
+//! Tests that `Display` for `FedEvent` skips building children, by counting the allocations made
+//! while formatting hand-built events
+
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use fed::*;
+
+use common::{created, HOME_TEAM};
+
+/// Counts allocations made on the current thread, so tests running in parallel don't interfere
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn display_allocations(event: &FedEvent) -> usize {
+    allocations(|| { event.to_string(); })
+}
+
+fn feed_event_allocations(event: &FedEvent) -> usize {
+    allocations(|| { event.clone().into_feed_event(); })
+}
+
+fn sub_event(n: u128) -> SubEvent {
+    SubEvent {
+        id: Uuid::from_u128(n),
+        created: created() + Duration::seconds(1),
+        nuts: 0,
+    }
+}
+
+fn game(unscatter: Option<Unscatter>) -> GameEvent {
+    GameEvent { unscatter, ..common::game(10) }
+}
+
+fn event(data: FedEventData) -> FedEvent {
+    common::event(100, created(), data)
+}
+
+fn party(unscatter: Option<Unscatter>) -> FedEvent {
+    event(FedEventData::Party {
+        game: game(unscatter),
+        team_id: HOME_TEAM,
+        player_id: Uuid::from_u128(4),
+        player_name: "Jessica Telephone".to_string(),
+        sub_event: sub_event(200),
+        rating_before: 0.5,
+        rating_after: 0.55,
+    })
+}
+
+fn return_from_elsewhere(scattered: Option<Scattered>) -> FedEvent {
+    event(FedEventData::ReturnFromElsewhere {
+        game: game(None),
+        player_name: "Jessica Telephone".to_string(),
+        flavor: ReturnFromElsewhereFlavor::Full {
+            team_id: HOME_TEAM,
+            player_id: Uuid::from_u128(4),
+            is_peanut: false,
+            sub_event: sub_event(200),
+            time_elsewhere: TimeElsewhere::Days(3),
+            scattered,
+            recongealed_differently: None,
+        },
+    })
+}
+
+#[test]
+fn display_skips_unscatter_child() {
+    // The unscattered player's name is empty so that cloning the game doesn't allocate
+    let unscatter = Unscatter {
+        sub_event: sub_event(300),
+        team_id: HOME_TEAM,
+        player_id: Uuid::from_u128(5),
+        player_name: String::new(),
+    };
+    let without_child = party(None);
+    let with_child = party(Some(unscatter));
+
+    assert!(feed_event_allocations(&with_child) > feed_event_allocations(&without_child));
+    assert_eq!(display_allocations(&with_child), display_allocations(&without_child));
+}
+
+#[test]
+fn display_skips_event_children() {
+    let scattered = Scattered {
+        scattered_name: "J3ss1c4 T3l3ph0n3".to_string(),
+        sub_event: sub_event(300),
+    };
+    let without_child = return_from_elsewhere(None);
+    let with_child = return_from_elsewhere(Some(scattered));
+
+    assert!(feed_event_allocations(&with_child) > feed_event_allocations(&without_child));
+    assert_eq!(display_allocations(&with_child), display_allocations(&without_child));
+}
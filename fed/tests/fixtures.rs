@@ -0,0 +1,153 @@
+//! Round-trip tests for the fixture corpus in `tests/fixtures`. Every feed event in
+//! `tests/fixtures/events` must parse, turn back into exactly the same feed event, and parse into
+//! the same `FedEvent` JSON that's saved under the same name in `tests/fixtures/parsed`.
+//!
+//! Samples from the feed dump have one event per distinct `WithStructure` structure, as dumped by
+//! `cargo run --bin test -- fed/tests/fixtures/events` from the workspace root; see
+//! `tests/fixtures/README.md`. The test fails if the corpus is empty. After adding fixtures or
+//! intentionally changing parser output, run this test with `UPDATE_SNAPSHOTS=1` to rewrite the
+//! parsed JSON, and review the diff.
+//!
+//! `tests/fixtures/game_updates` holds whole games saved by `check_game_updates --capture`: their
+//! feed events and the game updates Chronicler recorded for them. Every play's generated lastUpdate
+//! must match the recorded one.
+
+mod common;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use eventually_api::EventuallyEvent;
+use fed::{parse_feed_event, GameUpdate};
+use serde_json::Value;
+
+use common::check_round_trip;
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures")
+}
+
+fn fixture_paths(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("Couldn't read fixture directory {}: {err}", dir.display()))
+        .map(|entry| entry.expect("Couldn't read fixture directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn check_fixture(path: &Path, snapshot_path: &Path, update_snapshots: bool) -> Result<(), String> {
+    let parsed = check_round_trip(path)?;
+
+    let parsed_json = serde_json::to_string_pretty(&parsed)
+        .map_err(|err| format!("Couldn't serialize parsed event: {err}"))? + "\n";
+    if update_snapshots {
+        return fs::write(snapshot_path, parsed_json)
+            .map_err(|err| format!("Couldn't write snapshot {}: {err}", snapshot_path.display()));
+    }
+
+    let snapshot = fs::read_to_string(snapshot_path)
+        .map_err(|err| format!("Couldn't read snapshot {} (run with UPDATE_SNAPSHOTS=1 to create it): {err}",
+                               snapshot_path.display()))?;
+    if snapshot != parsed_json {
+        return Err(format!("Parsed event doesn't match snapshot {}\nExpected: {snapshot}\nParsed: {parsed_json}",
+                           snapshot_path.display()));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn fixtures_round_trip() {
+    let dir = fixtures_dir();
+    let snapshot_dir = dir.join("parsed");
+    let update_snapshots = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    if update_snapshots {
+        fs::create_dir_all(&snapshot_dir).expect("Couldn't create snapshot directory");
+    }
+
+    let paths = fixture_paths(&dir.join("events"));
+    assert!(!paths.is_empty(), "The fixture corpus in {} is empty. Dump it from the feed with \
+             `cargo run --release --bin test -- fed/tests/fixtures/events`; see tests/fixtures/README.md.",
+            dir.join("events").display());
+
+    // Check every fixture before failing, so one run reports everything that broke
+    let failures: Vec<_> = paths.into_iter()
+        .filter_map(|path| {
+            let snapshot_path = snapshot_dir.join(path.file_name().unwrap());
+            check_fixture(&path, &snapshot_path, update_snapshots)
+                .err()
+                .map(|err| format!("{}: {err}", path.display()))
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{} fixture(s) failed:\n\n{}", failures.len(), failures.join("\n\n"));
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|err| format!("Couldn't read {}: {err}", path.display()))?;
+    serde_json::from_str(&json)
+        .map_err(|err| format!("Couldn't deserialize {}: {err}", path.display()))
+}
+
+/// Checks the lastUpdate of every play of one game saved by `check_game_updates --capture`
+/// against the game update recorded for it
+fn check_game_updates(dir: &Path) -> Result<(), String> {
+    let feed_events: Vec<EventuallyEvent> = read_json(&dir.join("events.json"))?;
+    let recorded: Vec<Value> = read_json(&dir.join("updates.json"))?;
+
+    let events = feed_events.iter()
+        .map(|feed_event| parse_feed_event(feed_event)
+            .map_err(|err| format!("Couldn't parse {:?}: {err}", feed_event.description)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let generated = GameUpdate::from_events(&events)
+        .map_err(|err| format!("Couldn't replay the game: {err}"))?;
+
+    let recorded_last_updates: HashMap<_, _> = recorded.iter()
+        .filter_map(|update| Some((update.get("playCount")?.as_i64()?, update.get("lastUpdate")?.as_str()?)))
+        .collect();
+    let compared: Vec<_> = generated.iter()
+        .filter_map(|update| Some((update, *recorded_last_updates.get(&update.play_count)?)))
+        .collect();
+    if compared.is_empty() {
+        return Err("No play has a recorded game update".to_string());
+    }
+
+    let mismatches: Vec<_> = compared.into_iter()
+        .filter(|(update, recorded)| update.last_update != *recorded)
+        .map(|(update, recorded)| format!("playCount {}: expected {recorded:?}, got {:?}",
+                                          update.play_count, update.last_update))
+        .collect();
+    if !mismatches.is_empty() {
+        return Err(mismatches.join("\n"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn game_updates_match_recordings() {
+    let dir = fixtures_dir().join("game_updates");
+    let mut game_dirs: Vec<_> = fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("Couldn't read fixture directory {}: {err}", dir.display()))
+        .map(|entry| entry.expect("Couldn't read fixture directory entry").path())
+        .filter(|path| path.is_dir())
+        .collect();
+    game_dirs.sort();
+    assert!(!game_dirs.is_empty(), "No recorded games in {}. Save some with \
+             `cargo run --release --bin check_game_updates -- --capture fed/tests/fixtures/game_updates`; \
+             see tests/fixtures/README.md.", dir.display());
+
+    let failures: Vec<_> = game_dirs.into_iter()
+        .filter_map(|game_dir| {
+            check_game_updates(&game_dir)
+                .err()
+                .map(|err| format!("{}: {err}", game_dir.display()))
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{} recorded game(s) failed:\n\n{}", failures.len(), failures.join("\n\n"));
+}
@@ -0,0 +1,175 @@
+//! Tests for `GameState`, replaying short hand-built games
+
+mod common;
+
+use uuid::Uuid;
+
+use fed::*;
+
+use common::{game, play_event};
+
+const AWAY_BATTER: Uuid = Uuid::from_u128(4);
+const HOME_BATTER: Uuid = Uuid::from_u128(5);
+
+fn half_inning_start(play: i64, top_of_inning: bool, batting_team_name: &str) -> FedEvent {
+    play_event(play, FedEventData::HalfInningStart {
+        game: game(play),
+        top_of_inning,
+        inning: 0,
+        batting_team_name: batting_team_name.to_string(),
+        subseasonal_mod_effects: vec![],
+    })
+}
+
+fn batter_up(play: i64, batter_name: &str, team_nickname: &str) -> FedEvent {
+    play_event(play, FedEventData::BatterUp {
+        game: game(play),
+        batter_name: batter_name.to_string(),
+        team_nickname: team_nickname.to_string(),
+        wielding_item: None,
+        inhabiting: None,
+        is_repeating: false,
+    })
+}
+
+fn hit(play: i64, batter_id: Uuid, batter_name: &str, hit_type: HitType) -> FedEvent {
+    play_event(play, FedEventData::Hit {
+        game: game(play),
+        pitch: GamePitch { double_strike: None },
+        batter_name: batter_name.to_string(),
+        batter_id,
+        hit_type,
+        scores: Scores { scores: vec![], free_refills: vec![] },
+        spicy_status: SpicyStatus::None,
+        stopped_inhabiting: None,
+        special_reason: None,
+        pitcher_item_damage: None,
+        batter_item_damage: None,
+        other_player_item_damage: None,
+    })
+}
+
+fn stolen_base(play: i64, runner_id: Uuid, runner_name: &str, base_stolen: Base) -> FedEvent {
+    play_event(play, FedEventData::StolenBase {
+        game: game(play),
+        runner_name: runner_name.to_string(),
+        runner_id,
+        base_stolen,
+        blaserunning: false,
+        free_refill: None,
+        runner_item_damage: None,
+        special_reason: None,
+    })
+}
+
+fn replay(events: &[FedEvent]) -> GameState {
+    let mut state = GameState::new(&game(0));
+    for event in events {
+        state.apply(event).unwrap_or_else(|err| panic!("Couldn't apply {:?}: {err}", event.data));
+    }
+    state
+}
+
+#[test]
+fn fifth_base_belongs_to_the_team_that_reached_it() {
+    let state = replay(&[
+        half_inning_start(0, true, "Away Team"),
+        batter_up(1, "Away Batter", "Away"),
+        hit(2, AWAY_BATTER, "Away Batter", HitType::Quadruple),
+    ]);
+    assert_eq!(state.away.num_bases, 5);
+    assert_eq!(state.home.num_bases, 4);
+    assert_eq!(state.num_bases(), 5);
+    assert_eq!(state.baserunners.len(), 1);
+    assert_eq!(state.baserunners[0].base, 3);
+
+    // The home team still has four bases, so stealing fourth base is stealing home
+    let state = replay(&[
+        half_inning_start(0, true, "Away Team"),
+        batter_up(1, "Away Batter", "Away"),
+        hit(2, AWAY_BATTER, "Away Batter", HitType::Quadruple),
+        half_inning_start(3, false, "Home Team"),
+        batter_up(4, "Home Batter", "Home"),
+        hit(5, HOME_BATTER, "Home Batter", HitType::Triple(None)),
+        stolen_base(6, HOME_BATTER, "Home Batter", Base::Fourth),
+    ]);
+    assert_eq!(state.num_bases(), 4);
+    assert_eq!(state.home.score, 1.);
+    assert_eq!(state.away.score, 0.);
+    assert!(state.baserunners.is_empty());
+}
+
+#[test]
+fn fourth_base_steal_with_fifth_base_does_not_score() {
+    let state = replay(&[
+        half_inning_start(0, true, "Away Team"),
+        batter_up(1, "Away Batter", "Away"),
+        hit(2, AWAY_BATTER, "Away Batter", HitType::Quadruple),
+        stolen_base(3, AWAY_BATTER, "Away Batter", Base::Fifth),
+        batter_up(4, "Away Batter 2", "Away"),
+        hit(5, Uuid::from_u128(6), "Away Batter 2", HitType::Triple(None)),
+        stolen_base(6, Uuid::from_u128(6), "Away Batter 2", Base::Fourth),
+    ]);
+    assert_eq!(state.away.score, 1.);
+    assert_eq!(state.baserunners.len(), 1);
+    assert_eq!(state.baserunners[0].base, 3);
+}
+
+#[test]
+fn game_updates_show_the_batting_team_bases() {
+    let events = [
+        half_inning_start(0, true, "Away Team"),
+        batter_up(1, "Away Batter", "Away"),
+        hit(2, AWAY_BATTER, "Away Batter", HitType::Quadruple),
+        half_inning_start(3, false, "Home Team"),
+    ];
+    let updates = GameUpdate::from_events(&events).unwrap();
+
+    let after_quadruple = &updates[2];
+    assert_eq!(after_quadruple.away_bases, Some(5));
+    assert_eq!(after_quadruple.home_bases, None);
+
+    let home_batting = &updates[3];
+    assert_eq!(home_batting.home_bases, Some(4));
+    assert_eq!(home_batting.away_bases, None);
+}
+
+#[test]
+fn sibling_events_share_one_game_update() {
+    let hit = hit(2, AWAY_BATTER, "Away Batter", HitType::Single);
+    let mut steal = stolen_base(2, AWAY_BATTER, "Away Batter", Base::Second);
+    steal.id = Uuid::from_u128(2002);
+    let events = [
+        half_inning_start(0, true, "Away Team"),
+        batter_up(1, "Away Batter", "Away"),
+        hit.clone(),
+        steal.clone(),
+    ];
+    let updates = GameUpdate::from_events(&events).unwrap();
+
+    assert_eq!(updates.len(), 3);
+    let update = &updates[2];
+    assert_eq!(update.play_count, 3);
+    assert_eq!(update.last_update, format!("{}\n{}", hit.last_update(), steal.last_update()));
+    assert_eq!(update.bases_occupied, [1]);
+    assert_eq!(update.base_runners, Some(vec![AWAY_BATTER]));
+}
+
+#[test]
+fn game_update_needs_at_least_one_event() {
+    assert_eq!(GameUpdate::from_state(&GameState::new(&game(0)), &[], 0), None);
+}
+
+#[test]
+fn game_update_diff_reports_generated_and_recorded_values() {
+    let events = [half_inning_start(0, true, "Away Team")];
+    let update = &GameUpdate::from_events(&events).unwrap()[0];
+    let mut recorded = serde_json::to_value(update).unwrap();
+    recorded["inning"] = serde_json::json!(7);
+
+    assert_eq!(update.diff(&recorded), [GameUpdateMismatch {
+        field: "inning".to_string(),
+        generated: serde_json::json!(update.inning),
+        recorded: serde_json::json!(7),
+    }]);
+}
@@ -0,0 +1,116 @@
+//! Tests for `parse_game_update`, using hand-built game updates. Every gameplay event type is also
+//! covered by the game update round trips in `round_trip.rs`.
+
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use fed::*;
+
+const GAME_ID: Uuid = Uuid::from_u128(1);
+const HOME_TEAM: Uuid = Uuid::from_u128(2);
+const AWAY_TEAM: Uuid = Uuid::from_u128(3);
+const BATTER: Uuid = Uuid::from_u128(4);
+const RUNNER: Uuid = Uuid::from_u128(5);
+
+fn game_update(last_update: &str) -> Value {
+    json!({
+        "id": GAME_ID.to_string(),
+        "homeTeam": HOME_TEAM.to_string(),
+        "awayTeam": AWAY_TEAM.to_string(),
+        "season": 13,
+        "day": 20,
+        "playCount": 40,
+        "lastUpdate": last_update,
+    })
+}
+
+fn game() -> GameEvent {
+    GameEvent {
+        game_id: GAME_ID,
+        home_team: HOME_TEAM,
+        away_team: AWAY_TEAM,
+        play: 39,
+        unscatter: None,
+        attractor_secret_base: None,
+    }
+}
+
+#[test]
+fn player_ids_come_from_the_update_the_previous_update_or_are_nil() {
+    let description = "York Silk draws a walk.\nJessica Telephone scores!";
+    let mut previous = game_update(description);
+    previous["baseRunnerNames"] = json!(["Jessica Telephone"]);
+    previous["baseRunners"] = json!([RUNNER.to_string()]);
+    let mut update = game_update(description);
+    update["homeBatterName"] = Value::from("York Silk");
+    update["homeBatter"] = Value::from(BATTER.to_string());
+
+    let data = parse_game_update(&update, Some(&previous)).unwrap();
+    let FedEventData::Walk { game: parsed_game, batter_name, batter_id, scores, .. } = data else {
+        panic!("Expected a Walk, got {data:?}");
+    };
+    assert_eq!(parsed_game, game());
+    assert_eq!(batter_name, "York Silk");
+    assert_eq!(batter_id, BATTER);
+    assert_eq!(scores.scores.len(), 1);
+    assert_eq!(scores.scores[0].player_name, "Jessica Telephone");
+    assert_eq!(scores.scores[0].player_id, RUNNER);
+
+    let data = parse_game_update(&update, None).unwrap();
+    let FedEventData::Walk { scores, .. } = data else { panic!("Expected a Walk, got {data:?}") };
+    assert_eq!(scores.scores[0].player_id, Uuid::nil());
+}
+
+#[test]
+fn game_end_uses_the_score_fields() {
+    let mut update = game_update("Game over.");
+    update["homeTeamName"] = Value::from("Hades Tigers");
+    update["awayTeamName"] = Value::from("Boston Flowers");
+    update["homeScore"] = Value::from(2.5);
+    update["awayScore"] = Value::from(4);
+
+    assert_eq!(parse_game_update(&update, None).unwrap(), FedEventData::GameEnd {
+        game: game(),
+        winner_id: AWAY_TEAM,
+        winning_team_name: "Boston Flowers".to_string(),
+        winning_team_score: 4.,
+        losing_team_name: "Hades Tigers".to_string(),
+        losing_team_score: 2.5,
+        temp_stolen_player_returned: None,
+    });
+
+    update["homeScore"] = Value::from(4);
+    let data = parse_game_update(&update, None).unwrap();
+    let FedEventData::GameEnd { winner_id, .. } = data else { panic!("Expected a GameEnd, got {data:?}") };
+    assert_eq!(winner_id, Uuid::nil());
+}
+
+#[test]
+fn feed_final_score_text_is_not_a_game_update() {
+    // Game updates show "Game over." for the end of the game, never the feed's final score
+    let update = game_update("Hades Tigers 4, Boston Flowers 2");
+
+    match parse_game_update(&update, None) {
+        Err(FeedParseError::UnrecognizedGameUpdate { .. }) => {}
+        other => panic!("Expected UnrecognizedGameUpdate, got {other:?}"),
+    }
+}
+
+#[test]
+fn missing_game_fields_are_errors() {
+    for field in ["id", "playCount"] {
+        let mut update = game_update("Ball. 1-0");
+        update.as_object_mut().unwrap().remove(field);
+
+        match parse_game_update(&update, None) {
+            Err(FeedParseError::MissingGameUpdateField { field: missing }) => assert_eq!(missing, field),
+            other => panic!("Expected MissingGameUpdateField for {field}, got {other:?}"),
+        }
+    }
+
+    let update = game_update("Let's Go!");
+    match parse_game_update(&update, None) {
+        Err(FeedParseError::MissingGameUpdateField { field: "weather" }) => {}
+        other => panic!("Expected MissingGameUpdateField for weather, got {other:?}"),
+    }
+}
@@ -0,0 +1,139 @@
+//! Tests for `ItemLedger`, using short sequences of hand-built events
+
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use fed::*;
+
+const ITEM_ID: Uuid = Uuid::from_u128(1);
+const TEAM_ID: Uuid = Uuid::from_u128(2);
+const OWNER_ID: Uuid = Uuid::from_u128(3);
+const OTHER_PLAYER_ID: Uuid = Uuid::from_u128(4);
+
+fn time(seconds: i64) -> DateTime<Utc> {
+    common::created() + Duration::seconds(seconds)
+}
+
+fn event(id: u128, data: FedEventData) -> FedEvent {
+    common::event(id, time(id as i64), data)
+}
+
+fn chest_opens(id: u128) -> FedEvent {
+    event(id, FedEventData::CommunityChestOpens {
+        item_id: ITEM_ID,
+        item_name: "Bat".to_string(),
+        item_mods: vec![],
+        player_item_rating_before: Some(0.),
+        player_item_rating_after: Some(0.2),
+        player_rating: 3.,
+        team_id: TEAM_ID,
+        player_name: "Item Owner".to_string(),
+        player_id: OWNER_ID,
+    })
+}
+
+fn drops_item(id: u128, player_id: Uuid) -> FedEvent {
+    event(id, FedEventData::PlayerDropsItem {
+        item_id: ITEM_ID,
+        item_name: "Bat".to_string(),
+        item_mods: vec![],
+        player_item_rating_before: 0.2,
+        player_item_rating_after: 0.,
+        player_rating: 3.,
+        team_id: TEAM_ID,
+        player_name: "Someone".to_string(),
+        player_id,
+    })
+}
+
+/// A single by the item's owner that damages the item down to `health`
+fn damaging_hit(id: u128, health: i64) -> FedEvent {
+    let game = GameEvent {
+        game_id: Uuid::from_u128(10),
+        home_team: TEAM_ID,
+        away_team: Uuid::from_u128(11),
+        play: id as i64,
+        unscatter: None,
+        attractor_secret_base: None,
+    };
+    event(id, FedEventData::Hit {
+        game,
+        pitch: GamePitch { double_strike: None },
+        batter_name: "Item Owner".to_string(),
+        batter_id: OWNER_ID,
+        hit_type: HitType::Single,
+        scores: Scores { scores: vec![], free_refills: vec![] },
+        spicy_status: SpicyStatus::None,
+        stopped_inhabiting: None,
+        special_reason: None,
+        pitcher_item_damage: None,
+        batter_item_damage: Some(ItemDamaged {
+            item_id: ITEM_ID,
+            item_name: "Bat".to_string(),
+            item_name_plural: Some(false),
+            item_mods: vec![],
+            durability: 3,
+            health,
+            player_item_rating_before: 0.2,
+            player_item_rating_after: 0.1,
+            player_rating: 3.,
+            team_id: TEAM_ID,
+            player_id: OWNER_ID,
+            sub_event: SubEvent { id: Uuid::from_u128(id + 100), created: time(id as i64), nuts: 0 },
+        }),
+        other_player_item_damage: None,
+    })
+}
+
+fn ledger(events: &[FedEvent]) -> ItemLedger {
+    let mut ledger = ItemLedger::new();
+    for event in events {
+        ledger.apply(event).unwrap_or_else(|err| panic!("Couldn't apply {:?}: {err}", event.data));
+    }
+    ledger
+}
+
+#[test]
+fn item_is_gained_damaged_broken_and_dropped() {
+    let ledger = ledger(&[
+        chest_opens(1),
+        damaging_hit(2, 2),
+        damaging_hit(3, 1),
+        damaging_hit(4, 0),
+        drops_item(5, OWNER_ID),
+    ]);
+
+    let item = ledger.item(ITEM_ID).unwrap();
+    assert_eq!(item.item_name, "Bat");
+    assert_eq!(item.durability, Some(3));
+    assert_eq!(item.health, Some(0));
+    assert!(item.is_broken());
+    assert_eq!(item.times_broken(), 1);
+    assert_eq!(item.owner, None);
+    assert_eq!(item.health_history().map(|(_, health)| health).collect::<Vec<_>>(), [2, 1, 0]);
+    assert_eq!(item.history.first().map(|entry| &entry.change),
+               Some(&ItemHistoryChange::Gained { owner: ItemOwner { player_id: OWNER_ID, team_id: Some(TEAM_ID) } }));
+    assert!(ledger.inconsistencies().is_empty(), "{:?}", ledger.inconsistencies());
+    assert_eq!(ledger.items_held_by(OWNER_ID).count(), 0);
+}
+
+#[test]
+fn disagreements_are_recorded() {
+    let ledger = ledger(&[
+        chest_opens(1),
+        damaging_hit(2, 1),
+        // This damage starts from 2 health, but the item was already down to 1
+        damaging_hit(3, 1),
+        drops_item(4, OTHER_PLAYER_ID),
+    ]);
+
+    let inconsistencies: Vec<_> = ledger.inconsistencies().iter()
+        .map(|entry| entry.inconsistency.clone())
+        .collect();
+    assert_eq!(inconsistencies, [
+        ItemInconsistency::HealthMismatch { expected: 1, actual: 2 },
+        ItemInconsistency::OwnerMismatch { expected: OWNER_ID, actual: OTHER_PLAYER_ID },
+    ]);
+}
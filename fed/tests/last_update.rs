@@ -0,0 +1,128 @@
+//! Tests for the lastUpdate text of game updates, using hand-built events. The expected strings
+//! follow the rules documented on `feed_event_last_update`. They are not copied from recorded game
+//! updates.
+
+mod common;
+
+use eventually_api::EventuallyEvent;
+use uuid::Uuid;
+
+use fed::*;
+
+use common::{created, game, AWAY_TEAM};
+
+const BATTER: Uuid = Uuid::from_u128(4);
+const RUNNER: Uuid = Uuid::from_u128(5);
+
+fn event(data: FedEventData) -> FedEvent {
+    common::event(100, created(), data)
+}
+
+fn ball() -> FedEvent {
+    event(FedEventData::Ball {
+        game: game(10),
+        balls: 1,
+        strikes: 0,
+        batter_item_damage: None,
+    })
+}
+
+fn walk() -> FedEvent {
+    event(FedEventData::Walk {
+        game: game(11),
+        batter_name: "York Silk".to_string(),
+        batter_id: BATTER,
+        scores: Scores {
+            scores: vec![ScoringPlayer {
+                player_id: RUNNER,
+                player_name: "Jessica Telephone".to_string(),
+                item_damage: None,
+                attraction: None,
+            }],
+            free_refills: vec![],
+        },
+        base_instincts: None,
+        batter_item_damage: None,
+        stopped_inhabiting: None,
+        special_reason: None,
+    })
+}
+
+fn stolen_base() -> FedEvent {
+    event(FedEventData::StolenBase {
+        game: game(12),
+        runner_name: "Jessica Telephone".to_string(),
+        runner_id: RUNNER,
+        base_stolen: Base::Second,
+        blaserunning: false,
+        free_refill: None,
+        runner_item_damage: None,
+        special_reason: None,
+    })
+}
+
+fn inning_end() -> FedEvent {
+    event(FedEventData::InningEnd {
+        game: game(13),
+        inning_num: 9,
+        lost_triple_threat: vec![],
+    })
+}
+
+fn game_end() -> FedEvent {
+    event(FedEventData::GameEnd {
+        game: game(14),
+        winner_id: AWAY_TEAM,
+        winning_team_name: "Boston Flowers".to_string(),
+        winning_team_score: 4.,
+        losing_team_name: "Hades Tigers".to_string(),
+        losing_team_score: 2.,
+        temp_stolen_player_returned: None,
+    })
+}
+
+/// Makes a feed event whose siblings are `events`, with siblingIds in the given order. The parent
+/// is the first sibling, as the feed stream leaves it.
+fn with_siblings(events: Vec<FedEvent>, sibling_order: &[usize]) -> EventuallyEvent {
+    let mut events: Vec<_> = events.into_iter().map(FedEvent::into_feed_event).collect();
+    for (i, event) in events.iter_mut().enumerate() {
+        event.id = Uuid::from_u128(i as u128 + 1);
+    }
+    let sibling_ids = sibling_order.iter().map(|&i| events[i].id).collect();
+    let mut parent = events[0].clone();
+    parent.metadata.sibling_ids = Some(sibling_ids);
+    parent.metadata.siblings = events;
+    parent
+}
+
+#[test]
+fn ordinary_events_show_their_description() {
+    for event in [ball(), walk(), stolen_base()] {
+        let feed_event = event.clone().into_feed_event();
+        assert_eq!(event.last_update(), feed_event.description);
+        assert_eq!(feed_event_last_update(&feed_event), feed_event.description);
+    }
+}
+
+#[test]
+fn game_end_shows_game_over() {
+    let event = game_end();
+    assert_eq!(event.last_update(), "Game over.");
+    assert_eq!(feed_event_last_update(&event.into_feed_event()), "Game over.");
+}
+
+#[test]
+fn siblings_are_joined_in_sibling_order() {
+    let expected = format!("{}\n{}", stolen_base(), walk());
+
+    let feed_event = with_siblings(vec![walk(), stolen_base()], &[1, 0]);
+    assert_eq!(feed_event_last_update(&feed_event), expected);
+}
+
+#[test]
+fn game_end_sibling_shows_game_over() {
+    let expected = format!("{}\nGame over.", inning_end());
+
+    let feed_event = with_siblings(vec![inning_end(), game_end()], &[0, 1]);
+    assert_eq!(feed_event_last_update(&feed_event), expected);
+}
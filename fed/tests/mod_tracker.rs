@@ -0,0 +1,106 @@
+//! Tests for `ModTracker`, using short sequences of hand-built events
+
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use fed::*;
+
+const PLAYER_ID: Uuid = Uuid::from_u128(1);
+const TEAM_ID: Uuid = Uuid::from_u128(2);
+const PLAYER: ModHolder = ModHolder::Player(PLAYER_ID);
+
+fn time(minutes: i64) -> DateTime<Utc> {
+    common::created() + Duration::minutes(minutes)
+}
+
+fn event(id: u128, season: i32, data: FedEventData) -> FedEvent {
+    FedEvent { season, ..common::event(id, time(id as i64), data) }
+}
+
+fn tarot_mod(id: u128, season: i32, r#mod: Mod, mod_duration: ModDuration, mod_removed: bool) -> FedEvent {
+    let verb = if mod_removed { "lost" } else { "gained" };
+    event(id, season, FedEventData::TarotReadingAddedOrRemovedMod {
+        team_id: TEAM_ID,
+        player_id: Some(PLAYER_ID),
+        description: format!("Test Player {verb} {}.", r#mod.display_name()),
+        r#mod,
+        mod_duration,
+        mod_removed,
+    })
+}
+
+fn mod_expires(id: u128, season: i32, mods: Vec<Mod>, mod_duration: ModDuration) -> FedEvent {
+    event(id, season, FedEventData::PlayerModExpires {
+        team_id: TEAM_ID,
+        player_id: PLAYER_ID,
+        player_name: "Test Player".to_string(),
+        mods,
+        mod_duration,
+    })
+}
+
+fn track(events: &[FedEvent]) -> ModTracker {
+    let mut tracker = ModTracker::new();
+    for event in events {
+        tracker.apply(event).unwrap_or_else(|err| panic!("Couldn't apply {:?}: {err}", event.data));
+    }
+    tracker
+}
+
+fn mod_ids(mods: &[ActiveMod]) -> Vec<Mod> {
+    mods.iter().map(|m| m.mod_id.clone()).collect()
+}
+
+#[test]
+fn mods_are_added_and_expire() {
+    let events = [
+        tarot_mod(1, 13, Mod::Fireproof, ModDuration::Permanent, false),
+        tarot_mod(2, 13, Mod::Overperforming, ModDuration::Seasonal, false),
+        mod_expires(3, 13, vec![Mod::Overperforming], ModDuration::Seasonal),
+        tarot_mod(4, 13, Mod::Fireproof, ModDuration::Permanent, true),
+    ];
+    let tracker = track(&events);
+
+    assert!(tracker.current_mods(PLAYER).is_empty());
+    assert_eq!(tracker.history(PLAYER).len(), 4);
+    assert_eq!(mod_ids(&tracker.mods_at_event(PLAYER, Uuid::from_u128(2)).unwrap()), [Mod::Fireproof, Mod::Overperforming]);
+    assert_eq!(mod_ids(&tracker.mods_at(PLAYER, time(3))), [Mod::Fireproof]);
+    assert_eq!(tracker.mods_at_event(PLAYER, Uuid::from_u128(99)), None);
+}
+
+#[test]
+fn seasonal_mods_are_dropped_at_a_new_season() {
+    let tracker = track(&[
+        tarot_mod(1, 13, Mod::Fireproof, ModDuration::Permanent, false),
+        tarot_mod(2, 13, Mod::Overperforming, ModDuration::Seasonal, false),
+        tarot_mod(3, 14, Mod::Chunky, ModDuration::Permanent, false),
+    ]);
+
+    assert_eq!(mod_ids(tracker.current_mods(PLAYER)), [Mod::Fireproof, Mod::Chunky]);
+}
+
+#[test]
+fn weekly_mods_are_active_until_they_expire() {
+    let tracker = track(&[
+        tarot_mod(1, 13, Mod::Fireproof, ModDuration::Permanent, false),
+        tarot_mod(2, 13, Mod::Wired, ModDuration::Weekly, false),
+        mod_expires(3, 13, vec![Mod::Wired], ModDuration::Weekly),
+    ]);
+
+    assert_eq!(mod_ids(&tracker.mods_at_event(PLAYER, Uuid::from_u128(2)).unwrap()), [Mod::Fireproof, Mod::Wired]);
+    assert_eq!(mod_ids(&tracker.mods_at(PLAYER, time(2))), [Mod::Fireproof, Mod::Wired]);
+    assert_eq!(mod_ids(tracker.current_mods(PLAYER)), [Mod::Fireproof]);
+}
+
+#[test]
+fn weekly_mods_are_dropped_at_a_new_season() {
+    let tracker = track(&[
+        tarot_mod(1, 13, Mod::Wired, ModDuration::Weekly, false),
+        tarot_mod(2, 14, Mod::Chunky, ModDuration::Permanent, false),
+    ]);
+
+    assert_eq!(mod_ids(&tracker.mods_at_event(PLAYER, Uuid::from_u128(1)).unwrap()), [Mod::Wired]);
+    assert_eq!(mod_ids(tracker.current_mods(PLAYER)), [Mod::Chunky]);
+}
@@ -0,0 +1,23 @@
+//! Tests for the `Mod` enum's serialization, which must stay compatible with feed metadata and
+//! previously saved events
+
+use serde_json::json;
+
+use fed::*;
+
+#[test]
+fn known_mods_serialize_as_their_id() {
+    assert_eq!(serde_json::to_value(Mod::OnFire).unwrap(), json!("ON_FIRE"));
+    assert_eq!(serde_json::from_value::<Mod>(json!("ON_FIRE")).unwrap(), Mod::OnFire);
+    assert_eq!(Mod::OnFire.id(), "ON_FIRE");
+    assert_eq!(Mod::OnFire.to_string(), "Red Hot");
+}
+
+#[test]
+fn unknown_mods_keep_their_id() {
+    let parsed: Vec<Mod> = serde_json::from_value(json!(["SCATTERED", "NOT_A_REAL_MOD"])).unwrap();
+    assert_eq!(parsed, [Mod::Scattered, Mod::Unknown("NOT_A_REAL_MOD".to_string())]);
+    assert!(!parsed[1].is_known());
+    assert_eq!(parsed[1].display_name(), "NOT_A_REAL_MOD");
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), json!(["SCATTERED", "NOT_A_REAL_MOD"]));
+}
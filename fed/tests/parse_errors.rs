@@ -0,0 +1,148 @@
+//! Feed events that the parser has to reject rather than silently drop part of
+
+mod common;
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use fed::*;
+
+use common::{created, game, HOME_TEAM};
+
+const TEAM_ID: Uuid = HOME_TEAM;
+const BATTER: Uuid = Uuid::from_u128(4);
+
+/// Makes a feed event from `data`, as JSON so the tests can change it
+fn feed_event_json(data: FedEventData) -> Value {
+    serde_json::to_value(common::event(100, created(), data).into_feed_event()).unwrap()
+}
+
+fn parse_json(json: &Value) -> Result<FedEvent, FeedParseError> {
+    parse_feed_event(&feed_event_from_json(&json.to_string()).unwrap())
+}
+
+/// A Hit whose batter has one item broken before the hit, in feed form
+fn hit_with_batter_item_damage() -> Value {
+    feed_event_json(FedEventData::Hit {
+        game: game(10),
+        pitch: GamePitch { double_strike: None },
+        batter_name: "Silvaire Roadhouse".to_string(),
+        batter_id: BATTER,
+        hit_type: HitType::Single,
+        scores: Scores { scores: vec![], free_refills: vec![] },
+        spicy_status: SpicyStatus::None,
+        stopped_inhabiting: None,
+        special_reason: None,
+        pitcher_item_damage: None,
+        batter_item_damage: Some(ItemDamaged {
+            item_id: Uuid::from_u128(6),
+            item_name: "Bat".to_string(),
+            item_name_plural: None,
+            item_mods: vec![],
+            durability: 2,
+            health: 0,
+            player_item_rating_before: 1.,
+            player_item_rating_after: 0.5,
+            player_rating: 2.,
+            team_id: TEAM_ID,
+            player_id: BATTER,
+            sub_event: SubEvent::nil(),
+        }),
+        other_player_item_damage: None,
+    })
+}
+
+/// The first half-inning of a game where the batting team becomes Middling, in feed form
+fn half_inning_start_middling() -> Value {
+    feed_event_json(FedEventData::HalfInningStart {
+        game: game(10),
+        top_of_inning: true,
+        inning: 1,
+        batting_team_name: "Hades Tigers".to_string(),
+        subseasonal_mod_effects: vec![TeamPerformingChanged {
+            team_nickname: "Tigers".to_string(),
+            team_id: TEAM_ID,
+            source_mod_id: SubseasonalMod::Middling,
+            source_mod_name: "Middling".to_string(),
+            was_added: true,
+            sub_event: SubEvent::nil(),
+        }],
+    })
+}
+
+/// A Wired Coffee Bean, in feed form
+fn wired_coffee_bean() -> Value {
+    feed_event_json(FedEventData::CoffeeBean {
+        game: game(10),
+        player_id: BATTER,
+        player_name: "Silvaire Roadhouse".to_string(),
+        roast: "Light Roast".to_string(),
+        notes: "Blueberry".to_string(),
+        which_mod: CoffeeBeanMod::Wired,
+        gained_mod: true,
+        sub_event: SubEvent::nil(),
+        team_id: Some(TEAM_ID),
+        previous: None,
+    })
+}
+
+#[test]
+fn hit_with_two_batter_item_damages_is_an_error() {
+    let mut json = hit_with_batter_item_damage();
+    let description = json["description"].as_str().unwrap().to_string();
+    let (damage_line, hit_line) = description.split_once('\n').unwrap();
+    json["description"] = Value::from(format!("{damage_line}\n{damage_line}\n{hit_line}"));
+    let children = json["metadata"]["children"].as_array_mut().unwrap();
+    let mut second_damage = children[0].clone();
+    second_damage["id"] = Value::from("00000000-0000-0000-0000-000000000001");
+    children.insert(1, second_damage);
+
+    match parse_json(&json) {
+        Err(FeedParseError::TooManyItemDamages { player_name, .. }) => {
+            assert_eq!(player_name, "Silvaire Roadhouse");
+        }
+        other => panic!("Expected TooManyItemDamages, got {other:?}"),
+    }
+}
+
+#[test]
+fn half_inning_with_unknown_subseasonal_mod_is_an_error() {
+    let mut json = half_inning_start_middling();
+    let description = json["description"].as_str().unwrap().replace("Middling", "Overperforming");
+    json["description"] = Value::from(description);
+
+    match parse_json(&json) {
+        Err(FeedParseError::UnexpectedSubseasonalMod { mod_name, .. }) => {
+            assert_eq!(mod_name, "Overperforming");
+        }
+        other => panic!("Expected UnexpectedSubseasonalMod, got {other:?}"),
+    }
+}
+
+#[test]
+fn half_inning_with_mismatched_source_mod_is_an_error() {
+    let mut json = half_inning_start_middling();
+    json["metadata"]["children"][0]["metadata"]["source"] = Value::from("LATE_TO_PARTY");
+
+    match parse_json(&json) {
+        Err(FeedParseError::UnexpectedMetadataValue { field, value, .. }) => {
+            assert_eq!(field, "source");
+            assert_eq!(value, "LATE_TO_PARTY");
+        }
+        other => panic!("Expected UnexpectedMetadataValue, got {other:?}"),
+    }
+}
+
+#[test]
+fn coffee_bean_with_mismatched_mod_is_an_error() {
+    let mut json = wired_coffee_bean();
+    json["metadata"]["children"][0]["metadata"]["mod"] = Value::from("TIRED");
+
+    match parse_json(&json) {
+        Err(FeedParseError::UnexpectedMetadataValue { field, value, .. }) => {
+            assert_eq!(field, "mod");
+            assert_eq!(value, "TIRED");
+        }
+        other => panic!("Expected UnexpectedMetadataValue, got {other:?}"),
+    }
+}
@@ -0,0 +1,152 @@
+//! Tests for `PlayerRegistry`'s name history and scatter handling
+
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use fed::*;
+
+const PLAYER_ID: Uuid = Uuid::from_u128(3);
+const TEAM_ID: Uuid = Uuid::from_u128(2);
+
+fn time(minutes: i64) -> DateTime<Utc> {
+    common::created() + Duration::minutes(minutes)
+}
+
+fn game(unscatter: Option<Unscatter>) -> GameEvent {
+    GameEvent {
+        game_id: Uuid::from_u128(4),
+        away_team: Uuid::from_u128(5),
+        unscatter,
+        ..common::game(0)
+    }
+}
+
+fn event(id: u128, minutes: i64, data: FedEventData) -> FedEvent {
+    common::event(id, time(minutes), data)
+}
+
+fn lost_mod(id: u128, minutes: i64, player_name: &str) -> FedEvent {
+    event(id, minutes, FedEventData::PlayerLostMod {
+        team_id: TEAM_ID,
+        player_id: PLAYER_ID,
+        player_name: player_name.to_string(),
+        r#mod: Mod::Superallergic,
+        mod_name: Mod::Superallergic.display_name().to_string(),
+    })
+}
+
+fn returned_scattered(id: u128, minutes: i64) -> FedEvent {
+    event(id, minutes, FedEventData::ReturnFromElsewhere {
+        game: game(None),
+        player_name: "Jessica Telephone".to_string(),
+        flavor: ReturnFromElsewhereFlavor::Full {
+            team_id: TEAM_ID,
+            player_id: PLAYER_ID,
+            is_peanut: false,
+            sub_event: SubEvent::nil(),
+            time_elsewhere: TimeElsewhere::Days(3),
+            scattered: Some(Scattered {
+                scattered_name: "J-ss-ca T-l-ph-n-".to_string(),
+                sub_event: SubEvent::nil(),
+            }),
+            recongealed_differently: None,
+        },
+    })
+}
+
+fn unscattered(id: u128, minutes: i64) -> FedEvent {
+    event(id, minutes, FedEventData::PlayBall {
+        game: game(Some(Unscatter {
+            sub_event: SubEvent::nil(),
+            team_id: TEAM_ID,
+            player_id: PLAYER_ID,
+            player_name: "Jessica Telephone".to_string(),
+        })),
+    })
+}
+
+#[test]
+fn scattering_is_not_a_name_change() {
+    let events = [
+        lost_mod(1, 0, "Jessica Telephone"),
+        returned_scattered(2, 10),
+        lost_mod(3, 20, "J-ss-ca T-l-ph-n-"),
+        unscattered(4, 30),
+    ];
+    let registry = PlayerRegistry::from_events(&events);
+
+    let record = registry.player(PLAYER_ID).unwrap();
+    let names: Vec<_> = record.names.iter().map(|entry| (entry.name.as_str(), entry.kind)).collect();
+    assert_eq!(names, [
+        ("Jessica Telephone", PlayerNameKind::Canonical),
+        ("J-ss-ca T-l-ph-n-", PlayerNameKind::Scattered),
+        ("Jessica Telephone", PlayerNameKind::Canonical),
+    ]);
+    assert_eq!(record.names[1].last_event_id, Uuid::from_u128(3));
+
+    assert_eq!(registry.name_at(PLAYER_ID, time(-1)), None);
+    assert_eq!(registry.name_at(PLAYER_ID, time(5)), Some("Jessica Telephone"));
+    assert_eq!(registry.name_at(PLAYER_ID, time(25)), Some("J-ss-ca T-l-ph-n-"));
+    assert_eq!(registry.canonical_name_at(PLAYER_ID, time(25)), Some("Jessica Telephone"));
+    assert!(registry.inconsistencies().is_empty());
+}
+
+#[test]
+fn first_seen_scattered_is_reclassified() {
+    let events = [lost_mod(1, 0, "J-ss-ca T-l-ph-n-"), unscattered(2, 10)];
+    let registry = PlayerRegistry::from_events(&events);
+
+    let record = registry.player(PLAYER_ID).unwrap();
+    assert_eq!(record.names[0].kind, PlayerNameKind::Scattered);
+    assert_eq!(record.canonical_name(), Some("Jessica Telephone"));
+    assert!(registry.inconsistencies().is_empty());
+}
+
+#[test]
+fn renames_and_shared_names_are_flagged() {
+    let other_player = event(3, 20, FedEventData::PlayerLostMod {
+        team_id: TEAM_ID,
+        player_id: Uuid::from_u128(6),
+        player_name: "York Silk".to_string(),
+        r#mod: Mod::Siphon,
+        mod_name: Mod::Siphon.display_name().to_string(),
+    });
+    let events = [lost_mod(1, 0, "Jessica Telephone"), lost_mod(2, 10, "York Silk"), other_player];
+    let registry = PlayerRegistry::from_events(&events);
+
+    let inconsistencies: Vec<_> = registry.inconsistencies().iter()
+        .map(|entry| entry.inconsistency.clone())
+        .collect();
+    assert_eq!(inconsistencies, [
+        PlayerInconsistency::NameChanged {
+            player_id: PLAYER_ID,
+            previous_name: "Jessica Telephone".to_string(),
+            name: "York Silk".to_string(),
+        },
+        PlayerInconsistency::NameShared {
+            name: "York Silk".to_string(),
+            player_id: Uuid::from_u128(6),
+            other_player_id: PLAYER_ID,
+        },
+    ]);
+    assert_eq!(registry.players_named("York Silk"), [PLAYER_ID, Uuid::from_u128(6)]);
+}
+
+#[test]
+fn longest_name_prefix_allows_possessives_in_names() {
+    let events = [lost_mod(1, 0, "Sixpack Dogwalker"), event(2, 0, FedEventData::PlayerLostMod {
+        team_id: TEAM_ID,
+        player_id: Uuid::from_u128(6),
+        player_name: "Sixpack Dogwalker's Twin".to_string(),
+        r#mod: Mod::Siphon,
+        mod_name: Mod::Siphon.display_name().to_string(),
+    })];
+    let registry = PlayerRegistry::from_events(&events);
+
+    assert_eq!(registry.longest_name_prefix("Sixpack Dogwalker's Twin's mods wore off."),
+               Some("Sixpack Dogwalker's Twin"));
+    assert_eq!(registry.longest_name_prefix("Sixpack Dogwalker's mods wore off."), Some("Sixpack Dogwalker"));
+    assert_eq!(registry.longest_name_prefix("Sixpack Dogwalkers"), None);
+}
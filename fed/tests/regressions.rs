@@ -0,0 +1,36 @@
+//! Replays the regression corpus in `tests/regressions`. The `test` binary in fed_tools, given
+//! `--capture fed/tests/regressions`, saves every event that fails to parse or round-trip into
+//! `tests/regressions/<event id>/`, with `event.json` holding the feed event and `failure.txt`
+//! holding the failure. Every saved event must now parse and round-trip exactly, so that fixed
+//! events stay fixed.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use common::check_round_trip;
+
+#[test]
+fn regressions_round_trip() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("regressions");
+    let mut event_dirs: Vec<_> = fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("Couldn't read regression directory {}: {err}", dir.display()))
+        .map(|entry| entry.expect("Couldn't read regression directory entry").path())
+        .filter(|path| path.is_dir())
+        .collect();
+    event_dirs.sort();
+
+    // Check every event before failing, so one run reports everything that's still broken
+    let failures: Vec<_> = event_dirs.into_iter()
+        .filter_map(|event_dir| {
+            let err = check_round_trip(&event_dir.join("event.json")).err()?;
+            let original_failure = fs::read_to_string(event_dir.join("failure.txt"))
+                .map(|failure| failure.lines().next().unwrap_or_default().to_string())
+                .unwrap_or_else(|_| "unknown".to_string());
+            Some(format!("{} (originally {original_failure}): {err}", event_dir.display()))
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{} regression(s) failed:\n\n{}", failures.len(), failures.join("\n\n"));
+}
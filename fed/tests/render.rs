@@ -0,0 +1,127 @@
+//! Tests for `EventRenderer`, using single hand-built events
+
+mod common;
+
+use uuid::Uuid;
+
+use fed::*;
+
+use common::{created, game, AWAY_TEAM, GAME_ID, HOME_TEAM};
+
+const BATTER: Uuid = Uuid::from_u128(4);
+const RUNNER: Uuid = Uuid::from_u128(5);
+
+fn event(data: FedEventData) -> FedEvent {
+    common::event(100, created(), data)
+}
+
+fn renderer() -> EventRenderer {
+    EventRenderer::new(LinkTemplates::new("/player/{id}", "/team/{id}"))
+}
+
+fn walk() -> FedEvent {
+    event(FedEventData::Walk {
+        game: game(10),
+        batter_name: "York Silk".to_string(),
+        batter_id: BATTER,
+        scores: Scores {
+            scores: vec![ScoringPlayer {
+                player_id: RUNNER,
+                player_name: "Jessica Telephone".to_string(),
+                item_damage: None,
+                attraction: None,
+            }],
+            free_refills: vec![],
+        },
+        base_instincts: None,
+        batter_item_damage: None,
+        stopped_inhabiting: None,
+        special_reason: None,
+    })
+}
+
+#[test]
+fn players_are_linked_by_their_uuid() {
+    let renderer = EventRenderer::new(LinkTemplates::new("/player/{id}", "/team/{id}").with_game("/game/{id}"));
+
+    assert_eq!(
+        renderer.markdown(&walk()),
+        format!("[York Silk](/player/{BATTER}) draws a walk.  \n[Jessica Telephone](/player/{RUNNER}) scores! ([game](/game/{GAME_ID}))"),
+    );
+    assert_eq!(
+        renderer.html(&walk()),
+        format!(concat!(
+            r#"<a class="player" href="/player/{BATTER}">York Silk</a> draws a walk.<br>"#, "\n",
+            r#"<a class="player" href="/player/{RUNNER}">Jessica Telephone</a> scores! "#,
+            r#"<a class="game" href="/game/{GAME_ID}">(game)</a>"#,
+        ), BATTER = BATTER, RUNNER = RUNNER, GAME_ID = GAME_ID),
+    );
+}
+
+#[test]
+fn names_without_a_uuid_are_not_linked() {
+    let batter_up = event(FedEventData::BatterUp {
+        game: game(10),
+        batter_name: "York Silk".to_string(),
+        team_nickname: "Tigers".to_string(),
+        wielding_item: None,
+        inhabiting: None,
+        is_repeating: false,
+    });
+
+    assert_eq!(renderer().markdown(&batter_up), "York Silk batting for the Tigers.");
+}
+
+#[test]
+fn game_end_links_both_teams() {
+    let game_end = event(FedEventData::GameEnd {
+        game: game(10),
+        winner_id: AWAY_TEAM,
+        winning_team_name: "Boston Flowers".to_string(),
+        winning_team_score: 4.,
+        losing_team_name: "Hades Tigers".to_string(),
+        losing_team_score: 2.,
+        temp_stolen_player_returned: None,
+    });
+
+    assert_eq!(
+        renderer().html(&game_end),
+        format!(r#"<a class="team" href="/team/{AWAY_TEAM}">Boston Flowers</a> 4, <a class="team" href="/team/{HOME_TEAM}">Hades Tigers</a> 2"#),
+    );
+}
+
+#[test]
+fn weather_is_marked_in_the_events_it_caused() {
+    let mister = event(FedEventData::PeanutMister {
+        game: game(10),
+        player_id: BATTER,
+        player_name: "York Silk".to_string(),
+        superallergy: None,
+    });
+
+    assert_eq!(
+        renderer().html(&mister),
+        format!(concat!(
+            r#"The <span class="weather weather-peanuts">Peanut</span> Mister activates!<br>"#, "\n",
+            r#"<a class="player" href="/player/{BATTER}">York Silk</a> has been cured of their peanut allergy!"#,
+        ), BATTER = BATTER),
+    );
+
+    // Superyummy players love Peanuts whatever the weather
+    let superyummy = event(FedEventData::SuperyummyGameStart {
+        game: game(10),
+        toggle: TogglePerforming {
+            player_id: BATTER,
+            team_id: HOME_TEAM,
+            player_name: "York Silk".to_string(),
+            is_overperforming: true,
+            is_first_proc: true,
+            sub_event: SubEvent::nil(),
+        },
+    });
+
+    assert_eq!(
+        renderer().html(&superyummy),
+        format!(r#"<a class="player" href="/player/{BATTER}">York Silk</a> loves Peanuts."#),
+    );
+}
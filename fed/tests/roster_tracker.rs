@@ -0,0 +1,97 @@
+//! Tests for `RosterTracker`, using short sequences of hand-built events
+
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use fed::*;
+
+const TEAM_A: Uuid = Uuid::from_u128(1);
+const TEAM_B: Uuid = Uuid::from_u128(2);
+const PLAYER_A: Uuid = Uuid::from_u128(3);
+const PLAYER_B: Uuid = Uuid::from_u128(4);
+const OTHER_PLAYER: Uuid = Uuid::from_u128(5);
+
+fn time(minutes: i64) -> DateTime<Utc> {
+    common::created() + Duration::minutes(minutes)
+}
+
+fn event(id: u128, data: FedEventData) -> FedEvent {
+    common::event(id, time(id as i64), data)
+}
+
+fn feedback_player(team_id: Uuid, player_id: Uuid, player_name: &str) -> FeedbackPlayerData {
+    FeedbackPlayerData {
+        team_id,
+        team_nickname: "Team".to_string(),
+        player_id,
+        player_name: player_name.to_string(),
+        location: ActivePositionType::Lineup,
+    }
+}
+
+fn feedback(id: u128) -> FedEvent {
+    event(id, FedEventData::Feedback {
+        game: GameEvent {
+            game_id: Uuid::from_u128(10),
+            home_team: TEAM_A,
+            away_team: TEAM_B,
+            play: 10,
+            unscatter: None,
+            attractor_secret_base: None,
+        },
+        players: (feedback_player(TEAM_A, PLAYER_A, "Player A"), feedback_player(TEAM_B, PLAYER_B, "Player B")),
+        position_type: ActivePositionType::Lineup,
+        sub_event: SubEvent { id: Uuid::from_u128(id + 100), created: time(id as i64), nuts: 0 },
+    })
+}
+
+fn localized(id: u128, team_id: Uuid, player_id: Uuid) -> FedEvent {
+    event(id, FedEventData::PlayerLocalized {
+        team_id,
+        team_nickname: "Team".to_string(),
+        player_id,
+        player_name: "Localized Player".to_string(),
+        location: ActivePositionType::Rotation,
+    })
+}
+
+fn tracker() -> RosterTracker {
+    let mut tracker = RosterTracker::new();
+    tracker.set_roster(TEAM_A, TeamRoster::new(vec![OTHER_PLAYER, PLAYER_A], vec![], vec![], vec![]));
+    tracker.set_roster(TEAM_B, TeamRoster::new(vec![PLAYER_B], vec![], vec![], vec![]));
+    tracker
+}
+
+fn apply(tracker: &mut RosterTracker, events: &[FedEvent]) {
+    for event in events {
+        tracker.apply(event).unwrap_or_else(|err| panic!("Couldn't apply {:?}: {err}", event.data));
+    }
+}
+
+#[test]
+fn feedback_trades_players_between_teams() {
+    let mut tracker = tracker();
+    apply(&mut tracker, &[feedback(1)]);
+
+    assert_eq!(tracker.current_roster(TEAM_A).unwrap().lineup, [OTHER_PLAYER, PLAYER_B]);
+    assert_eq!(tracker.current_roster(TEAM_B).unwrap().lineup, [PLAYER_A]);
+    assert_eq!(tracker.team_of_player_at(PLAYER_A, time(0)), Some(TEAM_A));
+    assert_eq!(tracker.team_of_player_at(PLAYER_A, time(1)), Some(TEAM_B));
+    assert_eq!(tracker.team_of_player_at(PLAYER_B, time(1)), Some(TEAM_A));
+    assert!(tracker.inconsistencies().is_empty(), "{:?}", tracker.inconsistencies());
+}
+
+#[test]
+fn player_on_two_teams_belongs_to_the_one_that_gained_them_last() {
+    let mut tracker = tracker();
+    // The feed never says that OTHER_PLAYER left team A, so the tracker has them on both teams
+    apply(&mut tracker, &[localized(1, TEAM_B, OTHER_PLAYER)]);
+
+    assert!(tracker.current_roster(TEAM_A).unwrap().contains(OTHER_PLAYER));
+    assert_eq!(tracker.current_roster(TEAM_B).unwrap().rotation, [OTHER_PLAYER]);
+    assert_eq!(tracker.team_of_player_at(OTHER_PLAYER, time(0)), Some(TEAM_A));
+    assert_eq!(tracker.team_of_player_at(OTHER_PLAYER, time(1)), Some(TEAM_B));
+    assert_eq!(tracker.team_of_player_at(Uuid::from_u128(99), time(1)), None);
+}
@@ -0,0 +1,2384 @@
+//! Round-trip tests for generated events. Every `FedEvent` these strategies produce must come back
+//! unchanged from `parse_feed_event(&event.into_feed_event())`, which catches builder/parser
+//! asymmetries without needing the full feed dump. Gameplay events must also come back from
+//! `parse_game_update` on the game update they'd produce, with the same text.
+//!
+//! The strategies produce every combination that real events actually have. The few places where
+//! they're narrower than the types allow have a comment saying which real events they follow.
+
+use chrono::{DateTime, TimeZone, Utc};
+use eventually_api::EventMetadata;
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence};
+use uuid::Uuid;
+
+use fed::*;
+
+// None of these end in "s" (the possessive would be ambiguous) and none of them contain
+// punctuation, digits, or phrases that appear in event text
+const PLAYER_NAMES: &[&str] = &[
+    "Jessica Telephone", "York Silk", "Parker Macmillan", "Aldon Cashmoney", "Chorby Soul",
+    "Don Mitchell", "Sutton Dreamy", "Nandy Fantastic", "Wyatt Quitter", "Comfort Septemberish",
+    "Mooney Doctor", "Baby Triumphant", "Goodwin Morin", "Lenny Spruce", "Silvaire Roadhouse",
+    "Summers Pony", "Patty Fox", "Castillo Turner", "Emmett Internet", "Tot Fox",
+    "PolkaDot Patterson", "Peanut Holloway", "Richmond Harrison", "Cory Twelve", "Alyssa Harrell",
+];
+
+const TEAM_NICKNAMES: &[&str] = &[
+    "Fridays", "Moist Talkers", "Lovers", "Jazz Hands", "Sunbeams", "Tigers", "Wild Wings",
+    "Flowers", "Millennials", "Pies", "Garages", "Dale", "Lift", "Firefighters", "Steaks", "Magic",
+    "Breath Mints", "Spies", "Shoe Thieves", "Tacos", "Georgias", "Worms", "Crabs", "Mechanics",
+];
+
+const TEAM_NAMES: &[&str] = &[
+    "Hawai'i Fridays", "Canada Moist Talkers", "San Francisco Lovers", "Seattle Garages",
+    "Breckenridge Jazz Hands", "Hellmouth Sunbeams", "Hades Tigers", "Mexico City Wild Wings",
+    "Boston Flowers", "New York Millennials", "Philly Pies", "Miami Dale", "Tokyo Lift",
+    "Chicago Firefighters", "Dallas Steaks", "Yellowstone Magic", "Kansas City Breath Mints",
+    "Houston Spies", "Charleston Shoe Thieves", "LA Unlimited Tacos", "Atlantis Georgias",
+    "Ohio Worms", "Baltimore Crabs", "Core Mechanics",
+];
+
+// Item name and whether it's plural
+const ITEMS: &[(&str, bool)] = &[
+    ("Bat", false), ("Cap", false), ("Glove", false), ("Necklace", false), ("Ring", false),
+    ("Parasitic Bat", false), ("Chaotic Jersey of Vitality", false), ("Fire Socks", true),
+    ("Cryogenic Shoes", true), ("Sunglasses", true), ("Inflatable Sunglasses", true),
+];
+
+// Batting attributes and their ids, for Parasite
+const BATTING_ATTRIBUTES: &[(&str, i64)] = &[
+    ("Tragicness", 0), ("Buoyancy", 1), ("Thwackability", 2), ("Moxie", 3), ("Divinity", 4),
+    ("Musclitude", 5), ("Patheticism", 6), ("Martyrdom", 7),
+];
+
+const COFFEE_ROASTS: &[&str] = &["Light", "Medium", "Dark", "Espresso", "Decaf"];
+
+const COFFEE_NOTES: &[&str] = &[
+    "notes of Cinnamon and Plum", "notes of Milk Chocolate and Nutmeg",
+    "notes of Blueberry and Honey", "notes of Toasted Almond and Caramel",
+];
+
+const COFFEE_INGREDIENTS: &[&str] = &["Cinnamon", "Plum", "Milk Chocolate", "Nutmeg", "Honey", "Caramel"];
+
+fn uuid() -> impl Strategy<Value = Uuid> {
+    any::<[u8; 16]>().prop_map(|bytes| uuid::Builder::from_random_bytes(bytes).into_uuid())
+}
+
+fn created() -> impl Strategy<Value = DateTime<Utc>> {
+    // Roughly the Expansion Era
+    (1_614_000_000_000i64..1_628_000_000_000).prop_map(|ms| Utc.timestamp_millis_opt(ms).unwrap())
+}
+
+fn phase() -> impl Strategy<Value = SimPhase> {
+    select(vec![
+        SimPhase::Earlseason, SimPhase::Earlsiesta, SimPhase::Midseason, SimPhase::Latesiesta,
+        SimPhase::Lateseason, SimPhase::Earlpostseason, SimPhase::Latepostseason,
+    ])
+}
+
+fn player_name() -> impl Strategy<Value = String> {
+    select(PLAYER_NAMES).prop_map(str::to_string)
+}
+
+/// `n` different player names, in random order
+fn player_names(n: usize) -> impl Strategy<Value = Vec<String>> {
+    subsequence(PLAYER_NAMES, n)
+        .prop_shuffle()
+        .prop_map(|names| names.into_iter().map(str::to_string).collect())
+}
+
+fn team_nickname() -> impl Strategy<Value = String> {
+    select(TEAM_NICKNAMES).prop_map(str::to_string)
+}
+
+fn team_name() -> impl Strategy<Value = String> {
+    select(TEAM_NAMES).prop_map(str::to_string)
+}
+
+/// A rating on the 0-1 scale, with few enough digits that it survives a trip through JSON
+fn rating() -> impl Strategy<Value = f64> {
+    (0..=1000u32).prop_map(|n| n as f64 / 1000.)
+}
+
+fn sub_event() -> impl Strategy<Value = SubEvent> {
+    (uuid(), created(), 0..20i32).prop_map(|(id, created, nuts)| SubEvent { id, created, nuts })
+}
+
+prop_compose! {
+    fn unscatter()(sub_event in sub_event(), team_id in uuid(), player_id in uuid(),
+                   player_name in player_name()) -> Unscatter {
+        Unscatter { sub_event, team_id, player_id, player_name }
+    }
+}
+
+prop_compose! {
+    fn game()(game_id in uuid(), home_team in uuid(), away_team in uuid(), play in 0..400i64,
+              unscatter in option::weighted(0.1, unscatter()),
+              attractor_secret_base in option::weighted(0.1, player_info())) -> GameEvent {
+        GameEvent { game_id, home_team, away_team, play, unscatter, attractor_secret_base }
+    }
+}
+
+fn pitch() -> impl Strategy<Value = GamePitch> {
+    option::weighted(0.2, player_name()).prop_map(|double_strike| GamePitch { double_strike })
+}
+
+prop_compose! {
+    fn item_damage()(item in select(ITEMS), durability in 1..=5i64, health in 0..5i64,
+                     item_id in uuid(), player_item_rating_before in rating(),
+                     player_item_rating_after in rating(), player_rating in rating(),
+                     team_id in uuid(), player_id in uuid(),
+                     item_mods in item_mods(),
+                     sub_event in sub_event()) -> ItemDamaged {
+        let (item_name, plural) = item;
+        let health = health % durability;
+        ItemDamaged {
+            item_id,
+            item_name: item_name.to_string(),
+            // Broken items don't say whether they're plural
+            item_name_plural: if health == 0 { None } else { Some(plural) },
+            item_mods,
+            durability,
+            health,
+            player_item_rating_before,
+            player_item_rating_after,
+            player_rating,
+            team_id,
+            player_id,
+            sub_event,
+        }
+    }
+}
+
+fn named_item_damage() -> impl Strategy<Value = (String, ItemDamaged)> {
+    (player_name(), item_damage())
+}
+
+prop_compose! {
+    fn free_refill()(sub_event in sub_event(), player_name in player_name(),
+                     player_id in uuid(), team_id in option::of(uuid())) -> FreeRefill {
+        FreeRefill { sub_event, player_name, player_id, team_id }
+    }
+}
+
+prop_compose! {
+    fn attraction()(team_nickname in team_nickname(), team_id in uuid(),
+                    sub_event in sub_event()) -> Attraction {
+        Attraction { team_nickname, team_id, sub_event }
+    }
+}
+
+prop_compose! {
+    fn attraction_with_player()(team_nickname in team_nickname(), team_id in uuid(),
+                                player_name in player_name(), player_id in uuid(),
+                                sub_event in sub_event()) -> AttractionWithPlayer {
+        AttractionWithPlayer { team_nickname, team_id, player_name, player_id, sub_event }
+    }
+}
+
+prop_compose! {
+    fn scoring_player()(player_id in uuid(), player_name in player_name(),
+                        item_damage in option::weighted(0.2, item_damage()),
+                        attraction in option::weighted(0.1, attraction())) -> ScoringPlayer {
+        ScoringPlayer { player_id, player_name, item_damage, attraction }
+    }
+}
+
+fn scores() -> impl Strategy<Value = Scores> {
+    vec(scoring_player(), 0..=3)
+        .prop_flat_map(|scores| {
+            // Free refills are only used when someone scores
+            let max_refills = if scores.is_empty() { 0 } else { 2 };
+            (Just(scores), vec(free_refill(), 0..=max_refills))
+        })
+        .prop_map(|(scores, free_refills)| Scores { scores, free_refills })
+}
+
+/// Scores where none of the scorers has one of `names`. Players who can't score on an event, like
+/// the fielder, would make the damage lines ambiguous.
+fn scores_excluding(names: Vec<String>) -> impl Strategy<Value = Scores> {
+    scores().prop_filter("A player who can't score scored", move |scores| {
+        scores.scores.iter().all(|score| !names.contains(&score.player_name))
+    })
+}
+
+prop_compose! {
+    fn stopped_inhabiting()(sub_event in sub_event(), inhabiting_player_name in player_name(),
+                            inhabiting_player_id in uuid(),
+                            inhabiting_player_team_id in option::of(uuid())) -> StoppedInhabiting {
+        StoppedInhabiting { sub_event, inhabiting_player_name, inhabiting_player_id, inhabiting_player_team_id }
+    }
+}
+
+/// Sets the Inhabiting player to the batter, for events that only parse the batter's Inhabiting
+fn inhabited_by(stopped_inhabiting: Option<StoppedInhabiting>, batter_id: Uuid) -> Option<StoppedInhabiting> {
+    stopped_inhabiting.map(|si| StoppedInhabiting { inhabiting_player_id: batter_id, ..si })
+}
+
+prop_compose! {
+    fn mod_change()(sub_event in sub_event(), team_id in uuid()) -> ModChangeSubEvent {
+        ModChangeSubEvent { sub_event, team_id }
+    }
+}
+
+prop_compose! {
+    fn mod_change_with_player()(sub_event in sub_event(), team_id in uuid(),
+                                player_id in uuid()) -> ModChangeSubEventWithPlayer {
+        ModChangeSubEventWithPlayer { sub_event, team_id, player_id }
+    }
+}
+
+prop_compose! {
+    fn mod_change_with_named_player()(sub_event in sub_event(), team_id in uuid(),
+                                      player_id in uuid(),
+                                      player_name in player_name()) -> ModChangeSubEventWithNamedPlayer {
+        ModChangeSubEventWithNamedPlayer { sub_event, team_id, player_id, player_name }
+    }
+}
+
+fn spicy_status() -> impl Strategy<Value = SpicyStatus> {
+    prop_oneof![
+        3 => Just(SpicyStatus::None),
+        1 => Just(SpicyStatus::HeatingUp),
+        1 => option::of(mod_change()).prop_map(SpicyStatus::RedHot),
+    ]
+}
+
+prop_compose! {
+    fn batter_debt()(batter_id in uuid(), fielder_id in uuid(),
+                     sub_event in option::of(mod_change())) -> BatterDebt {
+        BatterDebt { batter_id, fielder_id, sub_event }
+    }
+}
+
+prop_compose! {
+    fn parasite()(names in player_names(2), attribute in select(BATTING_ATTRIBUTES),
+                  batter_team_id in uuid(), batter_id in uuid(), pitcher_team_id in uuid(),
+                  pitcher_id in uuid(), batter_rating_before in rating(),
+                  batter_rating_after in rating(), batter_sub_event in sub_event(),
+                  pitcher_rating_before in rating(), pitcher_rating_after in rating(),
+                  pitcher_sub_event in sub_event()) -> Parasite {
+        let (attribute_name, attribute_id) = attribute;
+        Parasite {
+            batter_team_id,
+            batter_id,
+            batter_name: names[0].clone(),
+            pitcher_team_id,
+            pitcher_id,
+            pitcher_name: names[1].clone(),
+            attribute_name: attribute_name.to_string(),
+            attribute_id,
+            batter_rating_before,
+            batter_rating_after,
+            batter_sub_event,
+            pitcher_rating_before,
+            pitcher_rating_after,
+            pitcher_sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn player_stat_change()(team_id in uuid(), player_id in uuid(), player_name in player_name(),
+                            rating_before in rating(), rating_after in rating(),
+                            sub_event in sub_event()) -> PlayerStatChange {
+        PlayerStatChange { team_id, player_id, player_name, rating_before, rating_after, sub_event }
+    }
+}
+
+prop_compose! {
+    fn player_info()(player_id in uuid(), player_name in player_name()) -> PlayerInfo {
+        PlayerInfo { player_id, player_name }
+    }
+}
+
+fn position_type() -> impl Strategy<Value = PositionType> {
+    select(vec![PositionType::Lineup, PositionType::Rotation, PositionType::Bench, PositionType::Bullpen])
+}
+
+fn active_position_type() -> impl Strategy<Value = ActivePositionType> {
+    select(vec![ActivePositionType::Lineup, ActivePositionType::Rotation])
+}
+
+fn mod_duration() -> impl Strategy<Value = ModDuration> {
+    select(vec![ModDuration::Permanent, ModDuration::Seasonal, ModDuration::Weekly, ModDuration::Game])
+}
+
+fn base() -> impl Strategy<Value = Base> {
+    select(vec![Base::First, Base::Second, Base::Third, Base::Fourth, Base::Fifth])
+}
+
+prop_compose! {
+    fn player_moved_teams()(player_id in uuid(), player_name in player_name(),
+                            location in position_type(), previous_team_id in uuid(),
+                            previous_team_nickname in team_nickname(), new_team_id in uuid(),
+                            new_team_nickname in team_nickname(),
+                            sub_event in sub_event()) -> PlayerMovedTeams {
+        PlayerMovedTeams {
+            player_id,
+            player_name,
+            location,
+            previous_team_id,
+            previous_team_nickname,
+            new_team_id,
+            new_team_nickname,
+            sub_event,
+        }
+    }
+}
+
+fn fed_event(data: impl Strategy<Value = FedEventData>) -> impl Strategy<Value = FedEvent> {
+    // Seasons on both sides of s15d3, where the leading space on item damage lines went away
+    (uuid(), created(), 11..24i32, 0..110i32, phase(), 0..1000i32, data)
+        .prop_map(|(id, created, season, day, phase, nuts, data)| FedEvent {
+            id,
+            created,
+            sim: "thisidisstaticyo".to_string(),
+            tournament: -1,
+            season,
+            day,
+            phase,
+            nuts,
+            data,
+        })
+}
+
+// Copied from the parser, which only recognizes tarot mod and item changes by their ids
+const TAROT_EVENT_IDS: &[&str] = &[
+    "0d96d9ed-8e40-47ca-a543-b27518b276ef", "6dd0204e-213b-4798-9fad-e042a232edc6",
+    "760ee47b-7698-4216-9612-e67c13ba12ef", "17df7d13-41df-4caf-af56-da75577a43e8",
+    "6a9e3ad7-f6a7-437c-9bd5-22b602a32cc3", "b0457046-0e88-482a-b3b4-aed27c598a5c",
+    "77df7273-e3c3-49b1-9ce5-4baec629d75a", "9cd56488-5ee2-436e-9196-37a76593cdaf",
+    "1bb3708a-a43f-472b-a7df-a4b2f52c313f", "00bb210e-d0c6-41bf-a6f7-01de9070582a",
+    "4872996e-f641-455b-bf45-cb0f0c4de8cf", "91079f04-4257-479f-8884-1752831ea7b8",
+    "ec493d47-8c48-46ef-9f00-94394630deb9", "015262ca-5903-4960-82af-d9c682255796",
+];
+
+fn tarot_event(data: impl Strategy<Value = FedEventData>) -> impl Strategy<Value = FedEvent> {
+    (fed_event(data), select(TAROT_EVENT_IDS))
+        .prop_map(|(event, id)| FedEvent { id: Uuid::parse_str(id).unwrap(), ..event })
+}
+
+/// The reason the parser gives a Special event when no other field shows why it's Special
+fn hidden_special_reason(is_special: bool, shown: bool, reason: SpecialReason) -> Option<SpecialReason> {
+    (is_special && !shown).then_some(reason)
+}
+
+fn scoring_reason(scores: &Scores) -> SpecialReason {
+    if scores.scores.is_empty() { SpecialReason::Unknown } else { SpecialReason::WiredOrTired }
+}
+
+fn assert_round_trips(event: FedEvent) -> Result<(), TestCaseError> {
+    let feed_event = event.clone().into_feed_event();
+    let parsed = parse_feed_event(&feed_event)
+        .map_err(|err| TestCaseError::fail(format!("{err} while parsing {:?}", feed_event.description)))?;
+    prop_assert_eq!(&event.to_string(), &feed_event.description);
+
+    let differences = event.diff(&parsed);
+    prop_assert!(differences.is_empty(), "{:?} parsed differently:\n{}", feed_event.description,
+                 differences.iter().map(|difference| difference.to_string()).collect::<Vec<_>>().join("\n"));
+
+    Ok(())
+}
+
+/// The game update that would follow `event`, with the batter (if the event has one) as the home
+/// batter
+fn game_update_for(event: &FedEvent) -> serde_json::Value {
+    let data = serde_json::to_value(&event.data).unwrap();
+    let mut update = serde_json::json!({
+        "id": data["gameId"],
+        "homeTeam": data["homeTeam"],
+        "awayTeam": data["awayTeam"],
+        "season": event.season,
+        "day": event.day,
+        "playCount": data["play"].as_i64().unwrap() + 1,
+        "lastUpdate": event.last_update(),
+    });
+    if let Some(batter_id) = data.get("batterId") {
+        update["homeBatterName"] = data["batterName"].clone();
+        update["homeBatter"] = batter_id.clone();
+    }
+    if let FedEventData::LetsGo { weather, .. } = &event.data {
+        update["weather"] = serde_json::Value::from(i32::from(*weather));
+    }
+    update
+}
+
+fn assert_game_update_parses(event: FedEvent) -> Result<(), TestCaseError> {
+    let update = game_update_for(&event);
+    let parsed = parse_game_update(&update, None)
+        .map_err(|err| TestCaseError::fail(format!("{err} while parsing game update {:?}", update["lastUpdate"])))?;
+
+    prop_assert_eq!(parsed.as_ref(), event.data.as_ref());
+    let parsed_json = serde_json::to_value(&parsed).unwrap();
+    prop_assert_eq!(parsed_json.get("batterId"), update.get("homeBatter"));
+    let parsed_event = FedEvent { data: parsed, ..event.clone() };
+    prop_assert_eq!(parsed_event.last_update(), event.last_update());
+
+    Ok(())
+}
+
+fn being_speech() -> impl Strategy<Value = FedEventData> {
+    let beings = vec![
+        Being::TheShelledOne, Being::TheMonitor, Being::TheCoin, Being::TheReader,
+        Being::TheMicrophone, Being::Lootcrates, Being::Namerifeht,
+    ];
+    let messages = vec![
+        "I HAVE WATCHED YOU CLOSELY.", "The Coin is happy to see you.",
+        "The Reader has a story to tell.", "...",
+    ];
+    (select(beings), select(messages))
+        .prop_map(|(being, message)| FedEventData::BeingSpeech { being, message: message.to_string() })
+}
+
+prop_compose! {
+    fn lets_go()(game in game(), weather in select(vec![
+                     Weather::Sun2, Weather::SolarEclipse, Weather::Blooddrain, Weather::Peanuts,
+                     Weather::Birds, Weather::Feedback, Weather::Reverb, Weather::BlackHole,
+                     Weather::Coffee, Weather::Coffee2, Weather::Coffee3s, Weather::Flooding,
+                     Weather::Salmon, Weather::PolarityPlus, Weather::PolarityMinus,
+                     Weather::SumSun, Weather::Night,
+                 ]),
+                 stadium_id in option::of(uuid())) -> FedEventData {
+        FedEventData::LetsGo { game, weather, stadium_id }
+    }
+}
+
+prop_compose! {
+    fn play_ball()(game in game()) -> FedEventData {
+        FedEventData::PlayBall { game }
+    }
+}
+
+prop_compose! {
+    fn team_performing_changed()(team_nickname in team_nickname(), team_id in uuid(),
+                                 source_mod_id in select(vec![SubseasonalMod::Middling, SubseasonalMod::LateToParty, SubseasonalMod::EarlyToParty]),
+                                 was_added in any::<bool>(),
+                                 sub_event in sub_event()) -> TeamPerformingChanged {
+        TeamPerformingChanged {
+            team_nickname,
+            team_id,
+            source_mod_name: source_mod_id.source_mod().display_name().to_string(),
+            source_mod_id,
+            was_added,
+            sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn half_inning_start()(game in game(), top_of_inning in any::<bool>(), inning in 1..12i32,
+                           batting_team_name in team_name(),
+                           subseasonal_mod_effects in vec(team_performing_changed(), 0..=2)) -> FedEventData {
+        FedEventData::HalfInningStart { game, top_of_inning, inning, batting_team_name, subseasonal_mod_effects }
+    }
+}
+
+prop_compose! {
+    fn inhabiting()(sub_event in option::of(sub_event()), inhabited_player_name in player_name(),
+                    inhabited_player_id in uuid(), inhabiting_player_id in uuid(),
+                    inhabiting_player_team_id in uuid()) -> Inhabiting {
+        Inhabiting {
+            // The team id is only stored on the sub-event
+            inhabiting_player_team_id: sub_event.map(|_| inhabiting_player_team_id),
+            sub_event,
+            inhabited_player_name,
+            inhabited_player_id,
+            inhabiting_player_id,
+        }
+    }
+}
+
+prop_compose! {
+    fn batter_up()(game in game(), batter_name in player_name(), team_nickname in team_nickname(),
+                   wielding_item in option::weighted(0.1, select(vec![
+                       "the Iffey Jr.", "a Golden Rainbow Bat", "Literally a Pipe",
+                   ])),
+                   inhabiting in option::weighted(0.1, inhabiting()),
+                   is_repeating in proptest::bool::weighted(0.1)) -> FedEventData {
+        FedEventData::BatterUp {
+            game,
+            batter_name,
+            team_nickname,
+            wielding_item: wielding_item.map(str::to_string),
+            inhabiting,
+            is_repeating,
+        }
+    }
+}
+
+prop_compose! {
+    fn toggle_performing()(player_id in uuid(), team_id in uuid(), player_name in player_name(),
+                           is_overperforming in any::<bool>(), is_first_proc in any::<bool>(),
+                           sub_event in sub_event()) -> TogglePerforming {
+        TogglePerforming { player_id, team_id, player_name, is_overperforming, is_first_proc, sub_event }
+    }
+}
+
+prop_compose! {
+    fn superyummy_game_start()(game in game(), toggle in toggle_performing()) -> FedEventData {
+        FedEventData::SuperyummyGameStart { game, toggle }
+    }
+}
+
+prop_compose! {
+    fn echoed_superyummy_game_start()(game in game(), player_name in player_name(),
+                                      peanuts_present in any::<bool>()) -> FedEventData {
+        FedEventData::EchoedSuperyummyGameStart { game, player_name, peanuts_present }
+    }
+}
+
+prop_compose! {
+    fn ball()(game in game(), balls in 1..4i32, strikes in 0..3i32,
+              batter_item_damage in option::weighted(0.2, named_item_damage())) -> FedEventData {
+        FedEventData::Ball { game, balls, strikes, batter_item_damage }
+    }
+}
+
+prop_compose! {
+    fn foul_ball()(game in game(), pitch in pitch(), balls in 0..4i32, strikes in 1..3i32,
+                   batter_item_damage in option::weighted(0.2, named_item_damage()),
+                   birds in option::weighted(0.1, 1..300i32)) -> FedEventData {
+        FedEventData::FoulBall { game, pitch, balls, strikes, batter_item_damage, birds }
+    }
+}
+
+fn strike() -> impl Strategy<Value = FedEventData> {
+    (0..3u8, game(), pitch(), 0..4i32, 1..3i32, option::weighted(0.2, named_item_damage()))
+        .prop_map(|(strike_type, game, pitch, balls, strikes, pitcher_item_damage)| match strike_type {
+            0 => FedEventData::StrikeSwinging { game, pitch, balls, strikes, pitcher_item_damage },
+            1 => FedEventData::StrikeLooking { game, pitch, balls, strikes, pitcher_item_damage },
+            _ => FedEventData::StrikeFlinching { game, pitch, balls, strikes, pitcher_item_damage },
+        })
+}
+
+prop_compose! {
+    fn flyout()(names in player_names(3))
+               (scores in scores_excluding(names.clone()), names in Just(names), game in game(),
+                pitch in pitch(),
+                stopped_inhabiting in option::weighted(0.1, stopped_inhabiting()),
+                cooled_off in option::weighted(0.1, mod_change_with_player()),
+                is_special in proptest::bool::weighted(0.1),
+                batter_debt in option::weighted(0.1, batter_debt()),
+                batter_item_damage in option::weighted(0.2, item_damage()),
+                fielder_item_damage in option::weighted(0.2, item_damage()),
+                other_player_item_damage in option::weighted(0.1, item_damage()),
+                parasite in option::weighted(0.1, parasite())) -> FedEventData {
+        FedEventData::Flyout {
+            special_reason: hidden_special_reason(is_special, pitch.double_strike.is_some() ||
+                scores.used_refill() || cooled_off.is_some() || batter_debt.is_some(), scoring_reason(&scores)),
+            game,
+            pitch,
+            batter_name: names[0].clone(),
+            fielder_name: names[1].clone(),
+            scores,
+            stopped_inhabiting,
+            cooled_off,
+            batter_debt,
+            batter_item_damage,
+            fielder_item_damage,
+            other_player_item_damage: other_player_item_damage.map(|damage| (names[2].clone(), damage)),
+            parasite,
+        }
+    }
+}
+
+prop_compose! {
+    fn ground_out()(names in player_names(3))
+                   (scores in scores_excluding(names.clone()), names in Just(names), game in game(),
+                    pitch in pitch(),
+                    stopped_inhabiting in option::weighted(0.1, stopped_inhabiting()),
+                    cooled_off in option::weighted(0.1, mod_change_with_player()),
+                    is_special in proptest::bool::weighted(0.1),
+                    batter_debt in option::weighted(0.1, batter_debt()),
+                    pitcher_item_damage in option::weighted(0.2, item_damage()),
+                    batter_item_damage in option::weighted(0.2, item_damage()),
+                    fielder_item_damage in option::weighted(0.2, item_damage())) -> FedEventData {
+        FedEventData::GroundOut {
+            special_reason: hidden_special_reason(is_special, pitch.double_strike.is_some() ||
+                scores.used_refill() || cooled_off.is_some() || batter_debt.is_some(), scoring_reason(&scores)),
+            game,
+            pitch,
+            batter_name: names[0].clone(),
+            fielder_name: names[1].clone(),
+            scores,
+            stopped_inhabiting,
+            cooled_off,
+            batter_debt,
+            batter_item_damage,
+            pitcher_item_damage: pitcher_item_damage.map(|damage| (names[2].clone(), damage)),
+            fielder_item_damage,
+        }
+    }
+}
+
+prop_compose! {
+    fn fielders_choice()(names in player_names(4))
+                        (scores in scores_excluding(names.clone()), names in Just(names),
+                         game in game(), pitch in pitch(), out_at_base in base(),
+                         stopped_inhabiting in option::weighted(0.1, stopped_inhabiting()),
+                         cooled_off in option::weighted(0.1, mod_change_with_player()),
+                         is_special in proptest::bool::weighted(0.1),
+                         damages in vec(item_damage(), 0..=2)) -> FedEventData {
+        FedEventData::FieldersChoice {
+            special_reason: hidden_special_reason(is_special, pitch.double_strike.is_some() ||
+                scores.used_refill() || cooled_off.is_some(), scoring_reason(&scores)),
+            game,
+            pitch,
+            batter_name: names[0].clone(),
+            runner_out_name: names[1].clone(),
+            out_at_base,
+            scores,
+            stopped_inhabiting,
+            cooled_off,
+            damaged_items: names[2..].iter().cloned().zip(damages).collect(),
+        }
+    }
+}
+
+prop_compose! {
+    fn double_play()(game in game(), pitch in pitch(), batter_name in player_name(),
+                     scores in scores(),
+                     stopped_inhabiting in option::weighted(0.1, stopped_inhabiting()),
+                     cooled_off in option::weighted(0.1, mod_change_with_player())) -> FedEventData {
+        FedEventData::DoublePlay { game, pitch, batter_name, scores, stopped_inhabiting, cooled_off }
+    }
+}
+
+prop_compose! {
+    fn hit()(game in game(), pitch in pitch(), names in player_names(2), batter_id in uuid(),
+             hit_type in prop_oneof![
+                 Just(HitType::Single),
+                 Just(HitType::Double),
+                 option::weighted(0.2, mod_change()).prop_map(HitType::Triple),
+                 Just(HitType::Quadruple),
+             ],
+             scores in scores(), spicy_status in spicy_status(),
+             stopped_inhabiting in option::weighted(0.1, stopped_inhabiting()),
+             is_special in proptest::bool::weighted(0.1),
+             pitcher_item_damage in option::weighted(0.2, item_damage()),
+             batter_item_damage in option::weighted(0.2, item_damage()),
+             other_player_item_damage in option::weighted(0.1, named_item_damage())) -> FedEventData {
+        FedEventData::Hit {
+            special_reason: hidden_special_reason(is_special, pitch.double_strike.is_some() ||
+                scores.used_refill() || spicy_status.is_special(), scoring_reason(&scores)),
+            game,
+            pitch,
+            // The pitcher's damage line is told apart from the batter's by the name
+            pitcher_item_damage: pitcher_item_damage.map(|damage| (names[1].clone(), damage)),
+            batter_name: names[0].clone(),
+            batter_id,
+            hit_type,
+            scores,
+            spicy_status,
+            stopped_inhabiting: inhabited_by(stopped_inhabiting, batter_id),
+            batter_item_damage,
+            other_player_item_damage,
+        }
+    }
+}
+
+fn home_run_type() -> impl Strategy<Value = HomeRunType> {
+    select(vec![HomeRunType::Solo, HomeRunType::TwoRun, HomeRunType::ThreeRun, HomeRunType::GrandSlam])
+}
+
+prop_compose! {
+    fn home_run()(game in game(), pitch in pitch(), magmatic in option::weighted(0.1, mod_change()),
+                  batter_name in player_name(), batter_id in uuid(), home_run_type in home_run_type(),
+                  stopped_inhabiting in option::weighted(0.1, stopped_inhabiting()),
+                  free_refills in vec(free_refill(), 0..=1), spicy_status in spicy_status(),
+                  is_special in proptest::bool::weighted(0.1),
+                  big_bucket in proptest::bool::weighted(0.1),
+                  damaged_items in vec(named_item_damage(), 0..=2),
+                  attraction in option::weighted(0.1, attraction_with_player())) -> FedEventData {
+        FedEventData::HomeRun {
+            special_reason: hidden_special_reason(is_special, pitch.double_strike.is_some() ||
+                !free_refills.is_empty() || spicy_status.is_special(), SpecialReason::WiredOrTired),
+            game,
+            pitch,
+            magmatic,
+            batter_name,
+            batter_id,
+            home_run_type,
+            stopped_inhabiting: inhabited_by(stopped_inhabiting, batter_id),
+            free_refills,
+            spicy_status,
+            big_bucket,
+            attraction,
+            damaged_items,
+        }
+    }
+}
+
+prop_compose! {
+    fn stolen_base()(game in game(), runner_name in player_name(), runner_id in uuid(),
+                     base_stolen in base(), blaserunning in proptest::bool::weighted(0.1),
+                     free_refill in option::weighted(0.1, free_refill()),
+                     runner_item_damage in option::weighted(0.2, item_damage()),
+                     is_special in proptest::bool::weighted(0.1)) -> FedEventData {
+        FedEventData::StolenBase {
+            // The old builder makes events with an attractor Special
+            special_reason: hidden_special_reason(is_special || game.attractor_secret_base.is_some(),
+                                                  blaserunning || free_refill.is_some(),
+                                                  SpecialReason::Unknown),
+            game,
+            runner_name,
+            runner_id,
+            base_stolen,
+            blaserunning,
+            runner_item_damage,
+            free_refill,
+        }
+    }
+}
+
+prop_compose! {
+    fn caught_stealing()(game in game(), runner_name in player_name(),
+                         base_stolen in base()) -> FedEventData {
+        FedEventData::CaughtStealing { game, runner_name, base_stolen }
+    }
+}
+
+fn strikeout() -> impl Strategy<Value = FedEventData> {
+    (
+        any::<bool>(),
+        game(),
+        pitch(),
+        player_name(),
+        option::weighted(0.1, stopped_inhabiting()),
+        option::weighted(0.2, named_item_damage()),
+        option::weighted(0.1, free_refill()),
+        proptest::bool::weighted(0.1),
+        option::weighted(0.1, parasite()),
+    )
+        .prop_map(|(swinging, game, pitch, batter_name, stopped_inhabiting, pitcher_item_damage, free_refill, is_special, parasite)| {
+            let special_reason = hidden_special_reason(is_special, pitch.double_strike.is_some() || free_refill.is_some(),
+                                                       SpecialReason::UnrunStrikeout);
+            if swinging {
+                FedEventData::StrikeoutSwinging { game, pitch, batter_name, stopped_inhabiting, pitcher_item_damage, free_refill, special_reason, parasite }
+            } else {
+                FedEventData::StrikeoutLooking { game, pitch, batter_name, stopped_inhabiting, pitcher_item_damage, free_refill, special_reason, parasite }
+            }
+        })
+}
+
+prop_compose! {
+    fn walk()(game in game(), batter_name in player_name(), batter_id in uuid(), scores in scores(),
+              base_instincts in option::weighted(0.1, 2..=4i32),
+              batter_item_damage in option::weighted(0.2, item_damage()),
+              stopped_inhabiting in option::weighted(0.1, stopped_inhabiting()),
+              is_special in proptest::bool::weighted(0.1)) -> FedEventData {
+        FedEventData::Walk {
+            special_reason: hidden_special_reason(is_special, base_instincts.is_some() || scores.used_refill(),
+                                                  scoring_reason(&scores)),
+            game,
+            batter_name,
+            batter_id,
+            scores,
+            base_instincts,
+            batter_item_damage,
+            stopped_inhabiting: inhabited_by(stopped_inhabiting, batter_id),
+        }
+    }
+}
+
+prop_compose! {
+    fn inning_end()(game in game(), inning_num in 1..12i32,
+                    lost_triple_threat in vec(mod_change_with_named_player(), 0..=2)) -> FedEventData {
+        FedEventData::InningEnd { game, inning_num, lost_triple_threat }
+    }
+}
+
+prop_compose! {
+    fn charm_strikeout()(game in game(), charmer_id in uuid(), charmed_id in uuid(),
+                         names in player_names(2),
+                         stopped_inhabiting in option::weighted(0.1, stopped_inhabiting()),
+                         num_swings in 3..=4i32) -> FedEventData {
+        FedEventData::CharmStrikeout {
+            game,
+            charmer_id,
+            charmer_name: names[0].clone(),
+            charmed_id,
+            charmed_name: names[1].clone(),
+            stopped_inhabiting,
+            num_swings,
+        }
+    }
+}
+
+prop_compose! {
+    fn strike_zapped()(game in game()) -> FedEventData {
+        FedEventData::StrikeZapped { game }
+    }
+}
+
+prop_compose! {
+    fn peanut_flavor_text()(game in game(), message in select(vec![
+                                "A loud Peanut-scented wind blows through the stadium.",
+                                "The crowd chants for Peanuts.",
+                                "Someone in the stands is eating a Peanut.",
+                            ])) -> FedEventData {
+        FedEventData::PeanutFlavorText { game, message: message.to_string() }
+    }
+}
+
+prop_compose! {
+    fn game_end()(game in game(), winner_id in uuid(), names in subsequence(TEAM_NAMES, 2),
+                  // Scores are in tenths of a run so they print and parse exactly
+                  runs in (0..300i32, 0..300i32),
+                  temp_stolen_player_returned in option::weighted(0.1, player_moved_teams())) -> FedEventData {
+        let (winning, losing) = if runs.0 >= runs.1 { runs } else { (runs.1, runs.0) };
+        FedEventData::GameEnd {
+            game,
+            winner_id,
+            winning_team_name: names[0].to_string(),
+            winning_team_score: winning as f32 / 10.,
+            losing_team_name: names[1].to_string(),
+            losing_team_score: losing as f32 / 10.,
+            temp_stolen_player_returned,
+        }
+    }
+}
+
+prop_compose! {
+    fn mild_pitch()(game in game(), pitcher_id in uuid(), pitcher_name in player_name(),
+                    balls in 1..4i32, strikes in 0..3i32, runners_advance in any::<bool>(),
+                    scores in scores()) -> FedEventData {
+        FedEventData::MildPitch { game, pitcher_id, pitcher_name, balls, strikes, runners_advance, scores }
+    }
+}
+
+prop_compose! {
+    fn mild_pitch_walk()(game in game(), pitcher_id in uuid(), batter_id in uuid(),
+                         names in player_names(2), scores in scores()) -> FedEventData {
+        FedEventData::MildPitchWalk {
+            game,
+            pitcher_id,
+            pitcher_name: names[0].clone(),
+            batter_id,
+            batter_name: names[1].clone(),
+            scores,
+        }
+    }
+}
+
+prop_compose! {
+    fn charm_walk()(game in game(), batter_id in uuid(), names in player_names(2),
+                    // A charm walk only has room for one damage line, the batter's or the pitcher's
+                    (batter_item_damage, pitcher_item_damage) in prop_oneof![
+                        3 => Just((None, None)),
+                        1 => item_damage().prop_map(|damage| (Some(damage), None)),
+                        1 => item_damage().prop_map(|damage| (None, Some(damage))),
+                    ],
+                    scores in scores()) -> FedEventData {
+        FedEventData::CharmWalk {
+            game,
+            batter_id,
+            batter_name: names[0].clone(),
+            pitcher_name: names[1].clone(),
+            pitcher_item_damage,
+            batter_item_damage,
+            scores,
+        }
+    }
+}
+
+prop_compose! {
+    fn mind_trick_walk()(game in game(), swinging in any::<bool>(), batter_id in uuid(),
+                         batter_name in player_name(), scores in scores()) -> FedEventData {
+        FedEventData::MindTrickWalk {
+            game,
+            strikeout_type: if swinging { StrikeoutType::Swinging } else { StrikeoutType::Looking },
+            batter_id,
+            batter_name,
+            scores,
+        }
+    }
+}
+
+prop_compose! {
+    fn mind_trick_strikeout()(game in game(), batter_id in uuid(),
+                              names in player_names(2)) -> FedEventData {
+        FedEventData::MindTrickStrikeout {
+            game,
+            batter_id,
+            batter_name: names[0].clone(),
+            pitcher_name: names[1].clone(),
+        }
+    }
+}
+
+prop_compose! {
+    fn hit_by_pitch()(game in game(), pitcher_id in uuid(), batter_team_id in uuid(),
+                      batter_id in uuid(), names in player_names(2), sub_event in sub_event(),
+                      scores in scores()) -> FedEventData {
+        FedEventData::HitByPitch {
+            game,
+            pitcher_id,
+            pitcher_name: names[0].clone(),
+            batter_team_id,
+            batter_id,
+            batter_name: names[1].clone(),
+            sub_event,
+            scores,
+        }
+    }
+}
+
+prop_compose! {
+    fn batter_skipped()(game in game(), batter_name in player_name(),
+                        reason in prop_oneof![
+                            Just(BatterSkippedReason::Shelled),
+                            uuid().prop_map(BatterSkippedReason::Elsewhere),
+                        ]) -> FedEventData {
+        FedEventData::BatterSkipped { game, batter_name, reason }
+    }
+}
+
+prop_compose! {
+    fn party()(game in game(), team_id in uuid(), player_id in uuid(), player_name in player_name(),
+               sub_event in sub_event(), rating_before in rating(),
+               rating_after in rating()) -> FedEventData {
+        FedEventData::Party { game, team_id, player_id, player_name, sub_event, rating_before, rating_after }
+    }
+}
+
+prop_compose! {
+    fn pitcher_change()(game in game(), team_nickname in team_nickname(), pitcher_id in uuid(),
+                        pitcher_name in player_name()) -> FedEventData {
+        FedEventData::PitcherChange { game, team_nickname, pitcher_id, pitcher_name }
+    }
+}
+
+prop_compose! {
+    // In tenths of a run, so they print and parse exactly
+    fn runs_overflowing()(game in game(), team_nickname in team_nickname(),
+                          runs in prop_oneof![1..=100i32, -100..=-1i32]) -> FedEventData {
+        FedEventData::RunsOverflowing { game, team_nickname, num_runs: runs as f32 / 10. }
+    }
+}
+
+fn coffee_bean_mod() -> impl Strategy<Value = CoffeeBeanMod> {
+    select(vec![CoffeeBeanMod::Wired, CoffeeBeanMod::Tired])
+}
+
+prop_compose! {
+    fn coffee_bean()(game in game(), player_id in uuid(), player_name in player_name(),
+                     roast in select(COFFEE_ROASTS), notes in select(COFFEE_NOTES),
+                     which_mod in coffee_bean_mod(), gained_mod in any::<bool>(),
+                     sub_event in sub_event(), team_id in option::of(uuid()),
+                     previous in option::weighted(0.2, coffee_bean_mod())) -> FedEventData {
+        FedEventData::CoffeeBean {
+            game,
+            player_id,
+            player_name,
+            roast: roast.to_string(),
+            notes: notes.to_string(),
+            gained_mod,
+            which_mod,
+            sub_event,
+            team_id,
+            previous,
+        }
+    }
+}
+
+prop_compose! {
+    fn became_magmatic()(game in game(), player_id in uuid(), player_name in player_name(),
+                         is_unstable in proptest::bool::weighted(0.1),
+                         magmatic_mod_added in option::of(mod_change())) -> FedEventData {
+        FedEventData::BecameMagmatic {
+            game,
+            player_id,
+            // The only time this happened the Unstable player was Nagomi Mcdaniel, and the parser
+            // expects exactly that
+            player_name: if is_unstable { "Nagomi Mcdaniel".to_string() } else { player_name },
+            is_unstable,
+            magmatic_mod_added,
+        }
+    }
+}
+
+fn attr_category() -> impl Strategy<Value = AttrCategory> {
+    select(vec![AttrCategory::Batting, AttrCategory::Pitching, AttrCategory::Defense, AttrCategory::Baserunning])
+}
+
+prop_compose! {
+    fn blooddrain()(game in game(), is_siphon in any::<bool>(), sipped_category in attr_category(),
+                    names in player_names(2), sipper in player_stat_change(),
+                    sipped in player_stat_change()) -> FedEventData {
+        FedEventData::Blooddrain {
+            game,
+            is_siphon,
+            sipped_category,
+            sipper: PlayerStatChange { player_name: names[0].clone(), ..sipper },
+            sipped: PlayerStatChange { player_name: names[1].clone(), ..sipped },
+        }
+    }
+}
+
+fn blooddrain_action() -> impl Strategy<Value = BlooddrainAction> {
+    prop_oneof![
+        Just(BlooddrainAction::AddBall),
+        Just(BlooddrainAction::RemoveBall),
+        option::of(player_name()).prop_map(BlooddrainAction::AddStrike),
+        Just(BlooddrainAction::RemoveStrike),
+        Just(BlooddrainAction::AddOut),
+        Just(BlooddrainAction::RemoveOut),
+    ]
+}
+
+prop_compose! {
+    fn special_blooddrain()(game in game(), sipper_id in uuid(), sipped_id in uuid(),
+                            sipped_team_id in uuid(), names in player_names(2),
+                            sipped_category in attr_category(), action in blooddrain_action(),
+                            sipped_event in sub_event(), rating_before in rating(),
+                            rating_after in rating()) -> FedEventData {
+        FedEventData::SpecialBlooddrain {
+            game,
+            sipper_id,
+            sipper_name: names[0].clone(),
+            sipped_id,
+            sipped_team_id,
+            sipped_name: names[1].clone(),
+            sipped_category,
+            action,
+            sipped_event,
+            rating_before,
+            rating_after,
+        }
+    }
+}
+
+/// Durations that wear off
+fn expiring_mod_duration() -> impl Strategy<Value = ModDuration> {
+    select(vec![ModDuration::Seasonal, ModDuration::Weekly, ModDuration::Game])
+}
+
+fn mod_ids() -> impl Strategy<Value = Vec<Mod>> {
+    subsequence(vec![Mod::Wired, Mod::Tired, Mod::Overperforming, Mod::Underperforming, Mod::TempStolen, Mod::Superallergic], 1..=3)
+}
+
+prop_compose! {
+    fn player_mod_expires()(team_id in uuid(), player_id in uuid(), player_name in player_name(),
+                            mods in mod_ids(),
+                            mod_duration in expiring_mod_duration()) -> FedEventData {
+        FedEventData::PlayerModExpires { team_id, player_id, player_name, mods, mod_duration }
+    }
+}
+
+prop_compose! {
+    fn team_mod_expires()(team_id in uuid(), team_nickname in team_nickname(), mods in mod_ids(),
+                          mod_duration in expiring_mod_duration()) -> FedEventData {
+        FedEventData::TeamModExpires { team_id, team_nickname, mods, mod_duration }
+    }
+}
+
+prop_compose! {
+    fn birds_circle()(game in game()) -> FedEventData {
+        FedEventData::BirdsCircle { game }
+    }
+}
+
+prop_compose! {
+    fn ambushed_by_crows()(game in game(), batter_id in uuid(), names in player_names(2),
+                           pitcher_id in option::weighted(0.2, uuid())) -> FedEventData {
+        FedEventData::AmbushedByCrows {
+            game,
+            batter_id,
+            batter_name: names[0].clone(),
+            friend_of_crows: pitcher_id.map(|pitcher_id| PitcherInfo { pitcher_id, pitcher_name: names[1].clone() }),
+        }
+    }
+}
+
+prop_compose! {
+    fn sun2_set_win()(team_id in uuid(), team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::Sun2SetWin { team_id, team_nickname }
+    }
+}
+
+prop_compose! {
+    fn black_hole_swallowed_win()(team_id in uuid(), team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::BlackHoleSwallowedWin { team_id, team_nickname }
+    }
+}
+
+prop_compose! {
+    fn sun2()(game in game(), team_nickname in team_nickname(),
+              caught_some_rays in option::weighted(0.2, player_stat_change())) -> FedEventData {
+        FedEventData::Sun2 { game, team_nickname, caught_some_rays }
+    }
+}
+
+prop_compose! {
+    fn carcinization()(mv in player_moved_teams(), new_team_name in team_name(),
+                       mod_added_sub_event in sub_event()) -> Carcinization {
+        Carcinization { mv, new_team_name, mod_added_sub_event }
+    }
+}
+
+prop_compose! {
+    fn black_hole()(game in game(), nicknames in subsequence(TEAM_NICKNAMES, 2),
+                    carcinization in option::weighted(0.2, carcinization()),
+                    compressed_by_gamma in option::weighted(0.2, player_stat_change())) -> FedEventData {
+        FedEventData::BlackHole {
+            game,
+            scoring_team_nickname: nicknames[0].to_string(),
+            victim_team_nickname: nicknames[1].to_string(),
+            carcinization,
+            compressed_by_gamma,
+        }
+    }
+}
+
+prop_compose! {
+    fn team_did_shame()(shaming_team_id in uuid(), nicknames in subsequence(TEAM_NICKNAMES, 2),
+                        total_shames in 0..100i64, total_shamings in 0..100i64) -> FedEventData {
+        FedEventData::TeamDidShame {
+            shaming_team_id,
+            shaming_team_nickname: nicknames[0].to_string(),
+            shamed_team_nickname: nicknames[1].to_string(),
+            total_shames,
+            total_shamings,
+        }
+    }
+}
+
+prop_compose! {
+    fn team_was_shamed()(shamed_team_id in uuid(), nicknames in subsequence(TEAM_NICKNAMES, 2),
+                         total_shames in 0..100i64, total_shamings in 0..100i64) -> FedEventData {
+        FedEventData::TeamWasShamed {
+            shamed_team_id,
+            shamed_team_nickname: nicknames[0].to_string(),
+            shaming_team_nickname: nicknames[1].to_string(),
+            total_shames,
+            total_shamings,
+        }
+    }
+}
+
+prop_compose! {
+    fn gain_free_refill()(game in game(), team_id in option::of(uuid()), player_id in uuid(),
+                          player_name in player_name(), roast in select(COFFEE_ROASTS),
+                          ingredients in subsequence(COFFEE_INGREDIENTS, 2),
+                          sub_event in sub_event()) -> FedEventData {
+        FedEventData::GainFreeRefill {
+            game,
+            team_id,
+            player_id,
+            player_name,
+            roast: roast.to_string(),
+            ingredient1: ingredients[0].to_string(),
+            ingredient2: ingredients[1].to_string(),
+            sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn allergic_reaction()(game in game(), change in player_stat_change()) -> FedEventData {
+        FedEventData::AllergicReaction {
+            game,
+            team_id: change.team_id,
+            player_id: change.player_id,
+            player_name: change.player_name,
+            sub_event: change.sub_event,
+            rating_before: change.rating_before,
+            rating_after: change.rating_after,
+        }
+    }
+}
+
+prop_compose! {
+    fn perk_up()(game in game(), players in vec(mod_change_with_named_player(), 1..=3)) -> FedEventData {
+        FedEventData::PerkUp { game, players }
+    }
+}
+
+prop_compose! {
+    fn feedback_player_data()(team_id in uuid(), team_nickname in team_nickname(),
+                              player_id in uuid(), player_name in player_name(),
+                              location in active_position_type()) -> FeedbackPlayerData {
+        FeedbackPlayerData { team_id, team_nickname, player_id, player_name, location }
+    }
+}
+
+prop_compose! {
+    fn feedback()(game in game(), player_a in feedback_player_data(),
+                  player_b in feedback_player_data(), names in player_names(2),
+                  position_type in active_position_type(),
+                  sub_event in sub_event()) -> FedEventData {
+        FedEventData::Feedback {
+            game,
+            players: (
+                FeedbackPlayerData { player_name: names[0].clone(), ..player_a },
+                FeedbackPlayerData { player_name: names[1].clone(), ..player_b },
+            ),
+            position_type,
+            sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn bestow_reverberating()(game in game(), team_id in uuid(), player_id in uuid(),
+                              player_name in player_name(),
+                              sub_event in sub_event()) -> FedEventData {
+        FedEventData::BestowReverberating { game, team_id, player_id, player_name, sub_event }
+    }
+}
+
+prop_compose! {
+    fn player_swap()(names in player_names(2), first_player_id in uuid(),
+                     first_player_new_location in active_position_type(),
+                     second_player_id in uuid(),
+                     second_player_new_location in active_position_type(),
+                     sub_event in sub_event()) -> PlayerReverb {
+        PlayerReverb::Reverb {
+            first_player_id,
+            first_player_name: names[0].clone(),
+            first_player_new_location,
+            second_player_id,
+            second_player_name: names[1].clone(),
+            second_player_new_location,
+            sub_event,
+        }
+    }
+}
+
+fn several_players_reverb() -> impl Strategy<Value = ReverbType> {
+    // There has to be at least one swap for the parser to find the team id
+    (player_swap(), vec(prop_oneof![player_swap(), uuid().prop_map(PlayerReverb::RepeatId)], 0..=2))
+        .prop_map(|(first, rest)| ReverbType::SeveralPlayers(std::iter::once(first).chain(rest).collect()))
+}
+
+fn reverb() -> impl Strategy<Value = FedEventData> {
+    let reverb_type = prop_oneof![
+        sub_event().prop_map(ReverbType::Lineup),
+        sub_event().prop_map(ReverbType::Rotation),
+        sub_event().prop_map(ReverbType::Full),
+        several_players_reverb(),
+    ];
+
+    (game(), uuid(), team_nickname(), reverb_type, vec(player_info(), 0..=2))
+        .prop_map(|(game, team_id, team_nickname, reverb_type, gravity_players)| {
+            FedEventData::Reverb { game, team_id, team_nickname, reverb_type, gravity_players }
+        })
+}
+
+prop_compose! {
+    fn tarot_reading()(description in select(vec![
+                           "Fire Eaters\nThe Flowers get After Party.",
+                           "Death\nThe Fridays get Sinking Ship.",
+                       ]), spread in vec(0..22i64, 3), player_tags in vec(uuid(), 0..=1),
+                       team_tags in vec(uuid(), 1)) -> FedEventData {
+        FedEventData::TarotReading {
+            description: description.to_string(),
+            metadata: serde_json::json!({ "spread": spread }),
+            player_tags,
+            team_tags,
+        }
+    }
+}
+
+prop_compose! {
+    fn tarot_reading_added_or_removed_mod()(team_id in uuid(), player_id in option::of(uuid()),
+                                            description in select(vec![
+                                                "The Fridays get Sinking Ship.",
+                                                "Jimmy Bats loses Superyummy.",
+                                            ]),
+                                            r#mod in select(vec![Mod::SinkingShip, Mod::Superyummy, Mod::AfterParty]),
+                                            mod_duration in mod_duration(),
+                                            mod_removed in any::<bool>()) -> FedEventData {
+        FedEventData::TarotReadingAddedOrRemovedMod {
+            team_id,
+            player_id,
+            description: description.to_string(),
+            r#mod,
+            mod_duration,
+            mod_removed,
+        }
+    }
+}
+
+prop_compose! {
+    fn team_entered_party_time()(team_id in uuid(), team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::TeamEnteredPartyTime { team_id, team_nickname }
+    }
+}
+
+fn become_triple_threat() -> impl Strategy<Value = FedEventData> {
+    (game(), 1..=2usize, player_names(2), vec(mod_change_with_named_player(), 2))
+        .prop_map(|(game, num_pitchers, names, pitchers)| {
+            let pitchers = pitchers.into_iter()
+                .zip(names)
+                .take(num_pitchers)
+                .map(|(pitcher, player_name)| ModChangeSubEventWithNamedPlayer { player_name, ..pitcher })
+                .collect();
+            FedEventData::BecomeTripleThreat { game, pitchers }
+        })
+}
+
+prop_compose! {
+    fn under_over()(game in game(), team_id in uuid(), player_id in uuid(),
+                    player_name in player_name(), on in any::<bool>(),
+                    sub_event in sub_event()) -> FedEventData {
+        FedEventData::UnderOver { game, team_id, player_id, player_name, on, sub_event }
+    }
+}
+
+prop_compose! {
+    fn over_under()(game in game(), team_id in uuid(), player_id in uuid(),
+                    player_name in player_name(), on in any::<bool>(),
+                    sub_event in sub_event()) -> FedEventData {
+        FedEventData::OverUnder { game, team_id, player_id, player_name, on, sub_event }
+    }
+}
+
+prop_compose! {
+    fn taste_the_infinite()(game in game(), sheller_id in uuid(), shellee_team_id in uuid(),
+                            shellee_id in uuid(), names in player_names(2),
+                            sub_event in sub_event()) -> FedEventData {
+        FedEventData::TasteTheInfinite {
+            game,
+            sheller_id,
+            sheller_name: names[0].clone(),
+            shellee_team_id,
+            shellee_id,
+            shellee_name: names[1].clone(),
+            sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn feedback_blocked()(game in game(), resisted_id in uuid(), names in player_names(2),
+                          tangled in player_stat_change()) -> FedEventData {
+        FedEventData::FeedbackBlocked {
+            game,
+            resisted_id,
+            resisted_name: names[0].clone(),
+            tangled_id: tangled.player_id,
+            tangled_team_id: tangled.team_id,
+            tangled_name: names[1].clone(),
+            tangled_rating_before: tangled.rating_before,
+            tangled_rating_after: tangled.rating_after,
+            sub_event: tangled.sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn flag_planted()(team_id in uuid(), team_nickname in team_nickname(),
+                      ballpark_name in select(vec!["The Bat Cave", "Big Garage", "Peanut Dome"]),
+                      prefab_name in select(vec!["Oval", "Diamond", "Rectangle"]),
+                      renovation_id in select(vec!["build_oval", "build_diamond", "build_rectangle"]),
+                      votes in 0..100_000i64, is_first in any::<bool>()) -> FedEventData {
+        FedEventData::FlagPlanted {
+            team_id,
+            team_nickname,
+            ballpark_name: ballpark_name.to_string(),
+            prefab_name: prefab_name.to_string(),
+            renovation_id: renovation_id.to_string(),
+            votes,
+            is_first,
+        }
+    }
+}
+
+prop_compose! {
+    fn emergency_alert()(message in select(vec![
+                             "EMERGENCY ALERT\nTHE BLASEBALL GODS HAVE DETECTED AN ANOMALY",
+                             "The Hall Stars have entered the League!",
+                         ]), team_tags in vec(uuid(), 0..=2)) -> FedEventData {
+        FedEventData::EmergencyAlert { message: message.to_string(), team_tags }
+    }
+}
+
+prop_compose! {
+    fn team_joined_ilb()(team_id in uuid(), team_nickname in team_nickname(),
+                         division_id in uuid(),
+                         division_name in select(vec!["Wild High", "Wild Low", "Mild High", "Mild Low"])) -> FedEventData {
+        FedEventData::TeamJoinedILB { team_id, team_nickname, division_id, division_name: division_name.to_string() }
+    }
+}
+
+fn flooding_swept_effect() -> impl Strategy<Value = FloodingSweptEffect> {
+    prop_oneof![
+        mod_change_with_named_player().prop_map(FloodingSweptEffect::Elsewhere),
+        player_info().prop_map(FloodingSweptEffect::Flippers),
+        player_info().prop_map(FloodingSweptEffect::Ego),
+    ]
+}
+
+prop_compose! {
+    fn flooding_swept()(game in game(), effects in vec(flooding_swept_effect(), 0..=3),
+                        free_refills in vec(free_refill(), 0..=1),
+                        flood_pumps in proptest::bool::weighted(0.1)) -> FedEventData {
+        FedEventData::FloodingSwept {
+            game,
+            effects,
+            flood_pumps,
+            free_refills,
+        }
+    }
+}
+
+fn time_elsewhere() -> impl Strategy<Value = TimeElsewhere> {
+    prop_oneof![
+        (1..60i32).prop_map(TimeElsewhere::Days),
+        (1..4i32).prop_map(TimeElsewhere::Seasons),
+    ]
+}
+
+prop_compose! {
+    fn scattered()(scattered_name in select(vec!["J-ss-ca T-l-ph-ne", "Y-rk S-lk", "Alys-a H-rr-ll"]),
+                   sub_event in sub_event()) -> Scattered {
+        Scattered { scattered_name: scattered_name.to_string(), sub_event }
+    }
+}
+
+fn return_from_elsewhere_flavor() -> impl Strategy<Value = ReturnFromElsewhereFlavor> {
+    prop_oneof![
+        (uuid(), uuid(), any::<bool>(), sub_event(), time_elsewhere(), option::weighted(0.2, scattered()),
+         option::weighted(0.2, player_stat_change()))
+            .prop_map(|(team_id, player_id, is_peanut, sub_event, time_elsewhere, scattered, recongealed_differently)| {
+                ReturnFromElsewhereFlavor::Full {
+                    team_id,
+                    player_id,
+                    is_peanut,
+                    sub_event,
+                    time_elsewhere,
+                    scattered,
+                    recongealed_differently,
+                }
+            }),
+        (uuid(), uuid(), any::<bool>(), sub_event())
+            .prop_map(|(team_id, player_id, is_peanut, sub_event)| {
+                ReturnFromElsewhereFlavor::Short { team_id, player_id, is_peanut, sub_event }
+            }),
+        any::<bool>().prop_map(|is_peanut| ReturnFromElsewhereFlavor::False { is_peanut }),
+    ]
+}
+
+prop_compose! {
+    fn return_from_elsewhere()(game in game(), player_name in player_name(),
+                               flavor in return_from_elsewhere_flavor()) -> FedEventData {
+        FedEventData::ReturnFromElsewhere { game, player_name, flavor }
+    }
+}
+
+prop_compose! {
+    fn incineration()(game in game(), team_id in uuid(), team_nickname in team_nickname(),
+                      victim_id in uuid(), replacement_id in uuid(), names in player_names(2),
+                      location in active_position_type(),
+                      unstable_chain in option::weighted(0.2, mod_change_with_named_player()),
+                      sub_events in (sub_event(), sub_event(), sub_event(), sub_event())) -> FedEventData {
+        FedEventData::Incineration {
+            game,
+            team_id,
+            team_nickname,
+            victim_id,
+            victim_name: names[0].clone(),
+            replacement_id,
+            replacement_name: names[1].clone(),
+            location,
+            unstable_chain,
+            sub_events,
+        }
+    }
+}
+
+prop_compose! {
+    fn player_hatched()(player_id in uuid(), player_name in player_name()) -> FedEventData {
+        FedEventData::PlayerHatched { player_id, player_name }
+    }
+}
+
+fn shadow_position_type() -> impl Strategy<Value = ShadowPositionType> {
+    select(vec![ShadowPositionType::Bench, ShadowPositionType::Bullpen])
+}
+
+prop_compose! {
+    fn postseason_birth()(team_id in uuid(), team_nickname in team_nickname(), player_id in uuid(),
+                          player_name in player_name(),
+                          location in shadow_position_type()) -> FedEventData {
+        FedEventData::PostseasonBirth { team_id, team_nickname, player_id, player_name, location }
+    }
+}
+
+fn division_name() -> impl Strategy<Value = String> {
+    select(vec!["Wild High", "Wild Low", "Mild High", "Mild Low"]).prop_map(str::to_string)
+}
+
+prop_compose! {
+    fn final_standings()(team_id in uuid(), team_nickname in team_nickname(), place in 0..12i32,
+                         division_name in division_name()) -> FedEventData {
+        FedEventData::FinalStandings { team_id, team_nickname, place, division_name }
+    }
+}
+
+prop_compose! {
+    fn team_left_party_time_for_postseason()(team_id in uuid(),
+                                             team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::TeamLeftPartyTimeForPostseason { team_id, team_nickname }
+    }
+}
+
+prop_compose! {
+    fn earned_postseason_slot()(team_id in uuid(), team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::EarnedPostseasonSlot { team_id, team_nickname }
+    }
+}
+
+prop_compose! {
+    fn postseason_advance()(team_id in uuid(), team_nickname in team_nickname(),
+                            round in option::of(1..=3i32),
+                            displayed_season in 12..25i32) -> FedEventData {
+        FedEventData::PostseasonAdvance { team_id, team_nickname, round, displayed_season }
+    }
+}
+
+prop_compose! {
+    fn postseason_eliminated()(team_id in uuid(), team_nickname in team_nickname(),
+                               displayed_season in 12..25i32) -> FedEventData {
+        FedEventData::PostseasonEliminated { team_id, team_nickname, displayed_season }
+    }
+}
+
+prop_compose! {
+    fn player_boosted()(team_id in uuid(), player_id in uuid(), player_name in player_name(),
+                        rating_before in rating(), rating_after in rating()) -> FedEventData {
+        FedEventData::PlayerBoosted { team_id, player_id, player_name, rating_before, rating_after }
+    }
+}
+
+prop_compose! {
+    fn team_won_internet_series()(team_id in uuid(), team_nickname in team_nickname(),
+                                  championships in 1..5i64) -> FedEventData {
+        FedEventData::TeamWonInternetSeries { team_id, team_nickname, championships }
+    }
+}
+
+prop_compose! {
+    fn bottom_dwellers()(team_id in uuid(), team_nickname in team_nickname(),
+                         rating_before in rating(), rating_after in rating()) -> FedEventData {
+        FedEventData::BottomDwellers { team_id, team_nickname, rating_before, rating_after }
+    }
+}
+
+/// Metadata for the events that keep all of it, without any of the fields the builder fills in
+fn plain_metadata() -> impl Strategy<Value = EventMetadata> {
+    (uuid(), 0..100i64).prop_map(|(id, votes)| EventMetadata {
+        other: serde_json::json!({ "id": id, "votes": votes }),
+        ..Default::default()
+    })
+}
+
+prop_compose! {
+    fn will_received()(team_id in uuid(),
+                       will_title in select(vec!["Foreshadow", "Plunder", "Revoke", "Swipe"]),
+                       metadata in plain_metadata()) -> FedEventData {
+        FedEventData::WillReceived { team_id, will_title: will_title.to_string(), metadata }
+    }
+}
+
+prop_compose! {
+    fn blessing_won()(team_tags in vec(uuid(), 1..=2),
+                      blessing_title in select(vec!["Targeted Shame", "Sticky Fingers", "Headhunter"]),
+                      metadata in plain_metadata()) -> FedEventData {
+        FedEventData::BlessingWon { team_tags, blessing_title: blessing_title.to_string(), metadata }
+    }
+}
+
+prop_compose! {
+    fn earlbirds_added_to_team()(game in game(), team_id in uuid(),
+                                 team_nickname in team_nickname(),
+                                 sub_event in sub_event()) -> FedEventData {
+        FedEventData::EarlbirdsAddedToTeam { game, team_id, team_nickname, sub_event }
+    }
+}
+
+prop_compose! {
+    fn decree_passed()(decree_title in select(vec!["Hall Monitor", "Ruthless Re-Order", "Forecast"]),
+                       metadata in plain_metadata()) -> FedEventData {
+        FedEventData::DecreePassed { decree_title: decree_title.to_string(), metadata }
+    }
+}
+
+prop_compose! {
+    fn player_joined_ilb()(player_id in uuid(), player_name in player_name()) -> FedEventData {
+        FedEventData::PlayerJoinedILB { player_id, player_name }
+    }
+}
+
+prop_compose! {
+    fn player_permitted_to_stay()(player_id in uuid(), player_name in player_name()) -> FedEventData {
+        FedEventData::PlayerPermittedToStay { player_id, player_name }
+    }
+}
+
+prop_compose! {
+    fn fireproof_incineration()(game in game(), player_id in uuid(),
+                                player_name in player_name()) -> FedEventData {
+        FedEventData::FireproofIncineration { game, player_id, player_name }
+    }
+}
+
+prop_compose! {
+    // This only happened once, and the parser only accepts that one
+    fn lineup_sorted()(team_id in uuid()) -> FedEventData {
+        FedEventData::LineupSorted { team_id, team_nickname: "Lovers".to_string() }
+    }
+}
+
+prop_compose! {
+    fn earlbirds_removed_from_team()(game in game(), team_id in uuid(),
+                                     sub_event in sub_event()) -> FedEventData {
+        FedEventData::EarlbirdsRemovedFromTeam { game, team_id, sub_event }
+    }
+}
+
+prop_compose! {
+    fn undersea()(game in game(), team_id in uuid(), team_name in team_name(),
+                  sub_event in sub_event()) -> FedEventData {
+        FedEventData::Undersea { game, team_id, team_name, sub_event }
+    }
+}
+
+fn renovation_votes() -> impl Strategy<Value = RenovationVotes> {
+    prop_oneof![
+        (0..100_000i64).prop_map(RenovationVotes::Normal),
+        Just(RenovationVotes::Manual("1".to_string())),
+    ]
+}
+
+prop_compose! {
+    fn renovation_built()(team_id in uuid(),
+                          renovation in select(vec![
+                              ("Tunnels have been built in Big Garage.", "tunnels", "Tunnels"),
+                              ("The Peanut Mister was built in The Bat Cave.", "peanut_mister", "Peanut Mister"),
+                          ]),
+                          votes in renovation_votes()) -> FedEventData {
+        let (description, renovation_id, renovation_title) = renovation;
+        FedEventData::RenovationBuilt {
+            team_id,
+            description: description.to_string(),
+            renovation_id: renovation_id.to_string(),
+            renovation_title: renovation_title.to_string(),
+            votes,
+        }
+    }
+}
+
+prop_compose! {
+    fn late_to_the_party_added()(game in game(), team_nickname in team_nickname(),
+                                 mod_added in option::of((uuid(), sub_event()))) -> FedEventData {
+        let (team_id, sub_event) = mod_added.unzip();
+        FedEventData::LateToThePartyAdded { game, team_id, team_nickname, sub_event }
+    }
+}
+
+prop_compose! {
+    fn peanut_mister()(game in game(), player_id in uuid(), player_name in player_name(),
+                       superallergy in option::of(mod_change())) -> FedEventData {
+        FedEventData::PeanutMister { game, player_id, player_name, superallergy }
+    }
+}
+
+prop_compose! {
+    fn player_named_mvp()(team_id in uuid(), player_id in uuid(), player_name in player_name(),
+                          level in 1..=3i32) -> FedEventData {
+        FedEventData::PlayerNamedMvp { team_id, player_id, player_name, level }
+    }
+}
+
+prop_compose! {
+    fn late_to_the_party_removed()(game in game(), team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::LateToThePartyRemoved { game, team_nickname }
+    }
+}
+
+prop_compose! {
+    fn birds_unshell()(game in game(), team_id in uuid(), player_id in uuid(),
+                       player_name in player_name(), pecked_free_event in sub_event(),
+                       superallergy_event in sub_event()) -> FedEventData {
+        FedEventData::BirdsUnshell { game, team_id, player_id, player_name, pecked_free_event, superallergy_event }
+    }
+}
+
+prop_compose! {
+    fn replace_returned_player_from_shadows()(team_id in uuid(), team_nickname in team_nickname(),
+                                              promoted_player_id in uuid(),
+                                              removed_player_id in uuid(),
+                                              names in player_names(2),
+                                              promoted_location in shadow_position_type(),
+                                              removed_location in active_position_type()) -> FedEventData {
+        FedEventData::ReplaceReturnedPlayerFromShadows {
+            team_id,
+            team_nickname,
+            promoted_player_id,
+            promoted_player_name: names[0].clone(),
+            promoted_location,
+            removed_player_id,
+            removed_player_name: names[1].clone(),
+            removed_location,
+        }
+    }
+}
+
+prop_compose! {
+    fn player_called_back_to_hall()(player_id in uuid(), player_name in player_name()) -> FedEventData {
+        FedEventData::PlayerCalledBackToHall { player_id, player_name }
+    }
+}
+
+prop_compose! {
+    fn team_used_free_will()(team_id in uuid(), team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::TeamUsedFreeWill { team_id, team_nickname }
+    }
+}
+
+prop_compose! {
+    fn player_lost_mod()(team_id in uuid(), player_id in uuid(), player_name in player_name(),
+                         r#mod in select(vec![Mod::Superallergic, Mod::Siphon, Mod::FireEater])) -> FedEventData {
+        FedEventData::PlayerLostMod {
+            team_id,
+            player_id,
+            player_name,
+            mod_name: r#mod.display_name().to_string(),
+            r#mod,
+        }
+    }
+}
+
+prop_compose! {
+    fn investigation_message()(player_id in uuid(),
+                               message in select(vec![
+                                   "Nagomi Nava is Investigating.",
+                                   "The Detective senses something fishy.",
+                               ])) -> FedEventData {
+        FedEventData::InvestigationMessage { player_id, message: message.to_string() }
+    }
+}
+
+prop_compose! {
+    fn high_pressure()(game in game(), team_id in uuid(), team_nickname in team_nickname(),
+                       is_on in any::<bool>(), sub_event in sub_event()) -> FedEventData {
+        FedEventData::HighPressure { game, team_id, team_nickname, is_on, sub_event }
+    }
+}
+
+prop_compose! {
+    fn player_pulled_through_rift()(player_id in uuid(), player_name in player_name()) -> FedEventData {
+        FedEventData::PlayerPulledThroughRift { player_id, player_name }
+    }
+}
+
+prop_compose! {
+    fn player_localized()(team_id in uuid(), team_nickname in team_nickname(), player_id in uuid(),
+                          player_name in player_name(),
+                          location in active_position_type()) -> FedEventData {
+        FedEventData::PlayerLocalized { team_id, team_nickname, player_id, player_name, location }
+    }
+}
+
+const ECHO_MODS: &[Mod] = &[Mod::BaseInstincts, Mod::Fiery, Mod::Reverberating, Mod::Spicy, Mod::Psychic, Mod::Acidic];
+
+prop_compose! {
+    fn multiple_mods()(mod_ids in subsequence(ECHO_MODS, 1..=3),
+                       sub_event in sub_event()) -> MultipleModsAddedOrRemoved {
+        MultipleModsAddedOrRemoved { mod_ids, sub_event }
+    }
+}
+
+prop_compose! {
+    fn echo_effect()(receiver_team_id in uuid(), receiver_id in uuid(), receiver_name in player_name(),
+                     mods_removed in option::of(multiple_mods()),
+                     mods_added in multiple_mods()) -> Echo {
+        Echo { receiver_team_id, receiver_id, receiver_name, mods_removed, mods_added }
+    }
+}
+
+prop_compose! {
+    fn echo()(game in game(), echoee_name in player_name(), primary_echo in echo_effect(),
+              receiver_echos in vec(echo_effect(), 0..=2)) -> FedEventData {
+        FedEventData::Echo { game, echoee_name, primary_echo, receiver_echos }
+    }
+}
+
+prop_compose! {
+    fn solar_panels_await()(game in game()) -> FedEventData {
+        FedEventData::SolarPanelsAwait { game }
+    }
+}
+
+prop_compose! {
+    fn echo_into_static_player()(team_id in uuid(), team_nickname in team_nickname(),
+                                 player_id in uuid(), player_name in player_name(),
+                                 removed_from_team_sub_event in sub_event(),
+                                 mod_changed_sub_event in sub_event()) -> EchoIntoStatic {
+        EchoIntoStatic {
+            team_id,
+            team_nickname,
+            player_id,
+            player_name,
+            removed_from_team_sub_event,
+            mod_changed_sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn echo_into_static()(game in game(), echoer in echo_into_static_player(),
+                          echoee in echo_into_static_player()) -> FedEventData {
+        FedEventData::EchoIntoStatic { game, echoer, echoee }
+    }
+}
+
+prop_compose! {
+    fn detective_activity()(detective_id in uuid(), detective_name in player_name(),
+                            sub_event in sub_event()) -> DetectiveActivity {
+        DetectiveActivity { detective_id, detective_name, sub_event }
+    }
+}
+
+fn consumer_attack_effect(team_id: Uuid) -> impl Strategy<Value = ConsumerAttackEffect> {
+    prop_oneof![
+        // The child is a PlayerStatDecrease, so the rating can't go up
+        (rating(), rating(), sub_event()).prop_map(|(a, b, sub_event)| ConsumerAttackEffect::Chomp {
+            rating_before: a.max(b),
+            rating_after: a.min(b),
+            sub_event,
+        }),
+        // The parser takes the team from the item's event. The all-caps damage line is the same for
+        // plural and singular names, so the event can't say which it is.
+        item_damage().prop_map(move |damage| ConsumerAttackEffect::DefendedWithItem(ItemDamaged {
+            item_name_plural: None,
+            team_id,
+            ..damage
+        })),
+    ]
+}
+
+fn consumer_attack() -> impl Strategy<Value = FedEventData> {
+    uuid().prop_flat_map(|team_id| {
+        (game(), uuid(), player_name(), consumer_attack_effect(team_id),
+         option::weighted(0.2, detective_activity()), proptest::bool::weighted(0.1))
+            .prop_map(move |(game, player_id, player_name, effect, sensed_something_fishy, scattered)| {
+                FedEventData::ConsumerAttack {
+                    game,
+                    team_id,
+                    player_id,
+                    player_name_all_caps: player_name.to_uppercase(),
+                    effect,
+                    sensed_something_fishy,
+                    scattered,
+                }
+            })
+    })
+}
+
+prop_compose! {
+    fn psychoacoustics()(game in game(),
+                         stadium_name in select(vec!["The Big Garage", "Hall Stadium", "Bathalian Arena"]),
+                         team_id in uuid(), team_nickname in team_nickname(),
+                         mod_id in select(vec![Mod::Acidic, Mod::Fireproof, Mod::Earlbirds, Mod::LateToParty]),
+                         sub_event in sub_event()) -> FedEventData {
+        FedEventData::Psychoacoustics {
+            game,
+            stadium_name: stadium_name.to_string(),
+            team_id,
+            team_nickname,
+            mod_name: mod_id.display_name().to_string(),
+            mod_id,
+            sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn echo_receiver()(game in game(), names in player_names(2), echoee_id in uuid(),
+                       echoee_team_id in uuid(), sub_event in sub_event()) -> FedEventData {
+        FedEventData::EchoReceiver {
+            game,
+            echoer_name: names[0].clone(),
+            echoee_name: names[1].clone(),
+            echoee_id,
+            echoee_team_id,
+            sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn team_gained_free_will()(team_id in uuid(), team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::TeamGainedFreeWill { team_id, team_nickname }
+    }
+}
+
+prop_compose! {
+    fn tidings()(message in select(vec![
+                     "The Tidings are in.",
+                     "Tidings from the Horizon.",
+                 ]),
+                 metadata in plain_metadata(),
+                 player_tags in vec(uuid(), 0..=2)) -> FedEventData {
+        FedEventData::Tidings { message: message.to_string(), metadata, player_tags }
+    }
+}
+
+prop_compose! {
+    fn homebody_game_start()(game in game(),
+                             homebodies in vec(toggle_performing(), 1..=3)) -> FedEventData {
+        FedEventData::HomebodyGameStart { game, homebodies }
+    }
+}
+
+prop_compose! {
+    // In tenths of a run, so they print and parse exactly
+    fn team_runs_lost()(runs in 1..=100i32, team_name in team_name()) -> TeamRunsLost {
+        TeamRunsLost { runs_lost: runs as f32 / 10., team_name }
+    }
+}
+
+fn run_losses_from_salmon() -> impl Strategy<Value = RunLossesFromSalmon> {
+    prop_oneof![
+        Just(RunLossesFromSalmon::None),
+        team_runs_lost().prop_map(RunLossesFromSalmon::OneTeam),
+        (team_runs_lost(), team_runs_lost()).prop_map(RunLossesFromSalmon::BothTeams),
+    ]
+}
+
+prop_compose! {
+    fn item_repaired()(item in select(ITEMS), durability in 1..=5i64, health in 1..=5i64,
+                       item_id in uuid(), player_item_rating_before in rating(),
+                       player_item_rating_after in rating(), player_rating in rating(),
+                       team_id in uuid(), player_id in uuid(), player_name in player_name(),
+                       sub_event in sub_event()) -> ItemRepaired {
+        ItemRepaired {
+            item_id,
+            item_name: item.0.to_string(),
+            item_mods: vec![],
+            durability,
+            health: health.min(durability),
+            player_item_rating_before,
+            player_item_rating_after,
+            player_rating,
+            team_id,
+            player_id,
+            player_name,
+            sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn salmon_swim()(game in game(), inning_num in 1..=9i32, run_losses in run_losses_from_salmon(),
+                     item_restored in option::weighted(0.2, item_repaired()),
+                     player_expelled in option::weighted(0.2, mod_change_with_named_player())) -> FedEventData {
+        FedEventData::SalmonSwim { game, inning_num, run_losses, item_restored, player_expelled }
+    }
+}
+
+prop_compose! {
+    // In tenths of a run, so they print and parse exactly
+    fn solar_panels_activate()(game in game(), runs in 1..=100i32,
+                               team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::SolarPanelsActivate { game, num_runs: runs as f32 / 10., team_nickname }
+    }
+}
+
+prop_compose! {
+    fn team_middling()(game in game(), team_nickname in team_nickname(), is_middling in any::<bool>(),
+                       change_event in mod_change()) -> FedEventData {
+        FedEventData::TeamMiddling { game, team_nickname, is_middling, change_event }
+    }
+}
+
+const STADIUM_NAMES: &[&str] = &["Big Garage", "Bathalian Arena", "Atlantis Georgias", "Yellowstone Magic"];
+
+prop_compose! {
+    fn enter_crime_scene()(game in game(), player_id in uuid(), player_name in player_name(),
+                           previous_team_id in uuid(), previous_team_name in team_nickname(),
+                           previous_location in position_type(), new_team_id in uuid(),
+                           new_team_name in team_nickname(), stadium_name in select(STADIUM_NAMES),
+                           rating_before in rating(), rating_after in rating(),
+                           enter_crime_scene_sub_event in sub_event(),
+                           enter_shadows_sub_event in sub_event()) -> FedEventData {
+        FedEventData::EnterCrimeScene {
+            game,
+            player_id,
+            player_name,
+            previous_team_id,
+            previous_team_name,
+            previous_location,
+            new_team_id,
+            new_team_name,
+            stadium_name: stadium_name.to_string(),
+            rating_before,
+            rating_after,
+            enter_crime_scene_sub_event,
+            enter_shadows_sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn return_from_investigation()(player_id in uuid(), player_name in player_name(),
+                                   previous_team_id in uuid(), previous_team_name in team_nickname(),
+                                   new_location in position_type(), new_team_id in uuid(),
+                                   new_team_name in team_nickname(),
+                                   emptyhanded in any::<bool>()) -> FedEventData {
+        FedEventData::ReturnFromInvestigation {
+            player_id,
+            player_name,
+            previous_team_id,
+            previous_team_name,
+            new_location,
+            new_team_id,
+            new_team_name,
+            emptyhanded,
+        }
+    }
+}
+
+prop_compose! {
+    fn investigation_concluded()(team_id in uuid(), stadium_name in select(STADIUM_NAMES)) -> FedEventData {
+        FedEventData::InvestigationConcluded { team_id, stadium_name: stadium_name.to_string() }
+    }
+}
+
+prop_compose! {
+    fn grind_rail_trick()(trick_name in select(vec!["Kickflip", "Ollie", "Pop Shuvit", "Casper Slide", "Heelflip"]),
+                          points in 0..=500i32) -> GrindRailTrick {
+        GrindRailTrick { trick_name: trick_name.to_string(), points }
+    }
+}
+
+fn grind_rail_success() -> impl Strategy<Value = GrindRailSuccess> {
+    prop_oneof![
+        grind_rail_trick().prop_map(GrindRailSuccess::Safe),
+        grind_rail_trick().prop_map(GrindRailSuccess::TaggedOut),
+        Just(GrindRailSuccess::Bailed),
+    ]
+}
+
+prop_compose! {
+    fn grind_rail()(game in game(), player_id in uuid(), player_name in player_name(),
+                    first_trick in grind_rail_trick(), success in grind_rail_success()) -> FedEventData {
+        FedEventData::GrindRail { game, player_id, player_name, first_trick, success }
+    }
+}
+
+prop_compose! {
+    fn enter_secret_base()(game in game(), player_id in uuid(), player_name in player_name()) -> FedEventData {
+        FedEventData::EnterSecretBase { game, player_id, player_name }
+    }
+}
+
+prop_compose! {
+    fn exit_secret_base()(game in game(), player_id in uuid(), player_name in player_name()) -> FedEventData {
+        FedEventData::ExitSecretBase { game, player_id, player_name }
+    }
+}
+
+prop_compose! {
+    fn echo_chamber()(game in game(), team_id in option::of(uuid()), player_id in uuid(),
+                      player_name in player_name(),
+                      which_mod in select(vec![EchoChamberModAdded::Repeating, EchoChamberModAdded::Reverberating]),
+                      sub_event in sub_event()) -> FedEventData {
+        FedEventData::EchoChamber { game, team_id, player_id, player_name, which_mod, sub_event }
+    }
+}
+
+prop_compose! {
+    fn roam()(player_id in uuid(), player_name in player_name(), location in position_type(),
+              previous_team_id in uuid(), previous_team_nickname in team_nickname(),
+              new_team_id in uuid(), new_team_nickname in team_nickname()) -> FedEventData {
+        FedEventData::Roam {
+            player_id,
+            player_name,
+            location,
+            previous_team_id,
+            previous_team_nickname,
+            new_team_id,
+            new_team_nickname,
+        }
+    }
+}
+
+fn item_name() -> impl Strategy<Value = String> {
+    select(ITEMS).prop_map(|(name, _)| name.to_string())
+}
+
+fn item_mods() -> impl Strategy<Value = Vec<Mod>> {
+    subsequence(vec![Mod::FireEater, Mod::Parasite, Mod::Chunky, Mod::Smooth], 0..=2)
+}
+
+prop_compose! {
+    fn item_dropped_for_new_item()(item_id in uuid(), item_name in item_name(), item_mods in item_mods(),
+                                   player_item_rating_before in rating(),
+                                   player_item_rating_after in rating(),
+                                   item_was_broken in any::<bool>(),
+                                   sub_event in sub_event()) -> ItemDroppedForNewItem {
+        ItemDroppedForNewItem {
+            item_id,
+            item_name,
+            item_mods,
+            player_item_rating_before,
+            player_item_rating_after,
+            item_was_broken,
+            sub_event,
+        }
+    }
+}
+
+prop_compose! {
+    fn item_gained()(item_id in uuid(), item_name in item_name(), item_mods in item_mods(),
+                     player_item_rating_before in rating(), player_item_rating_after in rating(),
+                     player_rating in rating(), team_id in uuid(), player_id in uuid(),
+                     sub_event in sub_event(),
+                     dropped_item in option::of(item_dropped_for_new_item())) -> ItemGained {
+        ItemGained {
+            item_id,
+            item_name,
+            item_mods,
+            player_item_rating_before,
+            player_item_rating_after,
+            player_rating,
+            team_id,
+            player_id,
+            sub_event,
+            dropped_item,
+        }
+    }
+}
+
+prop_compose! {
+    fn glitter_crate()(game in game(), player_name in player_name(),
+                       gained_item in item_gained()) -> FedEventData {
+        FedEventData::GlitterCrate { game, player_name, gained_item }
+    }
+}
+
+prop_compose! {
+    fn mod_desc()(mod_id in select(ECHO_MODS), mod_duration in mod_duration()) -> ModDesc {
+        ModDesc { mod_id, mod_duration }
+    }
+}
+
+prop_compose! {
+    fn mods_from_another_mod_removed()(team_id in uuid(), player_id in uuid(),
+                                       player_name in player_name(),
+                                       mods_removed in vec(mod_desc(), 1..=3),
+                                       source_mod_id in select(vec![Mod::Echo, Mod::Receiver])) -> FedEventData {
+        FedEventData::ModsFromAnotherModRemoved {
+            team_id,
+            player_id,
+            player_name,
+            mods_removed,
+            source_mod_name: source_mod_id.display_name().to_string(),
+            source_mod_id,
+        }
+    }
+}
+
+prop_compose! {
+    fn consumer_expelled()(game in game(), player_id in uuid()) -> FedEventData {
+        FedEventData::ConsumerExpelled { game, player_id }
+    }
+}
+
+prop_compose! {
+    fn earlbirds_added_to_player()(game in game(), team_id in uuid(), player_id in uuid(),
+                                   player_name in player_name(),
+                                   sub_event in sub_event()) -> FedEventData {
+        FedEventData::EarlbirdsAddedToPlayer { game, team_id, player_id, player_name, sub_event }
+    }
+}
+
+prop_compose! {
+    fn blooddrain_blocked()(game in game(), sipper_id in uuid(), sippee_id in uuid(),
+                            names in player_names(2)) -> FedEventData {
+        FedEventData::BlooddrainBlocked {
+            game,
+            sipper_id,
+            sipper_name: names[0].clone(),
+            sippee_id,
+            sippee_name: names[1].clone(),
+        }
+    }
+}
+
+prop_compose! {
+    fn earlbirds_removed_from_player()(game in game(), team_id in uuid(), player_id in uuid(),
+                                       player_name in player_name(),
+                                       sub_event in sub_event()) -> FedEventData {
+        FedEventData::EarlbirdsRemovedFromPlayer { game, team_id, player_id, player_name, sub_event }
+    }
+}
+
+prop_compose! {
+    fn tarot_reading_added_or_removed_item()(description in select(vec![
+                                                 "Baby Doyle's Bat was removed.",
+                                                 "Baby Doyle gained the Uncertain Necklace of Entanglement.",
+                                             ]),
+                                             item_id in uuid(), item_name in item_name(),
+                                             item_mods in item_mods(),
+                                             player_item_rating_before in rating(),
+                                             player_item_rating_after in rating(),
+                                             player_rating in rating(), team_id in uuid(),
+                                             player_id in uuid(),
+                                             item_gained in any::<bool>()) -> FedEventData {
+        FedEventData::TarotReadingAddedOrRemovedItem {
+            description: description.to_string(),
+            item_id,
+            item_name,
+            item_mods,
+            player_item_rating_before,
+            player_item_rating_after,
+            player_rating,
+            team_id,
+            player_id,
+            item_gained,
+        }
+    }
+}
+
+prop_compose! {
+    fn player_middling()(game in game(), is_middling in any::<bool>(),
+                         change_event in mod_change_with_named_player()) -> FedEventData {
+        FedEventData::PlayerMiddling { game, is_middling, change_event }
+    }
+}
+
+prop_compose! {
+    fn community_chest_opens()(item_id in uuid(), item_name in item_name(), item_mods in item_mods(),
+                               player_item_rating_before in option::of(rating()),
+                               player_item_rating_after in option::of(rating()),
+                               player_rating in rating(), team_id in uuid(),
+                               player_name in player_name(), player_id in uuid()) -> FedEventData {
+        FedEventData::CommunityChestOpens {
+            item_id,
+            item_name,
+            item_mods,
+            player_item_rating_before,
+            player_item_rating_after,
+            player_rating,
+            team_id,
+            player_name,
+            player_id,
+        }
+    }
+}
+
+prop_compose! {
+    fn player_drops_item()(item_id in uuid(), item_name in item_name(), item_mods in item_mods(),
+                           player_item_rating_before in rating(), player_item_rating_after in rating(),
+                           player_rating in rating(), team_id in uuid(), player_name in player_name(),
+                           player_id in uuid()) -> FedEventData {
+        FedEventData::PlayerDropsItem {
+            item_id,
+            item_name,
+            item_mods,
+            player_item_rating_before,
+            player_item_rating_after,
+            player_rating,
+            team_id,
+            player_name,
+            player_id,
+        }
+    }
+}
+
+prop_compose! {
+    fn community_chest_game_message()(game in game(), names in player_names(2),
+                                      gained_items in vec(item_name(), 2),
+                                      first_player_dropped_item in option::of(item_name()),
+                                      second_player_dropped_item in option::of(item_name())) -> FedEventData {
+        FedEventData::CommunityChestGameMessage {
+            game,
+            first_player_name: names[0].clone(),
+            first_player_item_name: gained_items[0].clone(),
+            first_player_dropped_item,
+            second_player_name: names[1].clone(),
+            second_player_item_name: gained_items[1].clone(),
+            second_player_dropped_item,
+        }
+    }
+}
+
+/// Generates one round-trip test per strategy. The tests are named after their strategies, so the
+/// strategies are called through `super::` to get past the test functions that shadow them.
+macro_rules! round_trip_tests {
+    ($wrap:ident: $($name:ident),* $(,)?) => {
+        round_trip_tests!($wrap, assert_round_trips: $($name),*);
+    };
+    ($wrap:ident, $assert:ident: $($name:ident),* $(,)?) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(event in $wrap(super::$name())) {
+                    $assert(event)?;
+                }
+            )*
+        }
+    };
+}
+
+mod round_trips {
+    use super::*;
+
+    round_trip_tests!(fed_event:
+        being_speech, lets_go, play_ball, half_inning_start, batter_up, superyummy_game_start,
+        echoed_superyummy_game_start, ball, foul_ball, strike, flyout, ground_out, fielders_choice,
+        double_play, hit, home_run, stolen_base, caught_stealing, strikeout, walk, inning_end,
+        charm_strikeout, strike_zapped, peanut_flavor_text, game_end, mild_pitch, mild_pitch_walk,
+        charm_walk, mind_trick_walk, mind_trick_strikeout, hit_by_pitch, batter_skipped, party,
+        pitcher_change, runs_overflowing, coffee_bean, became_magmatic, blooddrain,
+        special_blooddrain, player_mod_expires, team_mod_expires, birds_circle, ambushed_by_crows,
+        sun2_set_win, black_hole_swallowed_win, sun2, black_hole, team_did_shame, team_was_shamed,
+        gain_free_refill, allergic_reaction, perk_up, feedback, bestow_reverberating, reverb,
+        tarot_reading, team_entered_party_time, become_triple_threat, under_over, over_under,
+        taste_the_infinite, feedback_blocked, flag_planted, emergency_alert, team_joined_ilb,
+        flooding_swept, return_from_elsewhere, incineration, player_hatched, postseason_birth,
+        final_standings, team_left_party_time_for_postseason, earned_postseason_slot,
+        postseason_advance, postseason_eliminated, player_boosted, team_won_internet_series,
+        bottom_dwellers, will_received, blessing_won, earlbirds_added_to_team, decree_passed,
+        player_joined_ilb, player_permitted_to_stay, fireproof_incineration, lineup_sorted,
+        earlbirds_removed_from_team, undersea, renovation_built, late_to_the_party_added,
+        peanut_mister, player_named_mvp, late_to_the_party_removed, birds_unshell,
+        replace_returned_player_from_shadows, player_called_back_to_hall, team_used_free_will,
+        player_lost_mod, investigation_message, high_pressure, player_pulled_through_rift,
+        player_localized, echo, solar_panels_await, echo_into_static, consumer_attack,
+        psychoacoustics, echo_receiver, team_gained_free_will, tidings, homebody_game_start,
+        salmon_swim, solar_panels_activate, team_middling, enter_crime_scene,
+        return_from_investigation, investigation_concluded, grind_rail, enter_secret_base,
+        exit_secret_base, echo_chamber, roam, glitter_crate, mods_from_another_mod_removed,
+        consumer_expelled, earlbirds_added_to_player, blooddrain_blocked,
+        earlbirds_removed_from_player, player_middling, community_chest_opens, player_drops_item,
+        community_chest_game_message,
+    );
+
+    round_trip_tests!(tarot_event:
+        tarot_reading_added_or_removed_mod, tarot_reading_added_or_removed_item,
+    );
+}
+
+mod game_updates {
+    use super::*;
+
+    // GameEnd is left out because game updates show "Game over." instead of the final score, so
+    // there's nothing to parse
+    round_trip_tests!(fed_event, assert_game_update_parses:
+        lets_go, play_ball, half_inning_start, pitcher_change, batter_up, ball, foul_ball, strike,
+        strikeout, charm_strikeout, walk, charm_walk, mind_trick_walk, mind_trick_strikeout, flyout,
+        ground_out, fielders_choice, double_play, hit, home_run, stolen_base, caught_stealing,
+        inning_end,
+    );
+}
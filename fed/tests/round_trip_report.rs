@@ -0,0 +1,185 @@
+//! Tests for verify_round_trip and diff_feed_events, using hand-built events
+
+mod common;
+
+use eventually_api::{EventType, EventuallyEvent};
+use serde_json::Value;
+use uuid::Uuid;
+
+use fed::*;
+
+use common::{created, event, game, AWAY_TEAM, HOME_TEAM};
+
+const BATTER: Uuid = Uuid::from_u128(4);
+const PITCHER: Uuid = Uuid::from_u128(5);
+const PITCHER_DAMAGE: Uuid = Uuid::from_u128(10);
+const BATTER_DAMAGE: Uuid = Uuid::from_u128(11);
+
+fn item_damage(sub_event_id: Uuid, team_id: Uuid, player_id: Uuid) -> ItemDamaged {
+    ItemDamaged {
+        item_id: Uuid::from_u128(sub_event_id.as_u128() + 100),
+        item_name: "Bat".to_string(),
+        item_name_plural: Some(false),
+        item_mods: vec![],
+        durability: 2,
+        health: 1,
+        player_item_rating_before: 1.,
+        player_item_rating_after: 0.5,
+        player_rating: 2.,
+        team_id,
+        player_id,
+        sub_event: SubEvent {
+            id: sub_event_id,
+            created: created(),
+            nuts: 0,
+        },
+    }
+}
+
+/// A Hit that damages both the pitcher's and the batter's items, so its feed event has two children
+fn hit() -> FedEvent {
+    event(100, created(), FedEventData::Hit {
+        game: game(10),
+        pitch: GamePitch { double_strike: None },
+        batter_name: "Silvaire Roadhouse".to_string(),
+        batter_id: BATTER,
+        hit_type: HitType::Single,
+        scores: Scores { scores: vec![], free_refills: vec![] },
+        spicy_status: SpicyStatus::None,
+        stopped_inhabiting: None,
+        special_reason: None,
+        pitcher_item_damage: Some(("Don Mitchell".to_string(), item_damage(PITCHER_DAMAGE, HOME_TEAM, PITCHER))),
+        batter_item_damage: Some(item_damage(BATTER_DAMAGE, AWAY_TEAM, BATTER)),
+        other_player_item_damage: None,
+    })
+}
+
+fn feed_hit() -> EventuallyEvent {
+    hit().into_feed_event()
+}
+
+#[test]
+fn unchanged_event_round_trips() {
+    let report = verify_round_trip(&feed_hit());
+    assert!(report.is_ok(), "{:?}", report.differences);
+    assert_eq!(report.parsed.unwrap(), hit());
+    assert_eq!(report.reconstructed, Some(feed_hit()));
+}
+
+#[test]
+fn parse_error_is_reported() {
+    let mut event = feed_hit();
+    event.description = "Something unparseable happens.".to_string();
+
+    let report = verify_round_trip(&event);
+    assert!(matches!(report.parsed, Err(RoundTripError::Parse(_))), "{:?}", report.parsed);
+    assert_eq!(report.reconstructed, None);
+    assert!(report.differences.is_empty());
+}
+
+#[test]
+fn panic_is_reported() {
+    let mut event = feed_hit();
+    event.r#type = EventType::Undefined;
+
+    let report = verify_round_trip(&event);
+    assert!(matches!(report.parsed, Err(RoundTripError::Panicked(_))), "{:?}", report.parsed);
+    assert_eq!(report.reconstructed, None);
+}
+
+#[test]
+fn description_difference() {
+    let expected = feed_hit();
+    let mut actual = expected.clone();
+    actual.description = "Silvaire Roadhouse hits a Double!".to_string();
+
+    assert_eq!(diff_feed_events(&expected, &actual), vec![RoundTripDifference::Description {
+        expected: expected.description.clone(),
+        actual: actual.description.clone(),
+    }]);
+}
+
+#[test]
+fn tags_difference() {
+    let expected = feed_hit();
+    let mut actual = expected.clone();
+    actual.player_tags.push(PITCHER);
+
+    assert_eq!(diff_feed_events(&expected, &actual), vec![RoundTripDifference::Tags {
+        kind: TagKind::Player,
+        expected: expected.player_tags.clone(),
+        actual: actual.player_tags.clone(),
+    }]);
+}
+
+#[test]
+fn metadata_key_difference() {
+    let expected = feed_hit();
+    let mut actual = expected.clone();
+    actual.metadata.play = Some(11);
+    actual.metadata.other["extra"] = Value::from(true);
+
+    assert_eq!(diff_feed_events(&expected, &actual), vec![
+        RoundTripDifference::Metadata {
+            key: "play".to_string(),
+            expected: Some(Value::from(10)),
+            actual: Some(Value::from(11)),
+        },
+        RoundTripDifference::Metadata {
+            key: "extra".to_string(),
+            expected: None,
+            actual: Some(Value::from(true)),
+        },
+    ]);
+}
+
+#[test]
+fn children_order_difference() {
+    let expected = feed_hit();
+    let mut actual = expected.clone();
+    actual.metadata.children.reverse();
+
+    assert_eq!(diff_feed_events(&expected, &actual), vec![RoundTripDifference::ChildrenOrder {
+        expected: vec![PITCHER_DAMAGE, BATTER_DAMAGE],
+        actual: vec![BATTER_DAMAGE, PITCHER_DAMAGE],
+    }]);
+}
+
+#[test]
+fn missing_child_difference() {
+    let expected = feed_hit();
+    let mut actual = expected.clone();
+    actual.metadata.children.remove(0);
+
+    assert_eq!(diff_feed_events(&expected, &actual), vec![RoundTripDifference::MissingChild {
+        id: PITCHER_DAMAGE,
+    }]);
+}
+
+#[test]
+fn extra_child_difference() {
+    let expected = feed_hit();
+    let mut actual = expected.clone();
+    let mut extra_child = actual.metadata.children[0].clone();
+    extra_child.id = Uuid::from_u128(12);
+    actual.metadata.children.push(extra_child);
+
+    assert_eq!(diff_feed_events(&expected, &actual), vec![RoundTripDifference::ExtraChild {
+        id: Uuid::from_u128(12),
+    }]);
+}
+
+#[test]
+fn child_difference() {
+    let expected = feed_hit();
+    let mut actual = expected.clone();
+    actual.metadata.children[1].description = "Silvaire Roadhouse's Bat was damaged.".to_string();
+
+    assert_eq!(diff_feed_events(&expected, &actual), vec![RoundTripDifference::Child {
+        id: BATTER_DAMAGE,
+        differences: vec![RoundTripDifference::Description {
+            expected: expected.metadata.children[1].description.clone(),
+            actual: actual.metadata.children[1].description.clone(),
+        }],
+    }]);
+}
@@ -0,0 +1,93 @@
+//! Tests for `Standings`, using short sequences of hand-built events
+
+mod common;
+
+use uuid::Uuid;
+
+use fed::*;
+
+const SEASON: i32 = 13;
+const TEAM_A: Uuid = Uuid::from_u128(1);
+const TEAM_B: Uuid = Uuid::from_u128(2);
+
+fn event(id: u128, day: i32, phase: SimPhase, data: FedEventData) -> FedEvent {
+    FedEvent { season: SEASON, day, phase, ..common::event(id, common::created(), data) }
+}
+
+fn game_end(id: u128, day: i32, phase: SimPhase, winner_id: Uuid) -> FedEvent {
+    let (winning_team_name, losing_team_name) = if winner_id == TEAM_A { ("Team A", "Team B") } else { ("Team B", "Team A") };
+    event(id, day, phase, FedEventData::GameEnd {
+        game: GameEvent {
+            game_id: Uuid::from_u128(100 + id),
+            home_team: TEAM_A,
+            away_team: TEAM_B,
+            play: 100,
+            unscatter: None,
+            attractor_secret_base: None,
+        },
+        winner_id,
+        winning_team_name: winning_team_name.to_string(),
+        winning_team_score: 4.,
+        losing_team_name: losing_team_name.to_string(),
+        losing_team_score: 2.,
+        temp_stolen_player_returned: None,
+    })
+}
+
+fn sun2_set_win(id: u128, day: i32, phase: SimPhase, team_id: Uuid) -> FedEvent {
+    event(id, day, phase, FedEventData::Sun2SetWin { team_id, team_nickname: "Team".to_string() })
+}
+
+fn black_hole_swallowed_win(id: u128, day: i32, phase: SimPhase, team_id: Uuid) -> FedEvent {
+    event(id, day, phase, FedEventData::BlackHoleSwallowedWin { team_id, team_nickname: "Team".to_string() })
+}
+
+fn standings(events: &[FedEvent]) -> Standings {
+    let mut standings = Standings::new();
+    for event in events {
+        standings.apply(event);
+    }
+    standings
+}
+
+fn record(records: &[TeamRecord], team_id: Uuid) -> (i32, i32, i32, i32) {
+    let record = records.iter().find(|record| record.team_id == team_id).unwrap();
+    (record.games_won, record.games_lost, record.sun2_wins, record.black_hole_wins)
+}
+
+#[test]
+fn regular_season_records_count_games_and_wins_from_sun2_and_the_black_hole() {
+    let standings = standings(&[
+        game_end(1, 0, SimPhase::Midseason, TEAM_A),
+        sun2_set_win(2, 0, SimPhase::Midseason, TEAM_A),
+        game_end(3, 1, SimPhase::Midseason, TEAM_B),
+        black_hole_swallowed_win(4, 1, SimPhase::Midseason, TEAM_B),
+    ]);
+
+    let final_standings = standings.final_standings(SEASON);
+    assert_eq!(record(&final_standings, TEAM_A), (1, 1, 1, 0));
+    assert_eq!(record(&final_standings, TEAM_B), (1, 1, 0, 1));
+    assert_eq!(final_standings[0].team_id, TEAM_A);
+    assert_eq!(final_standings[0].wins(), 2);
+    assert_eq!(final_standings[1].wins(), 0);
+
+    assert_eq!(record(&standings.at(SEASON, 0), TEAM_A), (1, 0, 1, 0));
+}
+
+#[test]
+fn postseason_wins_are_left_out_of_the_regular_season_record() {
+    let standings = standings(&[
+        game_end(1, 0, SimPhase::Midseason, TEAM_A),
+        game_end(2, 99, SimPhase::Earlpostseason, TEAM_B),
+        sun2_set_win(3, 99, SimPhase::Earlpostseason, TEAM_B),
+        black_hole_swallowed_win(4, 100, SimPhase::Latepostseason, TEAM_A),
+    ]);
+
+    let final_standings = standings.final_standings(SEASON);
+    assert_eq!(record(&final_standings, TEAM_A), (1, 0, 0, 0));
+    assert_eq!(record(&final_standings, TEAM_B), (0, 1, 0, 0));
+
+    let bracket = standings.bracket(SEASON).unwrap();
+    assert_eq!(bracket.games.len(), 1);
+    assert_eq!(bracket.games[0].winner_id, TEAM_B);
+}
@@ -0,0 +1,196 @@
+//! Tests for `StatsAccumulator`, replaying short hand-built games
+
+mod common;
+
+use chrono::Duration;
+use uuid::Uuid;
+
+use fed::*;
+
+const TEAM_B: Uuid = Uuid::from_u128(3);
+const TEAM_C: Uuid = Uuid::from_u128(4);
+const TEAM_D: Uuid = Uuid::from_u128(7);
+const SAM_ON_B: Uuid = Uuid::from_u128(5);
+const SAM_ON_C: Uuid = Uuid::from_u128(6);
+
+/// Play `play` of a game between `common::HOME_TEAM` and `away_team`
+fn game(game_id: Uuid, away_team: Uuid, play: i64) -> GameEvent {
+    GameEvent { game_id, away_team, ..common::game(play) }
+}
+
+fn event(game: GameEvent, data: FedEventData) -> FedEvent {
+    let id = game.game_id.as_u128() * 1000 + game.play as u128;
+    common::event(id, common::created() + Duration::seconds(game.play * 5), data)
+}
+
+fn half_inning_start(game: GameEvent) -> FedEvent {
+    event(game.clone(), FedEventData::HalfInningStart {
+        game,
+        top_of_inning: true,
+        inning: 0,
+        batting_team_name: "Away Team".to_string(),
+        subseasonal_mod_effects: vec![],
+    })
+}
+
+fn batter_up(game: GameEvent, batter_name: &str) -> FedEvent {
+    event(game.clone(), FedEventData::BatterUp {
+        game,
+        batter_name: batter_name.to_string(),
+        team_nickname: "Away".to_string(),
+        wielding_item: None,
+        inhabiting: None,
+        is_repeating: false,
+    })
+}
+
+fn single(game: GameEvent, batter_id: Uuid, batter_name: &str) -> FedEvent {
+    event(game.clone(), FedEventData::Hit {
+        game,
+        pitch: GamePitch { double_strike: None },
+        batter_name: batter_name.to_string(),
+        batter_id,
+        hit_type: HitType::Single,
+        scores: Scores { scores: vec![], free_refills: vec![] },
+        spicy_status: SpicyStatus::None,
+        stopped_inhabiting: None,
+        special_reason: None,
+        pitcher_item_damage: None,
+        batter_item_damage: None,
+        other_player_item_damage: None,
+    })
+}
+
+fn strikeout(game: GameEvent, batter_name: &str) -> FedEvent {
+    event(game.clone(), FedEventData::StrikeoutSwinging {
+        game,
+        pitch: GamePitch { double_strike: None },
+        batter_name: batter_name.to_string(),
+        stopped_inhabiting: None,
+        pitcher_item_damage: None,
+        free_refill: None,
+        special_reason: None,
+        parasite: None,
+    })
+}
+
+/// One half-inning where `batter_name` strikes out, and then singles if `batter_id` is given
+fn at_bats(game_id: Uuid, away_team: Uuid, batter_name: &str, batter_id: Option<Uuid>) -> Vec<FedEvent> {
+    let mut events = vec![
+        half_inning_start(game(game_id, away_team, 0)),
+        batter_up(game(game_id, away_team, 1), batter_name),
+        strikeout(game(game_id, away_team, 2), batter_name),
+    ];
+    if let Some(batter_id) = batter_id {
+        events.push(batter_up(game(game_id, away_team, 3), batter_name));
+        events.push(single(game(game_id, away_team, 4), batter_id, batter_name));
+    }
+    events
+}
+
+fn accumulate(events: impl IntoIterator<Item=FedEvent>) -> Vec<PlayerSeasonStats> {
+    let mut stats = StatsAccumulator::new();
+    for event in events {
+        stats.push(&event).unwrap_or_else(|err| panic!("Couldn't apply {:?}: {err}", event.data));
+    }
+    stats.results()
+}
+
+#[test]
+fn name_only_stats_join_the_uuid_from_the_same_game() {
+    let results = accumulate(at_bats(Uuid::from_u128(100), TEAM_B, "Sam Same", Some(SAM_ON_B)));
+
+    assert_eq!(results.len(), 1);
+    let sam = &results[0];
+    assert_eq!(sam.player_id, Some(SAM_ON_B));
+    assert_eq!(sam.player_name, "Sam Same");
+    assert_eq!(sam.batting.plate_appearances, 2);
+    assert_eq!(sam.batting.strikeouts, 1);
+    assert_eq!(sam.batting.hits, 1);
+}
+
+#[test]
+fn players_with_the_same_name_are_kept_apart() {
+    let events = at_bats(Uuid::from_u128(100), TEAM_B, "Sam Same", Some(SAM_ON_B)).into_iter()
+        .chain(at_bats(Uuid::from_u128(101), TEAM_C, "Sam Same", Some(SAM_ON_C)))
+        // This Sam's uuid never shows up, so their strikeout can't be given to either of the others
+        .chain(at_bats(Uuid::from_u128(102), TEAM_D, "Sam Same", None));
+    let results = accumulate(events);
+
+    let mut by_id: Vec<_> = results.iter()
+        .map(|stats| (stats.player_id, stats.batting.strikeouts, stats.batting.hits))
+        .collect();
+    by_id.sort();
+    assert_eq!(by_id, vec![(None, 1, 0), (Some(SAM_ON_B), 1, 1), (Some(SAM_ON_C), 1, 1)]);
+}
+
+fn walk(game: GameEvent, batter_id: Uuid, batter_name: &str) -> FedEvent {
+    event(game.clone(), FedEventData::Walk {
+        game,
+        batter_name: batter_name.to_string(),
+        batter_id,
+        scores: Scores { scores: vec![], free_refills: vec![] },
+        base_instincts: None,
+        batter_item_damage: None,
+        stopped_inhabiting: None,
+        special_reason: None,
+    })
+}
+
+fn two_run_home_run(game: GameEvent, batter_id: Uuid, batter_name: &str) -> FedEvent {
+    event(game.clone(), FedEventData::HomeRun {
+        game,
+        pitch: GamePitch { double_strike: None },
+        magmatic: None,
+        batter_name: batter_name.to_string(),
+        batter_id,
+        home_run_type: HomeRunType::TwoRun,
+        stopped_inhabiting: None,
+        free_refills: vec![],
+        spicy_status: SpicyStatus::None,
+        special_reason: None,
+        big_bucket: false,
+        attraction: None,
+        damaged_items: vec![],
+    })
+}
+
+#[test]
+fn starting_pitchers_accumulate_pitching_stats() {
+    const PITCHER: Uuid = Uuid::from_u128(8);
+    let game_id = Uuid::from_u128(100);
+    let events = [
+        half_inning_start(game(game_id, TEAM_B, 0)),
+        batter_up(game(game_id, TEAM_B, 1), "Sam Same"),
+        strikeout(game(game_id, TEAM_B, 2), "Sam Same"),
+        batter_up(game(game_id, TEAM_B, 3), "Sam Same"),
+        walk(game(game_id, TEAM_B, 4), SAM_ON_B, "Sam Same"),
+        batter_up(game(game_id, TEAM_B, 5), "Ann Other"),
+        two_run_home_run(game(game_id, TEAM_B, 6), Uuid::from_u128(9), "Ann Other"),
+    ];
+
+    let mut stats = StatsAccumulator::new();
+    stats.push_game_start(game_id,
+                          PlayerRef { id: Some(PITCHER), name: "Pat Pitcher".to_string() },
+                          PlayerRef { id: Some(Uuid::from_u128(10)), name: "Away Pitcher".to_string() });
+    for event in &events {
+        stats.push(event).unwrap_or_else(|err| panic!("Couldn't apply {:?}: {err}", event.data));
+    }
+
+    let results = stats.results();
+    let pitcher = results.iter()
+        .find(|stats| stats.player_id == Some(PITCHER))
+        .expect("The starting pitcher should have stats");
+    assert_eq!(pitcher.player_name, "Pat Pitcher");
+    assert_eq!(pitcher.pitching, PitchingStats {
+        batters_faced: 3,
+        outs_recorded: 1,
+        hits_allowed: 1,
+        home_runs_allowed: 1,
+        runs_allowed: 2,
+        walks: 1,
+        hit_batters: 0,
+        strikeouts: 1,
+    });
+    assert!(results.iter().all(|stats| stats.player_id != Some(Uuid::from_u128(10))));
+}
@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+use clap::Parser;
+use fed::BoxScore;
+use uuid::Uuid;
+
+/// Print the box score and play-by-play for one game
+#[derive(Parser)]
+struct Args {
+    /// Uuid of the game
+    game_id: Uuid,
+
+    /// Print the box score as JSON instead of text
+    #[arg(long)]
+    json: bool,
+
+    /// Feed dump to read from, in ndjson format. May be gzipped.
+    #[arg(short, long, default_value = "feed_dump.filtered.ndjson.gz")]
+    input: PathBuf,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let mut events = Vec::new();
+    fed_tools::for_each_fed_event(&args.input, |event| {
+        if event.data.game().is_some_and(|game| game.game_id == args.game_id) {
+            events.push(event);
+        }
+        Ok(())
+    })?;
+    events.sort_by_key(|event| event.data.game().map(|game| game.play));
+
+    let box_score = BoxScore::from_events(&events)?
+        .ok_or_else(|| anyhow::anyhow!("No events found for game {}", args.game_id))?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&box_score)?);
+    } else {
+        print!("{box_score}");
+    }
+
+    Ok(())
+}
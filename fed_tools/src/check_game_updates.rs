@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::Context;
+use clap::Parser;
+use fed::{FedEvent, GameUpdate};
+use itertools::Itertools;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Compare the game updates generated from one game's events against recorded Chronicler game
+/// updates
+#[derive(Parser)]
+struct Args {
+    /// Uuid of the game
+    game_id: Uuid,
+
+    /// Recorded game updates. Accepts a Chronicler v1 game updates or v2 versions response, a JSON
+    /// array of game updates, or ndjson with one game update per line. Game updates may be bare or
+    /// wrapped in an object with a `data` field.
+    updates: PathBuf,
+
+    /// Feed dump to read from, in ndjson format. May be gzipped.
+    #[arg(short, long, default_value = "feed_dump.filtered.ndjson.gz")]
+    input: PathBuf,
+
+    /// Directory to save the game's feed events and recorded game updates to, in a subdirectory
+    /// named after the game id. The fed crate's fixture test checks the lastUpdate of every game
+    /// saved in fed/tests/fixtures/game_updates.
+    #[arg(long, value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    capture: Option<PathBuf>,
+}
+
+fn recorded_updates(path: &Path) -> anyhow::Result<Vec<Value>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Couldn't read {}", path.display()))?;
+
+    let items = match serde_json::from_str::<Value>(&contents) {
+        Ok(Value::Array(items)) => items,
+        Ok(Value::Object(mut response)) => {
+            match response.remove("data").or_else(|| response.remove("items")) {
+                Some(Value::Array(items)) => items,
+                _ => anyhow::bail!("Expected a `data` or `items` array in {}", path.display()),
+            }
+        }
+        Ok(_) => anyhow::bail!("Unrecognized game updates format in {}", path.display()),
+        Err(_) => contents.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()
+            .with_context(|| format!("Couldn't parse {} as JSON or ndjson", path.display()))?,
+    };
+
+    Ok(items.into_iter()
+        .map(|mut item| {
+            if item.get("data").is_some_and(Value::is_object) { item["data"].take() } else { item }
+        })
+        .collect())
+}
+
+/// Saves the game's events in feed form, and its recorded updates in play order
+fn capture_game(dir: &Path, events: &[FedEvent], recorded: &HashMap<i64, Value>) -> anyhow::Result<()> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Couldn't create {}", dir.display()))?;
+
+    let feed_events: Vec<_> = events.iter().cloned().map(FedEvent::into_feed_event).collect();
+    fs::write(dir.join("events.json"), serde_json::to_string_pretty(&feed_events)? + "\n")?;
+
+    let updates: Vec<_> = recorded.iter()
+        .sorted_by_key(|(play_count, _)| **play_count)
+        .map(|(_, update)| update)
+        .collect();
+    fs::write(dir.join("updates.json"), serde_json::to_string_pretty(&updates)? + "\n")?;
+
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let recorded: HashMap<i64, Value> = recorded_updates(&args.updates)?.into_iter()
+        .filter(|update| match update.get("id").and_then(|id| id.as_str()) {
+            Some(id) => id == args.game_id.to_string(),
+            None => true,
+        })
+        .filter_map(|update| {
+            let play_count = update.get("playCount")?.as_i64()?;
+            Some((play_count, update))
+        })
+        .collect();
+
+    let mut events = Vec::new();
+    fed_tools::for_each_fed_event(&args.input, |event| {
+        if event.data.game().is_some_and(|game| game.game_id == args.game_id) {
+            events.push(event);
+        }
+        Ok(())
+    })?;
+    events.sort_by_key(|event| event.data.game().map(|game| game.play));
+
+    let generated = GameUpdate::from_events(&events)?;
+    if generated.is_empty() {
+        anyhow::bail!("No events found for game {}", args.game_id);
+    }
+
+    if let Some(capture_dir) = &args.capture {
+        capture_game(&capture_dir.join(args.game_id.to_string()), &events, &recorded)?;
+    }
+
+    let mut num_missing = 0;
+    let mut num_mismatched = 0;
+    for update in &generated {
+        let Some(recorded_update) = recorded.get(&update.play_count) else {
+            num_missing += 1;
+            continue;
+        };
+
+        let mismatches = update.diff(recorded_update);
+        if !mismatches.is_empty() {
+            num_mismatched += 1;
+            println!("playCount {}: {:?}", update.play_count, update.last_update);
+            for mismatch in mismatches {
+                println!("    {}: recorded {}, generated {}", mismatch.field, mismatch.recorded, mismatch.generated);
+            }
+        }
+    }
+
+    println!("{} generated updates, {} mismatched, {} with no recorded update", generated.len(), num_mismatched, num_missing);
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use arrow_array::RecordBatch;
+use arrow_json::ReaderBuilder;
+use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef, TimeUnit};
+use fed::FedEvent;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use serde_json::{Map, Value};
+
+use crate::export::{conform_row, EventRows};
+use crate::schema::{Column, ColumnType, Table, Tables};
+
+const DEFAULT_BATCH_SIZE: usize = 8192;
+
+fn arrow_type(ty: &ColumnType) -> DataType {
+    match ty {
+        ColumnType::Boolean => DataType::Boolean,
+        ColumnType::Integer => DataType::Int64,
+        ColumnType::Float => DataType::Float64,
+        ColumnType::String | ColumnType::Uuid | ColumnType::Json => DataType::Utf8,
+        ColumnType::DateTime => DataType::Timestamp(TimeUnit::Millisecond, Some("+00:00".into())),
+        ColumnType::Struct(columns) => DataType::Struct(arrow_fields(columns)),
+        ColumnType::List(item_ty) => DataType::List(Arc::new(Field::new_list_field(arrow_type(item_ty), true))),
+    }
+}
+
+fn arrow_fields(columns: &[Column]) -> Fields {
+    columns.iter()
+        .map(|column| Field::new(&column.name, arrow_type(&column.ty), column.nullable))
+        .collect()
+}
+
+pub fn arrow_schema(table: &Table) -> Schema {
+    Schema::new(arrow_fields(&table.columns))
+}
+
+/// Collects rows for one table and turns them into Arrow record batches
+pub struct RecordBatcher {
+    table: Table,
+    schema: SchemaRef,
+    rows: Vec<Map<String, Value>>,
+    batch_size: usize,
+}
+
+impl RecordBatcher {
+    pub fn new(table: Table, batch_size: usize) -> Self {
+        Self {
+            schema: Arc::new(arrow_schema(&table)),
+            table,
+            rows: Vec::with_capacity(batch_size),
+            batch_size,
+        }
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Adds a row, returning a batch if that filled one up
+    pub fn push(&mut self, row: Map<String, Value>) -> anyhow::Result<Option<RecordBatch>> {
+        self.rows.push(conform_row(&self.table.columns, row));
+
+        if self.rows.len() >= self.batch_size {
+            self.flush()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns a batch of all the remaining rows, if there are any
+    pub fn flush(&mut self) -> anyhow::Result<Option<RecordBatch>> {
+        if self.rows.is_empty() {
+            return Ok(None);
+        }
+
+        let mut decoder = ReaderBuilder::new(self.schema.clone())
+            .with_batch_size(self.rows.len())
+            .build_decoder()?;
+        decoder.serialize(&self.rows)?;
+        self.rows.clear();
+
+        Ok(decoder.flush()?)
+    }
+}
+
+/// Turns a stream of FedEvents into Arrow record batches: one stream of batches for the common
+/// table and one for each variant.
+pub struct ArrowExporter {
+    tables: Tables,
+    batch_size: usize,
+    common: RecordBatcher,
+    variants: HashMap<String, RecordBatcher>,
+}
+
+impl ArrowExporter {
+    pub fn new(tables: Tables) -> Self {
+        Self::with_batch_size(tables, DEFAULT_BATCH_SIZE)
+    }
+
+    pub fn with_batch_size(tables: Tables, batch_size: usize) -> Self {
+        Self {
+            common: RecordBatcher::new(tables.common.clone(), batch_size),
+            tables,
+            batch_size,
+            variants: HashMap::new(),
+        }
+    }
+
+    /// Adds an event. Any batches that fill up as a result are passed to `sink` along with the
+    /// name of their table.
+    pub fn push(&mut self, event: FedEvent, mut sink: impl FnMut(&str, RecordBatch) -> anyhow::Result<()>) -> anyhow::Result<()> {
+        let rows = EventRows::new(event, &self.tables.common)?;
+
+        if let Some(batch) = self.common.push(rows.common)? {
+            sink(&self.tables.common.name, batch)?;
+        }
+
+        let batcher = match self.variants.get_mut(&rows.variant) {
+            Some(batcher) => batcher,
+            None => {
+                let table = self.tables.variant(&rows.variant)
+                    .ok_or_else(|| anyhow::anyhow!("No table for variant {}", rows.variant))?
+                    .clone();
+                self.variants.entry(rows.variant.clone())
+                    .or_insert(RecordBatcher::new(table, self.batch_size))
+            }
+        };
+        if let Some(batch) = batcher.push(rows.variant_row)? {
+            sink(&rows.variant, batch)?;
+        }
+
+        Ok(())
+    }
+
+    /// Passes every remaining partial batch to `sink`
+    pub fn finish(mut self, mut sink: impl FnMut(&str, RecordBatch) -> anyhow::Result<()>) -> anyhow::Result<()> {
+        if let Some(batch) = self.common.flush()? {
+            sink(&self.tables.common.name, batch)?;
+        }
+
+        for (name, batcher) in &mut self.variants {
+            if let Some(batch) = batcher.flush()? {
+                sink(name, batch)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a stream of FedEvents to a directory of Parquet files, one for the common table and one
+/// for each variant that appears in the stream
+pub struct ParquetExporter {
+    dir: PathBuf,
+    arrow: ArrowExporter,
+    writers: HashMap<String, ArrowWriter<File>>,
+}
+
+impl ParquetExporter {
+    pub fn new(dir: impl Into<PathBuf>, tables: Tables) -> anyhow::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            arrow: ArrowExporter::new(tables),
+            writers: HashMap::new(),
+        })
+    }
+
+    pub fn push(&mut self, event: FedEvent) -> anyhow::Result<()> {
+        let Self { dir, arrow, writers } = self;
+        arrow.push(event, |name, batch| write_batch(dir, writers, name, batch))
+    }
+
+    pub fn finish(self) -> anyhow::Result<()> {
+        let Self { dir, arrow, mut writers } = self;
+        arrow.finish(|name, batch| write_batch(&dir, &mut writers, name, batch))?;
+
+        for (_, writer) in writers {
+            writer.close()?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_batch(dir: &Path, writers: &mut HashMap<String, ArrowWriter<File>>, name: &str, batch: RecordBatch) -> anyhow::Result<()> {
+    let writer = match writers.get_mut(name) {
+        Some(writer) => writer,
+        None => {
+            let file = File::create(dir.join(format!("{name}.parquet")))?;
+            let props = WriterProperties::builder()
+                .set_compression(Compression::SNAPPY)
+                .build();
+            let writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+            writers.entry(name.to_string()).or_insert(writer)
+        }
+    };
+
+    writer.write(&batch)?;
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use fed::FedEvent;
+use serde_json::{Map, Value};
+
+use crate::export::EventRows;
+use crate::schema::{LeafColumn, Table, Tables};
+
+/// Separator between the name of a struct-valued field and the names of its fields when they're
+/// expanded into columns
+const PREFIX_SEPARATOR: &str = ".";
+
+fn cell(column: &LeafColumn, row: &Map<String, Value>) -> String {
+    match column.get(row) {
+        None => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Bool(b)) => b.to_string(),
+        Some(Value::Number(n)) => n.to_string(),
+        // Lists and anything without a fixed layout get written as JSON
+        Some(other) => other.to_string(),
+    }
+}
+
+/// Writes rows of one table to a CSV file
+pub struct CsvTableWriter {
+    columns: Vec<LeafColumn>,
+    writer: csv::Writer<File>,
+}
+
+impl CsvTableWriter {
+    pub fn create(path: &Path, table: &Table) -> anyhow::Result<Self> {
+        let columns = table.leaf_columns(PREFIX_SEPARATOR);
+
+        let mut writer = csv::Writer::from_path(path)?;
+        writer.write_record(columns.iter().map(|column| &column.name))?;
+
+        Ok(Self { columns, writer })
+    }
+
+    pub fn write_row(&mut self, row: &Map<String, Value>) -> anyhow::Result<()> {
+        self.writer.write_record(self.columns.iter().map(|column| cell(column, row)))?;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> anyhow::Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Writes a stream of FedEvents to a directory of CSV files, one for each variant that appears in
+/// the stream plus one with the fields common to every event. Nested structs are expanded into
+/// prefixed columns (e.g. `subEvent.id`) and lists are written as JSON.
+pub struct CsvExporter {
+    dir: PathBuf,
+    tables: Tables,
+    common: CsvTableWriter,
+    variants: HashMap<String, CsvTableWriter>,
+}
+
+impl CsvExporter {
+    pub fn new(dir: impl Into<PathBuf>, tables: Tables) -> anyhow::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            common: CsvTableWriter::create(&dir.join(format!("{}.csv", tables.common.name)), &tables.common)?,
+            dir,
+            tables,
+            variants: HashMap::new(),
+        })
+    }
+
+    pub fn push(&mut self, event: FedEvent) -> anyhow::Result<()> {
+        let rows = EventRows::new(event, &self.tables.common)?;
+        self.common.write_row(&rows.common)?;
+
+        let writer = match self.variants.get_mut(&rows.variant) {
+            Some(writer) => writer,
+            None => {
+                let table = self.tables.variant(&rows.variant)
+                    .ok_or_else(|| anyhow::anyhow!("No table for variant {}", rows.variant))?;
+                let writer = CsvTableWriter::create(&self.dir.join(format!("{}.csv", rows.variant)), table)?;
+                self.variants.entry(rows.variant.clone())
+                    .or_insert(writer)
+            }
+        };
+
+        writer.write_row(&rows.variant_row)
+    }
+
+    pub fn finish(self) -> anyhow::Result<()> {
+        self.common.finish()?;
+        for (_, writer) in self.variants {
+            writer.finish()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Convenience function to write every event from an iterator to CSV files in `dir`
+pub fn export_csv(events: impl IntoIterator<Item=FedEvent>, dir: impl Into<PathBuf>, tables: Tables) -> anyhow::Result<()> {
+    let mut exporter = CsvExporter::new(dir, tables)?;
+    for event in events {
+        exporter.push(event)?;
+    }
+
+    exporter.finish()
+}
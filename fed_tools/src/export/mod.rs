@@ -0,0 +1,80 @@
+mod arrow;
+mod csv;
+mod sqlite;
+
+pub use self::arrow::{ArrowExporter, ParquetExporter, RecordBatcher};
+pub use self::csv::{export_csv, CsvExporter, CsvTableWriter};
+pub use self::sqlite::SqliteExporter;
+
+use anyhow::anyhow;
+use enum_flatten::EnumFlatten;
+use fed::FedEvent;
+use itertools::Itertools;
+use serde_json::{Map, Value};
+
+use crate::schema::{Column, ColumnType, Table, TYPE_COLUMN_NAME};
+
+/// One serialized event, split up by table
+pub struct EventRows {
+    /// Name of the FedEventData variant, which is also the name of its table
+    pub variant: String,
+
+    /// Row for the common table
+    pub common: Map<String, Value>,
+
+    /// Row for the variant table. This is the serialized flattened struct, so it also includes the
+    /// common fields.
+    pub variant_row: Map<String, Value>,
+}
+
+impl EventRows {
+    pub fn new(event: FedEvent, common_table: &Table) -> anyhow::Result<Self> {
+        // The flat enum serializes as {"VariantName": { ...flattened struct... }}
+        let Value::Object(wrapper) = serde_json::to_value(event.flatten())? else {
+            return Err(anyhow!("Flattened event didn't serialize to an object"));
+        };
+        let (variant, variant_row) = wrapper.into_iter().exactly_one()
+            .map_err(|_| anyhow!("Flattened event didn't serialize to a single-key object"))?;
+        let Value::Object(variant_row) = variant_row else {
+            return Err(anyhow!("{variant} didn't serialize to an object"));
+        };
+
+        let common = common_table.columns.iter()
+            .map(|column| {
+                let value = if column.name == TYPE_COLUMN_NAME {
+                    Value::String(variant.clone())
+                } else {
+                    variant_row.get(&column.name).cloned().unwrap_or(Value::Null)
+                };
+                (column.name.clone(), value)
+            })
+            .collect();
+
+        Ok(Self { variant, common, variant_row })
+    }
+}
+
+/// Converts a value into the shape its column expects. Right now that means turning anything that
+/// has type Json into a string.
+pub fn conform_value(ty: &ColumnType, value: Value) -> Value {
+    match (ty, value) {
+        (_, Value::Null) => Value::Null,
+        (ColumnType::Json, value) => Value::String(value.to_string()),
+        (ColumnType::Struct(columns), Value::Object(obj)) => Value::Object(conform_row(columns, obj)),
+        (ColumnType::List(item_ty), Value::Array(items)) => {
+            Value::Array(items.into_iter().map(|item| conform_value(item_ty, item)).collect())
+        }
+        (_, value) => value,
+    }
+}
+
+/// Converts every value in a row into the shape its column expects, dropping anything that isn't
+/// a column and filling in nulls for any missing columns
+pub fn conform_row(columns: &[Column], mut row: Map<String, Value>) -> Map<String, Value> {
+    columns.iter()
+        .map(|column| {
+            let value = row.remove(&column.name).unwrap_or(Value::Null);
+            (column.name.clone(), conform_value(&column.ty, value))
+        })
+        .collect()
+}
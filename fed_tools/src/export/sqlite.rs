@@ -0,0 +1,222 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use fed::FedEvent;
+use itertools::Itertools;
+use rusqlite::{Connection, OptionalExtension, params};
+use rusqlite::types::Value as SqlValue;
+use serde_json::{Map, Value};
+
+use crate::export::EventRows;
+use crate::schema::{ColumnType, COMMON_TABLE_NAME, LeafColumn, Tables, TYPE_COLUMN_NAME};
+
+/// Separator between the name of a struct-valued field and the names of its fields when they're
+/// expanded into columns. This isn't "." like in CSVs so the column names don't need quoting.
+const PREFIX_SEPARATOR: &str = "_";
+
+/// The kinds of entity that get a link table, along with the name of the link table's id column
+const LINK_TABLES: [(&str, &str); 3] = [
+    ("event_players", "player_id"),
+    ("event_teams", "team_id"),
+    ("event_games", "game_id"),
+];
+
+/// Number of events to insert per transaction
+const EVENTS_PER_TRANSACTION: usize = 10_000;
+
+fn quote(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+fn sql_type(ty: &ColumnType) -> &'static str {
+    match ty {
+        ColumnType::Boolean | ColumnType::Integer => "INTEGER",
+        ColumnType::Float => "REAL",
+        // Lists, structs (which shouldn't get here), and Json are stored as JSON text. SQLite's
+        // JSON functions can pick them apart.
+        _ => "TEXT",
+    }
+}
+
+fn sql_value(value: Option<&Value>) -> SqlValue {
+    match value {
+        None | Some(Value::Null) => SqlValue::Null,
+        Some(Value::Bool(b)) => SqlValue::Integer(*b as i64),
+        Some(Value::Number(n)) => {
+            if let Some(i) = n.as_i64() {
+                SqlValue::Integer(i)
+            } else {
+                SqlValue::Real(n.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Some(Value::String(s)) => SqlValue::Text(s.clone()),
+        Some(other) => SqlValue::Text(other.to_string()),
+    }
+}
+
+struct SqlTable {
+    columns: Vec<LeafColumn>,
+    insert: String,
+}
+
+impl SqlTable {
+    fn new(name: &str, columns: Vec<LeafColumn>) -> (Self, String) {
+        let column_defs = columns.iter()
+            .map(|column| {
+                let mut def = format!("{} {}", quote(&column.name), sql_type(&column.ty));
+                if column.name == "id" {
+                    def += " PRIMARY KEY";
+                    if name != COMMON_TABLE_NAME {
+                        def += &format!(" REFERENCES {COMMON_TABLE_NAME}(id)");
+                    }
+                } else if !column.nullable {
+                    def += " NOT NULL";
+                }
+                def
+            })
+            .join(", ");
+        let create = format!("CREATE TABLE IF NOT EXISTS {} ({column_defs})", quote(name));
+
+        let insert = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            quote(name),
+            columns.iter().map(|column| quote(&column.name)).join(", "),
+            columns.iter().map(|_| "?").join(", "),
+        );
+
+        (Self { columns, insert }, create)
+    }
+
+    fn insert(&self, conn: &Connection, row: &Map<String, Value>) -> rusqlite::Result<()> {
+        let values = self.columns.iter()
+            .map(|column| sql_value(column.get(row)));
+        conn.prepare_cached(&self.insert)?
+            .execute(rusqlite::params_from_iter(values))?;
+        Ok(())
+    }
+}
+
+/// Writes a stream of FedEvents to a SQLite database. There is an `events` table with the fields
+/// common to every event, one table per variant keyed by event id, and link tables for the
+/// players, teams, and games that each event refers to. The links come from the event's typed
+/// ids (`FedEventData::named_players`, `team_ids`, and `game`), so a player is only linked when
+/// the event names them.
+///
+/// The variant tables are created up front from the flattened structs, so every variant has a
+/// table even if no events of that type are exported.
+///
+/// Exporting an event whose id is already in the database replaces it. Its old variant row and
+/// links are deleted first, so links that the new version doesn't have don't linger.
+pub struct SqliteExporter {
+    conn: Connection,
+    tables: Tables,
+    common: SqlTable,
+    variants: HashMap<String, SqlTable>,
+    events_in_transaction: usize,
+}
+
+impl SqliteExporter {
+    pub fn new(path: &Path, tables: Tables) -> anyhow::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+
+        let (common, create) = SqlTable::new(&tables.common.name, tables.common.leaf_columns(PREFIX_SEPARATOR));
+        conn.execute(&create, [])?;
+
+        // Variant tables only repeat the id from the common fields, to link them to the events
+        // table
+        let header_names: Vec<_> = tables.common.columns.iter()
+            .map(|column| column.name.as_str())
+            .filter(|&name| name != "id" && name != TYPE_COLUMN_NAME)
+            .collect();
+        let variants = tables.variants.iter()
+            .map(|table| {
+                let columns = table.leaf_columns(PREFIX_SEPARATOR).into_iter()
+                    .filter(|column| !header_names.contains(&column.path[0].as_str()))
+                    .collect();
+                let (sql_table, create) = SqlTable::new(&table.name, columns);
+                conn.execute(&create, [])?;
+                Ok((table.name.clone(), sql_table))
+            })
+            .collect::<rusqlite::Result<_>>()?;
+
+        for (table_name, id_column) in LINK_TABLES {
+            conn.execute(&format!(
+                "CREATE TABLE IF NOT EXISTS {table_name} (\
+                    event_id TEXT NOT NULL REFERENCES {COMMON_TABLE_NAME}(id), \
+                    {id_column} TEXT NOT NULL, \
+                    PRIMARY KEY (event_id, {id_column}))"
+            ), [])?;
+            conn.execute(&format!(
+                "CREATE INDEX IF NOT EXISTS {table_name}_{id_column} ON {table_name} ({id_column})"
+            ), [])?;
+        }
+
+        conn.execute_batch("BEGIN")?;
+
+        Ok(Self { conn, tables, common, variants, events_in_transaction: 0 })
+    }
+
+    pub fn push(&mut self, event: FedEvent) -> anyhow::Result<()> {
+        let event_id = event.id.to_string();
+        let players: BTreeSet<_> = event.data.named_players().into_iter()
+            .map(|player| player.player_id)
+            .collect();
+        let teams: BTreeSet<_> = event.data.team_ids().into_iter().collect();
+        let games: BTreeSet<_> = event.data.game().map(|game| game.game_id).into_iter().collect();
+        let rows = EventRows::new(event, &self.tables.common)?;
+
+        self.delete_event(&event_id)?;
+        self.common.insert(&self.conn, &rows.common)?;
+
+        let variant_table = self.variants.get(&rows.variant)
+            .ok_or_else(|| anyhow::anyhow!("No table for variant {}", rows.variant))?;
+        variant_table.insert(&self.conn, &rows.variant_row)?;
+
+        for ((table_name, id_column), ids) in LINK_TABLES.iter().zip([players, teams, games]) {
+            let sql = format!("INSERT INTO {table_name} (event_id, {id_column}) VALUES (?, ?)");
+            let mut statement = self.conn.prepare_cached(&sql)?;
+            for id in ids {
+                statement.execute(params![event_id, id.to_string()])?;
+            }
+        }
+
+        self.events_in_transaction += 1;
+        if self.events_in_transaction >= EVENTS_PER_TRANSACTION {
+            self.conn.execute_batch("COMMIT; BEGIN")?;
+            self.events_in_transaction = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Deletes every row an earlier export of this event id wrote, if there was one
+    fn delete_event(&self, event_id: &str) -> rusqlite::Result<()> {
+        let previous_type: Option<String> = self.conn
+            .prepare_cached(&format!("SELECT {} FROM {COMMON_TABLE_NAME} WHERE id = ?", quote(TYPE_COLUMN_NAME)))?
+            .query_row([event_id], |row| row.get(0))
+            .optional()?;
+        let Some(previous_type) = previous_type else { return Ok(()) };
+
+        if self.variants.contains_key(&previous_type) {
+            self.conn.prepare_cached(&format!("DELETE FROM {} WHERE id = ?", quote(&previous_type)))?
+                .execute([event_id])?;
+        }
+        for (table_name, _) in LINK_TABLES {
+            self.conn.prepare_cached(&format!("DELETE FROM {table_name} WHERE event_id = ?"))?
+                .execute([event_id])?;
+        }
+        self.conn.prepare_cached(&format!("DELETE FROM {COMMON_TABLE_NAME} WHERE id = ?"))?
+            .execute([event_id])?;
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> anyhow::Result<()> {
+        self.conn.execute_batch("COMMIT")?;
+        self.conn.close()
+            .map_err(|(_, err)| err)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+#![feature(let_chains)]
+
+pub mod schema;
+pub mod export;
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use anyhow::Context;
+use fed::FedEvent;
+use flate2::read::GzDecoder;
+use indicatif::{ProgressDrawTarget, ProgressStyle};
+
+/// Format of an input file of Eventually events
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum InputFormat {
+    /// One JSON event per line
+    Ndjson,
+    /// Gzipped ndjson
+    NdjsonGz,
+}
+
+impl InputFormat {
+    /// Guesses the format from the file extension
+    pub fn from_path(path: &Path) -> Self {
+        if path.extension().is_some_and(|ext| ext == "gz") {
+            InputFormat::NdjsonGz
+        } else {
+            InputFormat::Ndjson
+        }
+    }
+}
+
+/// Opens an ndjson file of Eventually events for reading line by line. If `format` is None it's
+/// guessed from the file extension.
+pub fn open_ndjson(path: &Path, format: Option<InputFormat>) -> anyhow::Result<Box<dyn BufRead + Send>> {
+    let file = File::open(path)
+        .with_context(|| format!("Couldn't open {}", path.display()))?;
+    Ok(match format.unwrap_or_else(|| InputFormat::from_path(path)) {
+        InputFormat::Ndjson => Box::new(BufReader::new(file)),
+        InputFormat::NdjsonGz => Box::new(BufReader::new(GzDecoder::new(file))),
+    })
+}
+
+/// Reads FedEvents from an ndjson file of Eventually events, which may be gzipped
+pub fn read_fed_events(path: &Path) -> anyhow::Result<impl Iterator<Item=anyhow::Result<FedEvent>>> {
+    let reader = open_ndjson(path, None)?;
+
+    Ok(reader.lines()
+        .map(|line| {
+            let line = line.context("Failed to read line from ndjson file")?;
+            let feed_event = fed::feed_event_from_json(&line)
+                .context("Failed to parse ndjson entry into EventuallyEvent")?;
+            fed::parse_feed_event(&feed_event)
+                .with_context(|| format!("Parsing {}: {:?}", feed_event.id, feed_event.description))
+        }))
+}
+
+/// Calls `f` with every event in an ndjson file of Eventually events while displaying progress.
+/// Events that fail to parse are reported and skipped.
+pub fn for_each_fed_event(path: &Path, mut f: impl FnMut(FedEvent) -> anyhow::Result<()>) -> anyhow::Result<()> {
+    let progress = indicatif::ProgressBar::new_spinner();
+    progress.set_style(ProgressStyle::with_template("{msg:7} {spinner} {human_pos} {elapsed}")?);
+    progress.set_draw_target(ProgressDrawTarget::stdout_with_hz(2 /* hz */));
+
+    let mut num_failed = 0;
+    for event in read_fed_events(path)? {
+        let event = match event {
+            Ok(event) => event,
+            Err(err) => {
+                progress.println(format!("Skipping event: {err:#}"));
+                num_failed += 1;
+                continue;
+            }
+        };
+        progress.set_message(format!("s{}d{}", event.season + 1, event.day + 1));
+        progress.inc(1);
+        f(event)?;
+    }
+
+    progress.finish();
+    if num_failed > 0 {
+        println!("{num_failed} events could not be parsed and were skipped");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::path::PathBuf;
+use clap::{Args, Parser, Subcommand};
+use fed_tools::export::{CsvExporter, ParquetExporter, SqliteExporter};
+use fed_tools::schema::Tables;
+
+/// Tools for working with parsed Feed events
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+// The variant names become the subcommand names, e.g. `export-csv`
+#[allow(clippy::enum_variant_names)]
+#[derive(Subcommand)]
+enum Command {
+    /// Export parsed Feed events to CSV, with one file per event type and one file with the
+    /// fields that are common to every event. Nested structs are expanded into prefixed columns.
+    ExportCsv(ExportDirArgs),
+
+    /// Export parsed Feed events to Parquet, with one file per event type and one file with the
+    /// fields that are common to every event
+    ExportParquet(ExportDirArgs),
+
+    /// Export parsed Feed events to a SQLite database, with an `events` table of the fields common
+    /// to every event, one table per event type, and tables linking events to the players, teams,
+    /// and games they refer to
+    ExportSqlite(ExportSqliteArgs),
+}
+
+#[derive(Args)]
+struct ExportDirArgs {
+    /// Directory to write the files to
+    #[arg(value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    output: PathBuf,
+
+    /// Feed dump to read from, in ndjson format. May be gzipped.
+    #[arg(short, long, default_value = "feed_dump.filtered.ndjson.gz")]
+    input: PathBuf,
+}
+
+#[derive(Args)]
+struct ExportSqliteArgs {
+    /// Path of the database to write to
+    #[arg(value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    output: PathBuf,
+
+    /// Feed dump to read from, in ndjson format. May be gzipped.
+    #[arg(short, long, default_value = "feed_dump.filtered.ndjson.gz")]
+    input: PathBuf,
+}
+
+fn export_csv(args: ExportDirArgs) -> anyhow::Result<()> {
+    let tables = Tables::from_flattened_structs();
+    let mut exporter = CsvExporter::new(&args.output, tables)?;
+    fed_tools::for_each_fed_event(&args.input, |event| exporter.push(event))?;
+
+    exporter.finish()
+}
+
+fn export_parquet(args: ExportDirArgs) -> anyhow::Result<()> {
+    let tables = Tables::from_flattened_structs();
+    let mut exporter = ParquetExporter::new(&args.output, tables)?;
+    fed_tools::for_each_fed_event(&args.input, |event| exporter.push(event))?;
+
+    exporter.finish()
+}
+
+fn export_sqlite(args: ExportSqliteArgs) -> anyhow::Result<()> {
+    let tables = Tables::from_flattened_structs();
+    let mut exporter = SqliteExporter::new(&args.output, tables)?;
+    fed_tools::for_each_fed_event(&args.input, |event| exporter.push(event))?;
+
+    exporter.finish()
+}
+
+fn main() -> anyhow::Result<()> {
+    match Cli::parse().command {
+        Command::ExportCsv(args) => export_csv(args),
+        Command::ExportParquet(args) => export_parquet(args),
+        Command::ExportSqlite(args) => export_sqlite(args),
+    }
+}
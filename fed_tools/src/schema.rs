@@ -0,0 +1,346 @@
+use itertools::Itertools;
+use fed::{FedEvent, FedEventFlat};
+use schemars::{
+    schema::SchemaObject,
+    visit::Visitor,
+    visit::visit_schema_object,
+};
+use schemars::gen::SchemaSettings;
+use schemars::schema::{InstanceType, RootSchema, Schema, SingleOrVec};
+use schemars::schema::Schema::Object;
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone)]
+pub struct SurfaceEnumTitle;
+
+impl Visitor for SurfaceEnumTitle {
+    fn visit_schema_object(&mut self, schema: &mut SchemaObject) {
+        // this is good coding
+        if let Some(subschemas) = &mut schema.subschemas {
+            if let Some(object) = &mut subschemas.one_of {
+                for schema in object {
+                    if let Object(obj) = schema {
+                        let values = if let Some(values) = &mut obj.enum_values {
+                            values
+                        } else if let Some(properties) = &mut obj.object {
+                            if let Some(type_prop) = properties.properties.get_mut("type") {
+                                if let Object(obj) = type_prop {
+                                    if let Some(values) = &mut obj.enum_values {
+                                        values
+                                    } else {
+                                        continue;
+                                    }
+                                } else {
+                                    continue;
+                                }
+                            } else if let Some((name, _)) = properties.properties.iter().exactly_one().ok() {
+                                if let Some(metadata) = &mut obj.metadata {
+                                    metadata.title.get_or_insert(name.to_string());
+                                }
+                                continue;
+                            } else {
+                                continue;
+                            }
+                        } else {
+                            continue;
+                        };
+                        if let Some(first_value) = values.first() {
+                            if let Some(name) = first_value.as_str() {
+                                if let Some(metadata) = &mut obj.metadata {
+                                    metadata.title.get_or_insert(name.to_string());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Then delegate to default implementation to visit any subschemas
+        visit_schema_object(self, schema);
+    }
+}
+
+/// The JSON schema for FedEvent, as printed by `generate_schema`
+pub fn fed_event_schema() -> RootSchema {
+    SchemaSettings::default()
+        .with_visitor(SurfaceEnumTitle)
+        .into_generator()
+        .into_root_schema_for::<FedEvent>()
+}
+
+/// The JSON schema for the flattened FedEvent structs, one per variant, that the export tables
+/// take their columns from
+pub fn flattened_fed_event_schema() -> RootSchema {
+    SchemaSettings::default()
+        .into_generator()
+        .into_root_schema_for::<FedEventFlat>()
+}
+
+/// The type of a column, as far as tabular formats are concerned
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnType {
+    Boolean,
+    Integer,
+    Float,
+    String,
+    Uuid,
+    DateTime,
+    /// An object with a fixed set of properties
+    Struct(Vec<Column>),
+    /// An array where every item has the same type
+    List(Box<ColumnType>),
+    /// Anything that doesn't map cleanly onto a table column (tagged enums, tuples, etc.). Values
+    /// of this type are stored as JSON text.
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    /// Name of the column, which is the serialized name of the field
+    pub name: String,
+    pub ty: ColumnType,
+    pub nullable: bool,
+}
+
+/// A set of columns which, together, hold one variant of FedEvent (or the fields common to all of
+/// them).
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub name: String,
+    pub columns: Vec<Column>,
+}
+
+/// A column that holds a single value, for formats that can't nest. Struct-valued columns are
+/// expanded into one of these for each of their fields.
+#[derive(Debug, Clone)]
+pub struct LeafColumn {
+    /// Name of the column, which is the names of all the fields on the way to this one joined by a
+    /// separator
+    pub name: String,
+
+    /// Keys to follow in the serialized event to get to this column's value
+    pub path: Vec<String>,
+
+    /// Type of the column. This is never ColumnType::Struct.
+    pub ty: ColumnType,
+
+    /// Whether this column or any of the structs containing it are nullable
+    pub nullable: bool,
+}
+
+impl LeafColumn {
+    /// Gets this column's value from a serialized row, or None if the value or any struct
+    /// containing it is null or missing
+    pub fn get<'a>(&self, row: &'a Map<String, Value>) -> Option<&'a Value> {
+        let (first, rest) = self.path.split_first()?;
+        let mut value = row.get(first)?;
+        for key in rest {
+            value = value.get(key)?;
+        }
+
+        if value.is_null() { None } else { Some(value) }
+    }
+}
+
+fn leaf_columns(columns: &[Column], prefix: &[String], parent_nullable: bool, separator: &str, out: &mut Vec<LeafColumn>) {
+    for column in columns {
+        let mut path = prefix.to_vec();
+        path.push(column.name.clone());
+        let nullable = parent_nullable || column.nullable;
+
+        if let ColumnType::Struct(children) = &column.ty {
+            leaf_columns(children, &path, nullable, separator, out);
+        } else {
+            out.push(LeafColumn {
+                name: path.join(separator),
+                path,
+                ty: column.ty.clone(),
+                nullable,
+            });
+        }
+    }
+}
+
+impl Table {
+    /// Columns of this table with every struct expanded into its fields, with the field names
+    /// prefixed by the struct's name and `separator`
+    pub fn leaf_columns(&self, separator: &str) -> Vec<LeafColumn> {
+        let mut out = Vec::new();
+        leaf_columns(&self.columns, &[], false, separator, &mut out);
+        out
+    }
+}
+
+/// Name of the table with the fields common to every event
+pub const COMMON_TABLE_NAME: &str = "events";
+
+/// Name of the column in the common table that says which variant the event is
+pub const TYPE_COLUMN_NAME: &str = "type";
+
+/// All the information about how to lay out FedEvents in tables
+#[derive(Debug, Clone)]
+pub struct Tables {
+    /// Fields common to every FedEvent, plus a column with the event type
+    pub common: Table,
+
+    /// One table per FedEventData variant. Each one has all the columns of the corresponding
+    /// flattened struct, including the common fields.
+    pub variants: Vec<Table>,
+}
+
+impl Tables {
+    /// Lays out one table per flattened FedEvent struct (e.g. `FedEventHit`), so every exporter
+    /// gets the same columns from the same place
+    pub fn from_flattened_structs() -> Self {
+        Self::from_schema(&flattened_fed_event_schema())
+    }
+
+    /// Lays out the tables from the schema of `FedEventFlat`, which has a one-property object for
+    /// each variant mapping its name to the flattened struct. The common table has the columns
+    /// every flattened struct shares, which are FedEvent's own fields.
+    pub fn from_schema(root: &RootSchema) -> Self {
+        let resolver = Resolver(root);
+
+        let variants: Vec<_> = root.schema.subschemas.iter()
+            .flat_map(|subschemas| subschemas.one_of.iter().flatten())
+            .filter_map(|schema| {
+                let (name, schema) = resolver.resolve(schema)?
+                    .object.as_ref()?
+                    .properties.iter()
+                    .exactly_one().ok()?;
+                let columns = resolver.object_columns(resolver.resolve(schema)?);
+
+                Some(Table { name: name.clone(), columns })
+            })
+            .collect();
+
+        let mut common_columns: Vec<_> = variants.first()
+            .map(|table| {
+                table.columns.iter()
+                    .filter(|column| variants.iter().all(|table| table.columns.contains(column)))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        common_columns.push(Column {
+            name: TYPE_COLUMN_NAME.to_string(),
+            ty: ColumnType::String,
+            nullable: false,
+        });
+
+        Self {
+            common: Table { name: COMMON_TABLE_NAME.to_string(), columns: common_columns },
+            variants,
+        }
+    }
+
+    pub fn variant(&self, name: &str) -> Option<&Table> {
+        self.variants.iter().find(|table| table.name == name)
+    }
+}
+
+struct Resolver<'a>(&'a RootSchema);
+
+impl<'a> Resolver<'a> {
+    fn resolve(&self, schema: &'a Schema) -> Option<&'a SchemaObject> {
+        let Object(obj) = schema else { return None };
+        self.resolve_object(obj)
+    }
+
+    // Follows $refs, and also the single-element allOf that schemars uses to attach a description
+    // to a $ref
+    fn resolve_object(&self, obj: &'a SchemaObject) -> Option<&'a SchemaObject> {
+        if let Some(reference) = &obj.reference {
+            let name = reference.strip_prefix("#/definitions/")?;
+            return self.resolve(self.0.definitions.get(name)?);
+        }
+
+        if let Some(subschemas) = &obj.subschemas &&
+            let Some(all_of) = &subschemas.all_of &&
+            let Ok(inner) = all_of.iter().exactly_one() {
+            return self.resolve(inner);
+        }
+
+        Some(obj)
+    }
+
+    fn object_columns(&self, obj: &SchemaObject) -> Vec<Column> {
+        let Some(object) = &obj.object else { return Vec::new() };
+
+        object.properties.iter()
+            .map(|(name, schema)| {
+                let (ty, nullable) = self.column_type(schema);
+                Column {
+                    name: name.clone(),
+                    ty,
+                    nullable: nullable || !object.required.contains(name),
+                }
+            })
+            .collect()
+    }
+
+    // Returns the column type and whether it's nullable
+    fn column_type(&self, schema: &Schema) -> (ColumnType, bool) {
+        let Some(obj) = self.resolve(schema) else {
+            return (ColumnType::Json, true);
+        };
+
+        // Option<T> that isn't a primitive comes out as anyOf [T, null]
+        if let Some(subschemas) = &obj.subschemas &&
+            let Some(any_of) = &subschemas.any_of {
+            let (nulls, non_nulls): (Vec<_>, Vec<_>) = any_of.iter()
+                .partition(|schema| self.is_null(schema));
+            return if let Ok(inner) = non_nulls.into_iter().exactly_one() {
+                let (ty, nullable) = self.column_type(inner);
+                (ty, nullable || !nulls.is_empty())
+            } else {
+                (ColumnType::Json, true)
+            };
+        }
+
+        let (instance_type, nullable) = match &obj.instance_type {
+            Some(SingleOrVec::Single(ty)) => (**ty, false),
+            Some(SingleOrVec::Vec(types)) => {
+                let non_null = types.iter()
+                    .filter(|&&ty| ty != InstanceType::Null)
+                    .exactly_one();
+                match non_null {
+                    Ok(&ty) => (ty, types.contains(&InstanceType::Null)),
+                    Err(_) => return (ColumnType::Json, true),
+                }
+            }
+            None => return (ColumnType::Json, true),
+        };
+
+        let ty = match instance_type {
+            InstanceType::Boolean => ColumnType::Boolean,
+            InstanceType::Integer => ColumnType::Integer,
+            InstanceType::Number => ColumnType::Float,
+            InstanceType::String => match obj.format.as_deref() {
+                Some("uuid") => ColumnType::Uuid,
+                Some("date-time") => ColumnType::DateTime,
+                _ => ColumnType::String,
+            },
+            InstanceType::Object if obj.subschemas.is_none() && obj.object.is_some() => {
+                ColumnType::Struct(self.object_columns(obj))
+            }
+            InstanceType::Array => match obj.array.as_ref().and_then(|a| a.items.as_ref()) {
+                Some(SingleOrVec::Single(item)) => {
+                    let (item_ty, _) = self.column_type(item);
+                    ColumnType::List(Box::new(item_ty))
+                }
+                // Tuples are not representable as a list
+                _ => ColumnType::Json,
+            },
+            _ => ColumnType::Json,
+        };
+
+        (ty, nullable)
+    }
+
+    fn is_null(&self, schema: &Schema) -> bool {
+        self.resolve(schema)
+            .is_some_and(|obj| obj.instance_type == Some(SingleOrVec::Single(Box::new(InstanceType::Null))))
+    }
+}
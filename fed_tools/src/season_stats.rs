@@ -0,0 +1,39 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use clap::Parser;
+use fed::StatsAccumulator;
+
+/// Compute per-season batting and pitching stats for every player and write them as JSON
+#[derive(Parser)]
+struct Args {
+    /// File to write the stats to
+    #[arg(value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    output: PathBuf,
+
+    /// Feed dump to read from, in ndjson format. May be gzipped.
+    #[arg(short, long, default_value = "feed_dump.filtered.ndjson.gz")]
+    input: PathBuf,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let mut stats = StatsAccumulator::new();
+    let mut game_errors = Vec::new();
+    fed_tools::for_each_fed_event(&args.input, |event| {
+        if let Err(err) = stats.push(&event) {
+            game_errors.push(format!("{}: {err}", event.id));
+        }
+        Ok(())
+    })?;
+
+    for err in &game_errors {
+        println!("Skipped rest of game after event {err}");
+    }
+
+    let writer = BufWriter::new(File::create(&args.output)?);
+    serde_json::to_writer_pretty(writer, &stats.results())?;
+
+    Ok(())
+}
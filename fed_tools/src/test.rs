@@ -1,61 +1,228 @@
 #![feature(let_chains)]
 
-use std::collections::HashSet;
-use std::fs::File;
-use std::io::{self, BufReader, prelude::*};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::io::{self, prelude::*};
+use std::panic;
+use std::path::{Path, PathBuf};
 use par_iter_sync::IntoParallelIteratorSync;
 use json_structural_diff::JsonDiff;
 use anyhow::{anyhow, Context};
-use indicatif::{ProgressDrawTarget, ProgressStyle};
-use fed::FedEvent;
-use flate2::read::GzDecoder;
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use eventually_api::EventuallyEvent;
+use fed::{FedEvent, RoundTripError};
+use fed_tools::InputFormat;
+use serde_json::{json, Value};
+use uuid::Uuid;
 use with_structure::WithStructure;
 use enum_flatten::{EnumFlatten, EnumFlattened};
 use clap::Parser;
 
-const NUM_EVENTS: u64 = 8299172;
+/// The stage an event failed at, with enough detail to group it with similar failures
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum FailureKind {
+    /// The line couldn't be read from the input
+    Read,
+    /// The line isn't a valid EventuallyEvent
+    Deserialize,
+    /// The parser returned an error. Contains the FeedParseError variant.
+    Parse(String),
+    /// The parser panicked
+    Panic,
+    /// Flattening and unflattening the FedEvent changed it
+    Flatten,
+    /// The reconstructed event doesn't match the original. Contains the fields that differ.
+    RoundTrip(Vec<String>),
+}
+
+impl FailureKind {
+    fn stage(&self) -> &'static str {
+        match self {
+            FailureKind::Read => "read",
+            FailureKind::Deserialize => "deserialize",
+            FailureKind::Parse(_) => "parse",
+            FailureKind::Panic => "panic",
+            FailureKind::Flatten => "flatten",
+            FailureKind::RoundTrip(_) => "round_trip",
+        }
+    }
+
+    fn detail(&self) -> Option<String> {
+        match self {
+            FailureKind::Parse(variant) => Some(variant.clone()),
+            FailureKind::RoundTrip(fields) => Some(fields.join(", ")),
+            _ => None,
+        }
+    }
+}
+
+impl Display for FailureKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.detail() {
+            Some(detail) => write!(f, "{} ({detail})", self.stage()),
+            None => write!(f, "{}", self.stage()),
+        }
+    }
+}
+
+struct Failure {
+    line: usize,
+    event_id: Option<Uuid>,
+    event_type: Option<String>,
+    description: Option<String>,
+    kind: FailureKind,
+    message: String,
+    feed_event: Option<EventuallyEvent>,
+}
+
+impl Failure {
+    fn new(line: usize, kind: FailureKind, message: String) -> Self {
+        Self {
+            line,
+            event_id: None,
+            event_type: None,
+            description: None,
+            kind,
+            message,
+            feed_event: None,
+        }
+    }
+
+    fn for_event(line: usize, event: &EventuallyEvent, kind: FailureKind, message: String) -> Self {
+        Self {
+            line,
+            event_id: Some(event.id),
+            event_type: Some(format!("{:?}", event.r#type)),
+            description: Some(event.description.clone()),
+            kind,
+            message,
+            feed_event: Some(event.clone()),
+        }
+    }
+
+    /// Saves the feed event and the failure message into `<dir>/<event id>/`, where the regression
+    /// test will replay it. Failures that happened before there was an event to key them by aren't
+    /// saved.
+    fn capture(&self, dir: &Path) -> anyhow::Result<()> {
+        let Some(feed_event) = &self.feed_event else {
+            return Ok(());
+        };
+
+        let event_dir = dir.join(feed_event.id.to_string());
+        std::fs::create_dir_all(&event_dir)
+            .with_context(|| format!("Couldn't create {}", event_dir.display()))?;
+        std::fs::write(event_dir.join("event.json"), serde_json::to_string_pretty(feed_event)? + "\n")?;
+        std::fs::write(event_dir.join("failure.txt"), format!("{}\n\n{}\n", self.kind, self.message))?;
+
+        Ok(())
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "line": self.line + 1,
+            "id": self.event_id.map(|id| id.to_string()),
+            "description": self.description,
+            "message": self.message,
+        })
+    }
+}
+
+impl From<Failure> for anyhow::Error {
+    fn from(failure: Failure) -> Self {
+        anyhow!(failure.message)
+            .context(format!("{} failure on line {}", failure.kind, failure.line + 1))
+    }
+}
 
-fn check_json_line((i, json_str): (usize, io::Result<String>)) -> anyhow::Result<(usize, FedEvent)> {
-    let str = json_str.context("Failed to read line from ndjson file")?;
+fn check_json_line((i, json_str): (usize, io::Result<String>)) -> Result<(usize, EventuallyEvent, FedEvent), Failure> {
+    let str = json_str
+        .map_err(|err| Failure::new(i, FailureKind::Read, format!("Failed to read line from ndjson file: {err}")))?;
     let feed_event = fed::feed_event_from_json(&str)
-        .context(str)
-        .context("Failed to parse ndjson entry into EventuallyEvent")?;
+        .map_err(|err| Failure::new(i, FailureKind::Deserialize,
+                                    format!("Failed to parse ndjson entry into EventuallyEvent: {err}\n{str}")))?;
 
-    let parsed_event = fed::parse_feed_event(&feed_event)
-        .with_context(|| format!("Parsing {}: {:?}", feed_event.id, feed_event.description))
-        .context("Failed to parse EventuallyEvent into FedEvent")?;
+    let report = fed::verify_round_trip(&feed_event);
+    let parsed_event = report.parsed
+        .map_err(|err| match err {
+            RoundTripError::Parse(err) => Failure::for_event(i, &feed_event, FailureKind::Parse(err.as_ref().to_string()),
+                                                             format!("Parsing {}: {:?}: Failed to parse EventuallyEvent into FedEvent: {err}",
+                                                                     feed_event.id, feed_event.description)),
+            RoundTripError::Panicked(message) => Failure::for_event(i, &feed_event, FailureKind::Panic,
+                                                                    format!("Parsing {}: {:?}: Parser panicked: {message}",
+                                                                            feed_event.id, feed_event.description)),
+        })?;
 
     let prased_event_flat = EnumFlatten::flatten(parsed_event.clone());
     let prased_event_inflat = EnumFlattened::unflatten(prased_event_flat.clone());
 
-    assert!(prased_event_inflat == parsed_event);
-
-    let reconstructed_event = parsed_event.clone().into_feed_event();
+    if prased_event_inflat != parsed_event {
+        return Err(Failure::for_event(i, &feed_event, FailureKind::Flatten,
+                                      format!("Flattening and unflattening changed the event: {parsed_event:#?}")));
+    }
 
-    // JsonDiff is expensive. Only run it if the events don't compare equal.
-    if feed_event != reconstructed_event {
+    if !report.differences.is_empty() {
+        let reconstructed_event = report.reconstructed
+            .expect("A parsed event should always have a reconstructed event");
         let original_event_json = serde_json::to_value(&feed_event)
-            .context("Failed to convert original event to serde_json::Value")?;
-
+            .expect("Failed to convert original event to serde_json::Value");
         let reconstructed_event_json = serde_json::to_value(reconstructed_event)
-            .context("Failed to convert reconstructed event to serde_json::Value")?;
-        JsonDiff::diff_string(&reconstructed_event_json, &original_event_json, false)
-            .map_or_else(|| Ok(()),
-                         |str| {
-                             let expected = serde_json::to_string_pretty(&original_event_json).unwrap();
-                             let actual = serde_json::to_string_pretty(&reconstructed_event_json).unwrap();
-                             Err(anyhow!("Received from Feed: {expected}\nParsed into: {parsed_event:#?}\n Produced: {actual}\nDiff: {str}"))
-                         })
-            .with_context(|| format!("Event not reconstructed exactly: {}", original_event_json.get("description").unwrap().as_str().unwrap()))?;
-    }
-    Ok((i, parsed_event))
+            .expect("Failed to convert reconstructed event to serde_json::Value");
+
+        let expected = serde_json::to_string_pretty(&original_event_json).unwrap();
+        let actual = serde_json::to_string_pretty(&reconstructed_event_json).unwrap();
+        let diff = JsonDiff::diff_string(&reconstructed_event_json, &original_event_json, false)
+            .unwrap_or_default();
+        let fields: Vec<_> = report.differences.iter()
+            .map(|difference| difference.field())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let differences = report.differences.iter().map(|difference| difference.to_string()).collect::<Vec<_>>().join("\n");
+        return Err(Failure::for_event(i, &feed_event, FailureKind::RoundTrip(fields),
+                                      format!("Event not reconstructed exactly: {}\nReceived from Feed: {expected}\nParsed into: {parsed_event:#?}\n Produced: {actual}\nDifferences:\n{differences}\nDiff: {diff}",
+                                              feed_event.description)));
+    }
+    Ok((i, feed_event, parsed_event))
 }
 
 #[derive(Parser)]
 struct Args {
-    /// Path to save sample outputs, if desired
+    /// Path to save one sample feed event per distinct structure, if desired. This is how
+    /// fed/tests/fixtures/events is generated.
     #[arg(value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
-    sample_outputs: Option<std::path::PathBuf>,
+    sample_outputs: Option<PathBuf>,
+
+    /// Feed events to test, one Eventually event per line
+    #[arg(short, long, default_value = "feed_dump.filtered.ndjson.gz", value_hint = clap::ValueHint::FilePath)]
+    input: PathBuf,
+
+    /// Format of the input. Guessed from the file extension if not given.
+    #[arg(long, value_enum)]
+    format: Option<InputFormat>,
+
+    /// Number of events in the input, if known, to show progress against
+    #[arg(long)]
+    num_events: Option<u64>,
+
+    /// Keep going after failures and summarize them at the end, instead of stopping at the first
+    #[arg(short, long)]
+    keep_going: bool,
+
+    /// Path to write a JSON report of the failures to. Implies --keep-going.
+    #[arg(long, value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    report: Option<PathBuf>,
+
+    /// Directory to save failing events to, keyed by event id. Nothing is saved if this isn't
+    /// given. The fed crate's regression test replays everything in fed/tests/regressions.
+    #[arg(long, value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    capture: Option<PathBuf>,
+}
+
+struct FailureGroup {
+    event_type: Option<String>,
+    kind: FailureKind,
+    count: usize,
+    example: Failure,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -70,12 +237,16 @@ fn main() -> anyhow::Result<()> {
 
 fn run_test(args: Args) -> anyhow::Result<()> {
     println!("Test starting...");
+    let keep_going = args.keep_going || args.report.is_some();
+    if keep_going {
+        // Panics are reported with the rest of the failures
+        panic::set_hook(Box::new(|_| {}));
+    }
 
-    // If this file doesn't exist, download feed_dump.ndjson from
+    // If the default input doesn't exist, download feed_dump.ndjson from
     // https://faculty.sibr.dev/~allie/feed_dump.ndjson.zstd
     // and run `filter_feed` to make feed_dump.filtered.ndjson
-    let file = File::open("feed_dump.filtered.ndjson.gz")?;
-    let reader = BufReader::new(GzDecoder::new(file));
+    let reader = fed_tools::open_ndjson(&args.input, args.format)?;
 
     let iter = reader.lines()
         .enumerate()
@@ -83,12 +254,49 @@ fn run_test(args: Args) -> anyhow::Result<()> {
         .into_par_iter_sync(|args| Ok::<_, ()>(check_json_line(args)));
 
     let mut with_structures = HashSet::<<FedEvent as WithStructure>::Structure>::new();
+    if let Some(ref sample_path) = args.sample_outputs {
+        std::fs::create_dir_all(sample_path)?;
+    }
 
-    let progress = indicatif::ProgressBar::new(NUM_EVENTS);
-    progress.set_style(ProgressStyle::with_template("{msg:7} {wide_bar} {human_pos}/{human_len} {elapsed} eta {eta}")?);
+    let progress = match args.num_events {
+        Some(num_events) => {
+            let progress = ProgressBar::new(num_events);
+            progress.set_style(ProgressStyle::with_template("{msg:7} {wide_bar} {human_pos}/{human_len} {elapsed} eta {eta}")?);
+            progress
+        }
+        None => {
+            let progress = ProgressBar::new_spinner();
+            progress.set_style(ProgressStyle::with_template("{msg:7} {spinner} {human_pos} {elapsed}")?);
+            progress
+        }
+    };
     progress.set_draw_target(ProgressDrawTarget::stdout_with_hz(2 /* hz */));
+
+    let mut num_checked = 0;
+    let mut num_failed = 0;
+    let mut failure_groups = HashMap::<(Option<String>, FailureKind), FailureGroup>::new();
     for item in iter {
-        let (i, value): (usize, FedEvent) = item?;
+        num_checked += 1;
+        if let Err(failure) = &item && let Some(capture_dir) = &args.capture {
+            failure.capture(capture_dir)?;
+        }
+        let (i, feed_event, value) = match item {
+            Ok(item) => item,
+            Err(failure) if keep_going => {
+                num_failed += 1;
+                progress.set_position(failure.line as u64);
+                failure_groups.entry((failure.event_type.clone(), failure.kind.clone()))
+                    .or_insert_with(|| FailureGroup {
+                        event_type: failure.event_type.clone(),
+                        kind: failure.kind.clone(),
+                        count: 0,
+                        example: failure,
+                    })
+                    .count += 1;
+                continue;
+            }
+            Err(failure) => return Err(failure.into()),
+        };
         progress.set_message(format!("s{}d{}", value.season + 1, value.day + 1));
         progress.set_position(i as u64);
 
@@ -101,14 +309,54 @@ fn run_test(args: Args) -> anyhow::Result<()> {
         if !with_structures.contains(&structure) {
             with_structures.insert(structure);
 
+            // Samples are saved in feed form so they can be used as parser fixtures
             std::fs::write(
-                sample_path.join(format!("/{}.json", value.id)),
-                serde_json::to_string_pretty(&value)?,
+                sample_path.join(format!("{}.json", feed_event.id)),
+                serde_json::to_string_pretty(&feed_event)? + "\n",
             )?;
         }
     }
 
     progress.finish();
 
+    if !keep_going {
+        return Ok(());
+    }
+
+    let mut failure_groups: Vec<_> = failure_groups.into_values().collect();
+    failure_groups.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.example.line.cmp(&b.example.line)));
+
+    println!("{num_failed} of {num_checked} events failed, in {} groups", failure_groups.len());
+    for group in &failure_groups {
+        println!();
+        println!("{} x {} {}", group.count, group.event_type.as_deref().unwrap_or("(unknown type)"), group.kind);
+        println!("Example from line {}:", group.example.line + 1);
+        println!("{}", group.example.message);
+    }
+
+    if let Some(report_path) = &args.report {
+        let report = json!({
+            "input": args.input,
+            "eventsChecked": num_checked,
+            "eventsFailed": num_failed,
+            "groups": failure_groups.iter()
+                .map(|group| json!({
+                    "eventType": group.event_type,
+                    "stage": group.kind.stage(),
+                    "detail": group.kind.detail(),
+                    "count": group.count,
+                    "example": group.example.to_json(),
+                }))
+                .collect::<Vec<_>>(),
+        });
+        std::fs::write(report_path, serde_json::to_string_pretty(&report)? + "\n")
+            .with_context(|| format!("Couldn't write report to {}", report_path.display()))?;
+        println!("\nReport written to {}", report_path.display());
+    }
+
+    if num_failed > 0 {
+        return Err(anyhow!("{num_failed} of {num_checked} events failed"));
+    }
+
     Ok(())
-}
\ No newline at end of file
+}
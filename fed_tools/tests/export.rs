@@ -0,0 +1,361 @@
+//! Tests for the CSV, Arrow, Parquet, and SQLite exporters, using a few hand-built events that
+//! are exported and then read back
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, RecordBatch};
+use chrono::{TimeZone, Utc};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use rusqlite::Connection;
+use uuid::Uuid;
+
+use fed::*;
+use fed_tools::export::{ArrowExporter, CsvExporter, ParquetExporter, SqliteExporter};
+use fed_tools::schema::Tables;
+
+const GAME_ID: Uuid = Uuid::from_u128(1);
+const HOME_TEAM: Uuid = Uuid::from_u128(2);
+const AWAY_TEAM: Uuid = Uuid::from_u128(3);
+const PLAYER: Uuid = Uuid::from_u128(4);
+const OTHER_PLAYER: Uuid = Uuid::from_u128(5);
+
+const PARTY_ID: Uuid = Uuid::from_u128(100);
+const REFILL_ID: Uuid = Uuid::from_u128(101);
+const INNING_END_ID: Uuid = Uuid::from_u128(102);
+const QUIET_INNING_END_ID: Uuid = Uuid::from_u128(103);
+
+const COMMON_COLUMNS: [&str; 9] = ["id", "created", "sim", "tournament", "season", "day", "phase", "nuts", "type"];
+
+fn sub_event(n: u128) -> SubEvent {
+    SubEvent {
+        id: Uuid::from_u128(n),
+        created: Utc.with_ymd_and_hms(2021, 3, 1, 16, 0, 1).unwrap(),
+        nuts: 0,
+    }
+}
+
+fn game(unscatter: Option<Unscatter>) -> GameEvent {
+    GameEvent {
+        game_id: GAME_ID,
+        home_team: HOME_TEAM,
+        away_team: AWAY_TEAM,
+        play: 10,
+        unscatter,
+        attractor_secret_base: None,
+    }
+}
+
+fn event(id: Uuid, data: FedEventData) -> FedEvent {
+    FedEvent {
+        id,
+        created: Utc.with_ymd_and_hms(2021, 3, 1, 16, 0, 0).unwrap(),
+        sim: "thisidisstaticyo".to_string(),
+        tournament: -1,
+        season: 13,
+        day: 20,
+        phase: SimPhase::Midseason,
+        nuts: 0,
+        data,
+    }
+}
+
+fn lost_triple_threat(player_id: Uuid, player_name: &str, sub_event_id: u128) -> ModChangeSubEventWithNamedPlayer {
+    ModChangeSubEventWithNamedPlayer {
+        sub_event: sub_event(sub_event_id),
+        team_id: HOME_TEAM,
+        player_id,
+        player_name: player_name.to_string(),
+    }
+}
+
+/// A Party with an unscatter, so it has a nested struct that itself contains a SubEvent; a free
+/// refill with no team id or unscatter, so it has nulls; and two inning ends, one with two players
+/// in a list column and one with an empty list
+fn events() -> Vec<FedEvent> {
+    vec![
+        event(PARTY_ID, FedEventData::Party {
+            game: game(Some(Unscatter {
+                sub_event: sub_event(200),
+                team_id: HOME_TEAM,
+                player_id: OTHER_PLAYER,
+                player_name: "Jessica Telephone".to_string(),
+            })),
+            team_id: HOME_TEAM,
+            player_id: PLAYER,
+            player_name: "York Silk".to_string(),
+            sub_event: sub_event(201),
+            rating_before: 0.5,
+            rating_after: 0.55,
+        }),
+        event(REFILL_ID, FedEventData::GainFreeRefill {
+            game: game(None),
+            team_id: None,
+            player_id: PLAYER,
+            player_name: "York Silk".to_string(),
+            roast: "Light".to_string(),
+            ingredient1: "Milk Substitute".to_string(),
+            ingredient2: "Cream".to_string(),
+            sub_event: sub_event(202),
+        }),
+        event(INNING_END_ID, FedEventData::InningEnd {
+            game: game(None),
+            inning_num: 3,
+            lost_triple_threat: vec![
+                lost_triple_threat(PLAYER, "York Silk", 203),
+                lost_triple_threat(OTHER_PLAYER, "Jessica Telephone", 204),
+            ],
+        }),
+        event(QUIET_INNING_END_ID, FedEventData::InningEnd {
+            game: game(None),
+            inning_num: 4,
+            lost_triple_threat: vec![],
+        }),
+    ]
+}
+
+fn field_names(batch: &RecordBatch) -> Vec<String> {
+    batch.schema().fields().iter().map(|field| field.name().clone()).collect()
+}
+
+/// Checks batches of the test events, whether they came straight from the ArrowExporter or were
+/// read back from Parquet
+fn check_batches(batches: &HashMap<String, RecordBatch>) {
+    let mut names: Vec<_> = batches.keys().map(String::as_str).collect();
+    names.sort();
+    assert_eq!(names, ["GainFreeRefill", "InningEnd", "Party", "events"]);
+
+    let common = &batches["events"];
+    assert_eq!(field_names(common), COMMON_COLUMNS);
+    let types: Vec<_> = common.column_by_name("type").unwrap().as_string::<i32>().iter().flatten().collect();
+    assert_eq!(types, ["Party", "GainFreeRefill", "InningEnd", "InningEnd"]);
+
+    let party = &batches["Party"];
+    assert_eq!(party.num_rows(), 1);
+    let unscatter = party.column_by_name("unscatter").unwrap().as_struct();
+    assert!(unscatter.is_valid(0));
+    let unscatter_sub_event = unscatter.column_by_name("subEvent").unwrap().as_struct();
+    assert_eq!(unscatter_sub_event.column_by_name("id").unwrap().as_string::<i32>().value(0), Uuid::from_u128(200).to_string());
+    assert!(party.column_by_name("attractorSecretBase").unwrap().is_null(0));
+
+    let refill = &batches["GainFreeRefill"];
+    assert!(refill.column_by_name("teamId").unwrap().is_null(0));
+    assert!(refill.column_by_name("unscatter").unwrap().is_null(0));
+    let sub_event = refill.column_by_name("subEvent").unwrap().as_struct();
+    assert!(sub_event.is_valid(0));
+    assert_eq!(sub_event.column_by_name("id").unwrap().as_string::<i32>().value(0), Uuid::from_u128(202).to_string());
+
+    let inning_end = &batches["InningEnd"];
+    assert_eq!(inning_end.num_rows(), 2);
+    let lost = inning_end.column_by_name("lostTripleThreat").unwrap().as_list::<i32>();
+    assert!(lost.is_valid(0) && lost.is_valid(1));
+    assert_eq!(lost.value_length(0), 2);
+    assert_eq!(lost.value_length(1), 0);
+    let names = lost.values().as_struct().column_by_name("playerName").unwrap().as_string::<i32>();
+    assert_eq!(names.iter().flatten().collect::<Vec<_>>(), ["York Silk", "Jessica Telephone"]);
+}
+
+#[test]
+fn arrow_exporter_batches() {
+    let mut batches = HashMap::new();
+    let mut sink = |name: &str, batch: RecordBatch| {
+        assert!(batches.insert(name.to_string(), batch).is_none(), "Got more than one batch for {name}");
+        Ok(())
+    };
+
+    let mut exporter = ArrowExporter::new(Tables::from_flattened_structs());
+    for event in events() {
+        exporter.push(event, &mut sink).unwrap();
+    }
+    exporter.finish(&mut sink).unwrap();
+
+    check_batches(&batches);
+}
+
+fn read_parquet(path: &Path) -> RecordBatch {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap()).unwrap()
+        .build().unwrap();
+    let mut batches: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+    assert_eq!(batches.len(), 1, "{} has more than one batch", path.display());
+    batches.remove(0)
+}
+
+#[test]
+fn parquet_exporter_round_trips() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut exporter = ParquetExporter::new(dir.path(), Tables::from_flattened_structs()).unwrap();
+    for event in events() {
+        exporter.push(event).unwrap();
+    }
+    exporter.finish().unwrap();
+
+    let batches = std::fs::read_dir(dir.path()).unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_stem().unwrap().to_str().unwrap().to_string();
+            (name, read_parquet(&path))
+        })
+        .collect();
+
+    check_batches(&batches);
+}
+
+fn read_csv(path: &Path) -> Vec<HashMap<String, String>> {
+    let mut reader = csv::Reader::from_path(path).unwrap();
+    let headers = reader.headers().unwrap().clone();
+    reader.records()
+        .map(|record| headers.iter().map(String::from).zip(record.unwrap().iter().map(String::from)).collect())
+        .collect()
+}
+
+fn csv_headers(path: &Path) -> Vec<String> {
+    csv::Reader::from_path(path).unwrap()
+        .headers().unwrap()
+        .iter().map(String::from).collect()
+}
+
+#[test]
+fn csv_exporter_round_trips() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut exporter = CsvExporter::new(dir.path(), Tables::from_flattened_structs()).unwrap();
+    for event in events() {
+        exporter.push(event).unwrap();
+    }
+    exporter.finish().unwrap();
+
+    assert_eq!(csv_headers(&dir.path().join("events.csv")), COMMON_COLUMNS);
+    let common = read_csv(&dir.path().join("events.csv"));
+    assert_eq!(common.iter().map(|row| row["type"].as_str()).collect::<Vec<_>>(), ["Party", "GainFreeRefill", "InningEnd", "InningEnd"]);
+    assert_eq!(common[0]["id"], PARTY_ID.to_string());
+
+    // Nested structs are expanded into prefixed columns, including inside other structs
+    let party_headers = csv_headers(&dir.path().join("Party.csv"));
+    for header in ["subEvent.id", "subEvent.created", "unscatter.subEvent.id", "unscatter.playerName", "attractorSecretBase.playerId"] {
+        assert!(party_headers.iter().any(|h| h == header), "Party.csv has no {header} column");
+    }
+    assert!(!party_headers.iter().any(|h| h == "subEvent" || h == "unscatter"));
+    let party = read_csv(&dir.path().join("Party.csv"));
+    assert_eq!(party[0]["unscatter.subEvent.id"], Uuid::from_u128(200).to_string());
+    assert_eq!(party[0]["unscatter.playerName"], "Jessica Telephone");
+    assert_eq!(party[0]["attractorSecretBase.playerId"], "");
+
+    // Nulls, and every field of a null struct, are empty cells
+    let refill = read_csv(&dir.path().join("GainFreeRefill.csv"));
+    assert_eq!(refill[0]["teamId"], "");
+    assert_eq!(refill[0]["unscatter.subEvent.id"], "");
+    assert_eq!(refill[0]["subEvent.id"], Uuid::from_u128(202).to_string());
+
+    // Lists are written as JSON
+    let inning_end = read_csv(&dir.path().join("InningEnd.csv"));
+    let lost: Vec<ModChangeSubEventWithNamedPlayer> = serde_json::from_str(&inning_end[0]["lostTripleThreat"]).unwrap();
+    assert_eq!(lost.iter().map(|change| change.player_id).collect::<Vec<_>>(), [PLAYER, OTHER_PLAYER]);
+    assert_eq!(inning_end[1]["lostTripleThreat"], "[]");
+}
+
+fn sqlite_columns(conn: &Connection, table: &str) -> Vec<String> {
+    conn.prepare(&format!("SELECT name FROM pragma_table_info('{table}')")).unwrap()
+        .query_map([], |row| row.get(0)).unwrap()
+        .collect::<Result<_, _>>().unwrap()
+}
+
+#[test]
+fn sqlite_exporter_round_trips() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("events.sqlite");
+    let mut exporter = SqliteExporter::new(&path, Tables::from_flattened_structs()).unwrap();
+    for event in events() {
+        exporter.push(event).unwrap();
+    }
+    exporter.finish().unwrap();
+
+    let conn = Connection::open(&path).unwrap();
+    assert_eq!(sqlite_columns(&conn, "events"), COMMON_COLUMNS);
+
+    // Variant tables only repeat the id from the common columns, and expand nested structs
+    let party_columns = sqlite_columns(&conn, "Party");
+    assert_eq!(party_columns.iter().filter(|&c| COMMON_COLUMNS.contains(&c.as_str())).collect::<Vec<_>>(), ["id"]);
+    for column in ["subEvent_id", "unscatter_subEvent_id", "unscatter_playerName", "attractorSecretBase_playerId"] {
+        assert!(party_columns.iter().any(|c| c == column), "Party has no {column} column");
+    }
+    let (unscatter_id, attractor_id): (String, Option<String>) = conn
+        .query_row("SELECT unscatter_subEvent_id, attractorSecretBase_playerId FROM Party", [], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+    assert_eq!(unscatter_id, Uuid::from_u128(200).to_string());
+    assert_eq!(attractor_id, None);
+
+    let (team_id, unscatter_id, sub_event_id): (Option<String>, Option<String>, String) = conn
+        .query_row("SELECT teamId, unscatter_subEvent_id, subEvent_id FROM GainFreeRefill", [], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .unwrap();
+    assert_eq!(team_id, None);
+    assert_eq!(unscatter_id, None);
+    assert_eq!(sub_event_id, Uuid::from_u128(202).to_string());
+
+    let lost: Vec<String> = conn.prepare("SELECT lostTripleThreat FROM InningEnd ORDER BY inningNum").unwrap()
+        .query_map([], |row| row.get(0)).unwrap()
+        .collect::<Result<_, _>>().unwrap();
+    let lost: Vec<Vec<ModChangeSubEventWithNamedPlayer>> = lost.iter().map(|json| serde_json::from_str(json).unwrap()).collect();
+    assert_eq!(lost[0].iter().map(|change| change.player_id).collect::<Vec<_>>(), [PLAYER, OTHER_PLAYER]);
+    assert!(lost[1].is_empty());
+
+    let types: Vec<String> = conn.prepare("SELECT type FROM events ORDER BY id").unwrap()
+        .query_map([], |row| row.get(0)).unwrap()
+        .collect::<Result<_, _>>().unwrap();
+    assert_eq!(types, ["Party", "GainFreeRefill", "InningEnd", "InningEnd"]);
+
+    let party_players: Vec<String> = conn.prepare("SELECT player_id FROM event_players WHERE event_id = ? ORDER BY player_id").unwrap()
+        .query_map([PARTY_ID.to_string()], |row| row.get(0)).unwrap()
+        .collect::<Result<_, _>>().unwrap();
+    // The unscattered player is named in the event too
+    assert_eq!(party_players, [PLAYER.to_string(), OTHER_PLAYER.to_string()]);
+    let num_game_links: i64 = conn.query_row("SELECT COUNT(*) FROM event_games WHERE game_id = ?", [GAME_ID.to_string()], |row| row.get(0)).unwrap();
+    assert_eq!(num_game_links, 4);
+}
+
+fn count(conn: &Connection, sql: &str) -> i64 {
+    conn.query_row(sql, [], |row| row.get(0)).unwrap()
+}
+
+#[test]
+fn sqlite_exporter_replaces_duplicate_events() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("events.sqlite");
+    let mut events = events();
+    // The same party without the unscatter, so it no longer names the unscattered player
+    let mut replacement = events[0].clone();
+    if let FedEventData::Party { game, .. } = &mut replacement.data {
+        game.unscatter = None;
+    }
+    events.push(replacement);
+    // A different event type with the same id as the free refill
+    events.push(event(REFILL_ID, FedEventData::PitcherChange {
+        game: game(None),
+        team_nickname: "Garages".to_string(),
+        pitcher_id: OTHER_PLAYER,
+        pitcher_name: "Jessica Telephone".to_string(),
+    }));
+
+    let mut exporter = SqliteExporter::new(&path, Tables::from_flattened_structs()).unwrap();
+    for event in events {
+        exporter.push(event).unwrap();
+    }
+    exporter.finish().unwrap();
+
+    let conn = Connection::open(&path).unwrap();
+    assert_eq!(count(&conn, "SELECT COUNT(*) FROM events"), 4);
+    assert_eq!(count(&conn, "SELECT COUNT(*) FROM Party WHERE unscatter_subEvent_id IS NULL"), 1);
+    assert_eq!(count(&conn, "SELECT COUNT(*) FROM Party"), 1);
+    assert_eq!(count(&conn, "SELECT COUNT(*) FROM GainFreeRefill"), 0);
+    assert_eq!(count(&conn, "SELECT COUNT(*) FROM PitcherChange"), 1);
+
+    let party_players: Vec<String> = conn.prepare("SELECT player_id FROM event_players WHERE event_id = ?").unwrap()
+        .query_map([PARTY_ID.to_string()], |row| row.get(0)).unwrap()
+        .collect::<Result<_, _>>().unwrap();
+    assert_eq!(party_players, [PLAYER.to_string()]);
+    let refill_players: Vec<String> = conn.prepare("SELECT player_id FROM event_players WHERE event_id = ?").unwrap()
+        .query_map([REFILL_ID.to_string()], |row| row.get(0)).unwrap()
+        .collect::<Result<_, _>>().unwrap();
+    assert_eq!(refill_players, [OTHER_PLAYER.to_string()]);
+    assert_eq!(count(&conn, "SELECT COUNT(*) FROM event_games"), 4);
+}
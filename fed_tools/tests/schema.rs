@@ -0,0 +1,129 @@
+//! Tests for laying out export tables from a JSON schema, using small hand-written types shaped
+//! like `FedEventFlat`
+
+use chrono::{DateTime, Utc};
+use schemars::{schema_for, JsonSchema};
+use uuid::Uuid;
+
+use fed_tools::schema::{Column, ColumnType, Tables, COMMON_TABLE_NAME, TYPE_COLUMN_NAME};
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct Inner {
+    id: Uuid,
+    created: DateTime<Utc>,
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct First {
+    id: Uuid,
+    season: i32,
+    is_special: bool,
+    rating: f64,
+    name: String,
+    nickname: Option<String>,
+    inner: Inner,
+    maybe_inner: Option<Inner>,
+    inners: Vec<Inner>,
+    ids: Vec<Uuid>,
+    pair: (i32, String),
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct Second {
+    id: Uuid,
+    season: i32,
+    name: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+enum Flat {
+    First(First),
+    Second(Second),
+}
+
+fn column(name: &str, ty: ColumnType, nullable: bool) -> Column {
+    Column { name: name.to_string(), ty, nullable }
+}
+
+fn inner_type() -> ColumnType {
+    ColumnType::Struct(vec![
+        column("id", ColumnType::Uuid, false),
+        column("created", ColumnType::DateTime, false),
+    ])
+}
+
+fn tables() -> Tables {
+    Tables::from_schema(&schema_for!(Flat))
+}
+
+#[test]
+fn variant_columns() {
+    let tables = tables();
+    assert_eq!(tables.variants.iter().map(|table| table.name.as_str()).collect::<Vec<_>>(), ["First", "Second"]);
+
+    assert_eq!(tables.variant("First").unwrap().columns, [
+        column("id", ColumnType::Uuid, false),
+        column("season", ColumnType::Integer, false),
+        column("isSpecial", ColumnType::Boolean, false),
+        column("rating", ColumnType::Float, false),
+        column("name", ColumnType::String, false),
+        column("nickname", ColumnType::String, true),
+        column("inner", inner_type(), false),
+        column("maybeInner", inner_type(), true),
+        column("inners", ColumnType::List(Box::new(inner_type())), false),
+        column("ids", ColumnType::List(Box::new(ColumnType::Uuid)), false),
+        // Tuples can't be a list, so they're stored as JSON
+        column("pair", ColumnType::Json, false),
+    ]);
+    assert!(tables.variant("Third").is_none());
+}
+
+#[test]
+fn common_columns_are_shared_by_every_variant() {
+    let tables = tables();
+
+    assert_eq!(tables.common.name, COMMON_TABLE_NAME);
+    // `name` is in both variants but only nullable in one, so it isn't common
+    assert_eq!(tables.common.columns, [
+        column("id", ColumnType::Uuid, false),
+        column("season", ColumnType::Integer, false),
+        column(TYPE_COLUMN_NAME, ColumnType::String, false),
+    ]);
+}
+
+#[test]
+fn leaf_columns_expand_structs() {
+    let tables = tables();
+    let leaves = tables.variant("First").unwrap().leaf_columns(".");
+    let leaf = |name: &str| leaves.iter().find(|leaf| leaf.name == name)
+        .unwrap_or_else(|| panic!("No leaf column {name}"));
+
+    assert!(!leaves.iter().any(|leaf| leaf.name == "inner" || leaf.name == "maybeInner"));
+    assert_eq!(leaf("inner.id").path, ["inner", "id"]);
+    assert!(!leaf("inner.id").nullable);
+    // Fields of a nullable struct are nullable even if they aren't nullable in the struct
+    assert!(leaf("maybeInner.created").nullable);
+    assert_eq!(leaf("maybeInner.created").ty, ColumnType::DateTime);
+    // Lists of structs stay one column
+    assert_eq!(leaf("inners").ty, ColumnType::List(Box::new(inner_type())));
+}
+
+#[test]
+fn flattened_fed_events_have_a_table_per_variant() {
+    let tables = Tables::from_flattened_structs();
+    let schema = fed_tools::schema::fed_event_schema();
+    let num_variants = schema.schema.subschemas.as_ref()
+        .and_then(|subschemas| subschemas.one_of.as_ref())
+        .map_or(0, |one_of| one_of.len());
+
+    assert_eq!(tables.variants.len(), num_variants);
+    assert_eq!(tables.common.columns.iter().map(|column| column.name.as_str()).collect::<Vec<_>>(),
+               ["id", "created", "sim", "tournament", "season", "day", "phase", "nuts", TYPE_COLUMN_NAME]);
+}